@@ -4,12 +4,33 @@ pub const GLOSSBOX: Color32 = Color32::from_rgb(0x44, 0xEA, 0xFC);
 
 pub const GLOSSBOX_BYFORMATION: Color32 = Color32::from_rgb(0x6A, 0xD8, 0x3F);
 
+pub const GLOSSBOX_MISSING: Color32 = Color32::from_rgb(0xFC, 0x44, 0x44);
+
+pub const GLOSSBOX_NO_GLOSS: Color32 = Color32::from_rgb(0x9A, 0x9A, 0x9A);
+
+pub const PHRASEBRACKET: Color32 = Color32::from_rgb(0xFC, 0x8C, 0x44);
+
+pub const TAG_CHIP: Color32 = Color32::from_rgb(0x8C, 0x6A, 0xFC);
+
+pub const CATEGORY_PALETTE: [[u8; 3]; 8] = [
+    [0xE6, 0x4B, 0x3C],
+    [0x3C, 0x8D, 0xE6],
+    [0x2E, 0xB8, 0x5C],
+    [0xE6, 0x9F, 0x3C],
+    [0x9B, 0x59, 0xB6],
+    [0x1A, 0xBC, 0x9C],
+    [0xD8, 0x4C, 0x9A],
+    [0x8C, 0x8C, 0x3C],
+];
+
 pub const SENTENCEBOX: Color32 = Color32::from_rgb(0xFC, 0x44, 0xF9);
 
 pub const FONT_LIGHT: Color32 = Color32::from_rgb(0x40, 0x40, 0x40);
 
 pub const FONT_DARK: Color32 = Color32::from_rgb(0xB0, 0xB0, 0xB0);
 
+pub const CURRENT_MATCH: Color32 = Color32::from_rgb(0xFC, 0x8C, 0x44);
+
 pub const HIGHLIGHT_BG: Color32 = Color32::from_rgb(0xFC, 0xC8, 0x44);
 
 pub const HIGHLIGHT_FG: Color32 = Color32::from_rgb(0, 0, 0);
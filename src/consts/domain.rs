@@ -2,10 +2,30 @@ pub const PROJECT_VERSION: u64 = 2;
 
 pub const DEFAULT_SIMILARITY_RESULTS: usize = 5;
 
+/// Neighbors each segment links to in the exported similarity graph
+pub const SIMILARITY_GRAPH_NEIGHBORS: usize = 3;
+
+/// Minimum cosine similarity for an edge in the exported similarity graph
+pub const SIMILARITY_GRAPH_THRESHOLD: f64 = 0.2;
+
+/// Similar segments examined when collecting translation suggestions for an untranslated one
+pub const TRANSLATION_SUGGESTION_CANDIDATES: usize = 20;
+
+/// Translations offered under an untranslated segment
+pub const TRANSLATION_SUGGESTION_RESULTS: usize = 3;
+
 pub const DEFAULT_RELATED_WORDS_COUNT: usize = 5;
 
+/// Number of recent filter queries kept in the filter history
+pub const FILTER_HISTORY_CAPACITY: usize = 20;
+
+/// Largest edit distance at which a word counts as related when nothing contains the query
+pub const RELATED_WORDS_MAX_DISTANCE: usize = 2;
+
 pub const MAX_SIMILAR_TOKENS_RESULTS: usize = 20;
 
+pub const DEFAULT_CONCORDANCE_CONTEXT: usize = 4;
+
 pub const MAX_SCRIPT_DEPTH: usize = 500000;
 
 pub const MAX_SCRIPT_OPERATIONS: u64 = 10000000;
@@ -4,6 +4,38 @@ pub const WINDOW_WIDTH: f32 = 1024.0;
 #[cfg(not(target_arch = "wasm32"))]
 pub const WINDOW_HEIGHT: f32 = 768.0;
 
+/// Smallest inner width a restored window is given
+#[cfg(not(target_arch = "wasm32"))]
+pub const MIN_WINDOW_WIDTH: f32 = 480.0;
+
+/// Smallest inner height a restored window is given
+#[cfg(not(target_arch = "wasm32"))]
+pub const MIN_WINDOW_HEIGHT: f32 = 360.0;
+
+/// Restored window positions beyond this distance from the origin are discarded
+#[cfg(not(target_arch = "wasm32"))]
+pub const MAX_WINDOW_COORD: f32 = 32_768.0;
+
+/// Native application name, which also names the `eframe` storage directory
+#[cfg(not(target_arch = "wasm32"))]
+pub const APP_NAME: &str = "Text Decryption Helper";
+
+/// File in the `eframe` storage directory holding the last window geometry
+#[cfg(not(target_arch = "wasm32"))]
+pub const WINDOW_GEOMETRY_FILE: &str = "window.json";
+
+/// `eframe` storage key for the selected theme
+pub const THEME_STORAGE_KEY: &str = "theme";
+
+/// `eframe` storage key for the selected page size preset
+pub const PAGE_SIZE_STORAGE_KEY: &str = "page_size";
+
+/// `eframe` storage key for the popups pinned when the app was last closed
+pub const PINNED_POPUPS_STORAGE_KEY: &str = "pinned_popups";
+
+/// `eframe` storage key for recently used filter queries
+pub const FILTER_HISTORY_STORAGE_KEY: &str = "filter_history";
+
 pub const POPUP_WIDTH: f32 = 400.0;
 
 pub const POPUP_DEFINITION_HEIGHT: f32 = 120.0;
@@ -12,6 +44,10 @@ pub const POPUP_REFERENCE_HEIGHT: f32 = 500.0;
 
 pub const POPUP_SIMILAR_HEIGHT: f32 = 480.0;
 
+pub const POPUP_CONCORDANCE_WIDTH: f32 = 640.0;
+
+pub const POPUP_SEGMENT_PAGE_SIZE: usize = 25;
+
 pub const TOKEN_FONT_SIZE: f32 = 20.0;
 
 pub const GLOSS_FONT_SIZE: f32 = 12.0;
@@ -28,8 +64,24 @@ pub const GLOSS_BOX_EXTRA_WIDTH: f32 = 10.0;
 
 pub const GLOSS_BOX_MIN_WIDTH: f32 = 40.0;
 
+/// Widest a token column may grow; longer tokens are truncated with an ellipsis
+pub const GLOSS_BOX_MAX_WIDTH: f32 = 320.0;
+
 pub const GLOSS_BOX_LAYOUT_EXTRA: f32 = 8.0;
 
+pub const PHRASE_BRACKET_HEIGHT: f32 = 6.0;
+
+pub const RULE_BADGE_FONT_SIZE: f32 = 9.0;
+
+/// Distance of a derived token's rule-type badge from the gloss box's right edge
+pub const RULE_BADGE_INSET: f32 = 6.0;
+
+pub const TAG_FONT_SIZE: f32 = 11.0;
+
+pub const TAG_CHIP_INNER_MARGIN: f32 = 2.0;
+
+pub const TAG_CHIP_ROUNDING: f32 = 6.0;
+
 pub const BOX_STROKE_WIDTH: f32 = 1.5;
 
 pub const GLOSS_BOX_INNER_MARGIN: f32 = 2.0;
@@ -48,7 +100,7 @@ pub const WORD_FORMATION_SCRIPT_ROWS: usize = 10;
 
 pub const PAGINATION_SIZE_SMALL: usize = 10;
 
-pub const PAGINATION_SIZE_MEDIUM: usize = 20;
+pub const PAGINATION_SIZE_MEDIUM: usize = 25;
 
 pub const PAGINATION_SIZE_LARGE: usize = 50;
 
@@ -56,6 +108,9 @@ pub const PAGINATION_SIZE_EXTRA_LARGE: usize = 100;
 
 pub const PAGINATION_DEFAULT_PAGE_SIZE: usize = PAGINATION_SIZE_SMALL;
 
+/// Segment count above which showing every segment on one page is flagged as slow
+pub const PAGINATION_SHOW_ALL_WARNING_THRESHOLD: usize = 500;
+
 pub const PAGINATION_NAV_WIDTH_DEDUCTION: f32 = 200.0;
 
 pub const PAGINATION_ITEM_WIDTH: f32 = 40.0;
@@ -66,4 +121,25 @@ pub const PAGINATION_BUTTON_SIDE_COUNT: isize = 2;
 
 pub const PAGINATION_DRAG_SPEED: f64 = 0.1;
 
+/// Seconds without edits before translation suggestions are recomputed
+pub const TRANSLATION_SUGGESTION_DEBOUNCE_SECS: f64 = 0.5;
+
 pub const PANEL_SPACING: f32 = 10.0;
+
+/// Number of segments shown in the import dialog's segmentation preview
+pub const IMPORT_PREVIEW_SEGMENTS: usize = 5;
+
+/// Initial guess for a segment's height in the central list, refined from measured rows
+pub const SEGMENT_ROW_HEIGHT_ESTIMATE: f32 = 120.0;
+
+/// Layout width, in points, of an exported segment image
+pub const SEGMENT_IMAGE_WIDTH: f32 = 720.0;
+
+/// Tallest layout, in points, an exported segment image may grow to
+pub const SEGMENT_IMAGE_MAX_HEIGHT: f32 = 4096.0;
+
+/// Pixels per point of an exported segment image
+pub const SEGMENT_IMAGE_SCALE: f32 = 2.0;
+
+/// Margin, in points, around the content of an exported segment image
+pub const SEGMENT_IMAGE_PADDING: f32 = 16.0;
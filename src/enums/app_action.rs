@@ -1,15 +1,45 @@
+use super::ExportFormat;
+
 /// High-level application actions triggered by menu commands or keyboard shortcuts
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AppAction {
+    /// Discard the current project and start an empty one
+    New,
+
     /// Trigger text import dialog to add new content to the project
     Import,
 
     /// Trigger project open dialog to load a saved project from disk
     Open,
 
-    /// Save the current project to disk
-    Export,
+    /// Export the current project in the given format
+    Export(ExportFormat),
+
+    /// Remove the segment at the given index from the project
+    DeleteSegment(usize),
 
     /// Close the application
     Quit,
 }
+
+impl AppAction {
+    /// Question asked before running this action while the project has unsaved changes
+    #[must_use]
+    pub fn unsaved_changes_prompt(self) -> &'static str {
+        match self {
+            Self::Quit => "You have unsaved changes. Are you sure you want to quit?",
+            Self::New => {
+                "You have unsaved changes. Starting a new project will discard them. Continue?"
+            }
+            Self::Import => {
+                "You have unsaved changes. Importing will replace the current project. Continue?"
+            }
+            Self::Open => {
+                "You have unsaved changes. Opening a project will replace the current one. Continue?"
+            }
+            Self::Export(_) | Self::DeleteSegment(_) => {
+                "You have unsaved changes. Continue anyway?"
+            }
+        }
+    }
+}
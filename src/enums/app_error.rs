@@ -12,6 +12,9 @@ pub enum AppError {
     /// Rhai script compilation or execution failed
     ScriptExecutionError(String),
 
+    /// Rhai script hit one of the engine's safety limits (operations or nesting depth)
+    ScriptLimitExceeded(String),
+
     /// User cancelled an operation (e.g., file dialog)
     OperationCancelled,
 }
@@ -24,6 +27,7 @@ impl fmt::Display for AppError {
                 write!(f, "Invalid project format: {msg}")
             }
             Self::ScriptExecutionError(msg) => write!(f, "Script error: {msg}"),
+            Self::ScriptLimitExceeded(msg) => write!(f, "Script limit exceeded: {msg}"),
             Self::OperationCancelled => write!(f, "Operation cancelled by user"),
         }
     }
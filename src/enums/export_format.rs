@@ -0,0 +1,57 @@
+/// Output formats available from the export menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Interlinear Typst document
+    Typst,
+
+    /// Typst table of source lines beside their translations
+    BilingualTypst,
+
+    /// LaTeX document of gb4e interlinear examples
+    Latex,
+
+    /// Markdown document with token/gloss tables
+    Markdown,
+
+    /// Self-contained HTML page showing glosses on hover
+    Html,
+
+    /// One CSV row per segment
+    Csv,
+
+    /// One CSV row per vocabulary word with its count and gloss status
+    FrequencyCsv,
+
+    /// Newline-separated list of words that still need glosses
+    UntranslatedWordlist,
+
+    /// Standalone vocabulary glossary as JSON
+    Glossary,
+
+    /// Formation rule library as JSON
+    FormationRules,
+
+    /// Vocabulary, formatted-word, and segment comments as a JSON sidecar
+    Comments,
+
+    /// Segment similarity graph as Graphviz DOT
+    SimilarityGraph,
+}
+
+impl ExportFormat {
+    /// Parse the format name accepted by the `--export` command-line mode
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn from_cli_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "typst" | "typ" => Some(Self::Typst),
+            "bilingual" => Some(Self::BilingualTypst),
+            "latex" | "tex" => Some(Self::Latex),
+            "markdown" | "md" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            "csv" => Some(Self::Csv),
+            "frequencies" | "freq" => Some(Self::FrequencyCsv),
+            "dot" | "graphviz" => Some(Self::SimilarityGraph),
+            _ => None,
+        }
+    }
+}
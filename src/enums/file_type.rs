@@ -4,6 +4,9 @@ pub enum FileType {
     /// Plain text files containing segments to be translated
     Text,
 
+    /// Source files accepted by Import Text: plain text or pre-tokenized JSON Lines
+    ImportSource,
+
     /// JSON project files (see `SavedProjectV2` format)
     Json,
 
@@ -12,6 +15,24 @@ pub enum FileType {
 
     /// Typst markup files for academic publishing and typesetting
     Typst,
+
+    /// LaTeX sources for linguistics papers using gb4e
+    Latex,
+
+    /// Markdown documents for sharing interlinear glosses as plain text
+    Markdown,
+
+    /// Self-contained HTML pages for sharing on the web
+    Html,
+
+    /// Comma-separated values for spreadsheets
+    Csv,
+
+    /// Graphviz graph descriptions
+    Dot,
+
+    /// PNG images of rendered segments
+    Png,
 }
 
 impl FileType {
@@ -19,9 +40,16 @@ impl FileType {
     pub fn filter_name(&self) -> &'static str {
         match self {
             FileType::Text => "Text",
+            FileType::ImportSource => "Text or JSON Lines",
             FileType::Json => "JSON",
             FileType::Font => "Font",
             FileType::Typst => "Typst",
+            FileType::Latex => "LaTeX",
+            FileType::Markdown => "Markdown",
+            FileType::Html => "HTML",
+            FileType::Csv => "CSV",
+            FileType::Dot => "Graphviz",
+            FileType::Png => "PNG",
         }
     }
 
@@ -29,9 +57,16 @@ impl FileType {
     pub fn extensions(&self) -> &'static [&'static str] {
         match self {
             FileType::Text => &["txt"],
+            FileType::ImportSource => &["txt", "jsonl"],
             FileType::Json => &["json"],
             FileType::Font => &["ttf", "otf", "ttc"],
             FileType::Typst => &["typ"],
+            FileType::Latex => &["tex"],
+            FileType::Markdown => &["md"],
+            FileType::Html => &["html", "htm"],
+            FileType::Csv => &["csv"],
+            FileType::Dot => &["dot", "gv"],
+            FileType::Png => &["png"],
         }
     }
 }
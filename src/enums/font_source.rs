@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+/// Where a project font's bytes come from when the project is opened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FontSource {
+    /// Bytes were embedded in the project file
+    Embedded,
+
+    /// Bytes must be read from this readable path
+    Path(PathBuf),
+
+    /// Neither embedded bytes nor a readable path are available
+    Missing,
+}
@@ -15,3 +15,15 @@ pub enum FormationType {
     #[serde(rename = "nonmorphological")]
     Nonmorphological,
 }
+
+impl FormationType {
+    /// Short tag shown before rule names and on derived tokens
+    #[must_use]
+    pub fn badge(self) -> &'static str {
+        match self {
+            Self::Derivation => "[D]",
+            Self::Inflection => "[I]",
+            Self::Nonmorphological => "[N]",
+        }
+    }
+}
@@ -3,27 +3,59 @@
 /// Includes:
 /// - `AppAction`: High-level menu actions
 /// - `AppError`: Error types
+/// - `ExportFormat`: Export menu output formats
 /// - `FileType`: Supported file types for I/O
+/// - `FontSource`: Origin of a project font when opening a project
 /// - `FormationType`: Word formation rule categories
+/// - `PageSizePreset`: Persisted pagination page size
 /// - `PopupRequest`: Popup window requests
+/// - `PunctuationMode`: Punctuation handling for text import
+/// - `ScoreDisplay`: Similarity score formatting
+/// - `SegmentationMode`: Built-in tokenization for text import
+/// - `SimilarityMetric`: Segment similarity scoring in the Similar popup
 /// - `SortMode`: Segment sorting options
+/// - `TextDirection`: Source script reading direction
+/// - `ThemeChoice`: Persisted color theme
+/// - `ToolAction`: Tools menu actions
 /// - `UiAction`: UI element actions
 /// - `CommentTarget`: Comment attachment targets
 pub mod app_action;
 pub mod app_error;
+pub mod export_format;
 pub mod file_type;
+pub mod font_source;
 pub mod formation_type;
+pub mod page_size;
 pub mod popups;
+pub mod punctuation_mode;
+pub mod score_display;
+pub mod segmentation_mode;
+pub mod similarity_metric;
 pub mod sort_mode;
+pub mod text_direction;
+pub mod theme;
+pub mod tool_action;
 pub mod ui_action;
 pub mod word_ref;
 
 pub use app_action::AppAction;
 pub use app_error::AppError;
+pub use export_format::ExportFormat;
 pub use file_type::FileType;
+pub use font_source::FontSource;
 pub use formation_type::FormationType;
-pub use popups::{DictionaryPopupType, PinnedPopup, PopupRequest};
+pub use page_size::PageSizePreset;
+pub use popups::{
+    DefinitionScope, DictionaryPopupType, PinnedPopup, PinnedPopupDescriptor, PopupRequest,
+};
+pub use punctuation_mode::PunctuationMode;
+pub use score_display::ScoreDisplay;
+pub use segmentation_mode::SegmentationMode;
+pub use similarity_metric::SimilarityMetric;
 pub use sort_mode::{SortDirection, SortField, SortMode};
+pub use text_direction::TextDirection;
+pub use theme::ThemeChoice;
+pub use tool_action::ToolAction;
 pub use ui_action::UiAction;
 
 pub use word_ref::CommentTarget;
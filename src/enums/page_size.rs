@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+use crate::consts::ui::{
+    PAGINATION_SIZE_EXTRA_LARGE, PAGINATION_SIZE_LARGE, PAGINATION_SIZE_MEDIUM,
+    PAGINATION_SIZE_SMALL,
+};
+
+/// Page size choices offered by the pagination bar, persisted between sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PageSizePreset {
+    /// `PAGINATION_SIZE_SMALL` segments per page
+    #[default]
+    Small,
+    /// `PAGINATION_SIZE_MEDIUM` segments per page
+    Medium,
+    /// `PAGINATION_SIZE_LARGE` segments per page
+    Large,
+    /// `PAGINATION_SIZE_EXTRA_LARGE` segments per page
+    ExtraLarge,
+    /// Every filtered segment on a single page
+    All,
+}
+
+impl PageSizePreset {
+    /// All presets in menu order
+    pub const PRESETS: [Self; 5] = [
+        Self::Small,
+        Self::Medium,
+        Self::Large,
+        Self::ExtraLarge,
+        Self::All,
+    ];
+
+    /// Number of segments per page when `filtered_count` segments pass the filter.
+    /// `All` fits them on one page; the result is never zero.
+    #[must_use]
+    pub const fn page_size(self, filtered_count: usize) -> usize {
+        match self {
+            Self::Small => PAGINATION_SIZE_SMALL,
+            Self::Medium => PAGINATION_SIZE_MEDIUM,
+            Self::Large => PAGINATION_SIZE_LARGE,
+            Self::ExtraLarge => PAGINATION_SIZE_EXTRA_LARGE,
+            Self::All => {
+                if filtered_count == 0 {
+                    1
+                } else {
+                    filtered_count
+                }
+            }
+        }
+    }
+
+    /// Label for the page size selector
+    #[must_use]
+    pub fn display_text(self) -> String {
+        match self {
+            Self::All => "Show all".to_string(),
+            preset => format!("Show {} per page", preset.page_size(0)),
+        }
+    }
+}
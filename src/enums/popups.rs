@@ -1,11 +1,17 @@
+use serde::{Deserialize, Serialize};
+
 /// Requests to open different types of popup windows
 pub enum PopupRequest {
     /// Show definition or reference popup for a word
     Dictionary(String, DictionaryPopupType),
     /// Show segments similar to a given segment (by index)
     Similar(usize),
+    /// Compare a segment with one of its similar segments: (target, neighbor)
+    Compare(usize, usize),
     /// Show tokens similar to a given word (morphologically related)
     SimilarTokens(String),
+    /// Show a keyword-in-context concordance for a word
+    Concordance(String),
     /// Show context menu for a specific word in a segment
     WordMenu(String, usize, usize, egui::Pos2),
     /// Show context menu for a segment
@@ -14,10 +20,12 @@ pub enum PopupRequest {
     FormattingChain(usize, usize),
     /// Apply a filter query to the segment list
     Filter(String),
+    /// Move the segment list to the page showing a segment
+    JumpToSegment(usize),
 }
 
 /// Type of dictionary popup to display
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DictionaryPopupType {
     /// Show the definition field
     Definition,
@@ -25,6 +33,55 @@ pub enum DictionaryPopupType {
     Reference,
 }
 
+/// Which segments the Definition popup lists for a word
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DefinitionScope {
+    /// Segments whose first token is the word
+    #[default]
+    Headword,
+    /// Every segment containing the word
+    AnyOccurrence,
+}
+
+impl DefinitionScope {
+    /// Both scopes in the order the Definition popup lists them
+    pub const SCOPES: [Self; 2] = [Self::Headword, Self::AnyOccurrence];
+
+    /// Pick the headword or usage lookup according to the scope
+    #[must_use]
+    pub const fn select<'a, T>(self, headword: &'a T, usage: &'a T) -> &'a T {
+        match self {
+            Self::Headword => headword,
+            Self::AnyOccurrence => usage,
+        }
+    }
+
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::Headword => "Headword",
+            Self::AnyOccurrence => "All occurrences",
+        }
+    }
+
+    #[must_use]
+    pub const fn hover_text(self) -> &'static str {
+        match self {
+            Self::Headword => "Segments starting with this word",
+            Self::AnyOccurrence => "Every segment containing this word",
+        }
+    }
+
+    /// Message shown when no segment matches
+    #[must_use]
+    pub const fn empty_text(self) -> &'static str {
+        match self {
+            Self::Headword => "No segments start with this word.",
+            Self::AnyOccurrence => "No usages found.",
+        }
+    }
+}
+
 /// A popup that persists across updates (pinned to the screen)
 #[derive(Debug, Clone)]
 pub enum PinnedPopup {
@@ -33,3 +90,42 @@ pub enum PinnedPopup {
     /// Pinned dictionary popup: (word, `popup_type`, `popup_id`, `display_text`)
     Dictionary(String, DictionaryPopupType, u64, String),
 }
+
+impl PinnedPopup {
+    /// What the popup shows, without its computed contents, for saving between sessions
+    #[must_use]
+    pub fn descriptor(&self) -> PinnedPopupDescriptor {
+        match self {
+            Self::Similar(segment_idx, _, _, title) => PinnedPopupDescriptor::Similar {
+                segment_idx: *segment_idx,
+                title: title.clone(),
+            },
+            Self::Dictionary(word, mode, _, title) => PinnedPopupDescriptor::Dictionary {
+                word: word.clone(),
+                mode: *mode,
+                title: title.clone(),
+            },
+        }
+    }
+}
+
+/// Persisted form of a pinned popup; contents are recomputed when it is restored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PinnedPopupDescriptor {
+    /// Similarity results for a segment
+    Similar {
+        /// Index of the segment the results were computed for
+        segment_idx: usize,
+        /// Window title
+        title: String,
+    },
+    /// Definition or references of a word
+    Dictionary {
+        /// The word looked up
+        word: String,
+        /// Which dictionary view was pinned
+        mode: DictionaryPopupType,
+        /// Window title
+        title: String,
+    },
+}
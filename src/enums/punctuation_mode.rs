@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// How punctuation produced by the built-in tokenizers is handled on import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PunctuationMode {
+    /// Keep punctuation attached to the neighboring token, so it never stands alone
+    #[default]
+    Keep,
+    /// Drop punctuation entirely
+    Strip,
+    /// Split every punctuation mark into a token of its own
+    Separate,
+}
+
+impl PunctuationMode {
+    /// All modes in the order the import dialog lists them
+    pub const MODES: [Self; 3] = [Self::Keep, Self::Strip, Self::Separate];
+
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::Keep => "Keep punctuation",
+            Self::Strip => "Strip punctuation",
+            Self::Separate => "Punctuation as separate tokens",
+        }
+    }
+
+    #[must_use]
+    pub const fn hover_text(self) -> &'static str {
+        match self {
+            Self::Keep => "Punctuation stays attached to the preceding token",
+            Self::Strip => "Punctuation is removed from every token",
+            Self::Separate => "Each punctuation mark becomes its own token",
+        }
+    }
+}
@@ -0,0 +1,41 @@
+/// How similarity scores are shown in the Similar popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoreDisplay {
+    /// Raw similarity score (0.0 to 1.0)
+    #[default]
+    Raw,
+    /// Percentage of the top result's score (0 to 100)
+    Relative,
+}
+
+impl ScoreDisplay {
+    /// Label for the display toggle
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::Raw => "Raw",
+            Self::Relative => "Relative (0-100)",
+        }
+    }
+
+    /// Map a raw score for display given the best score in the list.
+    /// Relative mode yields 0 when the top score is not positive.
+    #[must_use]
+    pub fn display_value(self, score: f64, top_score: f64) -> f64 {
+        match self {
+            Self::Raw => score,
+            Self::Relative if top_score > 0.0 => score / top_score * 100.0,
+            Self::Relative => 0.0,
+        }
+    }
+
+    /// Format a raw score for display given the best score in the list
+    #[must_use]
+    pub fn format(self, score: f64, top_score: f64) -> String {
+        let value = self.display_value(score, top_score);
+        match self {
+            Self::Raw => format!("{value:.2}"),
+            Self::Relative => format!("{value:.0}"),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::libs::eval::TokenizationRule;
+use crate::libs::tokenizer::{CharTokenizer, Tokenizer, WhitespaceTokenizer};
+
+/// Built-in tokenization strategies offered when importing a text file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SegmentationMode {
+    /// Split each line on whitespace
+    #[default]
+    Whitespace,
+    /// Treat every character as its own token
+    Character,
+}
+
+impl SegmentationMode {
+    /// All modes in the order the import dialog lists them
+    pub const MODES: [Self; 2] = [Self::Whitespace, Self::Character];
+
+    /// Tokenizer implementing this mode
+    #[must_use]
+    pub fn tokenizer(self) -> Box<dyn Tokenizer> {
+        match self {
+            Self::Whitespace => Box::new(WhitespaceTokenizer),
+            Self::Character => Box::new(CharTokenizer),
+        }
+    }
+
+    /// Rhai script equivalent to this mode, offered as a starting point for custom scripts
+    #[must_use]
+    pub fn tokenization_rule(self) -> TokenizationRule {
+        match self {
+            Self::Whitespace => TokenizationRule::default_whitespace(),
+            Self::Character => TokenizationRule::default_character(),
+        }
+    }
+
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::Whitespace => "Word-based (Spaces)",
+            Self::Character => "Character-based",
+        }
+    }
+
+    #[must_use]
+    pub const fn hover_text(self) -> &'static str {
+        match self {
+            Self::Whitespace => "Split text by whitespace (e.g. English)",
+            Self::Character => "Treat each character as a token (e.g. Chinese, Japanese)",
+        }
+    }
+}
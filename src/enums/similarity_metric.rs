@@ -0,0 +1,30 @@
+/// How the Similar popup scores a pair of segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SimilarityMetric {
+    /// Cosine similarity of TF-IDF vectors
+    #[default]
+    Cosine,
+    /// Overlap of the two token sets (intersection over union)
+    Jaccard,
+}
+
+impl SimilarityMetric {
+    /// All metrics in the order the Similar popup lists them
+    pub const METRICS: [Self; 2] = [Self::Cosine, Self::Jaccard];
+
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::Cosine => "TF-IDF",
+            Self::Jaccard => "Jaccard",
+        }
+    }
+
+    #[must_use]
+    pub const fn hover_text(self) -> &'static str {
+        match self {
+            Self::Cosine => "Cosine similarity of TF-IDF vectors; rare shared words count more",
+            Self::Jaccard => "Shared words divided by all distinct words of both segments",
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+/// Reading direction of the source script
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextDirection {
+    /// Left-to-right
+    #[default]
+    Ltr,
+    /// Right-to-left
+    Rtl,
+}
+
+impl TextDirection {
+    /// Both directions in the order the import dialog lists them
+    pub const DIRECTIONS: [Self; 2] = [Self::Ltr, Self::Rtl];
+
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::Ltr => "Left to right",
+            Self::Rtl => "Right to left",
+        }
+    }
+
+    #[must_use]
+    pub const fn is_rtl(self) -> bool {
+        matches!(self, Self::Rtl)
+    }
+
+    /// Token indices in the order they are laid out from left to right on screen
+    #[must_use]
+    pub fn display_order(self, token_count: usize) -> Vec<usize> {
+        match self {
+            Self::Ltr => (0..token_count).collect(),
+            Self::Rtl => (0..token_count).rev().collect(),
+        }
+    }
+}
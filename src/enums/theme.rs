@@ -0,0 +1,46 @@
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+/// User-selected color theme, persisted between sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ThemeChoice {
+    /// Follow the operating system theme
+    #[default]
+    System,
+    /// Always use light visuals
+    Light,
+    /// Always use dark visuals
+    Dark,
+}
+
+impl ThemeChoice {
+    /// All choices in menu order
+    pub const ALL: [Self; 3] = [Self::System, Self::Light, Self::Dark];
+
+    /// Label for the View menu
+    #[must_use]
+    pub const fn display_text(self) -> &'static str {
+        match self {
+            Self::System => "System",
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+        }
+    }
+
+    /// Apply the theme to the context.
+    /// Forced themes also switch egui's active theme so `ui.visuals().dark_mode`
+    /// matches the visuals being drawn.
+    pub fn apply(self, ctx: &egui::Context) {
+        match self {
+            Self::System => ctx.set_theme(egui::ThemePreference::System),
+            Self::Light => {
+                ctx.set_theme(egui::ThemePreference::Light);
+                ctx.set_visuals(egui::Visuals::light());
+            }
+            Self::Dark => {
+                ctx.set_theme(egui::ThemePreference::Dark);
+                ctx.set_visuals(egui::Visuals::dark());
+            }
+        }
+    }
+}
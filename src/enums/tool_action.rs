@@ -0,0 +1,30 @@
+/// Analysis tools available from the Tools menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolAction {
+    /// List groups of segments with identical token sequences
+    FindDuplicates,
+
+    /// Rank unique tokens by occurrence count
+    WordFrequencies,
+
+    /// Fold one vocabulary word and its occurrences into another
+    MergeWords,
+
+    /// Fill segment translations from a line-aligned parallel text
+    PasteParallelTranslations,
+
+    /// Edit the project's stopword list
+    EditStopwords,
+
+    /// Edit the word category palette
+    WordCategories,
+
+    /// List, inspect, and delete formation rules
+    FormationRules,
+
+    /// Derive every token matching an affix pattern from a stripped base word
+    ApplyRuleToMatches,
+
+    /// Edit project-wide settings such as the project name
+    ProjectSettings,
+}
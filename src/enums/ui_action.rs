@@ -5,8 +5,10 @@ use std::sync::Arc;
 pub enum UiAction {
     /// No action occurred
     None,
-    /// Some data was modified
-    Changed,
+    /// The gloss of a vocabulary word was modified (vocabulary key)
+    GlossChanged(Arc<str>),
+    /// The segment translation was modified
+    TranslationChanged,
     /// Apply text filter to segment list
     Filter(Arc<str>),
     /// Request to show similar segments (desktop only)
@@ -22,4 +24,12 @@ pub enum UiAction {
     ShowSentenceMenu(usize),
     /// Show context menu for a word (with segment and word indices)
     ShowWordMenu(Arc<str>, usize),
+    /// Pressed Enter in a token's gloss box (token index)
+    GlossSubmitted(usize),
+    /// Shift-clicked a token to mark one end of a phrase (token index)
+    SelectToken(usize),
+    /// Edit the phrase gloss starting at a token index
+    EditPhrase(usize),
+    /// Fold or unfold the segment in the list
+    ToggleCollapsed,
 }
@@ -0,0 +1,103 @@
+use std::path::Path;
+
+use crate::consts::domain::{SIMILARITY_GRAPH_NEIGHBORS, SIMILARITY_GRAPH_THRESHOLD};
+use crate::enums::{AppError, AppResult, ExportFormat};
+use crate::libs::project::importer::migrate_to_latest;
+use crate::libs::project::{
+    LoadIssues, Project, check_saved_project, load_project_from_json, parse_project_json,
+};
+
+use super::{
+    generate_bilingual_typst, generate_csv_content, generate_frequency_csv, generate_html_content,
+    generate_latex_content, generate_markdown_content, generate_typst_content,
+    generate_untranslated_wordlist,
+};
+
+/// Outcome of validating a project file with `--validate`
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    /// Warnings and hard errors found by the importer
+    pub issues: LoadIssues,
+
+    /// Number of segments in the project
+    pub segment_count: usize,
+
+    /// Number of base vocabulary words in the project
+    pub vocabulary_count: usize,
+}
+
+impl ValidationReport {
+    /// Whether the project would fail to open
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        !self.issues.errors.is_empty()
+    }
+}
+
+fn read_project_json(input: &Path) -> AppResult<serde_json::Value> {
+    let json = std::fs::read_to_string(input)
+        .map_err(|e| AppError::IoError(format!("Failed to read '{}': {e}", input.display())))?;
+    parse_project_json(&json).map_err(AppError::InvalidProjectFormat)
+}
+
+/// Run a saved project through the importer without opening it, collecting every
+/// problem found. Unreadable files, malformed JSON, and unsupported versions are
+/// returned as errors; everything else is reported in the `ValidationReport`.
+pub fn validate_project_file(input: &Path) -> AppResult<ValidationReport> {
+    let saved =
+        migrate_to_latest(read_project_json(input)?).map_err(AppError::InvalidProjectFormat)?;
+    let mut report = ValidationReport {
+        issues: check_saved_project(&saved),
+        segment_count: saved.sentences.len(),
+        vocabulary_count: saved.vocabulary.original.len(),
+    };
+    if !report.has_errors() {
+        match Project::from_saved(saved) {
+            Some(project) => {
+                for word in project.cyclic_derived_words() {
+                    report.issues.warnings.push(format!(
+                        "Derived word '{word}' resolves back to itself through its base words"
+                    ));
+                }
+            }
+            None => report
+                .issues
+                .errors
+                .push("Failed to convert project format".to_string()),
+        }
+    }
+    Ok(report)
+}
+
+/// Load a saved project from `input`, render it with the generator for `format`,
+/// and write the result to `output`. Used by the `--export` command-line mode.
+pub fn export_project_file(format: ExportFormat, input: &Path, output: &Path) -> AppResult<()> {
+    let json = std::fs::read_to_string(input)
+        .map_err(|e| AppError::IoError(format!("Failed to read '{}': {e}", input.display())))?;
+    let value = parse_project_json(&json).map_err(AppError::InvalidProjectFormat)?;
+    let project = load_project_from_json(value).map_err(AppError::InvalidProjectFormat)?;
+
+    let content = match format {
+        ExportFormat::Typst => generate_typst_content(&project),
+        ExportFormat::BilingualTypst => generate_bilingual_typst(&project),
+        ExportFormat::Latex => generate_latex_content(&project),
+        ExportFormat::Markdown => generate_markdown_content(&project),
+        ExportFormat::Html => generate_html_content(&project),
+        ExportFormat::Csv => generate_csv_content(&project),
+        ExportFormat::FrequencyCsv => generate_frequency_csv(&project),
+        ExportFormat::UntranslatedWordlist => generate_untranslated_wordlist(&project),
+        ExportFormat::Glossary => super::glossary::generate_glossary_json(&project)?,
+        ExportFormat::FormationRules => {
+            super::rules::generate_rules_json(&project.formation_rules)?
+        }
+        ExportFormat::Comments => super::comments::generate_comments_json(&project)?,
+        ExportFormat::SimilarityGraph => super::generate_similarity_dot(
+            &project,
+            SIMILARITY_GRAPH_NEIGHBORS,
+            SIMILARITY_GRAPH_THRESHOLD,
+        ),
+    };
+
+    std::fs::write(output, content)
+        .map_err(|e| AppError::IoError(format!("Failed to write '{}': {e}", output.display())))
+}
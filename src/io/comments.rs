@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enums::{AppError, AppResult};
+use crate::io::json_formatter::Formatter;
+use crate::libs::{Project, Segment};
+
+/// Annotations exported apart from the project so a reviewer can edit only the notes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentSidecar {
+    /// Comments on vocabulary words, sorted by word
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vocabulary: Vec<WordComment>,
+
+    /// Comments on derived (formatted) words, sorted by word
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub formatted_words: Vec<WordComment>,
+
+    /// Comments on segments, in segment order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub segments: Vec<SegmentComment>,
+}
+
+/// A comment keyed by the word it annotates
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordComment {
+    pub word: String,
+    pub comment: String,
+}
+
+/// A comment keyed by segment index, with a hash of the segment text at export time
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentComment {
+    /// Zero-based segment index
+    pub index: usize,
+
+    /// [`segment_content_hash`] of the segment when the comment was exported
+    pub hash: String,
+
+    pub comment: String,
+}
+
+/// Outcome of reattaching a comment sidecar to a project
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommentImport {
+    /// Comments that changed the project
+    pub applied: usize,
+
+    /// Zero-based indices of segment comments skipped because the segment text changed
+    pub mismatched: Vec<usize>,
+
+    /// Zero-based indices of segment comments skipped because the segment no longer exists
+    pub missing: Vec<usize>,
+}
+
+/// Stable FNV-1a hash of a segment's token sequence, as 16 hex digits.
+/// Translations, comments, and glosses do not affect the hash.
+#[must_use]
+pub fn segment_content_hash(segment: &Segment) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let mut hash = OFFSET_BASIS;
+    for token in &segment.tokens {
+        // A separator byte keeps ["ab", "c"] and ["a", "bc"] apart
+        for byte in token.original.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+/// Collect every non-empty vocabulary, formatted-word, and segment comment
+#[must_use]
+pub fn build_comment_sidecar(project: &Project) -> CommentSidecar {
+    fn word_comments(comments: &HashMap<String, String>) -> Vec<WordComment> {
+        let mut entries: Vec<WordComment> = comments
+            .iter()
+            .filter(|(_, comment)| !comment.is_empty())
+            .map(|(word, comment)| WordComment {
+                word: word.clone(),
+                comment: comment.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.word.cmp(&b.word));
+        entries
+    }
+
+    CommentSidecar {
+        vocabulary: word_comments(&project.vocabulary_comments),
+        formatted_words: word_comments(&project.formatted_word_comments),
+        segments: project
+            .segments
+            .iter()
+            .enumerate()
+            .filter(|(_, segment)| !segment.comment.is_empty())
+            .map(|(index, segment)| SegmentComment {
+                index,
+                hash: segment_content_hash(segment),
+                comment: segment.comment.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Serialize the project's comments as a JSON sidecar using the project JSON style
+pub fn generate_comments_json(project: &Project) -> AppResult<String> {
+    let sidecar = build_comment_sidecar(project);
+    let mut buf = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buf, Formatter::new());
+    sidecar
+        .serialize(&mut serializer)
+        .map_err(|e| AppError::IoError(format!("Failed to serialize comments: {e}")))?;
+    String::from_utf8(buf).map_err(|e| AppError::IoError(format!("Failed to encode comments: {e}")))
+}
+
+/// Parse a comment sidecar JSON object
+pub fn parse_comments_json(content: &str) -> AppResult<CommentSidecar> {
+    serde_json::from_str(content)
+        .map_err(|e| AppError::InvalidProjectFormat(format!("Invalid comments file: {e}")))
+}
+
+/// Reattach sidecar comments to the project, replacing the comments they key.
+/// Segment comments are skipped when the segment is gone or its tokens no longer hash
+/// to the exported value, so notes never land on a different sentence.
+pub fn apply_comment_sidecar(project: &mut Project, sidecar: &CommentSidecar) -> CommentImport {
+    let mut result = CommentImport::default();
+
+    let word_targets = [
+        (&sidecar.vocabulary, &mut project.vocabulary_comments),
+        (
+            &sidecar.formatted_words,
+            &mut project.formatted_word_comments,
+        ),
+    ];
+    for (entries, comments) in word_targets {
+        for entry in entries {
+            let comment = comments.entry(entry.word.clone()).or_default();
+            if *comment != entry.comment {
+                comment.clone_from(&entry.comment);
+                result.applied += 1;
+            }
+        }
+    }
+
+    for entry in &sidecar.segments {
+        let Some(segment) = project.segments.get_mut(entry.index) else {
+            result.missing.push(entry.index);
+            continue;
+        };
+        if segment_content_hash(segment) != entry.hash {
+            result.mismatched.push(entry.index);
+            continue;
+        }
+        if segment.comment != entry.comment {
+            segment.comment.clone_from(&entry.comment);
+            result.applied += 1;
+        }
+    }
+
+    result
+}
@@ -0,0 +1,67 @@
+use crate::libs::Project;
+
+use super::plaintext::token_gloss;
+
+/// Quote a field per RFC 4180 when it contains a delimiter, quote, or line break
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Render the project as CSV with one row per segment.
+/// Columns are the 1-based segment number, the space-joined tokens, their glosses
+/// (joined by ` | `), and the translation.
+#[must_use]
+pub fn generate_csv_content(project: &Project) -> String {
+    let mut content = String::from("segment,text,gloss,translation\r\n");
+
+    for (idx, segment) in project.segments.iter().enumerate() {
+        let text: Vec<&str> = segment
+            .tokens
+            .iter()
+            .map(|token| token.original.as_str())
+            .collect();
+        let glosses: Vec<String> = segment
+            .tokens
+            .iter()
+            .map(|token| token_gloss(token, &project.vocabulary, &project.formation_rules))
+            .collect();
+
+        content.push_str(&format!(
+            "{},{},{},{}\r\n",
+            idx + 1,
+            escape_csv_field(&text.join(" ")),
+            escape_csv_field(&glosses.join(" | ")),
+            escape_csv_field(&segment.translation)
+        ));
+    }
+
+    content
+}
+
+/// Render the vocabulary as CSV with one row per word, most used first.
+/// Columns are the word, its token count (derived tokens count toward their base word),
+/// whether it has a gloss, and its vocabulary comment.
+#[must_use]
+pub fn generate_frequency_csv(project: &Project) -> String {
+    let mut content = String::from("word,count,glossed,comment\r\n");
+
+    for frequency in project.vocabulary_frequencies() {
+        let comment = project
+            .vocabulary_comments
+            .get(&frequency.word)
+            .map_or("", String::as_str);
+        content.push_str(&format!(
+            "{},{},{},{}\r\n",
+            escape_csv_field(&frequency.word),
+            frequency.count,
+            frequency.glossed,
+            escape_csv_field(comment)
+        ));
+    }
+
+    content
+}
@@ -1,22 +1,27 @@
+use std::path::Path;
+
 use eframe::egui;
 
-use crate::consts::domain::DEFAULT_RELATED_WORDS_COUNT;
-use crate::enums::{AppAction, AppError, FileType};
+use crate::consts::domain::{
+    DEFAULT_RELATED_WORDS_COUNT, SIMILARITY_GRAPH_NEIGHBORS, SIMILARITY_GRAPH_THRESHOLD,
+};
+use crate::enums::{AppAction, AppError, ExportFormat, FileType, FontSource};
 use crate::io;
+use crate::libs::project::{CustomFont, Project};
+use crate::libs::similarity_token::find_related_words;
 use crate::ui::states::state::DecryptionApp;
 
 impl DecryptionApp {
-    pub(crate) fn load_text_file(&mut self, _ctx: &egui::Context) {
+    pub(crate) fn load_text_file(&mut self, _ctx: &egui::Context, append: bool) {
+        self.import_append = append;
         let pending = self.pending_text_file.clone();
         io::FileIO::spawn(async move {
-            let file_type = FileType::Text;
+            let file_type = FileType::ImportSource;
             let result =
                 io::FileIO::pick_file(file_type.filter_name(), file_type.extensions()).await;
             let decoded = result
                 .and_then(|(bytes, filename, _path)| {
-                    String::from_utf8(bytes)
-                        .map(|content| (content, filename))
-                        .map_err(|e| AppError::IoError(format!("Failed to decode file: {e}")))
+                    io::decode_text_bytes(&bytes).map(|content| (content, filename))
                 })
                 .map_err(|e| e.to_string());
             let mut guard = pending
@@ -49,7 +54,10 @@ impl DecryptionApp {
     pub(crate) fn save_project(&mut self, _ctx: &egui::Context) {
         match crate::libs::project::convert_to_saved_project(&self.project) {
             Ok(saved_project) => {
-                let formatter = io::json_formatter::Formatter::new();
+                let formatter = io::json_formatter::Formatter::with_options(
+                    io::json_formatter::DEFAULT_INDENT,
+                    self.project.compact_json,
+                );
                 let mut buf = Vec::new();
                 let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
                 match serde::Serialize::serialize(&saved_project, &mut serializer) {
@@ -64,9 +72,8 @@ impl DecryptionApp {
                             let path = PathBuf::from(filename);
                             let pending = self.pending_save_result.clone();
                             io::FileIO::spawn(async move {
-                                let result = io::FileIO::save_file_to_path(&json_bytes, &path)
-                                    .await
-                                    .map_err(|e| e.to_string());
+                                let result =
+                                    io::FileIO::save_file_to_path(&json_bytes, &path).await;
                                 let mut guard = pending
                                     .lock()
                                     .expect("pending_save_result mutex poisoned while saving project to path");
@@ -86,8 +93,7 @@ impl DecryptionApp {
                         io::FileIO::spawn(async move {
                             let result =
                                 io::FileIO::save_file(&json_bytes, &filename, "JSON", &["json"])
-                                    .await
-                                    .map_err(|e| e.to_string());
+                                    .await;
                             let mut guard = pending
                                 .lock()
                                 .expect("pending_save_result mutex poisoned while saving project");
@@ -111,9 +117,14 @@ impl DecryptionApp {
             let file_type = FileType::Font;
             let result =
                 io::FileIO::pick_file(file_type.filter_name(), file_type.extensions()).await;
-            let converted = result
-                .map(|(bytes, filename, _path)| (bytes, filename))
-                .map_err(|e| e.to_string());
+            // WASM only reports the file name, which is no use as a path reference
+            let converted = result.map(|(bytes, filename, path)| {
+                (
+                    bytes,
+                    filename,
+                    path.filter(|_| !cfg!(target_arch = "wasm32")),
+                )
+            });
             let mut guard = pending
                 .lock()
                 .expect("pending_font_file mutex poisoned while loading font file");
@@ -126,30 +137,141 @@ impl DecryptionApp {
         ctx: &egui::Context,
         data: Vec<u8>,
         font_name: &str,
+        path: Option<String>,
     ) {
-        io::register_custom_font(ctx, data);
+        let font = CustomFont {
+            name: font_name.to_string(),
+            path,
+            data,
+        };
+        let relocated = self
+            .relocating_font
+            .take()
+            .and_then(|slot| self.project.fonts.get_mut(slot));
+        if let Some(slot) = relocated {
+            *slot = font;
+        } else {
+            self.project.fonts.push(font);
+        }
+        self.register_project_fonts(ctx);
 
-        self.project.font_path = Some(font_name.to_string());
+        self.update_dirty_status(true, ctx);
         self.update_title(ctx);
     }
 
+    /// Fill in path-referenced fonts after opening a project, queueing a warning for
+    /// every font whose file can no longer be read
+    pub(crate) fn resolve_project_fonts(&mut self) {
+        self.missing_fonts.clear();
+        for (slot, font) in self.project.fonts.iter_mut().enumerate() {
+            let data = match io::resolve_font_source(font, Path::is_file) {
+                FontSource::Embedded => continue,
+                FontSource::Path(path) => std::fs::read(path).ok(),
+                FontSource::Missing => None,
+            };
+            match data {
+                Some(data) => font.data = data,
+                None => self.missing_fonts.push(slot),
+            }
+        }
+    }
+
+    /// Drop a project font, shifting queued warnings for later fonts down by one
+    pub(crate) fn clear_font_reference(&mut self, ctx: &egui::Context, slot: usize) {
+        if self.project.remove_font(slot).is_none() {
+            return;
+        }
+        self.missing_fonts.retain(|&missing| missing != slot);
+        for missing in &mut self.missing_fonts {
+            if *missing > slot {
+                *missing -= 1;
+            }
+        }
+        self.register_project_fonts(ctx);
+        self.update_dirty_status(true, ctx);
+    }
+
+    /// Rebuild the font definitions from the project's fonts, e.g. after opening a project
+    /// with embedded fonts
+    pub(crate) fn register_project_fonts(&mut self, ctx: &egui::Context) {
+        self.font_definitions = io::default_font_definitions();
+        for (slot, font) in self.project.fonts.iter().enumerate() {
+            let family = io::sentence_font_family(slot);
+            if font.data.is_empty() {
+                io::register_placeholder_font(&mut self.font_definitions, &family);
+            } else {
+                io::register_custom_font(&mut self.font_definitions, &family, font.data.clone());
+            }
+            if slot == 0 {
+                io::set_default_sentence_font(&mut self.font_definitions, &family);
+            }
+        }
+        ctx.set_fonts(self.font_definitions.clone());
+    }
+
     pub fn initialize_fonts(ctx: &egui::Context) {
         io::initialize_fonts(ctx);
     }
 
+    /// Forget every loaded custom font and restore the default sentence family
+    pub(crate) fn reset_custom_fonts(&mut self, ctx: &egui::Context) {
+        self.project.fonts.clear();
+        self.missing_fonts.clear();
+        self.register_project_fonts(ctx);
+    }
+
     pub(crate) fn export_typst(&mut self) {
         let content = io::generate_typst_content(&self.project);
-        let filename = format!(
-            "{}.typ",
-            if self.project.project_name.is_empty() {
-                "export".to_string()
-            } else {
-                self.project.project_name.clone()
-            }
+        self.save_export(content.into_bytes(), "typ", FileType::Typst);
+    }
+
+    pub(crate) fn export_bilingual_typst(&mut self) {
+        let content = io::generate_bilingual_typst(&self.project);
+        self.save_export(content.into_bytes(), "bilingual.typ", FileType::Typst);
+    }
+
+    pub(crate) fn export_latex(&mut self) {
+        let content = io::generate_latex_content(&self.project);
+        self.save_export(content.into_bytes(), "tex", FileType::Latex);
+    }
+
+    pub(crate) fn export_markdown(&mut self) {
+        let content = io::generate_markdown_content(&self.project);
+        self.save_export(content.into_bytes(), "md", FileType::Markdown);
+    }
+
+    pub(crate) fn export_html(&mut self) {
+        let content = io::generate_html_content(&self.project);
+        self.save_export(content.into_bytes(), "html", FileType::Html);
+    }
+
+    pub(crate) fn export_csv(&mut self) {
+        let content = io::generate_csv_content(&self.project);
+        self.save_export(content.into_bytes(), "csv", FileType::Csv);
+    }
+
+    pub(crate) fn export_frequency_csv(&mut self) {
+        let content = io::generate_frequency_csv(&self.project);
+        self.save_export(content.into_bytes(), "frequencies.csv", FileType::Csv);
+    }
+
+    pub(crate) fn export_similarity_graph(&mut self) {
+        let content = io::generate_similarity_dot(
+            &self.project,
+            SIMILARITY_GRAPH_NEIGHBORS,
+            SIMILARITY_GRAPH_THRESHOLD,
         );
-        let content_bytes = content.into_bytes();
+        self.save_export(content.into_bytes(), "dot", FileType::Dot);
+    }
+
+    pub(crate) fn export_untranslated_wordlist(&mut self) {
+        let content = io::generate_untranslated_wordlist(&self.project);
+        self.save_export(content.into_bytes(), "untranslated.txt", FileType::Text);
+    }
+
+    fn save_export(&self, content_bytes: Vec<u8>, suffix: &str, file_type: FileType) {
+        let filename = io::export_filename(&self.project.project_name, suffix);
         io::FileIO::spawn(async move {
-            let file_type = FileType::Typst;
             let _result = io::FileIO::save_file(
                 &content_bytes,
                 &filename,
@@ -160,6 +282,124 @@ impl DecryptionApp {
         });
     }
 
+    pub(crate) fn export_glossary(&mut self) {
+        let content = match io::glossary::generate_glossary_json(&self.project) {
+            Ok(content) => content,
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                return;
+            }
+        };
+        self.save_export(content.into_bytes(), "glossary.json", FileType::Json);
+    }
+
+    pub(crate) fn export_formation_rules(&mut self) {
+        let content = match io::rules::generate_rules_json(&self.project.formation_rules) {
+            Ok(content) => content,
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                return;
+            }
+        };
+        self.save_export(content.into_bytes(), "rules.json", FileType::Json);
+    }
+
+    /// Save segment `seg_idx` as a PNG of its interlinear layout
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn export_segment_image(&mut self, seg_idx: usize) {
+        let Some(segment) = self.project.segments.get(seg_idx) else {
+            return;
+        };
+        let family = segment_font_family(segment.font, self.project.fonts.len());
+        let image = io::segment_image::render_segment_image(
+            segment,
+            &self.project.vocabulary,
+            &self.project.formation_rules,
+            self.font_definitions.clone(),
+            family.as_deref(),
+        );
+        let content_bytes = match io::segment_image::encode_png(&image) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                return;
+            }
+        };
+        self.save_export(
+            content_bytes,
+            &format!("{}.png", seg_idx + 1),
+            FileType::Png,
+        );
+    }
+
+    pub(crate) fn export_comments(&mut self) {
+        let content = match io::comments::generate_comments_json(&self.project) {
+            Ok(content) => content,
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                return;
+            }
+        };
+        self.save_export(content.into_bytes(), "comments.json", FileType::Json);
+    }
+
+    pub(crate) fn load_comments_file(&mut self, _ctx: &egui::Context) {
+        let pending = self.pending_comments_file.clone();
+        io::FileIO::spawn(async move {
+            let file_type = FileType::Json;
+            let result =
+                io::FileIO::pick_file(file_type.filter_name(), file_type.extensions()).await;
+            let decoded = result
+                .and_then(|(bytes, _filename, _path)| {
+                    String::from_utf8(bytes)
+                        .map_err(|e| AppError::IoError(format!("Failed to decode file: {e}")))
+                })
+                .map_err(|e| e.to_string());
+            let mut guard = pending
+                .lock()
+                .expect("pending_comments_file mutex poisoned while loading comments");
+            *guard = Some(decoded);
+        });
+    }
+
+    pub(crate) fn load_rules_file(&mut self, _ctx: &egui::Context) {
+        let pending = self.pending_rules_file.clone();
+        io::FileIO::spawn(async move {
+            let file_type = FileType::Json;
+            let result =
+                io::FileIO::pick_file(file_type.filter_name(), file_type.extensions()).await;
+            let decoded = result
+                .and_then(|(bytes, _filename, _path)| {
+                    String::from_utf8(bytes)
+                        .map_err(|e| AppError::IoError(format!("Failed to decode file: {e}")))
+                })
+                .map_err(|e| e.to_string());
+            let mut guard = pending
+                .lock()
+                .expect("pending_rules_file mutex poisoned while loading formation rules");
+            *guard = Some(decoded);
+        });
+    }
+
+    pub(crate) fn load_glossary_file(&mut self, _ctx: &egui::Context) {
+        let pending = self.pending_glossary_file.clone();
+        io::FileIO::spawn(async move {
+            let file_type = FileType::Json;
+            let result =
+                io::FileIO::pick_file(file_type.filter_name(), file_type.extensions()).await;
+            let decoded = result
+                .and_then(|(bytes, _filename, _path)| {
+                    String::from_utf8(bytes)
+                        .map_err(|e| AppError::IoError(format!("Failed to decode file: {e}")))
+                })
+                .map_err(|e| e.to_string());
+            let mut guard = pending
+                .lock()
+                .expect("pending_glossary_file mutex poisoned while loading glossary");
+            *guard = Some(decoded);
+        });
+    }
+
     pub(crate) fn update_title(&self, ctx: &egui::Context) {
         let dirty_mark = if self.is_dirty { "*" } else { "" };
         let title = if self.project.project_name.is_empty() {
@@ -173,7 +413,13 @@ impl DecryptionApp {
         ctx.send_viewport_cmd(egui::ViewportCommand::Title(title));
     }
 
-    pub(crate) fn update_dirty_status(&mut self, new_flag: bool, ctx: &egui::Context) {
+    pub fn update_dirty_status(&mut self, new_flag: bool, ctx: &egui::Context) {
+        if new_flag {
+            self.translation_suggestions
+                .invalidate(ctx.input(|i| i.time));
+        } else {
+            self.change_summary.reset();
+        }
         if self.is_dirty != new_flag {
             self.is_dirty = new_flag;
             self.update_title(ctx);
@@ -183,24 +429,42 @@ impl DecryptionApp {
         }
     }
 
-    pub(crate) fn trigger_action(&mut self, action: AppAction, ctx: &egui::Context) {
+    /// Run `action`, first asking for confirmation while the project has unsaved changes
+    pub fn trigger_action(&mut self, action: AppAction, ctx: &egui::Context) {
         if self.is_dirty {
-            let msg = match action {
-                AppAction::Quit => "You have unsaved changes. Are you sure you want to quit?",
-                _ => "You have unsaved changes. Continue anyway?",
-            };
-            self.confirmation = Some((msg.to_string(), action));
+            self.confirmation = Some((action.unsaved_changes_prompt().to_string(), action));
             return;
         }
 
         self.execute_action(action, ctx);
     }
 
-    pub(crate) fn execute_action(&mut self, action: AppAction, ctx: &egui::Context) {
+    /// Run `action` without asking about unsaved changes
+    pub fn execute_action(&mut self, action: AppAction, ctx: &egui::Context) {
         match action {
-            AppAction::Import => self.load_text_file(ctx),
+            AppAction::New => self.new_project(ctx),
+            AppAction::Import => self.load_text_file(ctx, false),
             AppAction::Open => self.load_project(ctx),
-            AppAction::Export => self.export_typst(),
+            AppAction::Export(ExportFormat::Typst) => self.export_typst(),
+            AppAction::Export(ExportFormat::BilingualTypst) => self.export_bilingual_typst(),
+            AppAction::Export(ExportFormat::Latex) => self.export_latex(),
+            AppAction::Export(ExportFormat::Markdown) => self.export_markdown(),
+            AppAction::Export(ExportFormat::Html) => self.export_html(),
+            AppAction::Export(ExportFormat::Csv) => self.export_csv(),
+            AppAction::Export(ExportFormat::FrequencyCsv) => self.export_frequency_csv(),
+            AppAction::Export(ExportFormat::UntranslatedWordlist) => {
+                self.export_untranslated_wordlist();
+            }
+            AppAction::Export(ExportFormat::Glossary) => self.export_glossary(),
+            AppAction::Export(ExportFormat::FormationRules) => self.export_formation_rules(),
+            AppAction::Export(ExportFormat::Comments) => self.export_comments(),
+            AppAction::Export(ExportFormat::SimilarityGraph) => self.export_similarity_graph(),
+            AppAction::DeleteSegment(idx) => {
+                if self.project.remove_segment(idx).is_some() {
+                    self.collapsed_segments.segment_removed(idx);
+                    self.mark_segments_changed(ctx);
+                }
+            }
             AppAction::Quit => {
                 self.update_dirty_status(false, ctx);
                 ctx.send_viewport_cmd(egui::ViewportCommand::Close);
@@ -208,68 +472,129 @@ impl DecryptionApp {
         }
     }
 
+    /// Replace the project with an empty one and drop everything derived from the old one
+    fn new_project(&mut self, ctx: &egui::Context) {
+        self.project = Project::default();
+        self.reset_custom_fonts(ctx);
+        self.current_path = None;
+        self.project_filename = None;
+        self.lookup_cache.invalidate();
+        self.tfidf_cache.invalidate();
+        self.translation_suggestions.clear();
+        self.filter_dirty = true;
+        self.lookups_dirty = true;
+        self.tfidf_dirty = true;
+        self.filter_text.clear();
+        self.highlight_word = None;
+        self.collapsed_segments.expand_all();
+        self.segment_heights.clear();
+        self.match_cursor = None;
+        self.pending_gloss_focus = None;
+        self.current_page = 0;
+        self.clear_popups();
+        self.update_dirty_status(false, ctx);
+        self.update_title(ctx);
+    }
+
     pub(crate) fn find_related_words(&self, prefix: &str) -> Vec<String> {
-        if prefix.is_empty() {
-            return Vec::new();
-        }
+        find_related_words(
+            self.project.vocabulary.keys(),
+            prefix,
+            DEFAULT_RELATED_WORDS_COUNT,
+        )
+    }
+}
 
-        let prefix_lower = prefix.to_lowercase();
-        let mut matches: Vec<String> = self
-            .project
-            .vocabulary
-            .keys()
-            .filter(|word| {
-                let word_lower = word.to_lowercase();
-                word_lower.starts_with(&prefix_lower) || word_lower.contains(&prefix_lower)
-            })
-            .take(DEFAULT_RELATED_WORDS_COUNT)
-            .cloned()
-            .collect();
-
-        matches.sort_by(|a, b| {
-            let a_lower = a.to_lowercase();
-            let b_lower = b.to_lowercase();
-            let a_starts = a_lower.starts_with(&prefix_lower);
-            let b_starts = b_lower.starts_with(&prefix_lower);
-
-            match (a_starts, b_starts) {
-                (true, false) => std::cmp::Ordering::Less,
-                (false, true) => std::cmp::Ordering::Greater,
-                _ => a_lower.cmp(&b_lower),
-            }
-        });
+/// Family used for sentence text when a segment has no font override.
+/// Backed by the first loaded custom font, or the proportional fonts if none is loaded.
+pub const DEFAULT_SENTENCE_FONT: &str = "SentenceFont";
+
+/// Family name of the `slot`-th loaded custom font (`SentenceFont1`, `SentenceFont2`, ...)
+#[must_use]
+pub fn sentence_font_family(slot: usize) -> String {
+    format!("{DEFAULT_SENTENCE_FONT}{}", slot + 1)
+}
 
-        matches
+/// Family a segment is drawn with, given its override and the number of loaded fonts.
+/// Returns None when no custom font is loaded; out-of-range overrides fall back to the default.
+#[must_use]
+pub fn segment_font_family(font_override: Option<usize>, loaded_fonts: usize) -> Option<String> {
+    if loaded_fonts == 0 {
+        return None;
     }
+    Some(match font_override {
+        Some(slot) if slot < loaded_fonts => sentence_font_family(slot),
+        _ => DEFAULT_SENTENCE_FONT.to_string(),
+    })
 }
 
-pub fn register_custom_font(ctx: &egui::Context, data: Vec<u8>) {
+/// Register font bytes as their own `family`, falling back to the proportional fonts.
+/// Registering a family again replaces its font data.
+pub fn register_custom_font(fonts: &mut egui::FontDefinitions, family: &str, data: Vec<u8>) {
     use std::sync::Arc;
 
-    let mut fonts = egui::FontDefinitions::default();
-
     fonts.font_data.insert(
-        "custom_font".to_owned(),
+        family.to_owned(),
         Arc::new(egui::FontData::from_owned(data)),
     );
 
-    let fallbacks = fonts
+    let fallbacks = egui::FontDefinitions::default()
         .families
         .get(&egui::FontFamily::Proportional)
         .cloned()
         .unwrap_or_default();
 
-    let mut custom_list = vec!["custom_font".to_owned()];
+    let mut custom_list = vec![family.to_owned()];
     custom_list.extend(fallbacks);
 
     fonts
         .families
-        .insert(egui::FontFamily::Name("SentenceFont".into()), custom_list);
+        .insert(egui::FontFamily::Name(family.into()), custom_list);
+}
 
-    ctx.set_fonts(fonts);
+/// Register `family` as a plain alias of the proportional fonts, standing in for a font
+/// whose bytes are unavailable so that lookups of the family never fail
+pub fn register_placeholder_font(fonts: &mut egui::FontDefinitions, family: &str) {
+    let fallbacks = egui::FontDefinitions::default()
+        .families
+        .get(&egui::FontFamily::Proportional)
+        .cloned()
+        .unwrap_or_default();
+
+    fonts.font_data.remove(family);
+    fonts
+        .families
+        .insert(egui::FontFamily::Name(family.into()), fallbacks);
 }
 
-pub fn initialize_fonts(ctx: &egui::Context) {
+/// Decide where a project font's bytes come from.
+/// Embedded bytes win; otherwise the referenced path must pass `is_readable`.
+pub fn resolve_font_source(font: &CustomFont, is_readable: impl Fn(&Path) -> bool) -> FontSource {
+    if !font.data.is_empty() {
+        return FontSource::Embedded;
+    }
+    match font.path.as_deref().map(Path::new) {
+        Some(path) if is_readable(path) => FontSource::Path(path.to_path_buf()),
+        _ => FontSource::Missing,
+    }
+}
+
+/// Point the default sentence family at an already registered `family`
+pub fn set_default_sentence_font(fonts: &mut egui::FontDefinitions, family: &str) {
+    if let Some(list) = fonts
+        .families
+        .get(&egui::FontFamily::Name(family.into()))
+        .cloned()
+    {
+        fonts
+            .families
+            .insert(egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()), list);
+    }
+}
+
+/// Default font definitions with the sentence family aliased to the proportional fonts
+#[must_use]
+pub fn default_font_definitions() -> egui::FontDefinitions {
     let mut fonts = egui::FontDefinitions::default();
 
     let fallbacks = fonts
@@ -278,9 +603,14 @@ pub fn initialize_fonts(ctx: &egui::Context) {
         .cloned()
         .unwrap_or_default();
 
+    fonts.families.insert(
+        egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
+        fallbacks,
+    );
+
     fonts
-        .families
-        .insert(egui::FontFamily::Name("SentenceFont".into()), fallbacks);
+}
 
-    ctx.set_fonts(fonts);
+pub fn initialize_fonts(ctx: &egui::Context) {
+    ctx.set_fonts(default_font_definitions());
 }
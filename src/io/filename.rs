@@ -0,0 +1,16 @@
+/// Fallback stem used when the project has no name
+const DEFAULT_EXPORT_STEM: &str = "export";
+
+/// Default filename offered when exporting, e.g. `"notes.md"` for project `notes`
+///
+/// Whitespace around the project name is ignored; an empty name falls back to `export`.
+#[must_use]
+pub fn export_filename(project_name: &str, suffix: &str) -> String {
+    let stem = project_name.trim();
+    let stem = if stem.is_empty() {
+        DEFAULT_EXPORT_STEM
+    } else {
+        stem
+    };
+    format!("{stem}.{suffix}")
+}
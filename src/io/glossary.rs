@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::enums::{AppError, AppResult};
+use crate::io::json_formatter::Formatter;
+use crate::libs::Project;
+
+/// A single glossary entry, independent of the project file format
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlossaryEntry {
+    /// The vocabulary word
+    pub word: String,
+
+    /// The gloss (definition) of the word
+    #[serde(default)]
+    pub gloss: String,
+
+    /// Optional comment/note about the word
+    #[serde(default)]
+    pub comment: String,
+}
+
+/// Collect every vocabulary word with a gloss or comment, sorted by word
+#[must_use]
+pub fn build_glossary(project: &Project) -> Vec<GlossaryEntry> {
+    let mut words: Vec<&String> = project
+        .vocabulary
+        .keys()
+        .chain(project.vocabulary_comments.keys())
+        .collect();
+    words.sort();
+    words.dedup();
+
+    words
+        .into_iter()
+        .map(|word| GlossaryEntry {
+            word: word.clone(),
+            gloss: project.vocabulary.get(word).cloned().unwrap_or_default(),
+            comment: project
+                .vocabulary_comments
+                .get(word)
+                .cloned()
+                .unwrap_or_default(),
+        })
+        .filter(|entry| !entry.gloss.is_empty() || !entry.comment.is_empty())
+        .collect()
+}
+
+/// One word in the glossary appendix of a Typst or Markdown export
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppendixEntry {
+    /// The word as written in the text
+    pub word: String,
+
+    /// The word's gloss; a derived word shows its base word's gloss
+    pub gloss: String,
+
+    /// Vocabulary or derived-word comment
+    pub comment: String,
+
+    /// Base word of a derived word
+    pub base_word: Option<String>,
+
+    /// Descriptions of the formation rules applied to the base word, in order
+    pub rules: Vec<String>,
+}
+
+impl AppendixEntry {
+    /// The base word followed by its formation rules, e.g. `"kaa + plural + genitive"`.
+    /// Empty for words that are not derived.
+    #[must_use]
+    pub fn derivation(&self) -> String {
+        let Some(base_word) = &self.base_word else {
+            return String::new();
+        };
+        std::iter::once(base_word.as_str())
+            .chain(self.rules.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+/// Collect every vocabulary word (glossed, commented, or neither) and every derived word
+/// used by a token, sorted by word
+#[must_use]
+pub fn build_glossary_appendix(project: &Project) -> Vec<AppendixEntry> {
+    let mut entries: BTreeMap<&str, AppendixEntry> = project
+        .vocabulary
+        .keys()
+        .chain(project.vocabulary_comments.keys())
+        .map(|word| {
+            let entry = AppendixEntry {
+                word: word.clone(),
+                gloss: project.vocabulary.get(word).cloned().unwrap_or_default(),
+                comment: project
+                    .vocabulary_comments
+                    .get(word)
+                    .cloned()
+                    .unwrap_or_default(),
+                ..Default::default()
+            };
+            (word.as_str(), entry)
+        })
+        .collect();
+
+    let derived_tokens = project
+        .segments
+        .iter()
+        .flat_map(|segment| &segment.tokens)
+        .filter(|token| {
+            token
+                .base_word
+                .as_ref()
+                .is_some_and(|base| *base != token.original)
+        });
+    for token in derived_tokens {
+        let entry = entries
+            .entry(token.original.as_str())
+            .or_insert_with(|| AppendixEntry {
+                word: token.original.clone(),
+                ..Default::default()
+            });
+        if entry.base_word.is_some() {
+            continue;
+        }
+        let base_word = token.base_word.clone().unwrap_or_default();
+        if entry.gloss.is_empty() {
+            entry.gloss = project
+                .vocabulary
+                .get(&base_word)
+                .cloned()
+                .unwrap_or_default();
+        }
+        if let Some(comment) = project.formatted_word_comments.get(&token.original)
+            && !comment.is_empty()
+        {
+            entry.comment.clone_from(comment);
+        }
+        entry.rules = token
+            .formation_rule_indices
+            .iter()
+            .filter_map(|idx| project.formation_rules.get(*idx))
+            .map(|rule| rule.description.clone())
+            .collect();
+        entry.base_word = Some(base_word);
+    }
+
+    entries.into_values().collect()
+}
+
+/// Serialize the project glossary as a JSON array using the project JSON style
+pub fn generate_glossary_json(project: &Project) -> AppResult<String> {
+    let glossary = build_glossary(project);
+    let mut buf = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buf, Formatter::new());
+    glossary
+        .serialize(&mut serializer)
+        .map_err(|e| AppError::IoError(format!("Failed to serialize glossary: {e}")))?;
+    String::from_utf8(buf).map_err(|e| AppError::IoError(format!("Failed to encode glossary: {e}")))
+}
+
+/// Parse a glossary JSON array
+pub fn parse_glossary_json(content: &str) -> AppResult<Vec<GlossaryEntry>> {
+    serde_json::from_str(content)
+        .map_err(|e| AppError::InvalidProjectFormat(format!("Invalid glossary: {e}")))
+}
+
+/// Merge glossary entries into the project vocabulary.
+/// Only empty glosses and comments are filled in; existing work is never overwritten.
+/// Returns the number of entries that changed the project.
+pub fn apply_glossary(project: &mut Project, entries: &[GlossaryEntry]) -> usize {
+    let mut changed = 0;
+
+    for entry in entries {
+        let mut entry_changed = false;
+
+        if !entry.gloss.is_empty() {
+            let gloss = project.vocabulary.entry(entry.word.clone()).or_default();
+            if gloss.is_empty() {
+                gloss.clone_from(&entry.gloss);
+                entry_changed = true;
+            }
+        }
+
+        if !entry.comment.is_empty() {
+            let comment = project
+                .vocabulary_comments
+                .entry(entry.word.clone())
+                .or_default();
+            if comment.is_empty() {
+                comment.clone_from(&entry.comment);
+                entry_changed = true;
+            }
+        }
+
+        if entry_changed {
+            changed += 1;
+        }
+    }
+
+    changed
+}
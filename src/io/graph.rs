@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use crate::libs::Project;
+use crate::libs::similarity_sentence::SimilarityEngine;
+
+/// Characters of segment text kept in a node label
+const NODE_LABEL_CHARS: usize = 40;
+
+/// Quote a string as a DOT ID, escaping quotes, backslashes and line breaks
+fn dot_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => {}
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Node label: the segment number followed by its translation, or its tokens when untranslated
+fn node_label(project: &Project, idx: usize) -> String {
+    let segment = &project.segments[idx];
+    let text = if segment.translation.trim().is_empty() {
+        segment
+            .tokens
+            .iter()
+            .map(|token| token.original.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    } else {
+        segment.translation.trim().to_string()
+    };
+    let mut label = format!("[{}] ", idx + 1);
+    label.extend(text.chars().take(NODE_LABEL_CHARS));
+    if text.chars().count() > NODE_LABEL_CHARS {
+        label.push_str("...");
+    }
+    label
+}
+
+/// Render the segment similarity graph as an undirected Graphviz DOT document.
+/// Every segment is a node; each is linked to at most `k` of its most similar segments
+/// whose TF-IDF cosine similarity is at least `threshold`. A pair found from both ends
+/// yields a single edge, weighted by its score.
+#[must_use]
+pub fn generate_similarity_dot(project: &Project, k: usize, threshold: f64) -> String {
+    let mut edges: BTreeMap<(usize, usize), f64> = BTreeMap::new();
+    if let Some(matrix) = SimilarityEngine::compute_tfidf_matrix(project) {
+        for source in 0..project.segments.len() {
+            for (target, score) in SimilarityEngine::find_similar(&matrix, source, k) {
+                if score >= threshold {
+                    edges
+                        .entry((source.min(target), source.max(target)))
+                        .or_insert(score);
+                }
+            }
+        }
+    }
+
+    let mut content = String::from("graph similarity {\n    node [shape=box];\n");
+    for idx in 0..project.segments.len() {
+        content.push_str(&format!(
+            "    s{} [label={}];\n",
+            idx + 1,
+            dot_string(&node_label(project, idx))
+        ));
+    }
+    for ((a, b), score) in &edges {
+        content.push_str(&format!(
+            "    s{} -- s{} [weight={score:.3}, label=\"{score:.2}\"];\n",
+            a + 1,
+            b + 1
+        ));
+    }
+    content.push_str("}\n");
+    content
+}
@@ -0,0 +1,133 @@
+use crate::libs::Project;
+use crate::libs::project::base64;
+
+use super::plaintext::token_gloss;
+
+const STYLE: &str = r#"body { font-family: sans-serif; max-width: 60em; margin: 2em auto; padding: 0 1em; line-height: 1.5; }
+.segment { margin-bottom: 2em; }
+.number { color: #888; font-size: 0.85em; }
+.tokens { display: flex; flex-wrap: wrap; gap: 0.3em 0.6em; }
+.token { position: relative; font-size: 1.4em; cursor: help; border-bottom: 1px dotted #888; }
+.token.unglossed { border-bottom-color: #e44; }
+.token:hover::after, .token.active::after { content: attr(data-gloss); position: absolute; left: 0; top: 100%; z-index: 1; white-space: nowrap; padding: 0.2em 0.5em; border-radius: 4px; background: #333; color: #fff; font-family: sans-serif; font-size: 0.6em; }
+.token.unglossed::after { display: none; }
+.translation { font-style: italic; margin: 0.5em 0 0; }
+"#;
+
+const SCRIPT: &str = r#"document.querySelectorAll(".token").forEach(function (token) {
+  token.addEventListener("click", function () { token.classList.toggle("active"); });
+});
+"#;
+
+/// Escape characters that would otherwise be read as HTML markup or end an attribute
+pub fn escape_html(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// MIME type of a font file, guessed from its name
+fn font_mime(name: &str) -> &'static str {
+    let lower = name.to_ascii_lowercase();
+    if lower.ends_with(".otf") {
+        "font/otf"
+    } else if lower.ends_with(".woff2") {
+        "font/woff2"
+    } else if lower.ends_with(".woff") {
+        "font/woff"
+    } else {
+        "font/ttf"
+    }
+}
+
+/// Render the project as a self-contained HTML page: each segment's tokens in a row,
+/// showing their gloss when hovered (or tapped), with the translation beneath.
+/// Loaded fonts are embedded as base64 `@font-face` rules so the page needs no other files.
+#[must_use]
+pub fn generate_html_content(project: &Project) -> String {
+    let title = if project.project_name.is_empty() {
+        "Untitled"
+    } else {
+        project.project_name.as_str()
+    };
+    let title = escape_html(title);
+
+    let mut style = String::new();
+    for (idx, font) in project.fonts.iter().enumerate() {
+        if font.data.is_empty() {
+            continue;
+        }
+        style.push_str(&format!(
+            "@font-face {{ font-family: \"tdector-font-{idx}\"; src: url(data:{};base64,{}); }}\n",
+            font_mime(&font.name),
+            base64::encode(&font.data)
+        ));
+        style.push_str(&format!(
+            ".font-{idx} .token {{ font-family: \"tdector-font-{idx}\", sans-serif; }}\n"
+        ));
+    }
+    style.push_str(STYLE);
+
+    let mut content = String::new();
+    content.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    content.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    content.push_str(&format!(
+        "<title>{title}</title>\n<style>\n{style}</style>\n"
+    ));
+    content.push_str(&format!("</head>\n<body>\n<h1>{title}</h1>\n"));
+
+    let dir = if project.text_direction.is_rtl() {
+        "rtl"
+    } else {
+        "ltr"
+    };
+    for (idx, segment) in project.segments.iter().enumerate() {
+        let font = segment.font.unwrap_or(0);
+        let font_class = if project.fonts.get(font).is_some_and(|f| !f.data.is_empty()) {
+            format!(" font-{font}")
+        } else {
+            String::new()
+        };
+        content.push_str(&format!(
+            "<div class=\"segment{font_class}\" id=\"segment-{}\">\n",
+            idx + 1
+        ));
+        content.push_str(&format!("<div class=\"number\">[{}]</div>\n", idx + 1));
+        content.push_str(&format!("<div class=\"tokens\" dir=\"{dir}\">"));
+        for token in &segment.tokens {
+            let gloss = token_gloss(token, &project.vocabulary, &project.formation_rules);
+            let class = if gloss.trim().is_empty() {
+                "token unglossed"
+            } else {
+                "token"
+            };
+            content.push_str(&format!(
+                "<span class=\"{class}\" data-gloss=\"{}\">{}</span>",
+                escape_html(&gloss),
+                escape_html(&token.original)
+            ));
+        }
+        content.push_str("</div>\n");
+
+        if !segment.translation.is_empty() {
+            let lines: Vec<String> = segment.translation.lines().map(escape_html).collect();
+            content.push_str(&format!(
+                "<p class=\"translation\">{}</p>\n",
+                lines.join("<br>")
+            ));
+        }
+        content.push_str("</div>\n");
+    }
+
+    content.push_str(&format!("<script>\n{SCRIPT}</script>\n</body>\n</html>\n"));
+    content
+}
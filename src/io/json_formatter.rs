@@ -1,16 +1,17 @@
 use std::io;
 
+/// Spaces per nesting level used by [`Formatter::new`]
+pub const DEFAULT_INDENT: usize = 2;
+
 pub struct Formatter {
     indent: Vec<u8>,
     current_indent: usize,
+    compact: bool,
 }
 
 impl Default for Formatter {
     fn default() -> Self {
-        Self {
-            indent: b"  ".to_vec(),
-            current_indent: 0,
-        }
+        Self::with_options(DEFAULT_INDENT, false)
     }
 }
 
@@ -18,6 +19,15 @@ impl Formatter {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Indent objects by `indent` spaces per level, or drop all whitespace when `compact` is set
+    pub fn with_options(indent: usize, compact: bool) -> Self {
+        Self {
+            indent: vec![b' '; indent],
+            current_indent: 0,
+            compact,
+        }
+    }
 }
 
 impl serde_json::ser::Formatter for Formatter {
@@ -41,6 +51,8 @@ impl serde_json::ser::Formatter for Formatter {
     {
         if first {
             Ok(())
+        } else if self.compact {
+            writer.write_all(b",")
         } else {
             writer.write_all(b", ")
         }
@@ -66,6 +78,9 @@ impl serde_json::ser::Formatter for Formatter {
         W: ?Sized + io::Write,
     {
         self.current_indent -= 1;
+        if self.compact {
+            return writer.write_all(b"}");
+        }
         if self.current_indent > 0 {
             writer.write_all(b"\n")?;
             for _ in 0..self.current_indent {
@@ -84,6 +99,9 @@ impl serde_json::ser::Formatter for Formatter {
         if !first {
             writer.write_all(b",")?;
         }
+        if self.compact {
+            return Ok(());
+        }
         writer.write_all(b"\n")?;
         for _ in 0..self.current_indent {
             writer.write_all(&self.indent)?;
@@ -102,7 +120,11 @@ impl serde_json::ser::Formatter for Formatter {
     where
         W: ?Sized + io::Write,
     {
-        writer.write_all(b": ")
+        if self.compact {
+            writer.write_all(b":")
+        } else {
+            writer.write_all(b": ")
+        }
     }
 
     fn end_object_value<W>(&mut self, _writer: &mut W) -> io::Result<()>
@@ -0,0 +1,72 @@
+use serde::Deserialize;
+
+use crate::libs::Segment;
+use crate::libs::tokenizer::plain_token;
+
+/// One pre-tokenized segment as written on a JSON Lines import line.
+/// Fields other than these are ignored.
+#[derive(Debug, Deserialize)]
+struct JsonlSegment {
+    tokens: Vec<String>,
+    #[serde(default)]
+    translation: String,
+}
+
+/// A line that could not be turned into a segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonlLineError {
+    /// One-based line number in the imported file
+    pub line: usize,
+
+    /// Why the line was skipped
+    pub message: String,
+}
+
+/// Segments read from a JSON Lines file, plus the lines that were skipped
+#[derive(Debug, Clone, Default)]
+pub struct JsonlImport {
+    /// Segments in file order
+    pub segments: Vec<Segment>,
+
+    /// Malformed lines, in file order
+    pub errors: Vec<JsonlLineError>,
+}
+
+/// Whether an imported file should be read as JSON Lines rather than plain text
+#[must_use]
+pub fn is_jsonl_filename(name: &str) -> bool {
+    name.rsplit_once('.')
+        .is_some_and(|(_, extension)| extension.eq_ignore_ascii_case("jsonl"))
+}
+
+/// Build segments from JSON Lines content, one `{"tokens": [...], "translation": "..."}`
+/// object per line, without running a tokenizer.
+/// Blank lines are skipped. Lines that are not such an object, or that have no tokens,
+/// are reported in `errors` and do not stop the import.
+#[must_use]
+pub fn parse_jsonl_segments(content: &str) -> JsonlImport {
+    let mut import = JsonlImport::default();
+
+    for (idx, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let error = |message: String| JsonlLineError {
+            line: idx + 1,
+            message,
+        };
+        match serde_json::from_str::<JsonlSegment>(line) {
+            Ok(parsed) if parsed.tokens.is_empty() => {
+                import.errors.push(error("no tokens".to_string()));
+            }
+            Ok(parsed) => import.segments.push(Segment {
+                tokens: parsed.tokens.into_iter().map(plain_token).collect(),
+                translation: parsed.translation,
+                ..Default::default()
+            }),
+            Err(e) => import.errors.push(error(e.to_string())),
+        }
+    }
+
+    import
+}
@@ -0,0 +1,84 @@
+use crate::libs::Project;
+
+use super::plaintext::token_gloss;
+
+/// Escape LaTeX special characters so text is typeset literally
+pub fn escape_latex(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                result.push('\\');
+                result.push(c);
+            }
+            '~' => result.push_str("\\textasciitilde{}"),
+            '^' => result.push_str("\\textasciicircum{}"),
+            '\\' => result.push_str("\\textbackslash{}"),
+            '\r' | '\n' => result.push(' '),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// One aligned word of a `\gll` line; empty or multi-word cells are grouped in braces
+fn gll_cell(text: &str) -> String {
+    let escaped = escape_latex(text.trim());
+    if escaped.is_empty() || escaped.contains(char::is_whitespace) {
+        format!("{{{escaped}}}")
+    } else {
+        escaped
+    }
+}
+
+/// Render the project as a gb4e document: one `\ex` per segment, with tokens and
+/// their glosses aligned by `\gll` and the translation on the `\trans` line.
+/// A project without segments gets an empty document body.
+#[must_use]
+pub fn generate_latex_content(project: &Project) -> String {
+    let mut content = String::new();
+    content.push_str("\\documentclass{article}\n");
+    content.push_str("\\usepackage{gb4e}\n\n");
+    content.push_str("\\begin{document}\n\n");
+    if !project.project_name.is_empty() {
+        content.push_str(&format!(
+            "\\section*{{{}}}\n\n",
+            escape_latex(&project.project_name)
+        ));
+    }
+
+    // gb4e and expex reject an `exe` environment without examples
+    if !project.segments.is_empty() {
+        content.push_str("\\begin{exe}\n");
+        for segment in &project.segments {
+            let tokens: Vec<String> = segment
+                .tokens
+                .iter()
+                .map(|token| gll_cell(&token.original))
+                .collect();
+            let glosses: Vec<String> = segment
+                .tokens
+                .iter()
+                .map(|token| {
+                    gll_cell(&token_gloss(
+                        token,
+                        &project.vocabulary,
+                        &project.formation_rules,
+                    ))
+                })
+                .collect();
+
+            content.push_str("\\ex\n");
+            content.push_str(&format!("\\gll {}\\\\\n", tokens.join(" ")));
+            content.push_str(&format!("     {}\\\\\n", glosses.join(" ")));
+            content.push_str(&format!(
+                "\\trans `{}'\n",
+                escape_latex(&segment.translation)
+            ));
+        }
+        content.push_str("\\end{exe}\n\n");
+    }
+    content.push_str("\\end{document}\n");
+
+    content
+}
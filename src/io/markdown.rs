@@ -0,0 +1,82 @@
+use crate::libs::Project;
+
+use super::glossary::build_glossary_appendix;
+use super::plaintext::token_gloss;
+
+/// Escape characters that would otherwise be read as Markdown or table syntax
+pub fn escape_markdown(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' => {
+                result.push('\\');
+                result.push(c);
+            }
+            '\r' | '\n' => result.push(' '),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Render the project as Markdown: one heading per segment, a two-row table of
+/// tokens over their glosses, and the translation as a block quote.
+/// With `include_glossary_appendix` set, a table of every word follows the segments.
+#[must_use]
+pub fn generate_markdown_content(project: &Project) -> String {
+    let mut content = String::new();
+    let title = if project.project_name.is_empty() {
+        "Untitled"
+    } else {
+        project.project_name.as_str()
+    };
+    content.push_str(&format!("# {}\n\n", escape_markdown(title)));
+
+    for (idx, segment) in project.segments.iter().enumerate() {
+        content.push_str(&format!("## [{}]\n\n", idx + 1));
+
+        if !segment.tokens.is_empty() {
+            let mut token_row = String::from("|");
+            let mut separator_row = String::from("|");
+            let mut gloss_row = String::from("|");
+            for token in &segment.tokens {
+                let gloss = token_gloss(token, &project.vocabulary, &project.formation_rules);
+                token_row.push_str(&format!(" {} |", escape_markdown(&token.original)));
+                separator_row.push_str(" --- |");
+                gloss_row.push_str(&format!(" {} |", escape_markdown(&gloss)));
+            }
+            content.push_str(&format!("{token_row}\n{separator_row}\n{gloss_row}\n\n"));
+        }
+
+        if !segment.translation.is_empty() {
+            for line in segment.translation.lines() {
+                content.push_str(&format!("> {}\n", escape_markdown(line)));
+            }
+            content.push('\n');
+        }
+    }
+
+    if project.include_glossary_appendix {
+        content.push_str(&glossary_appendix(project));
+    }
+
+    content
+}
+
+/// A `Glossary` section with one table row per word, sorted by word
+fn glossary_appendix(project: &Project) -> String {
+    let mut content = String::from("## Glossary\n\n");
+    content.push_str("| Word | Gloss | Derivation | Comment |\n");
+    content.push_str("| --- | --- | --- | --- |\n");
+    for entry in build_glossary_appendix(project) {
+        content.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            escape_markdown(&entry.word),
+            escape_markdown(&entry.gloss),
+            escape_markdown(&entry.derivation()),
+            escape_markdown(&entry.comment),
+        ));
+    }
+    content.push('\n');
+    content
+}
@@ -1,15 +1,57 @@
 /// File I/O, formatting, and export functionality
 ///
 /// Handles:
+/// - batch: Headless export and validation of saved project files from the command line
+/// - comments: Vocabulary, formatted-word, and segment comments as a JSON sidecar
+/// - csv: Export of segments and vocabulary frequencies as comma-separated rows
 /// - `file_io`: Cross-platform file operations with async support
-/// - `file_ops`: Font loading and registration
+/// - `file_ops`: Font loading and registration of named sentence font families
+/// - filename: Default filenames offered for exports
+/// - graph: Export of the segment similarity graph as Graphviz DOT
+/// - glossary: Standalone vocabulary glossary export and import
+/// - html: Export to a self-contained HTML page with hover glosses
+/// - jsonl: Import of pre-tokenized segments from JSON Lines
 /// - `json_formatter`: Custom JSON serialization formatting
-/// - typst: Export to Typst markup for academic publications
+/// - latex: Export to LaTeX gb4e interlinear examples
+/// - markdown: Export to Markdown with token/gloss tables
+/// - plaintext: Aligned plain-text interlinear rendering of segments and wordlists
+/// - `segment_image`: PNG rendering of a single segment's interlinear layout (native only)
+/// - rules: Standalone formation rule library export and import
+/// - `text_encoding`: BOM-aware decoding of imported text files
+/// - typst: Export to Typst markup for academic publications and bilingual review tables
+#[cfg(not(target_arch = "wasm32"))]
+pub mod batch;
+pub mod comments;
+mod csv;
 pub mod file_io;
 mod file_ops;
+mod filename;
+pub mod glossary;
+mod graph;
+pub mod html;
 pub mod json_formatter;
+pub mod jsonl;
+mod latex;
+mod markdown;
+mod plaintext;
+pub mod rules;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod segment_image;
+mod text_encoding;
 mod typst;
 
+pub use csv::{generate_csv_content, generate_frequency_csv};
 pub use file_io::FileIO;
-pub use file_ops::{initialize_fonts, register_custom_font};
-pub use typst::generate_typst_content;
+pub use file_ops::{
+    DEFAULT_SENTENCE_FONT, default_font_definitions, initialize_fonts, register_custom_font,
+    register_placeholder_font, resolve_font_source, segment_font_family, sentence_font_family,
+    set_default_sentence_font,
+};
+pub use filename::export_filename;
+pub use graph::generate_similarity_dot;
+pub use html::generate_html_content;
+pub use latex::generate_latex_content;
+pub use markdown::generate_markdown_content;
+pub use plaintext::{format_segment_plaintext, generate_untranslated_wordlist};
+pub use text_encoding::decode_text_bytes;
+pub use typst::{generate_bilingual_typst, generate_typst_content};
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::libs::eval::FormationRule;
+use crate::libs::{Project, Segment, Token};
+
+/// Gloss a token as its base word's meaning, followed by the descriptions of the
+/// formation rules applied to it (if any) in parentheses
+pub(crate) fn token_gloss(
+    token: &Token,
+    vocabulary: &HashMap<String, String>,
+    formation_rules: &[FormationRule],
+) -> String {
+    let base_word = token.base_word.as_ref().unwrap_or(&token.original);
+    let base_gloss = vocabulary.get(base_word).map(String::as_str).unwrap_or("");
+    let descriptions: Vec<&str> = token
+        .formation_rule_indices
+        .iter()
+        .filter_map(|idx| formation_rules.get(*idx))
+        .map(|rule| rule.description.as_str())
+        .collect();
+    if descriptions.is_empty() {
+        base_gloss.to_string()
+    } else {
+        format!("{base_gloss} ({})", descriptions.join("; "))
+    }
+}
+
+/// Render a segment as aligned plain text: tokens on the first line, glosses beneath,
+/// and the translation (if any) on a third line.
+/// Each column is padded to the wider of its token and gloss, measured in characters.
+#[must_use]
+pub fn format_segment_plaintext(
+    segment: &Segment,
+    vocabulary: &HashMap<String, String>,
+    formation_rules: &[FormationRule],
+) -> String {
+    let mut token_line = String::new();
+    let mut gloss_line = String::new();
+
+    for (idx, token) in segment.tokens.iter().enumerate() {
+        let gloss = token_gloss(token, vocabulary, formation_rules);
+
+        let width = token.original.chars().count().max(gloss.chars().count());
+        if idx > 0 {
+            token_line.push_str("  ");
+            gloss_line.push_str("  ");
+        }
+        token_line.push_str(&format!("{:<width$}", token.original));
+        gloss_line.push_str(&format!("{gloss:<width$}"));
+    }
+
+    let mut lines = vec![
+        token_line.trim_end().to_string(),
+        gloss_line.trim_end().to_string(),
+    ];
+    if !segment.translation.is_empty() {
+        lines.push(segment.translation.clone());
+    }
+    lines.join("\n")
+}
+
+/// List the project's untranslated words one per line, most frequent first
+#[must_use]
+pub fn generate_untranslated_wordlist(project: &Project) -> String {
+    let mut content = String::new();
+    for (word, _count) in project.untranslated_words() {
+        content.push_str(&word);
+        content.push('\n');
+    }
+    content
+}
@@ -0,0 +1,64 @@
+use serde::Serialize;
+
+use crate::enums::{AppError, AppResult};
+use crate::io::json_formatter::Formatter;
+use crate::libs::eval::FormationRule;
+
+/// Outcome of merging a rule library into a project
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleImport {
+    /// Rules appended to the project
+    pub added: usize,
+
+    /// Rules skipped because an identical description and command already existed
+    pub skipped: usize,
+
+    /// Descriptions of added rules that collide with a different rule's description
+    pub duplicate_descriptions: Vec<String>,
+}
+
+/// Serialize formation rules as a standalone JSON array using the project JSON style
+pub fn generate_rules_json(rules: &[FormationRule]) -> AppResult<String> {
+    let mut buf = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buf, Formatter::new());
+    rules
+        .serialize(&mut serializer)
+        .map_err(|e| AppError::IoError(format!("Failed to serialize formation rules: {e}")))?;
+    String::from_utf8(buf)
+        .map_err(|e| AppError::IoError(format!("Failed to encode formation rules: {e}")))
+}
+
+/// Parse a formation rule library JSON array
+pub fn parse_rules_json(content: &str) -> AppResult<Vec<FormationRule>> {
+    serde_json::from_str(content)
+        .map_err(|e| AppError::InvalidProjectFormat(format!("Invalid formation rules: {e}")))
+}
+
+/// Append `incoming` rules to `rules`, skipping any whose description and command both match
+/// a rule already present. Existing rule indices are untouched, so tokens need no remapping.
+pub fn import_rules(rules: &mut Vec<FormationRule>, incoming: Vec<FormationRule>) -> RuleImport {
+    let mut result = RuleImport::default();
+
+    for rule in incoming {
+        let is_identical = rules.iter().any(|existing| {
+            existing.description == rule.description && existing.command == rule.command
+        });
+        if is_identical {
+            result.skipped += 1;
+            continue;
+        }
+
+        if rules
+            .iter()
+            .any(|existing| existing.description == rule.description)
+            && !result.duplicate_descriptions.contains(&rule.description)
+        {
+            result.duplicate_descriptions.push(rule.description.clone());
+        }
+
+        rules.push(rule);
+        result.added += 1;
+    }
+
+    result
+}
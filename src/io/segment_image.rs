@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use eframe::egui;
+use eframe::egui::epaint::{ClippedPrimitive, Primitive, Vertex};
+use image::{ImageFormat, RgbaImage};
+
+use crate::consts::ui::{
+    GLOSS_FONT_SIZE, SEGMENT_IMAGE_MAX_HEIGHT, SEGMENT_IMAGE_PADDING, SEGMENT_IMAGE_SCALE,
+    SEGMENT_IMAGE_WIDTH, SEGMENT_VERTICAL_SPACING, TOKEN_FONT_SIZE, TOKEN_SPACING_X,
+};
+use crate::enums::{AppError, AppResult};
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::io::plaintext::token_gloss;
+use crate::libs::Segment;
+use crate::libs::eval::FormationRule;
+
+/// Premultiplied RGBA in `0.0..=1.0`
+type Rgba = [f32; 4];
+
+/// Render a segment's interlinear layout (tokens over glosses, then the translation)
+/// to an image on a white background.
+///
+/// The layout runs through a headless egui context using `fonts`, and the resulting
+/// meshes are rasterized in software, so no window or GPU is needed. `font_family`
+/// names the sentence font family, defaulting to the project sentence font.
+#[must_use]
+pub fn render_segment_image(
+    segment: &Segment,
+    vocabulary: &HashMap<String, String>,
+    formation_rules: &[FormationRule],
+    fonts: egui::FontDefinitions,
+    font_family: Option<&str>,
+) -> RgbaImage {
+    let ctx = egui::Context::default();
+    ctx.set_fonts(fonts);
+    ctx.set_visuals(egui::Visuals::light());
+
+    let family = egui::FontFamily::Name(font_family.unwrap_or(DEFAULT_SENTENCE_FONT).into());
+    let glosses: Vec<String> = segment
+        .tokens
+        .iter()
+        .map(|token| token_gloss(token, vocabulary, formation_rules))
+        .collect();
+
+    let mut input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(SEGMENT_IMAGE_WIDTH, SEGMENT_IMAGE_MAX_HEIGHT),
+        )),
+        ..Default::default()
+    };
+    input.viewports.insert(
+        egui::ViewportId::ROOT,
+        egui::ViewportInfo {
+            native_pixels_per_point: Some(SEGMENT_IMAGE_SCALE),
+            ..Default::default()
+        },
+    );
+
+    let mut textures = HashMap::new();
+    let mut content = egui::Rect::NOTHING;
+    let mut primitives = Vec::new();
+    let mut pixels_per_point = SEGMENT_IMAGE_SCALE;
+
+    // Areas measure themselves in an invisible first pass, so lay out twice and keep
+    // the second frame's shapes (and every frame's font atlas updates)
+    for _ in 0..2 {
+        let output = ctx.run_ui(input.clone(), |ui| {
+            content = egui::Area::new(egui::Id::new("segment_image"))
+                .fixed_pos(egui::Pos2::ZERO)
+                .show(ui.ctx(), |ui| {
+                    egui::Frame::NONE
+                        .fill(egui::Color32::WHITE)
+                        .inner_margin(SEGMENT_IMAGE_PADDING)
+                        .show(ui, |ui| {
+                            ui.set_max_width(SEGMENT_IMAGE_WIDTH - 2.0 * SEGMENT_IMAGE_PADDING);
+                            ui.horizontal_wrapped(|ui| {
+                                ui.spacing_mut().item_spacing.x = TOKEN_SPACING_X * 2.0;
+                                for (token, gloss) in segment.tokens.iter().zip(&glosses) {
+                                    ui.vertical(|ui| {
+                                        ui.label(
+                                            egui::RichText::new(&token.original)
+                                                .family(family.clone())
+                                                .size(TOKEN_FONT_SIZE)
+                                                .color(egui::Color32::BLACK),
+                                        );
+                                        ui.label(
+                                            egui::RichText::new(gloss)
+                                                .size(GLOSS_FONT_SIZE)
+                                                .color(egui::Color32::DARK_GRAY),
+                                        );
+                                    });
+                                }
+                            });
+                            if !segment.translation.is_empty() {
+                                ui.add_space(SEGMENT_VERTICAL_SPACING);
+                                ui.label(
+                                    egui::RichText::new(&segment.translation)
+                                        .italics()
+                                        .color(egui::Color32::BLACK),
+                                );
+                            }
+                        });
+                })
+                .response
+                .rect;
+        });
+
+        for (id, delta) in &output.textures_delta.set {
+            apply_texture_delta(&mut textures, *id, delta);
+        }
+        pixels_per_point = output.pixels_per_point;
+        primitives = ctx.tessellate(output.shapes, pixels_per_point);
+    }
+
+    rasterize(&primitives, &textures, pixels_per_point, content)
+}
+
+/// Encode an image as PNG bytes
+pub fn encode_png(image: &RgbaImage) -> AppResult<Vec<u8>> {
+    let mut bytes = Cursor::new(Vec::new());
+    image
+        .write_to(&mut bytes, ImageFormat::Png)
+        .map_err(|e| AppError::IoError(format!("Failed to encode PNG: {e}")))?;
+    Ok(bytes.into_inner())
+}
+
+fn apply_texture_delta(
+    textures: &mut HashMap<egui::TextureId, egui::ColorImage>,
+    id: egui::TextureId,
+    delta: &egui::epaint::ImageDelta,
+) {
+    #[allow(irrefutable_let_patterns)]
+    let egui::ImageData::Color(image) = &delta.image else {
+        return;
+    };
+
+    let Some([x0, y0]) = delta.pos else {
+        textures.insert(id, image.as_ref().clone());
+        return;
+    };
+    let Some(target) = textures.get_mut(&id) else {
+        return;
+    };
+    let [width, height] = image.size;
+    let target_width = target.size[0];
+    for y in 0..height {
+        let src = &image.pixels[y * width..(y + 1) * width];
+        let start = (y0 + y) * target_width + x0;
+        if let Some(dst) = target.pixels.get_mut(start..start + width) {
+            dst.copy_from_slice(src);
+        }
+    }
+}
+
+fn rasterize(
+    primitives: &[ClippedPrimitive],
+    textures: &HashMap<egui::TextureId, egui::ColorImage>,
+    pixels_per_point: f32,
+    content: egui::Rect,
+) -> RgbaImage {
+    let width = (content.width() * pixels_per_point).ceil().max(1.0) as u32;
+    let height = (content.height() * pixels_per_point).ceil().max(1.0) as u32;
+    let mut canvas = vec![[1.0; 4]; width as usize * height as usize];
+
+    for ClippedPrimitive {
+        clip_rect,
+        primitive,
+    } in primitives
+    {
+        let Primitive::Mesh(mesh) = primitive else {
+            continue;
+        };
+        let to_pixels = |pos: egui::Pos2| (pos - content.min) * pixels_per_point;
+        let clip_min = to_pixels(clip_rect.min);
+        let clip_max = to_pixels(clip_rect.max);
+        let bounds = [
+            clip_min.x.max(0.0),
+            clip_min.y.max(0.0),
+            clip_max.x.min(width as f32),
+            clip_max.y.min(height as f32),
+        ];
+        let texture = textures.get(&mesh.texture_id);
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertex = |i: u32| mesh.vertices.get(i as usize);
+            let (Some(a), Some(b), Some(c)) = (
+                vertex(triangle[0]),
+                vertex(triangle[1]),
+                vertex(triangle[2]),
+            ) else {
+                continue;
+            };
+            let corners = [a, b, c].map(|v| to_pixels(v.pos));
+            fill_triangle(&mut canvas, width, bounds, [a, b, c], corners, texture);
+        }
+    }
+
+    let mut image = RgbaImage::new(width, height);
+    for (pixel, color) in image.pixels_mut().zip(&canvas) {
+        // The canvas is opaque, so premultiplied and straight alpha agree
+        pixel.0 = color.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+    }
+    image
+}
+
+fn fill_triangle(
+    canvas: &mut [Rgba],
+    width: u32,
+    bounds: [f32; 4],
+    vertices: [&Vertex; 3],
+    corners: [egui::Vec2; 3],
+    texture: Option<&egui::ColorImage>,
+) {
+    let edge = |p: egui::Vec2, q: egui::Vec2, r: egui::Vec2| {
+        (q.x - p.x) * (r.y - p.y) - (q.y - p.y) * (r.x - p.x)
+    };
+    let [p0, p1, p2] = corners;
+    let area = edge(p0, p1, p2);
+    if area.abs() < f32::EPSILON {
+        return;
+    }
+
+    let min_x = p0.x.min(p1.x).min(p2.x).max(bounds[0]).floor() as u32;
+    let min_y = p0.y.min(p1.y).min(p2.y).max(bounds[1]).floor() as u32;
+    let max_x = p0.x.max(p1.x).max(p2.x).min(bounds[2]).ceil() as u32;
+    let max_y = p0.y.max(p1.y).max(p2.y).min(bounds[3]).ceil() as u32;
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let center = egui::vec2(x as f32 + 0.5, y as f32 + 0.5);
+            let weights = [
+                edge(p1, p2, center) / area,
+                edge(p2, p0, center) / area,
+                edge(p0, p1, center) / area,
+            ];
+            if weights.iter().any(|&w| w < 0.0) {
+                continue;
+            }
+
+            let mut color = [0.0; 4];
+            let mut uv = egui::Pos2::ZERO;
+            for (vertex, weight) in vertices.iter().zip(weights) {
+                for (channel, value) in color.iter_mut().zip(vertex.color.to_array()) {
+                    *channel += f32::from(value) / 255.0 * weight;
+                }
+                uv += vertex.uv.to_vec2() * weight;
+            }
+            let texel = sample(texture, uv);
+            let source: Rgba = std::array::from_fn(|i| color[i] * texel[i]);
+
+            let Some(target) = canvas.get_mut((y * width + x) as usize) else {
+                continue;
+            };
+            let coverage = 1.0 - source[3];
+            for (dst, src) in target.iter_mut().zip(source) {
+                *dst = src + *dst * coverage;
+            }
+        }
+    }
+}
+
+/// Nearest-neighbour lookup of a normalized texture coordinate; untextured meshes are white
+fn sample(texture: Option<&egui::ColorImage>, uv: egui::Pos2) -> Rgba {
+    let Some(texture) = texture else {
+        return [1.0; 4];
+    };
+    let [width, height] = texture.size;
+    if width == 0 || height == 0 {
+        return [1.0; 4];
+    }
+    let x = ((uv.x * width as f32) as usize).min(width - 1);
+    let y = ((uv.y * height as f32) as usize).min(height - 1);
+    texture.pixels[y * width + x]
+        .to_array()
+        .map(|channel| f32::from(channel) / 255.0)
+}
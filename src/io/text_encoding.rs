@@ -0,0 +1,39 @@
+use crate::enums::{AppError, AppResult};
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+const UTF16LE_BOM: &[u8] = &[0xFF, 0xFE];
+const UTF16BE_BOM: &[u8] = &[0xFE, 0xFF];
+
+/// Decode the bytes of an imported text file.
+/// A UTF-8 or UTF-16 (LE/BE) byte order mark selects the encoding and is stripped;
+/// files without one are read as UTF-8.
+pub fn decode_text_bytes(bytes: &[u8]) -> AppResult<String> {
+    if let Some(rest) = bytes.strip_prefix(UTF8_BOM) {
+        return decode_utf8(rest);
+    }
+    if let Some(rest) = bytes.strip_prefix(UTF16LE_BOM) {
+        return decode_utf16(rest, u16::from_le_bytes);
+    }
+    if let Some(rest) = bytes.strip_prefix(UTF16BE_BOM) {
+        return decode_utf16(rest, u16::from_be_bytes);
+    }
+    decode_utf8(bytes)
+}
+
+fn decode_utf8(bytes: &[u8]) -> AppResult<String> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|e| AppError::IoError(format!("Failed to decode file: {e}")))
+}
+
+fn decode_utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> AppResult<String> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(AppError::IoError(
+            "Failed to decode file: UTF-16 data has an odd number of bytes".to_string(),
+        ));
+    }
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| unit([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16(&units).map_err(|e| AppError::IoError(format!("Failed to decode file: {e}")))
+}
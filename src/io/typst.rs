@@ -1,3 +1,4 @@
+use crate::io::glossary::build_glossary_appendix;
 use crate::libs::Project;
 
 pub fn escape_typst(s: &str) -> String {
@@ -54,5 +55,55 @@ pub fn generate_typst_content(project: &Project) -> String {
         content.push_str(&format!("  *trans:* {trans}\n]\n#v(1em)\n"));
     }
 
+    if project.include_glossary_appendix {
+        content.push_str(&glossary_appendix(project));
+    }
+
+    content
+}
+
+/// Render the project as a two-column Typst table for side-by-side review:
+/// each row holds a segment's tokens joined by spaces and its translation.
+/// Segments without a translation get an empty right-hand cell.
+#[must_use]
+pub fn generate_bilingual_typst(project: &Project) -> String {
+    let mut content = String::new();
+    content.push_str("#set page(paper: \"a4\")\n");
+    content.push_str("#set text(size: 12pt)\n");
+    content.push_str(&format!("= {}\n\n", escape_typst(&project.project_name)));
+
+    content.push_str("#table(\n  columns: (1fr, 1fr),\n");
+    content.push_str("  [*Source*], [*Translation*],\n");
+    for segment in &project.segments {
+        let source: Vec<&str> = segment
+            .tokens
+            .iter()
+            .map(|token| token.original.as_str())
+            .collect();
+        content.push_str(&format!(
+            "  [{}], [{}],\n",
+            escape_typst(&source.join(" ")),
+            escape_typst(segment.translation.trim())
+        ));
+    }
+    content.push_str(")\n");
+    content
+}
+
+/// A `Glossary` heading followed by a four-column table of every word, sorted by word
+fn glossary_appendix(project: &Project) -> String {
+    let mut content = String::from("= Glossary\n\n");
+    content.push_str("#table(\n  columns: 4,\n");
+    content.push_str("  [*Word*], [*Gloss*], [*Derivation*], [*Comment*],\n");
+    for entry in build_glossary_appendix(project) {
+        content.push_str(&format!(
+            "  [{}], [{}], [{}], [{}],\n",
+            escape_typst(&entry.word),
+            escape_typst(&entry.gloss),
+            escape_typst(&entry.derivation()),
+            escape_typst(&entry.comment),
+        ));
+    }
+    content.push_str(")\n");
     content
 }
@@ -1,10 +1,20 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use ndarray::Array2;
 
+use crate::libs::Project;
+use crate::libs::normalization::normalize_word;
+use crate::libs::similarity_sentence::{
+    SimilarityEngine, TermOptions, TfidfIndex, TranslationSuggestion,
+};
+
 pub type LookupMap = HashMap<String, Vec<usize>>;
 pub type OptionalLookupMap = Option<LookupMap>;
 pub type LookupMapPair = (OptionalLookupMap, OptionalLookupMap);
+/// Maps a lookup key to `(segment_index, count)` pairs, in segment order
+pub type FrequencyMap = HashMap<String, Vec<(usize, usize)>>;
 
 /// Caches lookup maps for quick token searches across the project.
 /// Stores two separate lookup indices: one for headword (base word) lookups
@@ -15,6 +25,20 @@ pub struct LookupCache {
     headword_lookup: OptionalLookupMap,
     /// Maps words to all segment+token indices where they appear (including derived forms)
     usage_lookup: OptionalLookupMap,
+    /// Per-segment occurrence counts of every key, kept alongside the maps so the
+    /// References popup does not rescan the project each frame
+    frequencies: FrequencyMap,
+    /// Keys each segment contributed, so a single segment can be re-indexed without a rebuild
+    segment_keys: Vec<SegmentKeys>,
+}
+
+/// Lookup keys contributed by one segment
+#[derive(Debug, Clone, Default)]
+struct SegmentKeys {
+    /// Key of the segment's first token
+    headword: Option<String>,
+    /// Distinct keys of all tokens in the segment, with how often each occurs
+    usage: Vec<(String, usize)>,
 }
 
 impl LookupCache {
@@ -24,7 +48,95 @@ impl LookupCache {
         Self {
             headword_lookup: None,
             usage_lookup: None,
+            frequencies: FrequencyMap::new(),
+            segment_keys: Vec::new(),
+        }
+    }
+
+    /// Rebuild both lookup maps from every segment in the project
+    pub fn rebuild(&mut self, project: &Project) {
+        self.invalidate();
+        if project.segments.is_empty() {
+            return;
+        }
+
+        let mut headmap = LookupMap::new();
+        let mut usagemap = LookupMap::new();
+        self.segment_keys = (0..project.segments.len())
+            .map(|idx| segment_keys(project, idx))
+            .collect();
+
+        for (idx, keys) in self.segment_keys.iter().enumerate() {
+            if let Some(head) = &keys.headword {
+                headmap.entry(head.clone()).or_default().push(idx);
+            }
+            for (key, count) in &keys.usage {
+                usagemap.entry(key.clone()).or_default().push(idx);
+                self.frequencies
+                    .entry(key.clone())
+                    .or_default()
+                    .push((idx, *count));
+            }
         }
+
+        self.headword_lookup = Some(headmap);
+        self.usage_lookup = Some(usagemap);
+    }
+
+    /// Re-index a single segment whose tokens changed.
+    /// Returns `false` when the cache cannot be patched (maps missing, or segments were
+    /// added or removed since the last rebuild); callers should fall back to `rebuild`.
+    pub fn update_segment(&mut self, project: &Project, seg_idx: usize) -> bool {
+        if self.segment_keys.len() != project.segments.len() || seg_idx >= project.segments.len() {
+            return false;
+        }
+        let (Some(headmap), Some(usagemap)) =
+            (self.headword_lookup.as_mut(), self.usage_lookup.as_mut())
+        else {
+            return false;
+        };
+
+        let old_keys = std::mem::replace(
+            &mut self.segment_keys[seg_idx],
+            segment_keys(project, seg_idx),
+        );
+        let new_keys = &self.segment_keys[seg_idx];
+
+        if let Some(head) = &old_keys.headword {
+            remove_index(headmap, head, seg_idx);
+        }
+        for (key, _) in &old_keys.usage {
+            remove_index(usagemap, key, seg_idx);
+            remove_count(&mut self.frequencies, key, seg_idx);
+        }
+        if let Some(head) = &new_keys.headword {
+            insert_index(headmap, head, seg_idx);
+        }
+        for (key, count) in &new_keys.usage {
+            insert_index(usagemap, key, seg_idx);
+            insert_count(&mut self.frequencies, key, seg_idx, *count);
+        }
+        true
+    }
+
+    /// Headword lookup map, if built and not currently taken
+    #[allow(dead_code)]
+    pub fn headword_lookup(&self) -> Option<&LookupMap> {
+        self.headword_lookup.as_ref()
+    }
+
+    /// Usage lookup map, if built and not currently taken
+    #[allow(dead_code)]
+    pub fn usage_lookup(&self) -> Option<&LookupMap> {
+        self.usage_lookup.as_ref()
+    }
+
+    /// Total occurrences of a lookup key and its `(segment_index, count)` pairs.
+    /// `key` must already be in lookup form (see `lookup_key`).
+    pub fn token_frequency(&self, key: &str) -> (usize, &[(usize, usize)]) {
+        let per_segment = self.frequencies.get(key).map_or(&[][..], Vec::as_slice);
+        let total = per_segment.iter().map(|(_, count)| count).sum();
+        (total, per_segment)
     }
 
     /// Extract both lookup maps from the cache (ownership transfer).
@@ -43,6 +155,78 @@ impl LookupCache {
     pub fn invalidate(&mut self) {
         self.headword_lookup = None;
         self.usage_lookup = None;
+        self.frequencies.clear();
+        self.segment_keys.clear();
+    }
+}
+
+/// Key used for the headword/usage lookup maps, normalized when the project asks for it
+pub fn lookup_key(normalize: bool, word: &str) -> Cow<'_, str> {
+    if normalize {
+        Cow::Owned(normalize_word(word))
+    } else {
+        Cow::Borrowed(word)
+    }
+}
+
+fn segment_keys(project: &Project, seg_idx: usize) -> SegmentKeys {
+    let normalize = project.normalize_lookups;
+    let tokens = &project.segments[seg_idx].tokens;
+
+    let headword = tokens
+        .first()
+        .map(|token| lookup_key(normalize, &token.original).into_owned());
+
+    let mut usage: Vec<(String, usize)> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
+    for token in tokens {
+        let key = lookup_key(normalize, &token.original).into_owned();
+        match positions.get(&key) {
+            Some(&pos) => usage[pos].1 += 1,
+            None => {
+                positions.insert(key.clone(), usage.len());
+                usage.push((key, 1));
+            }
+        }
+    }
+
+    SegmentKeys { headword, usage }
+}
+
+fn remove_index(map: &mut LookupMap, key: &str, seg_idx: usize) {
+    if let Some(indices) = map.get_mut(key) {
+        if let Ok(pos) = indices.binary_search(&seg_idx) {
+            indices.remove(pos);
+        }
+        if indices.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+fn insert_index(map: &mut LookupMap, key: &str, seg_idx: usize) {
+    let indices = map.entry(key.to_string()).or_default();
+    if let Err(pos) = indices.binary_search(&seg_idx) {
+        indices.insert(pos, seg_idx);
+    }
+}
+
+fn remove_count(map: &mut FrequencyMap, key: &str, seg_idx: usize) {
+    if let Some(counts) = map.get_mut(key) {
+        if let Ok(pos) = counts.binary_search_by_key(&seg_idx, |&(idx, _)| idx) {
+            counts.remove(pos);
+        }
+        if counts.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+fn insert_count(map: &mut FrequencyMap, key: &str, seg_idx: usize, count: usize) {
+    let counts = map.entry(key.to_string()).or_default();
+    match counts.binary_search_by_key(&seg_idx, |&(idx, _)| idx) {
+        Ok(pos) => counts[pos].1 = count,
+        Err(pos) => counts.insert(pos, (seg_idx, count)),
     }
 }
 
@@ -52,17 +236,40 @@ impl LookupCache {
 pub struct CachedTfidf {
     /// The cached TF-IDF matrix (None means cache is invalid/dirty)
     matrix: Option<Array2<f64>>,
+    /// Term statistics the matrix was built from, used for incremental updates
+    index: Option<TfidfIndex>,
 }
 
 impl CachedTfidf {
     /// Create a new empty TF-IDF cache
     pub fn new() -> Self {
-        Self { matrix: None }
+        Self {
+            matrix: None,
+            index: None,
+        }
     }
 
-    /// Store a computed TF-IDF matrix in the cache
-    pub fn set_matrix(&mut self, matrix: Array2<f64>) {
-        self.matrix = Some(matrix);
+    /// Rebuild the index and matrix from every segment in the project
+    pub fn rebuild(&mut self, project: &Project) {
+        self.index = SimilarityEngine::build_index(project);
+        self.matrix = self.index.as_ref().map(TfidfIndex::matrix);
+    }
+
+    /// Re-weight the matrix after one segment's tokens changed.
+    /// Returns `false` when there is nothing to patch or segments were added or removed;
+    /// callers should then invalidate and rebuild.
+    pub fn update_segment(&mut self, project: &Project, seg_idx: usize) -> bool {
+        let (Some(index), Some(matrix)) = (self.index.as_mut(), self.matrix.as_mut()) else {
+            return false;
+        };
+        let Some(segment) = project.segments.get(seg_idx) else {
+            return false;
+        };
+        if index.num_documents() != project.segments.len() {
+            return false;
+        }
+        let terms = SimilarityEngine::document_terms(segment, TermOptions::from_project(project));
+        index.update_document(seg_idx, &terms, matrix)
     }
 
     /// Retrieve a reference to the cached TF-IDF matrix, if available
@@ -78,6 +285,7 @@ impl CachedTfidf {
     /// Clear the cached matrix
     pub fn invalidate(&mut self) {
         self.matrix = None;
+        self.index = None;
     }
 }
 
@@ -94,3 +302,118 @@ impl std::fmt::Debug for CachedTfidf {
             .finish()
     }
 }
+
+/// Finished background rebuild: the index and its matrix, or `None` for a project without segments
+type TfidfBuild = Option<(TfidfIndex, Array2<f64>)>;
+
+/// A TF-IDF rebuild running off the update loop, polled every frame like the pending file
+/// operations. Native targets build on a worker thread; wasm has no threads, so the rebuild
+/// runs immediately and its result is picked up by the next poll.
+#[derive(Debug, Default)]
+pub struct BackgroundTfidf {
+    /// Filled by the worker when the rebuild finishes
+    result: Arc<Mutex<Option<TfidfBuild>>>,
+    /// Whether a rebuild was started and its result not yet taken
+    running: bool,
+}
+
+impl BackgroundTfidf {
+    /// Start rebuilding from a snapshot of the project's segments and similarity settings.
+    /// Does nothing while a rebuild is already running.
+    pub fn start(&mut self, project: &Project) {
+        if self.running {
+            return;
+        }
+        self.running = true;
+
+        let segments = project.segments.clone();
+        let stopwords = project.stopwords.clone();
+        let by_base_word = project.similarity_by_base_word;
+        let result = Arc::clone(&self.result);
+        let job = move || {
+            let build = (!segments.is_empty()).then(|| {
+                let options = TermOptions {
+                    by_base_word,
+                    stopwords: &stopwords,
+                };
+                let index = TfidfIndex::build_documents(&segments, options);
+                let matrix = index.matrix();
+                (index, matrix)
+            });
+            if let Ok(mut guard) = result.lock() {
+                *guard = Some(build);
+            }
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        std::thread::spawn(job);
+        #[cfg(target_arch = "wasm32")]
+        job();
+    }
+
+    /// Whether a rebuild is in progress or finished but not yet polled
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Take the finished rebuild as a ready cache, or `None` while it is still running
+    /// (or when none was started)
+    pub fn poll(&mut self) -> Option<CachedTfidf> {
+        let build = self.result.try_lock().ok()?.take()?;
+        self.running = false;
+        let (index, matrix) = build.unzip();
+        Some(CachedTfidf { matrix, index })
+    }
+}
+
+/// Translation suggestions per segment index.
+/// Edits clear the cache and hold off recomputing until they pause, so typing in a
+/// translation box does not re-rank segments on every keystroke.
+#[derive(Debug, Clone, Default)]
+pub struct SuggestionCache {
+    entries: HashMap<usize, Vec<TranslationSuggestion>>,
+    /// Time of the last edit that has not yet been waited out
+    edited_at: Option<f64>,
+}
+
+impl SuggestionCache {
+    /// Cached suggestions for a segment, if they have been computed
+    pub fn get(&self, seg_idx: usize) -> Option<&[TranslationSuggestion]> {
+        self.entries.get(&seg_idx).map(Vec::as_slice)
+    }
+
+    /// Store the suggestions computed for a segment
+    pub fn insert(&mut self, seg_idx: usize, suggestions: Vec<TranslationSuggestion>) {
+        self.entries.insert(seg_idx, suggestions);
+    }
+
+    /// Whether suggestions for a segment have been computed
+    pub fn contains(&self, seg_idx: usize) -> bool {
+        self.entries.contains_key(&seg_idx)
+    }
+
+    /// Drop every entry after an edit made at time `now`
+    pub fn invalidate(&mut self, now: f64) {
+        self.entries.clear();
+        self.edited_at = Some(now);
+    }
+
+    /// Drop every entry without waiting before the next computation
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.edited_at = None;
+    }
+
+    /// Seconds still to wait at time `now` before recomputing, or `None` once `delay`
+    /// seconds have passed since the last edit
+    pub fn remaining_delay(&mut self, now: f64, delay: f64) -> Option<f64> {
+        let edited_at = self.edited_at?;
+        let remaining = edited_at + delay - now;
+        if remaining > 0.0 {
+            Some(remaining)
+        } else {
+            self.edited_at = None;
+            None
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+/// Running tally of edits made since the project was last saved or loaded
+///
+/// Glosses and translations are tracked as sets so repeated keystrokes in the same
+/// gloss box or translation count once; added formation rules are counted directly.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeSummary {
+    glosses: HashSet<String>,
+    translations: HashSet<usize>,
+    rules_added: usize,
+}
+
+impl ChangeSummary {
+    /// Note that the gloss of vocabulary word `word` was edited
+    pub fn record_gloss(&mut self, word: &str) {
+        if !self.glosses.contains(word) {
+            self.glosses.insert(word.to_string());
+        }
+    }
+
+    /// Note that the translation of segment `seg_idx` was edited
+    pub fn record_translation(&mut self, seg_idx: usize) {
+        self.translations.insert(seg_idx);
+    }
+
+    /// Note that a formation rule was added
+    pub const fn record_rule_added(&mut self) {
+        self.rules_added += 1;
+    }
+
+    /// Number of distinct vocabulary words whose gloss was edited
+    pub fn gloss_count(&self) -> usize {
+        self.glosses.len()
+    }
+
+    /// Number of distinct segments whose translation was edited
+    pub fn translation_count(&self) -> usize {
+        self.translations.len()
+    }
+
+    /// Whether no tracked edit has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.glosses.is_empty() && self.translations.is_empty() && self.rules_added == 0
+    }
+
+    /// Forget all recorded edits, e.g. after the project is saved or replaced
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// One human-readable line per non-zero tally, e.g. `"3 glosses edited"`
+    pub fn lines(&self) -> Vec<String> {
+        [
+            (self.gloss_count(), "gloss", "glosses", "edited"),
+            (
+                self.translation_count(),
+                "translation",
+                "translations",
+                "edited",
+            ),
+            (
+                self.rules_added,
+                "formation rule",
+                "formation rules",
+                "added",
+            ),
+        ]
+        .into_iter()
+        .filter(|&(count, ..)| count > 0)
+        .map(|(count, singular, plural, verb)| {
+            let noun = if count == 1 { singular } else { plural };
+            format!("{count} {noun} {verb}")
+        })
+        .collect()
+    }
+}
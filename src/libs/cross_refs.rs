@@ -0,0 +1,70 @@
+use std::ops::Range;
+
+/// A piece of comment text, either plain or a link to a segment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentSpan<'a> {
+    /// Text rendered as-is
+    Text(&'a str),
+    /// A `[n]` reference to an existing segment (0-based index)
+    SegmentLink { label: &'a str, segment_idx: usize },
+}
+
+/// Find every `[n]` segment reference in a comment.
+/// Returns the byte range of each reference (brackets included) and the 1-based number written.
+/// Brackets without a plain run of ASCII digits between them are ignored.
+pub fn parse_segment_refs(text: &str) -> Vec<(Range<usize>, usize)> {
+    let bytes = text.as_bytes();
+    let mut refs = Vec::new();
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        if bytes[pos] != b'[' {
+            pos += 1;
+            continue;
+        }
+
+        let digits_start = pos + 1;
+        let digits_end = bytes[digits_start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |offset| digits_start + offset);
+
+        if digits_end > digits_start
+            && bytes.get(digits_end) == Some(&b']')
+            && let Ok(number) = text[digits_start..digits_end].parse::<usize>()
+        {
+            refs.push((pos..digits_end + 1, number));
+            pos = digits_end + 1;
+        } else {
+            pos += 1;
+        }
+    }
+
+    refs
+}
+
+/// Split a comment into plain text and links to segments that exist.
+/// References outside `1..=segment_count` stay plain text.
+pub fn split_segment_refs(text: &str, segment_count: usize) -> Vec<CommentSpan<'_>> {
+    let mut spans = Vec::new();
+    let mut last = 0;
+
+    for (range, number) in parse_segment_refs(text) {
+        if number == 0 || number > segment_count {
+            continue;
+        }
+        if range.start > last {
+            spans.push(CommentSpan::Text(&text[last..range.start]));
+        }
+        spans.push(CommentSpan::SegmentLink {
+            label: &text[range.clone()],
+            segment_idx: number - 1,
+        });
+        last = range.end;
+    }
+
+    if last < text.len() {
+        spans.push(CommentSpan::Text(&text[last..]));
+    }
+    spans
+}
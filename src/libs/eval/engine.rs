@@ -1,6 +1,7 @@
 use std::cell::RefCell;
 
 use crate::consts::domain::{MAX_SCRIPT_DEPTH, MAX_SCRIPT_OPERATIONS};
+use crate::enums::AppError;
 
 thread_local! {
     /// Thread-local Rhai script engine for executing word formation rules and tokenization.
@@ -37,10 +38,95 @@ fn build_engine() -> rhai::Engine {
     engine.disable_symbol("spawn");
     engine.disable_symbol("command");
 
+    register_string_helpers(&mut engine);
+
     engine
 }
 
+/// Helper functions available to every script, as `(signature, description)` for the cheat-sheet
+pub const SCRIPT_HELPERS: &[(&str, &str)] = &[
+    ("reverse(text)", "Characters of text in reverse order"),
+    (
+        "capitalize(text)",
+        "Text with its first character uppercased",
+    ),
+    (
+        "replace_prefix(text, prefix, with)",
+        "Swap a leading prefix for with; unchanged if text does not start with prefix",
+    ),
+    (
+        "replace_suffix(text, suffix, with)",
+        "Swap a trailing suffix for with; unchanged if text does not end with suffix",
+    ),
+];
+
+/// Register pure string helpers; none of them touch I/O, so the sandbox is unaffected
+fn register_string_helpers(engine: &mut rhai::Engine) {
+    engine.register_fn("reverse", |text: &str| {
+        text.chars().rev().collect::<String>()
+    });
+    engine.register_fn("capitalize", |text: &str| {
+        let mut chars = text.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+            None => String::new(),
+        }
+    });
+    engine.register_fn(
+        "replace_prefix",
+        |text: &str, prefix: &str, with: &str| match text.strip_prefix(prefix) {
+            Some(rest) => format!("{with}{rest}"),
+            None => text.to_string(),
+        },
+    );
+    engine.register_fn(
+        "replace_suffix",
+        |text: &str, suffix: &str, with: &str| match text.strip_suffix(suffix) {
+            Some(rest) => format!("{rest}{with}"),
+            None => text.to_string(),
+        },
+    );
+}
+
 /// Execute a closure with access to the shared Rhai engine
 pub fn with_engine<R>(f: impl FnOnce(&rhai::Engine) -> R) -> R {
     ENGINE.with(|engine| f(&engine.borrow()))
 }
+
+fn operation_limit_error() -> AppError {
+    AppError::ScriptLimitExceeded(format!(
+        "operation limit of {MAX_SCRIPT_OPERATIONS} exceeded; simplify the script or shorten its loops"
+    ))
+}
+
+fn depth_limit_error() -> AppError {
+    AppError::ScriptLimitExceeded(
+        "depth limit exceeded; reduce nesting or recursion in the script".to_string(),
+    )
+}
+
+/// Convert a Rhai compilation error, singling out expressions nested past the depth limit
+pub fn compile_error(error: &rhai::ParseError) -> AppError {
+    match error.err_type() {
+        rhai::ParseErrorType::ExprTooDeep => depth_limit_error(),
+        _ => AppError::ScriptExecutionError(format!("Rhai compilation error: {error}")),
+    }
+}
+
+/// Convert a Rhai runtime error, singling out the engine's operation and depth limits.
+/// `context` names the failing step for ordinary errors, e.g. "Transform function".
+pub fn eval_error(context: &str, error: &rhai::EvalAltResult) -> AppError {
+    match error {
+        rhai::EvalAltResult::ErrorTooManyOperations(_) => operation_limit_error(),
+        rhai::EvalAltResult::ErrorStackOverflow(_) => depth_limit_error(),
+        rhai::EvalAltResult::ErrorParsing(rhai::ParseErrorType::ExprTooDeep, _) => {
+            depth_limit_error()
+        }
+        rhai::EvalAltResult::ErrorInFunctionCall(_, _, inner, _)
+        | rhai::EvalAltResult::ErrorInModule(_, inner, _) => match eval_error(context, inner) {
+            limit @ AppError::ScriptLimitExceeded(_) => limit,
+            _ => AppError::ScriptExecutionError(format!("{context} error: {error}")),
+        },
+        _ => AppError::ScriptExecutionError(format!("{context} error: {error}")),
+    }
+}
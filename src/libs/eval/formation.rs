@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::cell::OnceCell;
 use std::rc::Rc;
 
-use super::engine::with_engine;
+use super::engine::{compile_error, eval_error, with_engine};
 use crate::enums::{AppError, AppResult, FormationType};
 
 /// Create a new empty cached AST (Abstract Syntax Tree) placeholder
@@ -25,6 +25,10 @@ pub struct FormationRule {
     /// Must define a `transform(word: String) -> String` function.
     pub command: String,
 
+    /// Free-form note on why the rule exists and where it comes from
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub note: String,
+
     /// Compiled AST of the Rhai script, cached for performance.
     /// Lazily compiled on first execution and reused thereafter.
     #[serde(skip, default = "default_cached_ast")]
@@ -37,9 +41,9 @@ impl FormationRule {
     pub fn apply(&self, word: &str) -> AppResult<String> {
         with_engine(|engine| {
             if self.cached_ast.get().is_none() {
-                let ast = engine.compile(&self.command).map_err(|e| {
-                    AppError::ScriptExecutionError(format!("Rhai compilation error: {e}"))
-                })?;
+                let ast = engine
+                    .compile(&self.command)
+                    .map_err(|e| compile_error(&e))?;
                 let _ = self.cached_ast.set(ast);
             }
 
@@ -54,11 +58,78 @@ impl FormationRule {
                     "transform",
                     (word.to_string(),),
                 )
-                .map_err(|e| {
-                    AppError::ScriptExecutionError(format!("Transform function error: {e}"))
-                })?;
+                .map_err(|e| eval_error("Transform function", &e))?;
 
             Ok(result)
         })
     }
+
+    /// Whether applying this rule to `base_word` reproduces `word` exactly.
+    /// Script errors count as a mismatch.
+    pub fn round_trips(&self, base_word: &str, word: &str) -> bool {
+        self.apply(base_word).is_ok_and(|result| result == word)
+    }
+}
+
+/// Whether a rule's output for `base_word` is just `base_word` again.
+/// A rule that changes nothing was most likely picked for the wrong word or is miswritten.
+#[must_use]
+pub fn is_noop_result(base_word: &str, result: &str) -> bool {
+    !base_word.is_empty() && base_word == result
+}
+
+/// One step of a derivation chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormationStep {
+    /// The rule applied cleanly, producing `result`
+    Applied {
+        rule_idx: usize,
+        description: String,
+        rule_type: FormationType,
+        result: String,
+    },
+    /// The rule's script failed; the surface form carries over unchanged
+    Failed {
+        rule_idx: usize,
+        description: String,
+        rule_type: FormationType,
+        error: String,
+    },
+    /// The token references a rule index the project doesn't have
+    MissingRule { rule_idx: usize },
+}
+
+/// Re-derive a token by applying each rule in `rule_indices` to `base_word` in order,
+/// recording the intermediate surface form after every step
+pub fn formation_chain(
+    base_word: &str,
+    rule_indices: &[usize],
+    rules: &[FormationRule],
+) -> Vec<FormationStep> {
+    let mut current = base_word.to_string();
+    rule_indices
+        .iter()
+        .map(|&rule_idx| {
+            let Some(rule) = rules.get(rule_idx) else {
+                return FormationStep::MissingRule { rule_idx };
+            };
+            match rule.apply(&current) {
+                Ok(result) => {
+                    current.clone_from(&result);
+                    FormationStep::Applied {
+                        rule_idx,
+                        description: rule.description.clone(),
+                        rule_type: rule.rule_type,
+                        result,
+                    }
+                }
+                Err(e) => FormationStep::Failed {
+                    rule_idx,
+                    description: rule.description.clone(),
+                    rule_type: rule.rule_type,
+                    error: e.to_string(),
+                },
+            }
+        })
+        .collect()
 }
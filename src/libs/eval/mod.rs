@@ -2,6 +2,8 @@ pub mod engine;
 pub mod formation;
 pub mod tokenization;
 
-pub use engine::with_engine;
-pub use formation::{FormationRule, default_cached_ast};
+pub use engine::{SCRIPT_HELPERS, eval_error, with_engine};
+pub use formation::{
+    FormationRule, FormationStep, default_cached_ast, formation_chain, is_noop_result,
+};
 pub use tokenization::TokenizationRule;
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::cell::OnceCell;
 use std::rc::Rc;
 
-use super::engine::with_engine;
+use super::engine::{compile_error, eval_error, with_engine};
 use super::formation::default_cached_ast;
 use crate::enums::{AppError, AppResult};
 
@@ -29,9 +29,9 @@ impl TokenizationRule {
     pub fn tokenize(&self, line: &str) -> AppResult<Vec<String>> {
         with_engine(|engine| {
             if self.cached_ast.get().is_none() {
-                let ast = engine.compile(&self.command).map_err(|e| {
-                    AppError::ScriptExecutionError(format!("Rhai compilation error: {e}"))
-                })?;
+                let ast = engine
+                    .compile(&self.command)
+                    .map_err(|e| compile_error(&e))?;
                 let _ = self.cached_ast.set(ast);
             }
 
@@ -46,9 +46,7 @@ impl TokenizationRule {
                     "tokenize",
                     (line.to_string(),),
                 )
-                .map_err(|e| {
-                    AppError::ScriptExecutionError(format!("Tokenize function error: {e}"))
-                })?;
+                .map_err(|e| eval_error("Tokenize function", &e))?;
 
             let tokens: Vec<String> = result
                 .into_iter()
@@ -59,6 +57,31 @@ impl TokenizationRule {
         })
     }
 
+    /// Check that `tokens` reproduce `line` exactly, ignoring whitespace.
+    /// A mismatch means the rule dropped, duplicated, or altered characters.
+    pub fn verify(line: &str, tokens: &[String]) -> bool {
+        let produced = tokens
+            .iter()
+            .flat_map(|token| token.chars())
+            .filter(|c| !c.is_whitespace());
+        line.chars().filter(|c| !c.is_whitespace()).eq(produced)
+    }
+
+    /// Tokenize every non-empty line of `text` and return the 1-based line numbers
+    /// whose tokens fail [`Self::verify`]
+    pub fn find_lossy_lines(&self, text: &str) -> AppResult<Vec<usize>> {
+        let mut lossy = Vec::new();
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if !Self::verify(line, &self.tokenize(line)?) {
+                lossy.push(idx + 1);
+            }
+        }
+        Ok(lossy)
+    }
+
     pub fn default_whitespace() -> Self {
         Self {
             description: "Split by whitespace".to_string(),
@@ -1,4 +1,96 @@
-use crate::libs::Project;
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::consts::domain::FILTER_HISTORY_CAPACITY;
+use crate::libs::text_analysis::translated_ratio;
+use crate::libs::{Project, Segment};
+
+/// Recently used filter queries, most recent first, without duplicates
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FilterHistory {
+    entries: Vec<String>,
+}
+
+impl FilterHistory {
+    /// Record `query` as the most recent entry.
+    /// Blank queries are ignored, surrounding whitespace is trimmed, an earlier copy of the
+    /// same query moves to the front, and the oldest entries beyond
+    /// `FILTER_HISTORY_CAPACITY` are dropped.
+    pub fn push(&mut self, query: &str) {
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        if let Some(pos) = self.entries.iter().position(|entry| entry == query) {
+            let entry = self.entries.remove(pos);
+            self.entries.insert(0, entry);
+            return;
+        }
+        self.entries.insert(0, query.to_string());
+        self.entries.truncate(FILTER_HISTORY_CAPACITY);
+    }
+
+    /// Stored queries, most recent first
+    pub fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// Whether no query has been recorded
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// A filter query split into its structured terms and free text
+struct QueryTerms<'a> {
+    /// Tags from `tag:name` terms, all of which a segment must carry
+    tags: Vec<&'a str>,
+    /// Review status from the last `reviewed:` term, if any
+    reviewed: Option<bool>,
+    /// Bounds from `complete:` terms on the percentage of translated tokens, all of which
+    /// a segment must satisfy
+    complete: Vec<(Comparison, f64)>,
+    /// Remaining terms, matched against tokens and translations
+    text: Cow<'a, str>,
+}
+
+/// Comparison operator of a numeric filter term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+}
+
+impl Comparison {
+    /// Split a leading operator off `value`; a bare number compares for equality
+    fn parse(value: &str) -> (Self, &str) {
+        [
+            ("<=", Self::LessOrEqual),
+            (">=", Self::GreaterOrEqual),
+            ("<", Self::Less),
+            (">", Self::Greater),
+            ("=", Self::Equal),
+        ]
+        .into_iter()
+        .find_map(|(prefix, op)| value.strip_prefix(prefix).map(|rest| (op, rest)))
+        .unwrap_or((Self::Equal, value))
+    }
+
+    fn holds(self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Self::Less => lhs < rhs,
+            Self::LessOrEqual => lhs <= rhs,
+            Self::Greater => lhs > rhs,
+            Self::GreaterOrEqual => lhs >= rhs,
+            Self::Equal => (lhs - rhs).abs() < 1e-9,
+        }
+    }
+}
 
 /// Text filtering and search operations for finding relevant segments.
 pub struct FilterOperation;
@@ -19,26 +111,147 @@ impl FilterOperation {
 
     /// Filter segment indices to those matching the query string.
     /// A segment matches if the query appears in its translation text or in any of its tokens.
+    /// Terms written `tag:name` instead require the segment to carry that tag, and
+    /// `reviewed:true` or `reviewed:false` its review status, and `complete:<50`,
+    /// `complete:>=90` and the like (`<`, `>`, `<=`, `>=`, `=`) bound the percentage of its
+    /// tokens that are translated; the remaining terms form the text query.
     /// Empty query returns all segment indices.
     pub fn apply_filter(project: &Project, query: &str) -> Vec<usize> {
-        if query.is_empty() {
+        let QueryTerms {
+            tags,
+            reviewed,
+            complete,
+            text,
+        } = Self::split_query_terms(query);
+        if tags.is_empty() && reviewed.is_none() && complete.is_empty() && text.is_empty() {
             (0..project.segments.len()).collect()
         } else {
-            let query_lower = query.to_lowercase();
+            let query_lower = text.to_lowercase();
             project
                 .segments
                 .iter()
                 .enumerate()
                 .filter(|(_idx, seg)| {
-                    // Match if translation contains query or any token contains query
-                    Self::contains_ignore_case(&seg.translation, &query_lower)
-                        || seg
-                            .tokens
-                            .iter()
-                            .any(|t| Self::contains_ignore_case(&t.original, &query_lower))
+                    tags.iter().all(|tag| seg.has_tag(tag))
+                        && reviewed.is_none_or(|reviewed| seg.reviewed == reviewed)
+                        && (complete.is_empty() || {
+                            let percent = Self::translated_percent(project, seg);
+                            complete.iter().all(|&(op, bound)| op.holds(percent, bound))
+                        })
+                        && (query_lower.is_empty()
+                            // Match if translation contains query or any token contains query
+                            || Self::contains_ignore_case(&seg.translation, &query_lower)
+                            || seg
+                                .tokens
+                                .iter()
+                                .any(|t| Self::contains_ignore_case(&t.original, &query_lower)))
                 })
                 .map(|(idx, _)| idx)
                 .collect()
         }
     }
+
+    /// Position of the next (or previous) match when stepping through `len` filtered
+    /// results, wrapping around at either end. Without a current position, stepping forward
+    /// starts at the first match and stepping backward at the last.
+    /// Returns `None` when there are no matches.
+    #[must_use]
+    pub fn step_match(current: Option<usize>, len: usize, forward: bool) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+        Some(match (current.filter(|&pos| pos < len), forward) {
+            (Some(pos), true) => (pos + 1) % len,
+            (Some(pos), false) => (pos + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        })
+    }
+
+    /// Percentage of a segment's tokens counted as translated, 0 for an empty segment
+    fn translated_percent(project: &Project, segment: &Segment) -> f64 {
+        translated_ratio(segment, project) * 100.0
+    }
+
+    /// The part of a filter query that is matched against text, for highlighting matches.
+    /// `tag:`, `reviewed:` and `complete:` terms are left out; `None` when no text remains.
+    #[must_use]
+    pub fn text_term(query: &str) -> Option<Cow<'_, str>> {
+        let text = Self::split_query_terms(query).text;
+        (!text.is_empty()).then_some(text)
+    }
+
+    /// Separate `tag:`, `reviewed:` and `complete:` terms from the text part of a filter
+    /// query. Queries without such terms are returned unchanged as text. Empty tags and
+    /// review values other than `true` or `false` are ignored, and a `complete:` term
+    /// without a valid number stays in the text.
+    fn split_query_terms(query: &str) -> QueryTerms<'_> {
+        if !query.split_whitespace().any(|term| {
+            term.starts_with("tag:")
+                || term.starts_with("reviewed:")
+                || term.starts_with("complete:")
+        }) {
+            return QueryTerms {
+                tags: Vec::new(),
+                reviewed: None,
+                complete: Vec::new(),
+                text: Cow::Borrowed(query),
+            };
+        }
+
+        let mut tags = Vec::new();
+        let mut reviewed = None;
+        let mut complete = Vec::new();
+        let mut text = Vec::new();
+        for term in query.split_whitespace() {
+            if let Some(tag) = term.strip_prefix("tag:") {
+                if !tag.is_empty() {
+                    tags.push(tag);
+                }
+            } else if let Some(value) = term.strip_prefix("reviewed:") {
+                if value.eq_ignore_ascii_case("true") {
+                    reviewed = Some(true);
+                } else if value.eq_ignore_ascii_case("false") {
+                    reviewed = Some(false);
+                }
+            } else if let Some(bound) = term
+                .strip_prefix("complete:")
+                .and_then(Self::parse_completeness)
+            {
+                complete.push(bound);
+            } else {
+                text.push(term);
+            }
+        }
+        QueryTerms {
+            tags,
+            reviewed,
+            complete,
+            text: Cow::Owned(text.join(" ")),
+        }
+    }
+
+    /// Parse the value of a `complete:` term, such as `<50` or `>=90.5`
+    fn parse_completeness(value: &str) -> Option<(Comparison, f64)> {
+        let (op, number) = Comparison::parse(value);
+        let bound = number.parse::<f64>().ok().filter(|n| n.is_finite())?;
+        Some((op, bound))
+    }
+
+    /// Narrow a list of segment indices to those whose translation contains `query`,
+    /// ignoring case. Order is preserved, out-of-range indices are dropped,
+    /// and an empty query keeps every valid index.
+    pub fn filter_by_translation(project: &Project, indices: &[usize], query: &str) -> Vec<usize> {
+        let query_lower = query.trim().to_lowercase();
+        indices
+            .iter()
+            .copied()
+            .filter(|&idx| {
+                project
+                    .segments
+                    .get(idx)
+                    .is_some_and(|seg| Self::contains_ignore_case(&seg.translation, &query_lower))
+            })
+            .collect()
+    }
 }
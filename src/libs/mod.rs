@@ -8,21 +8,29 @@
 /// - `TokenizationRule`: Rhai script that splits text into tokens
 ///
 /// The library provides:
-/// - Text analysis: Tokenization and translation ratio calculations
+/// - Text analysis: Segmentation and translation ratio calculations
+/// - Tokenizers: Whitespace, character, and script-based line tokenizers behind one trait
 /// - Caching: Lookup maps and TF-IDF matrices for performance
+/// - Change summary: Tally of glosses, translations, and rules edited since the last save
+/// - Cross-references: `[n]` segment links inside comments
 /// - Filtering: Full-text search across segments and translations
+/// - Normalization: Case- and accent-insensitive vocabulary lookups
 /// - Sorting: Multiple sort criteria for segment ordering
 /// - Similarity: TF-IDF based semantic search (native only)
 /// - Script evaluation: Safe Rhai-based execution for word transformations and tokenization
 /// - Project I/O: Serialization with version migration support
 pub mod cache;
+pub mod change_summary;
+pub mod cross_refs;
 pub mod eval;
 pub mod filtering;
+pub mod normalization;
 pub mod project;
 pub mod similarity_sentence;
 pub mod similarity_token;
 pub mod sorting;
 pub mod text_analysis;
+pub mod tokenizer;
 pub mod types;
 
 pub use project::{Project, Segment, Token};
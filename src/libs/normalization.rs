@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Normalized word -> canonical vocabulary key
+pub type NormalizedIndex = HashMap<String, String>;
+
+/// Fold a word into its lookup form: lowercase with diacritics removed.
+/// The word is decomposed (NFD) and its combining marks are dropped, so `É`, `é`, `e\u{301}`
+/// and `e` all share the key `e`. Letters with no decomposition, such as `ø` or `ł`, are kept.
+pub fn normalize_word(word: &str) -> String {
+    word.to_lowercase()
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect()
+}
+
+/// Build an index from normalized words to the canonical vocabulary keys they belong to.
+/// Only glossed keys are indexed, since saved projects carry every surface form with an
+/// empty gloss; the same index serves normalized lookups and gloss suggestions for
+/// unglossed variants. When several keys fold to the same form, the lexicographically
+/// smallest one wins so the result does not depend on hash map iteration order.
+pub fn build_normalized_index(vocabulary: &HashMap<String, String>) -> NormalizedIndex {
+    let mut keys: Vec<&String> = vocabulary
+        .iter()
+        .filter(|(_, gloss)| !gloss.is_empty())
+        .map(|(key, _)| key)
+        .collect();
+    keys.sort();
+
+    let mut index = NormalizedIndex::with_capacity(keys.len());
+    for key in keys {
+        index
+            .entry(normalize_word(key))
+            .or_insert_with(|| key.clone());
+    }
+    index
+}
+
+/// Vocabulary key whose gloss can be offered for `word`: a different glossed key sharing the
+/// word's normalized form in `normalized_index`. `None` when the word already has a gloss
+/// of its own.
+pub fn suggest_gloss_key<'a>(
+    vocabulary: &HashMap<String, String>,
+    normalized_index: &'a NormalizedIndex,
+    word: &str,
+) -> Option<&'a str> {
+    if vocabulary.get(word).is_some_and(|gloss| !gloss.is_empty()) {
+        return None;
+    }
+    normalized_index
+        .get(&normalize_word(word))
+        .map(String::as_str)
+        .filter(|key| *key != word)
+}
+
+/// Resolve the vocabulary key a word should read from and write to.
+/// Exact matches with a gloss always win; otherwise, when a normalized index is given, the word
+/// is routed to the canonical key sharing its normalized form. An exact key with an empty gloss
+/// counts as missing. Falls back to the word itself.
+pub fn resolve_vocabulary_key<'a>(
+    vocabulary: &HashMap<String, String>,
+    normalized_index: Option<&'a NormalizedIndex>,
+    word: &'a str,
+) -> &'a str {
+    if vocabulary.get(word).is_some_and(|gloss| !gloss.is_empty()) {
+        return word;
+    }
+    normalized_index
+        .and_then(|index| index.get(&normalize_word(word)))
+        .map(|key| key.as_str())
+        .unwrap_or(word)
+}
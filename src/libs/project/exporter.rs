@@ -1,17 +1,29 @@
 use std::collections::HashMap;
 
+use super::base64;
 use super::models::{
-    FormattedWordEntry, Project, SavedProjectV2, SavedSentenceV2, SavedVocabularyV2, VocabEntry,
+    FormattedWordEntry, Project, SavedFontV2, SavedProjectV2, SavedSentenceV2, SavedVocabularyV2,
+    VocabEntry,
 };
 use crate::consts::domain::PROJECT_VERSION;
 use crate::enums::{AppError, AppResult};
 
+impl Project {
+    /// Convert this project to its saved form; shorthand for [`convert_to_saved_project`]
+    #[allow(dead_code)]
+    pub fn to_saved(&self) -> AppResult<SavedProjectV2> {
+        convert_to_saved_project(self)
+    }
+}
+
 /// Convert a runtime Project to its serializable `SavedProjectV2` format for JSON export.
 /// This handles:
 /// 1. Deduplicating vocabulary across all segments
 /// 2. Mapping token references to vocabulary indices
 /// 3. Preserving word formation rules and their application chains
 /// 4. Handling both base words and derived words with their rule histories
+///
+/// See [`Project`] for the few runtime details that are normalized along the way.
 pub fn convert_to_saved_project(project: &Project) -> AppResult<SavedProjectV2> {
     // Collect all unique vocabulary words from the project (for deduplication)
     let mut all_words: std::collections::BTreeSet<&String> = project.vocabulary.keys().collect();
@@ -39,10 +51,17 @@ pub fn convert_to_saved_project(project: &Project) -> AppResult<SavedProjectV2>
             .get(word)
             .cloned()
             .unwrap_or_default();
+        let category = project
+            .vocabulary_categories
+            .get(word)
+            .cloned()
+            .unwrap_or_default();
         vocabulary.push(VocabEntry {
             word: word.clone(),
             meaning,
             comment,
+            category,
+            no_gloss: project.no_gloss_words.contains(word),
         });
     }
 
@@ -128,6 +147,18 @@ pub fn convert_to_saved_project(project: &Project) -> AppResult<SavedProjectV2>
         formatted_word_map.insert(entry.word.clone(), idx);
     }
 
+    // Fonts that are neither embedded nor referenced by path are dropped, which shifts later indices
+    let saved_font_indices: Vec<Option<usize>> = project
+        .fonts
+        .iter()
+        .scan(0, |next, font| {
+            let kept = project.embed_fonts || font.path.is_some();
+            let idx = kept.then_some(*next);
+            *next += usize::from(kept);
+            Some(idx)
+        })
+        .collect();
+
     // Convert segments to the serializable format, resolving token references
     let sentences: Vec<SavedSentenceV2> = project
         .segments
@@ -190,6 +221,13 @@ pub fn convert_to_saved_project(project: &Project) -> AppResult<SavedProjectV2>
                 words,
                 meaning: segment.translation.clone(),
                 comment: segment.comment.clone(),
+                phrases: segment.phrases.clone(),
+                locked: segment.locked,
+                reviewed: segment.reviewed,
+                tags: segment.tags.clone(),
+                font: segment
+                    .font
+                    .and_then(|idx| saved_font_indices.get(idx).copied().flatten()),
             })
         })
         .collect::<AppResult<Vec<SavedSentenceV2>>>()?;
@@ -198,6 +236,29 @@ pub fn convert_to_saved_project(project: &Project) -> AppResult<SavedProjectV2>
         version: PROJECT_VERSION,
         project_name: project.project_name.clone(),
         formation: sorted_formation_rules,
+        normalize_lookups: project.normalize_lookups,
+        similarity_by_base_word: project.similarity_by_base_word,
+        stopwords: project.stopwords.iter().cloned().collect(),
+        text_direction: project.text_direction,
+        category_colors: project.category_colors.clone(),
+        import_settings: project.import_settings,
+        embed_fonts: project.embed_fonts,
+        compact_json: project.compact_json,
+        include_glossary_appendix: project.include_glossary_appendix,
+        fonts: project
+            .fonts
+            .iter()
+            .filter(|font| project.embed_fonts || font.path.is_some())
+            .map(|font| SavedFontV2 {
+                name: font.name.clone(),
+                path: font.path.clone(),
+                data: if project.embed_fonts {
+                    base64::encode(&font.data)
+                } else {
+                    String::new()
+                },
+            })
+            .collect(),
         vocabulary: SavedVocabularyV2 {
             original: vocabulary,
             formatted: formatted_word_entries,
@@ -1,10 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use serde_json::Value;
 
 use crate::consts::domain::PROJECT_VERSION;
 
-use super::models::{Project, SavedProjectV2, Segment, Token};
+use super::base64;
+use super::models::{CustomFont, PhraseGloss, Project, SavedProjectV2, Segment, Token};
 
 use super::update_v1::migrate_v1_to_v2;
 
@@ -39,14 +40,45 @@ pub fn migrate_to_latest(mut value: Value) -> Result<SavedProjectV2, String> {
     serde_json::from_value(value).map_err(|e| format!("Failed to parse migrated project: {e}"))
 }
 
+/// Parse the text of a project file into a JSON value. Syntax errors are reported with
+/// the line and column where parsing stopped, so hand-edited files can be fixed.
+pub fn parse_project_json(text: &str) -> Result<Value, String> {
+    serde_json::from_str(text).map_err(|e| describe_parse_error(&e))
+}
+
+/// Format a `serde_json` error as "Parse error at line L, column C: message"
+fn describe_parse_error(error: &serde_json::Error) -> String {
+    let (line, column) = (error.line(), error.column());
+    let full = error.to_string();
+    let message = full
+        .strip_suffix(&format!(" at line {line} column {column}"))
+        .unwrap_or(&full);
+    format!("Parse error at line {line}, column {column}: {message}")
+}
+
 /// Load a complete Project from a JSON value, handling version migration and format conversion.
 /// This is the main entry point for loading projects from saved JSON files.
 pub fn load_project_from_json(value: Value) -> Result<Project, String> {
     let saved_project = migrate_to_latest(value)?;
-    convert_from_saved_project_v2(saved_project)
-        .ok_or_else(|| "Failed to convert project format".to_string())
+    let issues = check_saved_project(&saved_project);
+    for warning in &issues.warnings {
+        log::warn!("{warning}");
+    }
+    if !issues.errors.is_empty() {
+        return Err(issues.errors.join("\n"));
+    }
+    Project::from_saved(saved_project).ok_or_else(|| "Failed to convert project format".to_string())
 }
 
+impl Project {
+    /// Rebuild a runtime project from its saved form; shorthand for [`convert_from_saved_project_v2`]
+    pub fn from_saved(saved: SavedProjectV2) -> Option<Self> {
+        convert_from_saved_project_v2(saved)
+    }
+}
+
+/// Convert a migrated `SavedProjectV2` back to the runtime `Project`, re-deriving formatted
+/// words from their rule chains. Returns `None` if a word reference cannot be resolved.
 pub fn convert_from_saved_project_v2(mut saved: SavedProjectV2) -> Option<Project> {
     // Initialize cached ASTs for all formation rules (needed for script execution)
     for rule in &mut saved.formation {
@@ -93,6 +125,22 @@ pub fn convert_from_saved_project_v2(mut saved: SavedProjectV2) -> Option<Projec
         .map(|entry| (entry.word.clone(), entry.meaning.clone()))
         .collect();
 
+    let vocabulary_categories: HashMap<String, String> = saved
+        .vocabulary
+        .original
+        .iter()
+        .filter(|entry| !entry.category.is_empty())
+        .map(|entry| (entry.word.clone(), entry.category.clone()))
+        .collect();
+
+    let no_gloss_words: BTreeSet<String> = saved
+        .vocabulary
+        .original
+        .iter()
+        .filter(|entry| entry.no_gloss)
+        .map(|entry| entry.word.clone())
+        .collect();
+
     let vocabulary_comments: HashMap<String, String> = saved
         .vocabulary
         .original
@@ -100,6 +148,18 @@ pub fn convert_from_saved_project_v2(mut saved: SavedProjectV2) -> Option<Projec
         .map(|entry| (entry.word.clone(), entry.comment.clone()))
         .collect();
 
+    let fonts = saved
+        .fonts
+        .into_iter()
+        .map(|font| {
+            base64::decode(&font.data).map(|data| CustomFont {
+                name: font.name,
+                path: font.path,
+                data,
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+
     // Convert saved segments back to the runtime Segment format, resolving word references
     let segments: Option<Vec<Segment>> = saved
         .sentences
@@ -147,17 +207,162 @@ pub fn convert_from_saved_project_v2(mut saved: SavedProjectV2) -> Option<Projec
                 tokens,
                 translation: sentence.meaning,
                 comment: sentence.comment,
+                font: sentence.font.filter(|idx| *idx < fonts.len()),
+                phrases: sanitize_phrases(&sentence.phrases, sentence.words.len()).0,
+                locked: sentence.locked,
+                reviewed: sentence.reviewed,
+                tags: sentence.tags,
             })
         })
         .collect();
 
     Some(Project {
         project_name: saved.project_name,
-        font_path: None,
+        fonts,
+        embed_fonts: saved.embed_fonts,
+        compact_json: saved.compact_json,
+        include_glossary_appendix: saved.include_glossary_appendix,
         vocabulary: vocabulary_map,
         vocabulary_comments,
+        vocabulary_categories,
+        no_gloss_words,
+        category_colors: saved.category_colors,
         formatted_word_comments,
         segments: segments?,
         formation_rules: saved.formation,
+        normalize_lookups: saved.normalize_lookups,
+        similarity_by_base_word: saved.similarity_by_base_word,
+        stopwords: saved.stopwords.into_iter().collect(),
+        text_direction: saved.text_direction,
+        import_settings: saved.import_settings,
     })
 }
+
+/// Sort a segment's saved phrases, keeping those that span at least two of its
+/// `token_count` tokens and do not overlap an earlier kept phrase.
+/// Returns the kept phrases and a description of each dropped one.
+fn sanitize_phrases(
+    phrases: &[PhraseGloss],
+    token_count: usize,
+) -> (Vec<PhraseGloss>, Vec<String>) {
+    let mut sorted: Vec<&PhraseGloss> = phrases.iter().collect();
+    sorted.sort_by_key(|phrase| (phrase.start, phrase.end));
+
+    let mut kept: Vec<PhraseGloss> = Vec::with_capacity(sorted.len());
+    let mut dropped = Vec::new();
+    for phrase in sorted {
+        if phrase.start >= phrase.end || phrase.end >= token_count {
+            dropped.push(format!(
+                "a phrase over tokens {}–{}, which is not a range of at least two of its {token_count} tokens",
+                phrase.start + 1,
+                phrase.end + 1
+            ));
+        } else if let Some(previous) = kept.last().filter(|previous| phrase.start <= previous.end) {
+            dropped.push(format!(
+                "a phrase over tokens {}–{} overlapping the phrase over tokens {}–{}",
+                phrase.start + 1,
+                phrase.end + 1,
+                previous.start + 1,
+                previous.end + 1
+            ));
+        } else {
+            kept.push(phrase.clone());
+        }
+    }
+    (kept, dropped)
+}
+
+/// Problems found in a saved project, split by whether loading can still succeed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LoadIssues {
+    /// Data the loader silently skips or merges (the project still opens)
+    pub warnings: Vec<String>,
+
+    /// Data that makes the loader reject the project
+    pub errors: Vec<String>,
+}
+
+/// Inspect a migrated project for the inconsistencies `convert_from_saved_project_v2`
+/// either drops quietly or fails on, so they can be reported instead of guessed at.
+#[must_use]
+pub fn check_saved_project(saved: &SavedProjectV2) -> LoadIssues {
+    let mut issues = LoadIssues::default();
+    let original = &saved.vocabulary.original;
+    let formatted = &saved.vocabulary.formatted;
+
+    let mut first_seen: HashMap<&str, usize> = HashMap::new();
+    for (idx, entry) in original.iter().enumerate() {
+        if let Some(first) = first_seen.insert(entry.word.as_str(), idx) {
+            issues.warnings.push(format!(
+                "Vocabulary word '{}' is duplicated at indices {first} and {idx}; the later entry wins",
+                entry.word
+            ));
+        }
+    }
+
+    for (idx, entry) in formatted.iter().enumerate() {
+        match entry.word.split_first() {
+            None => issues
+                .warnings
+                .push(format!("Derived word {idx} has an empty index chain")),
+            Some((vocab_idx, rule_indices)) => {
+                if *vocab_idx >= original.len() {
+                    issues.warnings.push(format!(
+                        "Derived word {idx} refers to missing vocabulary index {vocab_idx}"
+                    ));
+                }
+                for rule_idx in rule_indices {
+                    if *rule_idx >= saved.formation.len() {
+                        issues.warnings.push(format!(
+                            "Derived word {idx} refers to missing formation rule {rule_idx}"
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    for (sentence_idx, sentence) in saved.sentences.iter().enumerate() {
+        for word_ref in &sentence.words {
+            let resolves = if *word_ref >= 0 {
+                (*word_ref as usize) < original.len()
+            } else {
+                formatted
+                    .get((-*word_ref - 1) as usize)
+                    .and_then(|entry| entry.word.first())
+                    .is_some_and(|vocab_idx| *vocab_idx < original.len())
+            };
+            if !resolves {
+                issues.errors.push(format!(
+                    "Segment {} refers to missing word {word_ref}",
+                    sentence_idx + 1
+                ));
+            }
+        }
+        let (_, dropped) = sanitize_phrases(&sentence.phrases, sentence.words.len());
+        for reason in dropped {
+            issues.warnings.push(format!(
+                "Segment {} has {reason}; the phrase is dropped",
+                sentence_idx + 1
+            ));
+        }
+        if let Some(font_idx) = sentence.font
+            && font_idx >= saved.fonts.len()
+        {
+            issues.warnings.push(format!(
+                "Segment {} overrides missing font {font_idx}; the default font is used",
+                sentence_idx + 1
+            ));
+        }
+    }
+
+    for font in &saved.fonts {
+        if base64::decode(&font.data).is_none() {
+            issues
+                .errors
+                .push(format!("Font '{}' has corrupted embedded data", font.name));
+        }
+    }
+
+    issues
+}
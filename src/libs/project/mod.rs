@@ -5,11 +5,15 @@
 /// - Base vocabulary words are stored once and referenced by index
 /// - Derived words store the base word index and a chain of formation rule indices
 /// - Word references use positive integers for base words, negative for derived words
+///
+/// Queries and edits on the runtime `Project` live in `operations`.
+pub(crate) mod base64;
 pub mod exporter;
 pub mod importer;
 pub mod models;
+pub mod operations;
 pub mod update_v1;
 
 pub use exporter::convert_to_saved_project;
-pub use importer::load_project_from_json;
-pub use models::{Project, Segment, Token};
+pub use importer::{LoadIssues, check_saved_project, load_project_from_json, parse_project_json};
+pub use models::{CustomFont, ImportSettings, Project, Segment, Token};
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
+use crate::enums::{PunctuationMode, SegmentationMode, TextDirection};
 pub use crate::libs::eval::FormationRule;
 
 /// Represents a single token (word or character) within a segment.
@@ -34,17 +35,69 @@ pub struct Segment {
     /// User-provided notes or annotations for this entire segment
     #[serde(skip)]
     pub comment: String,
+
+    /// Index into the Project's `fonts` overriding the default sentence font for this segment.
+    /// Dropped on save when the referenced font is not itself saved (neither embedded nor on disk).
+    #[serde(skip)]
+    pub font: Option<usize>,
+
+    /// Glosses spanning several tokens, sorted by start and never overlapping
+    #[serde(skip)]
+    pub phrases: Vec<PhraseGloss>,
+
+    /// Finalized segments reject edits to their translation, glosses, and phrases
+    #[serde(skip)]
+    pub locked: bool,
+
+    /// Marked as checked in a review pass; purely informational
+    #[serde(skip)]
+    pub reviewed: bool,
+
+    /// User-defined categories such as "verse" or "uncertain", in display order
+    #[serde(skip)]
+    pub tags: Vec<String>,
+}
+
+/// A single gloss assigned to a contiguous range of tokens within one segment
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhraseGloss {
+    /// Index of the first token in the phrase
+    pub start: usize,
+
+    /// Index of the last token in the phrase (inclusive)
+    pub end: usize,
+
+    /// Meaning of the phrase as a whole
+    pub gloss: String,
 }
 
 /// Root container for a translation/decryption project.
 /// Manages all segments, vocabulary, word formation rules, and metadata.
+///
+/// A save and load (`to_saved` then `from_saved`) keeps every field except:
+/// - formation rules are reordered by type and description, and token rule indices follow them
+/// - comments in `formatted_word_comments` are kept only for derived words some token uses
+/// - fonts that are neither embedded nor loaded from a path are dropped, with any segment overrides pointing at them
+/// - every word used by a token gets a (possibly empty) vocabulary meaning and comment
+///
+/// Saving the loaded project again yields exactly the same file.
 #[derive(Debug, Clone, Default)]
 pub struct Project {
     /// User-assigned name for this project
     pub project_name: String,
 
-    /// Optional path to a custom font file for rendering special scripts
-    pub font_path: Option<String>,
+    /// Custom fonts loaded for rendering special scripts, in load order.
+    /// The first one is the default sentence font; segments may override it by index.
+    pub fonts: Vec<CustomFont>,
+
+    /// Whether font files are embedded in the saved project instead of being reloaded by hand
+    pub embed_fonts: bool,
+
+    /// Whether the project file is written without indentation or line breaks
+    pub compact_json: bool,
+
+    /// Whether Typst and Markdown exports end with a sorted glossary of every word
+    pub include_glossary_appendix: bool,
 
     /// Map of word -> definition for the project vocabulary.
     /// Deduplicates words across all segments to minimize file size.
@@ -53,6 +106,16 @@ pub struct Project {
     /// Comments/notes for vocabulary words, separate from definitions
     pub vocabulary_comments: HashMap<String, String>,
 
+    /// Category (such as a part of speech) assigned to vocabulary words
+    pub vocabulary_categories: HashMap<String, String>,
+
+    /// Vocabulary words that intentionally have no gloss, such as punctuation.
+    /// Their empty glosses are not counted as untranslated.
+    pub no_gloss_words: BTreeSet<String>,
+
+    /// Color used to tint tokens of each category, as RGB
+    pub category_colors: BTreeMap<String, [u8; 3]>,
+
     /// Comments for derived/formatted words created by applying formation rules
     pub formatted_word_comments: HashMap<String, String>,
 
@@ -61,6 +124,47 @@ pub struct Project {
 
     /// Word formation rules (Rhai scripts) for generating derived forms from base words
     pub formation_rules: Vec<FormationRule>,
+
+    /// Whether vocabulary lookups ignore case and diacritics.
+    /// Stored keys keep their canonical surface form; only lookups are normalized.
+    pub normalize_lookups: bool,
+
+    /// Whether sentence similarity uses each token's base word instead of its surface form,
+    /// so inflected forms of the same lemma count as one term
+    pub similarity_by_base_word: bool,
+
+    /// Terms excluded from similarity vectors (and optionally from frequency rankings)
+    pub stopwords: BTreeSet<String>,
+
+    /// Reading direction used to lay out segment tokens
+    pub text_direction: TextDirection,
+
+    /// Tokenization chosen for the last text import, offered again on the next one
+    pub import_settings: Option<ImportSettings>,
+}
+
+/// Built-in tokenization options picked in the import dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ImportSettings {
+    /// How lines are split into tokens
+    pub mode: SegmentationMode,
+
+    /// How punctuation in the tokens is handled
+    #[serde(default)]
+    pub punctuation: PunctuationMode,
+}
+
+/// A font file loaded into the project
+#[derive(Debug, Clone, Default)]
+pub struct CustomFont {
+    /// File name the font was loaded from
+    pub name: String,
+
+    /// Filesystem path the font was loaded from (native only), used when fonts are not embedded
+    pub path: Option<String>,
+
+    /// Raw font file bytes; empty while a path reference is unresolved
+    pub data: Vec<u8>,
 }
 
 /// Serialization format for a single vocabulary entry.
@@ -76,6 +180,14 @@ pub struct VocabEntry {
     /// Optional comment/note about this vocabulary entry
     #[serde(default)]
     pub comment: String,
+
+    /// Optional category used for color-coding
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub category: String,
+
+    /// Whether the empty meaning is intentional rather than not yet filled in
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub no_gloss: bool,
 }
 
 /// Serialization format for a word created by applying formation rules.
@@ -103,6 +215,21 @@ pub struct SavedVocabularyV2 {
     pub formatted: Vec<FormattedWordEntry>,
 }
 
+/// Serialization format for a font file, either embedded or referenced by path
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedFontV2 {
+    /// File name the font was loaded from
+    pub name: String,
+
+    /// Filesystem path the font was loaded from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Font file bytes, base64-encoded; empty for path references
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub data: String,
+}
+
 /// Serialization format for a single segment (sentence/line of text).
 /// Word references use positive integers for base vocabulary and negative integers for formatted words.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,6 +245,26 @@ pub struct SavedSentenceV2 {
     /// User annotation/notes for this segment
     #[serde(default)]
     pub comment: String,
+
+    /// Glosses spanning token ranges of this segment
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phrases: Vec<PhraseGloss>,
+
+    /// Whether the segment is locked against edits
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub locked: bool,
+
+    /// Whether the segment has been marked as reviewed
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reviewed: bool,
+
+    /// Categories assigned to this segment
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Index into the saved `fonts` overriding the default sentence font
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<usize>,
 }
 
 /// Complete project serialization format (version 2).
@@ -135,9 +282,54 @@ pub struct SavedProjectV2 {
     #[serde(default)]
     pub formation: Vec<FormationRule>,
 
+    /// Whether vocabulary lookups ignore case and diacritics
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub normalize_lookups: bool,
+
+    /// Whether sentence similarity compares base words instead of surface forms
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub similarity_by_base_word: bool,
+
+    /// Terms excluded from similarity search, sorted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stopwords: Vec<String>,
+
+    /// Reading direction of the source script
+    #[serde(default, skip_serializing_if = "is_ltr")]
+    pub text_direction: TextDirection,
+
+    /// Whether custom fonts are embedded on save
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub embed_fonts: bool,
+
+    /// Whether the project file is saved without whitespace
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compact_json: bool,
+
+    /// Whether Typst and Markdown exports end with a glossary appendix
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub include_glossary_appendix: bool,
+
+    /// Custom fonts, embedded when `embed_fonts` is set and referenced by path otherwise
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fonts: Vec<SavedFontV2>,
+
+    /// RGB colors of word categories
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub category_colors: BTreeMap<String, [u8; 3]>,
+
+    /// Tokenization used by the last text import
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_settings: Option<ImportSettings>,
+
     /// Vocabulary (original and derived/formatted words)
     pub vocabulary: SavedVocabularyV2,
 
     /// All text segments in the project
     pub sentences: Vec<SavedSentenceV2>,
 }
+
+/// LTR is the default direction, so it is omitted from saved files
+fn is_ltr(direction: &TextDirection) -> bool {
+    !direction.is_rtl()
+}
@@ -0,0 +1,839 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::models::{CustomFont, ImportSettings, PhraseGloss, Project, Segment, Token};
+use crate::libs::eval::FormationRule;
+use crate::libs::normalization::{NormalizedIndex, normalize_word, resolve_vocabulary_key};
+use crate::libs::text_analysis::suggest_segmentation;
+
+/// Occurrence statistics for one unique token
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WordFrequency {
+    /// The token text as it appears in segments
+    pub word: String,
+    /// Number of occurrences across all segments
+    pub count: usize,
+    /// Whether the word has a non-empty gloss in the vocabulary; a derived token is glossed
+    /// through its base word
+    pub glossed: bool,
+}
+
+/// A surface form the bulk rule tool proposes to derive from a base word
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleMatch {
+    /// The token text as it appears in segments
+    pub word: String,
+    /// Base word proposed by stripping the pattern; the user may correct it
+    pub base_word: String,
+    /// Number of underived tokens with this surface form
+    pub count: usize,
+    /// Whether the rule applied to `base_word` reproduces `word`
+    pub round_trips: bool,
+}
+
+/// Result of renaming a vocabulary word across the project
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameOutcome {
+    /// Nothing to do: the names are equal, the new name is blank, or the word is unused
+    Unchanged,
+    /// The new name is already a word in the project; nothing was changed
+    TargetExists,
+    /// The word was renamed; holds the number of tokens updated
+    Renamed(usize),
+    /// The word was folded into an existing one; holds the number of tokens updated
+    Merged(usize),
+}
+
+/// A derived word whose base word resolves, through other derived words, back to itself
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// Words visited from the derived word to the first repeat, e.g. `[a, b, a]`
+    pub chain: Vec<String>,
+}
+
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Base word chain loops: {}", self.chain.join(" → "))
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+/// Derived tokens (those with formation rules) keyed by spelling; the first occurrence wins
+fn derived_tokens(project: &Project) -> HashMap<&str, &Token> {
+    let mut derived = HashMap::new();
+    for token in project.segments.iter().flat_map(|segment| &segment.tokens) {
+        if !token.formation_rule_indices.is_empty() && token.base_word.is_some() {
+            derived.entry(token.original.as_str()).or_insert(token);
+        }
+    }
+    derived
+}
+
+/// Follow `base_word` through derived spellings until it reaches a word that is not
+/// derived, prepending each step's rules. Every step visits a new word or stops, so the
+/// walk ends even on cyclic data.
+fn walk_base_chain<'a>(
+    derived: &HashMap<&'a str, &'a Token>,
+    original: &'a str,
+    base_word: &'a str,
+    mut rules: Vec<usize>,
+) -> Result<(String, Vec<usize>), CycleError> {
+    let mut seen = vec![original];
+    let mut base = base_word;
+    loop {
+        if seen.contains(&base) {
+            seen.push(base);
+            return Err(CycleError {
+                chain: seen.into_iter().map(str::to_string).collect(),
+            });
+        }
+        let Some(&next) = derived.get(base) else {
+            return Ok((base.to_string(), rules));
+        };
+        seen.push(base);
+        rules.splice(0..0, next.formation_rule_indices.iter().copied());
+        base = next.base_word.as_deref().unwrap_or(&next.original);
+    }
+}
+
+/// Resolve a token to its root base word and the full chain of formation rules leading
+/// from that root to the token, following base words that are themselves derived.
+/// Tokens without formation rules resolve to their own base word with no rules.
+#[allow(dead_code)]
+pub fn resolve_base_chain(
+    project: &Project,
+    token: &Token,
+) -> Result<(String, Vec<usize>), CycleError> {
+    let base_word = token.base_word.as_deref().unwrap_or(&token.original);
+    if token.formation_rule_indices.is_empty() {
+        return Ok((base_word.to_string(), Vec::new()));
+    }
+    walk_base_chain(
+        &derived_tokens(project),
+        &token.original,
+        base_word,
+        token.formation_rule_indices.clone(),
+    )
+}
+
+/// Move the value stored under `from` to `to`. A non-empty value already at `to` is kept,
+/// with the moved value appended on a new line when `append` is set.
+fn move_word_entry(map: &mut HashMap<String, String>, from: &str, to: &str, append: bool) {
+    if let Some(value) = map.remove(from) {
+        let target = map.entry(to.to_string()).or_default();
+        if target.is_empty() {
+            *target = value;
+        } else if append && !value.is_empty() && *target != value {
+            target.push('\n');
+            target.push_str(&value);
+        }
+    }
+}
+
+/// Strip an affix pattern from `word`: `-ed` removes a suffix, `un-` removes a prefix,
+/// and a bare pattern is treated as a suffix.
+/// Returns `None` when the word doesn't carry the affix or nothing would remain.
+pub fn strip_affix(word: &str, pattern: &str) -> Option<String> {
+    let pattern = pattern.trim();
+    let stripped = if let Some(suffix) = pattern.strip_prefix('-') {
+        word.strip_suffix(suffix)
+    } else if let Some(prefix) = pattern.strip_suffix('-') {
+        word.strip_prefix(prefix)
+    } else {
+        word.strip_suffix(pattern)
+    }?;
+    (!pattern.trim_matches('-').is_empty() && !stripped.is_empty()).then(|| stripped.to_string())
+}
+
+impl Project {
+    /// Whether at least one custom sentence font is loaded
+    pub fn has_custom_font(&self) -> bool {
+        !self.fonts.is_empty()
+    }
+
+    /// Tokenization the import dialog starts with for `content`: the settings of the
+    /// project's last import, or a guess from the text's script when there was none
+    #[must_use]
+    pub fn import_defaults(&self, content: &str) -> ImportSettings {
+        self.import_settings.unwrap_or_else(|| ImportSettings {
+            mode: suggest_segmentation(content).0,
+            ..ImportSettings::default()
+        })
+    }
+
+    /// Count how often a word occurs as a token across the project.
+    /// Returns the total count and `(segment_index, count)` pairs for every segment containing it,
+    /// in segment order. Honors `normalize_lookups` when matching tokens.
+    ///
+    /// This is the uncached reference implementation, scanning every token: use it for one-off
+    /// checks and headless callers. Code that runs every frame reads the same counts from
+    /// `LookupCache::token_frequency` instead.
+    pub fn token_frequency(&self, word: &str) -> (usize, Vec<(usize, usize)>) {
+        let target = self.normalize_lookups.then(|| normalize_word(word));
+        let matches = |original: &str| match &target {
+            Some(normalized) => normalize_word(original) == *normalized,
+            None => original == word,
+        };
+
+        let per_segment: Vec<(usize, usize)> = self
+            .segments
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, segment)| {
+                let count = segment
+                    .tokens
+                    .iter()
+                    .filter(|token| matches(&token.original))
+                    .count();
+                (count > 0).then_some((idx, count))
+            })
+            .collect();
+
+        let total = per_segment.iter().map(|(_, count)| count).sum();
+        (total, per_segment)
+    }
+
+    /// Count every unique token `original` across the project.
+    /// Sorted by descending count, ties broken alphabetically.
+    pub fn word_frequencies(&self) -> Vec<WordFrequency> {
+        let mut counts: HashMap<&str, (usize, &str)> = HashMap::new();
+        for token in self.segments.iter().flat_map(|segment| &segment.tokens) {
+            let base = token.base_word.as_deref().unwrap_or(&token.original);
+            counts.entry(token.original.as_str()).or_insert((0, base)).0 += 1;
+        }
+        self.sorted_frequencies(counts)
+    }
+
+    /// Count token occurrences per vocabulary word.
+    /// A derived token counts toward its base word, not its own spelling, so the counts
+    /// measure how often each glossable word is used. Vocabulary words no token uses are
+    /// listed with a count of zero. Sorted like [`Self::word_frequencies`].
+    pub fn vocabulary_frequencies(&self) -> Vec<WordFrequency> {
+        let mut counts: HashMap<&str, (usize, &str)> = self
+            .vocabulary
+            .keys()
+            .map(|word| (word.as_str(), (0, word.as_str())))
+            .collect();
+        for token in self.segments.iter().flat_map(|segment| &segment.tokens) {
+            let word = token.base_word.as_deref().unwrap_or(&token.original);
+            counts.entry(word).or_insert((0, word)).0 += 1;
+        }
+        self.sorted_frequencies(counts)
+    }
+
+    /// Attach gloss status to per-word counts, sorted by descending count then alphabetically.
+    /// Each count is paired with the vocabulary word whose gloss decides the status.
+    fn sorted_frequencies(&self, counts: HashMap<&str, (usize, &str)>) -> Vec<WordFrequency> {
+        let mut frequencies: Vec<WordFrequency> = counts
+            .into_iter()
+            .map(|(word, (count, gloss_word))| WordFrequency {
+                word: word.to_string(),
+                count,
+                glossed: self
+                    .vocabulary
+                    .get(gloss_word)
+                    .is_some_and(|gloss| !gloss.is_empty()),
+            })
+            .collect();
+        frequencies.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.word.cmp(&b.word)));
+        frequencies
+    }
+
+    /// Merge the segment after `idx` into the one at `idx`.
+    /// Tokens are appended, translations and comments are joined with a space,
+    /// and every later segment shifts down by one index. The merged segment keeps the tags
+    /// of both, is reviewed only if both were, and keeps its own font override, taking the
+    /// next segment's when it has none.
+    /// Returns `false` without changes when `idx` has no following segment
+    /// or either segment is locked.
+    pub fn merge_segments(&mut self, idx: usize) -> bool {
+        if idx + 1 >= self.segments.len()
+            || self.segments[idx].locked
+            || self.segments[idx + 1].locked
+        {
+            return false;
+        }
+
+        let next = self.segments.remove(idx + 1);
+        let current = &mut self.segments[idx];
+        let offset = current.tokens.len();
+        current
+            .phrases
+            .extend(next.phrases.into_iter().map(|phrase| PhraseGloss {
+                start: phrase.start + offset,
+                end: phrase.end + offset,
+                gloss: phrase.gloss,
+            }));
+        current.tokens.extend(next.tokens);
+        join_with_space(&mut current.translation, &next.translation);
+        join_with_space(&mut current.comment, &next.comment);
+        for tag in next.tags {
+            if !current.has_tag(&tag) {
+                current.tags.push(tag);
+            }
+        }
+        current.reviewed &= next.reviewed;
+        current.font = current.font.or(next.font);
+        true
+    }
+
+    /// Group segments whose token sequences are identical, ignoring whitespace within tokens.
+    /// Each group lists segment indices in ascending order, and groups are ordered by their
+    /// first segment. Segments without tokens are never reported.
+    pub fn find_duplicate_segments(&self) -> Vec<Vec<usize>> {
+        let mut group_of: HashMap<Vec<String>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for (idx, segment) in self.segments.iter().enumerate() {
+            let key: Vec<String> = segment
+                .tokens
+                .iter()
+                .map(|token| token.original.split_whitespace().collect::<String>())
+                .filter(|token| !token.is_empty())
+                .collect();
+            if key.is_empty() {
+                continue;
+            }
+
+            let group_idx = *group_of.entry(key).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group_idx].push(idx);
+        }
+
+        groups.retain(|group| group.len() > 1);
+        groups
+    }
+
+    /// Propose a base word for every underived token matching `pattern` (see [`strip_affix`]),
+    /// checking each proposal by applying rule `rule_idx` to it.
+    /// Results are sorted by surface form; an unknown rule yields no proposals.
+    pub fn propose_rule_matches(&self, rule_idx: usize, pattern: &str) -> Vec<RuleMatch> {
+        let Some(rule) = self.formation_rules.get(rule_idx) else {
+            return Vec::new();
+        };
+
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for token in self.segments.iter().flat_map(|segment| &segment.tokens) {
+            if token.formation_rule_indices.is_empty() {
+                *counts.entry(token.original.as_str()).or_default() += 1;
+            }
+        }
+
+        counts
+            .into_iter()
+            .filter_map(|(word, count)| {
+                let base_word = strip_affix(word, pattern)?;
+                Some(RuleMatch {
+                    word: word.to_string(),
+                    round_trips: rule.round_trips(&base_word, word),
+                    base_word,
+                    count,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether deriving `word` from `base_word` would make a base word chain loop,
+    /// either directly (`word` is its own base) or through other derived words
+    pub fn derivation_cycles(&self, word: &str, base_word: &str) -> bool {
+        walk_base_chain(&derived_tokens(self), word, base_word, Vec::new()).is_err()
+    }
+
+    /// Spellings of derived tokens whose base word chain loops back on itself, sorted
+    pub fn cyclic_derived_words(&self) -> Vec<String> {
+        let derived = derived_tokens(self);
+        let mut cyclic: Vec<String> = self
+            .segments
+            .iter()
+            .flat_map(|segment| &segment.tokens)
+            .filter(|token| !token.formation_rule_indices.is_empty())
+            .filter(|token| {
+                let base_word = token.base_word.as_deref().unwrap_or(&token.original);
+                walk_base_chain(&derived, &token.original, base_word, Vec::new()).is_err()
+            })
+            .map(|token| token.original.clone())
+            .collect();
+        cyclic.sort_unstable();
+        cyclic.dedup();
+        cyclic
+    }
+
+    /// Mark every underived token spelled `word` as `base_word` plus rule `rule_idx`.
+    /// The word's own gloss and comment are dropped and the base word is added to the
+    /// vocabulary if needed. Nothing changes when the derivation would make the base word
+    /// chain loop. Returns the number of tokens changed.
+    pub fn derive_word(&mut self, word: &str, base_word: &str, rule_idx: usize) -> usize {
+        if self.derivation_cycles(word, base_word) {
+            return 0;
+        }
+
+        let mut changed = 0;
+        for token in self
+            .segments
+            .iter_mut()
+            .flat_map(|segment| &mut segment.tokens)
+        {
+            if token.original == word && token.formation_rule_indices.is_empty() {
+                token.base_word = Some(base_word.to_string());
+                token.formation_rule_indices = vec![rule_idx];
+                changed += 1;
+            }
+        }
+
+        if changed > 0 {
+            self.vocabulary.remove(word);
+            self.vocabulary_comments.remove(word);
+            self.vocabulary.entry(base_word.to_string()).or_default();
+        }
+        changed
+    }
+
+    /// Whether `word` still needs a gloss: its gloss is empty and it is not marked as
+    /// intentionally having none
+    pub fn needs_gloss(&self, word: &str) -> bool {
+        !self.no_gloss_words.contains(word)
+            && self
+                .vocabulary
+                .get(word)
+                .is_none_or(|gloss| gloss.trim().is_empty())
+    }
+
+    /// Mark `word` as intentionally having no gloss, or clear the mark.
+    /// Returns whether the mark changed.
+    pub fn set_no_gloss(&mut self, word: &str, no_gloss: bool) -> bool {
+        if no_gloss {
+            self.vocabulary.entry(word.to_string()).or_default();
+            self.no_gloss_words.insert(word.to_string())
+        } else {
+            self.no_gloss_words.remove(word)
+        }
+    }
+
+    /// Unique token `original`s whose gloss is still empty, with their occurrence counts.
+    /// Derived tokens inherit the gloss of their base word, and words marked as having no
+    /// gloss are left out. Sorted by descending count, ties broken alphabetically.
+    pub fn untranslated_words(&self) -> Vec<(String, usize)> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for token in self.segments.iter().flat_map(|segment| &segment.tokens) {
+            let base_word = token.base_word.as_ref().unwrap_or(&token.original);
+            if self.needs_gloss(base_word) {
+                *counts.entry(token.original.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut words: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(word, count)| (word.to_string(), count))
+            .collect();
+        words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        words
+    }
+
+    /// Rename the word `from` to `to` in every token, as a surface form or as a base word,
+    /// moving its gloss, comment, and category to the new key. Derived tokens built on it are
+    /// re-derived from the new base. Refuses with `TargetExists` when `to` is already used;
+    /// see `merge_words` for folding one word into another.
+    pub fn rename_word(&mut self, from: &str, to: &str) -> RenameOutcome {
+        self.replace_word(from, to, false)
+    }
+
+    /// Fold the word `source` into `target`: every occurrence of `source` is retargeted and
+    /// its vocabulary entry dropped. The target keeps its own gloss and category, taking
+    /// those of `source` only where empty, and the two comments are combined.
+    pub fn merge_words(&mut self, source: &str, target: &str) -> RenameOutcome {
+        self.replace_word(source, target, true)
+    }
+
+    fn word_exists(&self, word: &str) -> bool {
+        self.vocabulary.contains_key(word)
+            || self
+                .segments
+                .iter()
+                .flat_map(|s| &s.tokens)
+                .any(|token| token.original == word || token.base_word.as_deref() == Some(word))
+    }
+
+    fn replace_word(&mut self, from: &str, to: &str, allow_merge: bool) -> RenameOutcome {
+        let to = to.trim();
+        if to.is_empty() || from == to || !self.word_exists(from) {
+            return RenameOutcome::Unchanged;
+        }
+        let merging = self.word_exists(to);
+        if merging && !allow_merge {
+            return RenameOutcome::TargetExists;
+        }
+
+        let mut changed = 0;
+        let mut moved_comments = Vec::new();
+        for token in self
+            .segments
+            .iter_mut()
+            .flat_map(|segment| &mut segment.tokens)
+        {
+            if token.base_word.as_deref() == Some(from) {
+                token.base_word = Some(to.to_string());
+            } else if !(token.original == from && token.formation_rule_indices.is_empty()) {
+                continue;
+            }
+
+            let mut original = to.to_string();
+            for rule_idx in &token.formation_rule_indices {
+                if let Some(rule) = self.formation_rules.get(*rule_idx) {
+                    original = rule.apply(&original).unwrap_or(original);
+                }
+            }
+            if !token.formation_rule_indices.is_empty() && original != token.original {
+                moved_comments.push((token.original.clone(), original.clone()));
+            }
+            token.original = original;
+            changed += 1;
+        }
+
+        for (old_form, new_form) in moved_comments {
+            if let Some(comment) = self.formatted_word_comments.remove(&old_form) {
+                self.formatted_word_comments
+                    .entry(new_form)
+                    .or_insert(comment);
+            }
+        }
+
+        move_word_entry(&mut self.vocabulary, from, to, false);
+        move_word_entry(&mut self.vocabulary_comments, from, to, true);
+        move_word_entry(&mut self.vocabulary_categories, from, to, false);
+        if self.stopwords.remove(from) {
+            self.stopwords.insert(to.to_string());
+        }
+        if self.no_gloss_words.remove(from) && !merging {
+            self.no_gloss_words.insert(to.to_string());
+        }
+
+        if merging {
+            RenameOutcome::Merged(changed)
+        } else {
+            RenameOutcome::Renamed(changed)
+        }
+    }
+
+    /// Find the first untranslated token after `(seg_idx, word_idx)` in reading order:
+    /// later tokens of the same segment, then the tokens of each following segment.
+    /// A token is untranslated when it has no formation chain, no non-empty gloss, and is
+    /// not marked as having no gloss.
+    pub fn next_untranslated_token(
+        &self,
+        seg_idx: usize,
+        word_idx: usize,
+        normalized_index: Option<&NormalizedIndex>,
+    ) -> Option<(usize, usize)> {
+        let is_untranslated = |token: &Token| {
+            if !token.formation_rule_indices.is_empty() {
+                return false;
+            }
+            let base_word = token.base_word.as_ref().unwrap_or(&token.original);
+            let key = resolve_vocabulary_key(&self.vocabulary, normalized_index, base_word);
+            !self.no_gloss_words.contains(key)
+                && self
+                    .vocabulary
+                    .get(key)
+                    .is_none_or(|gloss| gloss.is_empty())
+        };
+
+        self.segments
+            .iter()
+            .enumerate()
+            .skip(seg_idx)
+            .find_map(|(idx, segment)| {
+                let first = if idx == seg_idx { word_idx + 1 } else { 0 };
+                segment
+                    .tokens
+                    .iter()
+                    .enumerate()
+                    .skip(first)
+                    .find(|(_, token)| is_untranslated(token))
+                    .map(|(token_idx, _)| (idx, token_idx))
+            })
+    }
+
+    /// Assign `category` to a vocabulary word, or clear it with `None`
+    pub fn set_word_category(&mut self, word: &str, category: Option<String>) {
+        match category {
+            Some(category) => {
+                self.vocabulary_categories
+                    .insert(word.to_string(), category);
+            }
+            None => {
+                self.vocabulary_categories.remove(word);
+            }
+        }
+    }
+
+    /// Add a category to the palette with `color`, keeping the color of an existing one.
+    /// Returns whether the category is new.
+    pub fn add_category(&mut self, name: &str, color: [u8; 3]) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.category_colors.contains_key(name) {
+            return false;
+        }
+        self.category_colors.insert(name.to_string(), color);
+        true
+    }
+
+    /// Remove a category from the palette and from every word assigned to it
+    pub fn remove_category(&mut self, name: &str) {
+        self.category_colors.remove(name);
+        self.vocabulary_categories
+            .retain(|_, category| category != name);
+    }
+
+    /// Number of vocabulary words assigned to each category
+    pub fn category_word_counts(&self) -> BTreeMap<&str, usize> {
+        self.vocabulary_categories
+            .values()
+            .fold(BTreeMap::new(), |mut counts, category| {
+                *counts.entry(category.as_str()).or_default() += 1;
+                counts
+            })
+    }
+
+    /// Number of tokens whose formation chain references each rule, indexed like `formation_rules`
+    pub fn rule_usage_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.formation_rules.len()];
+        for token in self.segments.iter().flat_map(|segment| &segment.tokens) {
+            for (rule_idx, count) in counts.iter_mut().enumerate() {
+                if token.formation_rule_indices.contains(&rule_idx) {
+                    *count += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Turn every token whose chain uses rule `rule_idx` back into a plain word,
+    /// keeping its surface form. Returns the number of tokens changed.
+    pub fn clear_formation_rule(&mut self, rule_idx: usize) -> usize {
+        let mut changed = 0;
+        for token in self
+            .segments
+            .iter_mut()
+            .flat_map(|segment| &mut segment.tokens)
+        {
+            if token.formation_rule_indices.contains(&rule_idx) {
+                token.formation_rule_indices.clear();
+                token.base_word = None;
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Delete rule `rule_idx`, shifting later rule references on every token down by one.
+    /// Returns `None` without changing anything if the index is out of bounds or still in use.
+    pub fn remove_formation_rule(&mut self, rule_idx: usize) -> Option<FormationRule> {
+        if rule_idx >= self.formation_rules.len()
+            || self
+                .segments
+                .iter()
+                .flat_map(|segment| &segment.tokens)
+                .any(|token| token.formation_rule_indices.contains(&rule_idx))
+        {
+            return None;
+        }
+        for token in self
+            .segments
+            .iter_mut()
+            .flat_map(|segment| &mut segment.tokens)
+        {
+            for idx in &mut token.formation_rule_indices {
+                if *idx > rule_idx {
+                    *idx -= 1;
+                }
+            }
+        }
+        Some(self.formation_rules.remove(rule_idx))
+    }
+
+    /// Remove the custom font at `slot`.
+    /// Segments using it fall back to the default font; later overrides shift down by one.
+    pub fn remove_font(&mut self, slot: usize) -> Option<CustomFont> {
+        if slot >= self.fonts.len() {
+            return None;
+        }
+        for segment in &mut self.segments {
+            segment.font = match segment.font {
+                Some(font) if font == slot => None,
+                Some(font) if font > slot => Some(font - 1),
+                other => other,
+            };
+        }
+        Some(self.fonts.remove(slot))
+    }
+
+    /// Assign line `i` of a parallel translation to segment `i`.
+    /// Blank lines and locked segments are skipped, and lines beyond the last segment are
+    /// ignored. Returns how many translations changed.
+    pub fn apply_parallel_translations(&mut self, lines: &[&str]) -> usize {
+        let mut changed = 0;
+        for (segment, line) in self.segments.iter_mut().zip(lines) {
+            let line = line.trim();
+            if line.is_empty() || segment.locked {
+                continue;
+            }
+            if segment.set_translation(line.to_string()) {
+                changed += 1;
+            }
+        }
+        changed
+    }
+
+    /// Add `segments` after the existing ones. Words they use that the vocabulary lacks get
+    /// an empty gloss; existing glosses, comments, and segments are left alone.
+    /// Returns the number of words added to the vocabulary.
+    pub fn append_segments(&mut self, segments: Vec<Segment>) -> usize {
+        let added = seed_vocabulary(
+            &mut self.vocabulary,
+            segments.iter().flat_map(|segment| &segment.tokens),
+        );
+        self.segments.extend(segments);
+        added
+    }
+
+    /// Give every word used by segment `idx` that the vocabulary lacks an empty gloss.
+    /// Derived tokens seed their base word. Returns the number of words added; 0 when
+    /// `idx` is out of bounds.
+    pub fn seed_vocabulary_from_segment(&mut self, idx: usize) -> usize {
+        match self.segments.get(idx) {
+            Some(segment) => seed_vocabulary(&mut self.vocabulary, &segment.tokens),
+            None => 0,
+        }
+    }
+
+    /// Remove the segment at `idx`, shifting later segments down by one.
+    /// Returns the removed segment, or `None` when `idx` is out of bounds.
+    pub fn remove_segment(&mut self, idx: usize) -> Option<Segment> {
+        (idx < self.segments.len()).then(|| self.segments.remove(idx))
+    }
+}
+
+/// Insert an empty gloss for the base word of each token missing from `vocabulary`,
+/// returning how many were inserted
+fn seed_vocabulary<'a>(
+    vocabulary: &mut HashMap<String, String>,
+    tokens: impl IntoIterator<Item = &'a Token>,
+) -> usize {
+    let mut added = 0;
+    for token in tokens {
+        let word = token.base_word.as_ref().unwrap_or(&token.original);
+        if !vocabulary.contains_key(word) {
+            vocabulary.insert(word.clone(), String::new());
+            added += 1;
+        }
+    }
+    added
+}
+
+impl PhraseGloss {
+    /// Whether token `idx` falls inside this phrase
+    pub fn contains(&self, idx: usize) -> bool {
+        (self.start..=self.end).contains(&idx)
+    }
+}
+
+impl Segment {
+    /// Whether the segment carries `tag`, ignoring case
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.to_lowercase();
+        self.tags.iter().any(|t| t.to_lowercase() == tag)
+    }
+
+    /// Replace the translation unless the segment is locked.
+    /// Returns whether the translation changed.
+    pub fn set_translation(&mut self, translation: String) -> bool {
+        if self.locked || self.translation == translation {
+            return false;
+        }
+        self.translation = translation;
+        true
+    }
+
+    /// The phrase gloss covering token `idx`, if any
+    pub fn phrase_at(&self, idx: usize) -> Option<&PhraseGloss> {
+        self.phrases.iter().find(|phrase| phrase.contains(idx))
+    }
+
+    /// Gloss tokens `start..=end` as one phrase, replacing the gloss of an identical range.
+    /// Returns `false` without changes when the segment is locked, the range covers fewer
+    /// than two tokens, runs past the last token, or overlaps a different phrase.
+    pub fn set_phrase_gloss(&mut self, start: usize, end: usize, gloss: String) -> bool {
+        if self.locked || start >= end || end >= self.tokens.len() {
+            return false;
+        }
+        if let Some(existing) = self
+            .phrases
+            .iter_mut()
+            .find(|phrase| phrase.start == start && phrase.end == end)
+        {
+            existing.gloss = gloss;
+            return true;
+        }
+        if self
+            .phrases
+            .iter()
+            .any(|phrase| phrase.start <= end && start <= phrase.end)
+        {
+            return false;
+        }
+
+        let pos = self.phrases.partition_point(|phrase| phrase.start < start);
+        self.phrases.insert(pos, PhraseGloss { start, end, gloss });
+        true
+    }
+
+    /// Remove the phrase starting at token `start`, unless the segment is locked
+    pub fn remove_phrase_gloss(&mut self, start: usize) -> Option<PhraseGloss> {
+        if self.locked {
+            return None;
+        }
+        let pos = self
+            .phrases
+            .iter()
+            .position(|phrase| phrase.start == start)?;
+        Some(self.phrases.remove(pos))
+    }
+}
+
+/// Tint color for `word`: the palette color of its category,
+/// or `None` when the word is uncategorized or its category has no color
+pub fn category_color(
+    categories: &HashMap<String, String>,
+    palette: &BTreeMap<String, [u8; 3]>,
+    word: &str,
+) -> Option<[u8; 3]> {
+    categories
+        .get(word)
+        .and_then(|category| palette.get(category))
+        .copied()
+}
+
+/// Split user input into tags on commas and whitespace.
+/// Empty entries and case-insensitive repeats are dropped; first-seen order is kept.
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        let tag = tag.trim();
+        if !tag.is_empty() && !tags.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+fn join_with_space(target: &mut String, other: &str) {
+    if other.is_empty() {
+        return;
+    }
+    if !target.is_empty() {
+        target.push(' ');
+    }
+    target.push_str(other);
+}
@@ -1,53 +1,363 @@
-use ndarray::Array2;
-use scirs2_text::{TfidfVectorizer, Vectorizer, WhitespaceTokenizer, cosine_similarity};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-use crate::libs::Project;
+use ndarray::{Array2, ArrayViewMut1};
+use scirs2_text::cosine_similarity;
+
+use crate::libs::{Project, Segment};
+
+/// Sparse term statistics behind a TF-IDF matrix.
+/// Kept alongside the matrix so a single edited document can be re-weighted
+/// without re-tokenizing the whole project.
+///
+/// Weights use the smoothed IDF `ln((1 + n) / (1 + df)) + 1` and every row is L2-normalized.
+#[derive(Debug, Clone, Default)]
+pub struct TfidfIndex {
+    /// Term -> matrix column
+    columns: HashMap<String, usize>,
+    /// Number of documents containing each column's term
+    doc_freq: Vec<usize>,
+    /// Per-document term counts keyed by column
+    doc_counts: Vec<BTreeMap<usize, usize>>,
+}
+
+type TermCounts<'a> = Vec<(&'a str, usize)>;
+
+/// Project settings that decide which terms a segment contributes to the TF-IDF model
+#[derive(Debug, Clone, Copy)]
+pub struct TermOptions<'a> {
+    /// Use each derived token's base word instead of its surface form
+    pub by_base_word: bool,
+    /// Terms dropped entirely
+    pub stopwords: &'a BTreeSet<String>,
+}
+
+impl<'a> TermOptions<'a> {
+    /// Read the term settings stored on a project
+    pub fn from_project(project: &'a Project) -> Self {
+        Self {
+            by_base_word: project.similarity_by_base_word,
+            stopwords: &project.stopwords,
+        }
+    }
+}
+
+impl TfidfIndex {
+    /// Count terms for every segment in the project.
+    /// With the `parallel` feature on native targets, documents are counted across threads;
+    /// the result is identical to `build_serial`.
+    pub fn build(project: &Project) -> Self {
+        Self::build_documents(&project.segments, TermOptions::from_project(project))
+    }
+
+    /// Count terms for every segment in `segments`; see [`Self::build`].
+    /// Takes the segments directly so the index can be built off the UI thread.
+    pub fn build_documents(segments: &[Segment], options: TermOptions<'_>) -> Self {
+        #[cfg(all(feature = "parallel", not(target_arch = "wasm32")))]
+        {
+            use rayon::prelude::*;
+
+            let documents: Vec<TermCounts<'_>> = segments
+                .par_iter()
+                .map(|segment| count_document(&SimilarityEngine::document_terms(segment, options)))
+                .collect();
+            Self::from_document_counts(documents)
+        }
+
+        #[cfg(not(all(feature = "parallel", not(target_arch = "wasm32"))))]
+        {
+            Self::build_documents_serial(segments, options)
+        }
+    }
+
+    /// Count terms for every segment on the current thread.
+    /// This is the only path on wasm, which has no thread pool.
+    #[allow(dead_code)]
+    pub fn build_serial(project: &Project) -> Self {
+        Self::build_documents_serial(&project.segments, TermOptions::from_project(project))
+    }
+
+    #[allow(dead_code)]
+    fn build_documents_serial(segments: &[Segment], options: TermOptions<'_>) -> Self {
+        let documents: Vec<TermCounts<'_>> = segments
+            .iter()
+            .map(|segment| count_document(&SimilarityEngine::document_terms(segment, options)))
+            .collect();
+        Self::from_document_counts(documents)
+    }
+
+    /// Assign columns in document order and merge per-document counts into document frequencies.
+    /// Done serially so column order never depends on how counting was scheduled.
+    fn from_document_counts(documents: Vec<TermCounts<'_>>) -> Self {
+        let mut index = Self::default();
+        for document in documents {
+            let counts = index.assign_columns(document);
+            for &column in counts.keys() {
+                index.doc_freq[column] += 1;
+            }
+            index.doc_counts.push(counts);
+        }
+        index
+    }
+
+    /// Number of documents (segments) indexed
+    pub fn num_documents(&self) -> usize {
+        self.doc_counts.len()
+    }
+
+    /// Number of distinct terms seen, i.e. matrix columns
+    pub fn num_terms(&self) -> usize {
+        self.doc_freq.len()
+    }
+
+    /// Build the dense TF-IDF matrix, one row per document
+    pub fn matrix(&self) -> Array2<f64> {
+        let mut matrix = Array2::zeros((self.num_documents(), self.num_terms()));
+        for (doc_idx, row) in matrix.rows_mut().into_iter().enumerate() {
+            self.write_row(doc_idx, row);
+        }
+        matrix
+    }
+
+    /// Replace one document's terms and patch `matrix` in place.
+    /// Other rows sharing a term whose document frequency changed are re-weighted as well,
+    /// since their IDF moved. Returns `false` when `doc_idx` or the matrix shape does not
+    /// match this index, in which case the caller should rebuild.
+    pub fn update_document(
+        &mut self,
+        doc_idx: usize,
+        terms: &[&str],
+        matrix: &mut Array2<f64>,
+    ) -> bool {
+        if doc_idx >= self.num_documents()
+            || matrix.dim() != (self.num_documents(), self.num_terms())
+        {
+            return false;
+        }
+
+        let new_counts = self.assign_columns(count_document(terms));
+        let old_counts = std::mem::replace(&mut self.doc_counts[doc_idx], new_counts);
+
+        let mut changed_columns = BTreeSet::new();
+        for &column in old_counts.keys() {
+            if !self.doc_counts[doc_idx].contains_key(&column) {
+                self.doc_freq[column] -= 1;
+                changed_columns.insert(column);
+            }
+        }
+        for &column in self.doc_counts[doc_idx].keys() {
+            if !old_counts.contains_key(&column) {
+                self.doc_freq[column] += 1;
+                changed_columns.insert(column);
+            }
+        }
+
+        if self.num_terms() != matrix.ncols() {
+            // New terms add columns; rebuild the dense matrix from the stored counts
+            *matrix = self.matrix();
+            return true;
+        }
+
+        for (idx, counts) in self.doc_counts.iter().enumerate() {
+            if idx == doc_idx || counts.keys().any(|column| changed_columns.contains(column)) {
+                self.write_row(idx, matrix.row_mut(idx));
+            }
+        }
+        true
+    }
+
+    fn assign_columns(&mut self, document: TermCounts<'_>) -> BTreeMap<usize, usize> {
+        let mut counts = BTreeMap::new();
+        for (term, count) in document {
+            let next = self.columns.len();
+            let column = *self.columns.entry(term.to_string()).or_insert(next);
+            if column == self.doc_freq.len() {
+                self.doc_freq.push(0);
+            }
+            counts.insert(column, count);
+        }
+        counts
+    }
+
+    fn idf(&self, column: usize) -> f64 {
+        let n = self.num_documents() as f64;
+        ((1.0 + n) / (1.0 + self.doc_freq[column] as f64)).ln() + 1.0
+    }
+
+    fn write_row(&self, doc_idx: usize, mut row: ArrayViewMut1<'_, f64>) {
+        row.fill(0.0);
+        let counts = &self.doc_counts[doc_idx];
+
+        let mut norm = 0.0;
+        for (&column, &count) in counts {
+            let weight = count as f64 * self.idf(column);
+            row[column] = weight;
+            norm += weight * weight;
+        }
+
+        if norm > 0.0 {
+            let norm = norm.sqrt();
+            for &column in counts.keys() {
+                row[column] /= norm;
+            }
+        }
+    }
+}
+
+/// Parse a stopword list typed by the user; any whitespace separates entries
+pub fn parse_stopwords(text: &str) -> BTreeSet<String> {
+    text.split_whitespace().map(str::to_string).collect()
+}
+
+/// Count each distinct term in a document, in order of first appearance
+fn count_document<'a>(terms: &[&'a str]) -> TermCounts<'a> {
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    let mut counts: TermCounts<'a> = Vec::new();
+    for &term in terms {
+        match positions.get(term) {
+            Some(&pos) => counts[pos].1 += 1,
+            None => {
+                positions.insert(term, counts.len());
+                counts.push((term, 1));
+            }
+        }
+    }
+    counts
+}
+
+/// Jaccard similarity of two term sets: the size of their intersection over the size of
+/// their union. Two empty sets score 0.
+#[must_use]
+pub fn jaccard_similarity(left: &HashSet<&str>, right: &HashSet<&str>) -> f64 {
+    let shared = left.intersection(right).count();
+    let union = left.len() + right.len() - shared;
+    if union == 0 {
+        0.0
+    } else {
+        shared as f64 / union as f64
+    }
+}
+
+/// A translation offered for an untranslated segment, taken from a similar segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslationSuggestion {
+    /// Index of the segment the translation comes from
+    pub segment_idx: usize,
+    /// Similarity of that segment to the untranslated one
+    pub score: f64,
+    /// The other segment's translation
+    pub translation: String,
+}
+
+/// Turn ranked (`segment_index`, `similarity_score`) pairs into translation suggestions.
+/// Segments with a blank translation are skipped, and a translation already offered by a
+/// more similar segment is not repeated. At most `limit` suggestions are returned.
+#[must_use]
+pub fn translation_suggestions(
+    project: &Project,
+    similar: &[(usize, f64)],
+    limit: usize,
+) -> Vec<TranslationSuggestion> {
+    let mut seen = HashSet::new();
+    similar
+        .iter()
+        .filter_map(|&(segment_idx, score)| {
+            let translation = project.segments.get(segment_idx)?.translation.trim();
+            (!translation.is_empty() && seen.insert(translation)).then(|| TranslationSuggestion {
+                segment_idx,
+                score,
+                translation: translation.to_string(),
+            })
+        })
+        .take(limit)
+        .collect()
+}
 
 /// Similarity search engine for finding semantically related segments using TF-IDF vectors.
 pub struct SimilarityEngine;
 
 impl SimilarityEngine {
+    /// Terms a segment contributes to the TF-IDF model: its tokens split on whitespace,
+    /// minus stopwords. With `by_base_word`, derived tokens contribute their base word.
+    pub fn document_terms<'a>(segment: &'a Segment, options: TermOptions<'_>) -> Vec<&'a str> {
+        segment
+            .tokens
+            .iter()
+            .map(|token| match &token.base_word {
+                Some(base) if options.by_base_word => base.as_str(),
+                _ => token.original.as_str(),
+            })
+            .flat_map(str::split_whitespace)
+            .filter(|term| !options.stopwords.contains(*term))
+            .collect()
+    }
+
     /// Compute the TF-IDF matrix from all segments in the project.
-    /// Each segment is treated as a document with tokens separated by whitespace.
-    /// Returns None if the project has no segments or computation fails.
+    /// Each segment is treated as a document.
+    /// Returns None if the project has no segments.
     pub fn compute_tfidf_matrix(project: &Project) -> Option<Array2<f64>> {
+        Self::build_index(project).map(|index| index.matrix())
+    }
+
+    /// Build the term index behind the TF-IDF matrix.
+    /// Returns None if the project has no segments.
+    pub fn build_index(project: &Project) -> Option<TfidfIndex> {
         if project.segments.is_empty() {
             return None;
         }
+        Some(TfidfIndex::build(project))
+    }
 
-        // Reconstruct document strings from token sequences
-        let mut documents: Vec<String> = Vec::with_capacity(project.segments.len());
-        for seg in &project.segments {
-            let estimated_len = seg.tokens.iter().map(|t| t.original.len()).sum::<usize>()
-                + seg.tokens.len().saturating_sub(1);
-            let mut doc = String::with_capacity(estimated_len);
-            for (idx, token) in seg.tokens.iter().enumerate() {
-                if idx > 0 {
-                    doc.push(' ');
-                }
-                doc.push_str(&token.original);
-            }
-            documents.push(doc);
-        }
-
-        // Create TF-IDF vectorizer with whitespace tokenization and L2 normalization
-        let tokenizer = Box::new(WhitespaceTokenizer::new());
-        let mut vectorizer =
-            TfidfVectorizer::with_tokenizer(tokenizer, false, true, Some("l2".to_string()));
-
-        let doc_refs: Vec<&str> = documents.iter().map(|s| s.as_str()).collect();
-        vectorizer.fit_transform(&doc_refs).ok()
+    /// Find the `k` segments most similar to `target` without any app-side caching.
+    /// Builds a fresh TF-IDF matrix on every call, so prefer [`Self::find_similar`]
+    /// with a cached matrix when querying repeatedly.
+    ///
+    /// ```
+    /// use tdector::libs::similarity_sentence::SimilarityEngine;
+    /// use tdector::libs::{Project, Segment, Token};
+    ///
+    /// let segment = |words: &[&str]| Segment {
+    ///     tokens: words
+    ///         .iter()
+    ///         .map(|w| Token {
+    ///             original: (*w).to_string(),
+    ///             base_word: None,
+    ///             formation_rule_indices: Vec::new(),
+    ///         })
+    ///         .collect(),
+    ///     ..Default::default()
+    /// };
+    /// let project = Project {
+    ///     segments: vec![
+    ///         segment(&["the", "cat", "sat"]),
+    ///         segment(&["the", "dog", "sat"]),
+    ///         segment(&["a", "bird", "flew"]),
+    ///     ],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let similar = SimilarityEngine::similar_segments(&project, 0, 5);
+    /// assert_eq!(similar.len(), 1);
+    /// assert_eq!(similar[0].0, 1);
+    /// ```
+    #[must_use]
+    #[allow(dead_code)]
+    pub fn similar_segments(project: &Project, target: usize, k: usize) -> Vec<(usize, f64)> {
+        Self::compute_tfidf_matrix(project)
+            .map(|matrix| Self::find_similar(&matrix, target, k))
+            .unwrap_or_default()
     }
 
     /// Find the most similar segments to a target segment using cosine similarity.
     /// Returns a vector of (`segment_index`, `similarity_score`) sorted by score in descending order.
     /// Scores are clamped to be > 0.0 to avoid near-zero or negative similarities.
+    /// A `limit` of zero yields no results; a `limit` beyond the corpus size yields every
+    /// similar segment, so callers need not clamp it.
     pub fn find_similar(
         matrix: &Array2<f64>,
         target_idx: usize,
         limit: usize,
     ) -> Vec<(usize, f64)> {
-        if target_idx >= matrix.nrows() {
+        if limit == 0 || target_idx >= matrix.nrows() {
             return Vec::new();
         }
 
@@ -75,4 +385,45 @@ impl SimilarityEngine {
         similarities.truncate(limit);
         similarities
     }
+
+    /// Find the segments sharing the most distinct terms with `target_idx`, scored by
+    /// [`jaccard_similarity`] of their term sets. Needs no TF-IDF matrix; stopwords and the
+    /// base-word setting apply as for [`Self::document_terms`].
+    /// Returns (`segment_index`, `similarity_score`) pairs with positive scores, best first.
+    pub fn find_similar_jaccard(
+        project: &Project,
+        target_idx: usize,
+        limit: usize,
+    ) -> Vec<(usize, f64)> {
+        if limit == 0 {
+            return Vec::new();
+        }
+        let Some(target) = project.segments.get(target_idx) else {
+            return Vec::new();
+        };
+        let options = TermOptions::from_project(project);
+        let target_terms: HashSet<&str> =
+            Self::document_terms(target, options).into_iter().collect();
+
+        let mut similarities: Vec<(usize, f64)> = project
+            .segments
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| *idx != target_idx)
+            .filter_map(|(idx, segment)| {
+                let terms: HashSet<&str> =
+                    Self::document_terms(segment, options).into_iter().collect();
+                let score = jaccard_similarity(&target_terms, &terms);
+                (score > 0.0).then_some((idx, score))
+            })
+            .collect();
+
+        similarities.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.0.cmp(&b.0))
+        });
+        similarities.truncate(limit);
+        similarities
+    }
 }
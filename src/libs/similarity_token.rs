@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use crate::consts::domain::MAX_SIMILAR_TOKENS_RESULTS;
+use crate::consts::domain::{MAX_SIMILAR_TOKENS_RESULTS, RELATED_WORDS_MAX_DISTANCE};
 use crate::libs::Project;
 
 /// Represents a similar token with its similarity metrics
@@ -95,3 +95,57 @@ pub fn find_similar_tokens(project: &Project, word: &str) -> Vec<SimilarToken> {
 
     similar_tokens
 }
+
+/// Pick up to `limit` words related to `query`, ignoring case.
+///
+/// Words containing the query are returned first, prefix matches ahead of the rest and
+/// each group alphabetical. Only when no word contains the query do words within
+/// `RELATED_WORDS_MAX_DISTANCE` edits qualify, so a typo still finds its intended word;
+/// these are ranked by edit distance, then alphabetically.
+pub fn find_related_words<'a>(
+    words: impl IntoIterator<Item = &'a String>,
+    query: &str,
+    limit: usize,
+) -> Vec<String> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let query_lower = query.to_lowercase();
+    let candidates: Vec<(String, &String)> = words
+        .into_iter()
+        .map(|word| (word.to_lowercase(), word))
+        .collect();
+
+    let mut contained: Vec<(bool, &str, &String)> = candidates
+        .iter()
+        .filter(|(lower, _)| lower.contains(&query_lower))
+        .map(|(lower, word)| (!lower.starts_with(&query_lower), lower.as_str(), *word))
+        .collect();
+    if !contained.is_empty() {
+        contained.sort_unstable();
+        return contained
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, word)| word.clone())
+            .collect();
+    }
+
+    let mut fuzzy: Vec<(usize, &str, &String)> = candidates
+        .iter()
+        .map(|(lower, word)| {
+            (
+                textdistance::str::levenshtein(&query_lower, lower),
+                lower.as_str(),
+                *word,
+            )
+        })
+        .filter(|&(distance, ..)| distance <= RELATED_WORDS_MAX_DISTANCE)
+        .collect();
+    fuzzy.sort_unstable();
+    fuzzy
+        .into_iter()
+        .take(limit)
+        .map(|(_, _, word)| word.clone())
+        .collect()
+}
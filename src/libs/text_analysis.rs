@@ -1,7 +1,122 @@
-use crate::enums::AppResult;
+use std::collections::HashSet;
+
+use unicode_general_category::{GeneralCategory, get_general_category};
+
+use crate::enums::{AppResult, PunctuationMode, SegmentationMode};
 use crate::libs::eval::TokenizationRule;
+use crate::libs::tokenizer::{ScriptTokenizer, Tokenizer, plain_token};
 use crate::libs::{Project, Segment, Token};
 
+/// A single keyword-in-context line for a concordance view
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConcordanceLine {
+    /// Index of the segment containing the occurrence
+    pub segment_idx: usize,
+    /// Index of the keyword token within the segment
+    pub token_idx: usize,
+    /// Tokens preceding the keyword, clipped at the segment start
+    pub left: Vec<String>,
+    /// The keyword as it appears in the segment
+    pub keyword: String,
+    /// Tokens following the keyword, clipped at the segment end
+    pub right: Vec<String>,
+}
+
+/// One step of a token alignment between two segments, see [`align_tokens`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    /// Token `.0` of the first segment matches token `.1` of the second
+    Equal(usize, usize),
+    /// Token of the first segment with no counterpart in the second
+    Delete(usize),
+    /// Token of the second segment with no counterpart in the first
+    Insert(usize),
+}
+
+impl DiffOp {
+    /// Index of the first segment's token covered by this step, if any
+    #[must_use]
+    pub const fn first(self) -> Option<usize> {
+        match self {
+            Self::Equal(idx, _) | Self::Delete(idx) => Some(idx),
+            Self::Insert(_) => None,
+        }
+    }
+
+    /// Index of the second segment's token covered by this step, if any
+    #[must_use]
+    pub const fn second(self) -> Option<usize> {
+        match self {
+            Self::Equal(_, idx) | Self::Insert(idx) => Some(idx),
+            Self::Delete(_) => None,
+        }
+    }
+}
+
+/// Token statistics of a single segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SegmentStats {
+    /// Number of tokens
+    pub token_count: usize,
+    /// Number of distinct token forms
+    pub unique_count: usize,
+    /// Number of translated tokens, see [`TextProcessor::count_segment_translated_tokens`]
+    pub translated_count: usize,
+}
+
+impl SegmentStats {
+    /// Type-token ratio: distinct forms over tokens, 0 for an empty segment
+    #[must_use]
+    pub fn type_token_ratio(&self) -> f64 {
+        ratio(self.unique_count, self.token_count)
+    }
+
+    /// Share of tokens with a gloss, 0 for an empty segment
+    #[must_use]
+    pub fn translated_ratio(&self) -> f64 {
+        ratio(self.translated_count, self.token_count)
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+/// Segments an import would produce, computed before the import is committed.
+/// The dialog shows a sample and the totals, then commits these exact segments.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentationPreview {
+    /// Every segment the import would create
+    pub segments: Vec<Segment>,
+    /// Total number of tokens across all segments
+    pub token_count: usize,
+}
+
+impl SegmentationPreview {
+    /// Segment `text` with the given mode and punctuation handling
+    pub fn new(
+        text: &str,
+        mode: SegmentationMode,
+        punctuation: PunctuationMode,
+    ) -> AppResult<Self> {
+        let segments = TextProcessor::segment_content(text, mode, punctuation)?;
+        let token_count = segments.iter().map(|segment| segment.tokens.len()).sum();
+        Ok(Self {
+            segments,
+            token_count,
+        })
+    }
+
+    #[must_use]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+}
+
 /// Text processing utility for tokenizing and analyzing text content.
 pub struct TextProcessor;
 
@@ -12,44 +127,74 @@ impl TextProcessor {
         text: &str,
         tokenization_rule: Option<&TokenizationRule>,
     ) -> AppResult<Vec<Segment>> {
-        let lines: Vec<&str> = text.lines().collect();
+        match tokenization_rule {
+            Some(rule) => Self::segment_with_tokenizer(text, &ScriptTokenizer(rule.clone())),
+            // Fail on the first non-empty line if no rule was provided
+            None => Self::segment_lines(text, |_| {
+                Err(crate::enums::AppError::InvalidProjectFormat(
+                    "No tokenization rule provided".to_string(),
+                ))
+            }),
+        }
+    }
+
+    /// Split text into segments using one of the built-in segmentation modes,
+    /// handling punctuation in the resulting tokens as requested
+    pub fn segment_content(
+        text: &str,
+        mode: SegmentationMode,
+        punctuation: PunctuationMode,
+    ) -> AppResult<Vec<Segment>> {
+        let tokenizer = mode.tokenizer();
+        Self::segment_lines(text, |line| {
+            let words = tokenizer
+                .tokenize(line)?
+                .into_iter()
+                .map(|token| token.original)
+                .collect();
+            Ok(apply_punctuation_mode(words, punctuation)
+                .into_iter()
+                .map(plain_token)
+                .collect())
+        })
+    }
+
+    /// Split text into segments with any tokenizer
+    pub fn segment_with_tokenizer(
+        text: &str,
+        tokenizer: &dyn Tokenizer,
+    ) -> AppResult<Vec<Segment>> {
+        Self::segment_lines(text, |line| tokenizer.tokenize(line))
+    }
+
+    /// Turn every non-empty line into a segment of the tokens `tokenize` yields for it.
+    /// Lines that produce no tokens are skipped.
+    fn segment_lines(
+        text: &str,
+        tokenize: impl Fn(&str) -> AppResult<Vec<Token>>,
+    ) -> AppResult<Vec<Segment>> {
         let mut segments = Vec::new();
 
-        for line in lines {
+        for line in text.lines() {
             // Skip empty lines
             if line.trim().is_empty() {
                 continue;
             }
 
-            // Get tokenization rule (fail if none provided)
-            let rule = tokenization_rule.ok_or_else(|| {
-                crate::enums::AppError::InvalidProjectFormat(
-                    "No tokenization rule provided".to_string(),
-                )
-            })?;
-
-            // Tokenize the line using the Rhai script
-            let token_strings = rule.tokenize(line)?;
-
-            // Convert token strings to Token objects
-            let tokens = token_strings
-                .into_iter()
-                .map(|text| Token {
-                    original: text,
-                    base_word: None,
-                    formation_rule_indices: Vec::new(),
-                })
-                .collect();
+            let tokens = tokenize(line)?;
 
             // Create and add segment if it has tokens
-            let segment = Segment {
-                tokens,
-                translation: String::new(),
-                comment: String::new(),
-            };
-
-            if !segment.tokens.is_empty() {
-                segments.push(segment);
+            if !tokens.is_empty() {
+                segments.push(Segment {
+                    tokens,
+                    translation: String::new(),
+                    comment: String::new(),
+                    font: None,
+                    phrases: Vec::new(),
+                    locked: false,
+                    reviewed: false,
+                    tags: Vec::new(),
+                });
             }
         }
 
@@ -69,20 +214,261 @@ impl TextProcessor {
         }
     }
 
-    /// Count how many tokens in a segment have vocabulary definitions.
-    /// A token is considered translated if its original form exists in the project vocabulary
-    /// and has a non-empty definition.
+    /// Count how many tokens in a segment are translated.
+    /// A token is translated when its base word, or its own form for a plain token, has a
+    /// non-blank gloss or is marked as intentionally having no gloss, matching
+    /// [`Project::untranslated_words`].
     pub fn count_segment_translated_tokens(segment: &Segment, project: &Project) -> usize {
         segment
             .tokens
             .iter()
             .filter(|token| {
-                project
-                    .vocabulary
-                    .get(&token.original)
-                    .map(|def| !def.trim().is_empty())
-                    .unwrap_or(false)
+                let base_word = token.base_word.as_ref().unwrap_or(&token.original);
+                !project.needs_gloss(base_word)
             })
             .count()
     }
+
+    /// Build keyword-in-context lines for every occurrence of a word.
+    /// Each line carries up to `context` tokens on either side of the keyword;
+    /// context never crosses segment boundaries.
+    pub fn concordance_lines(
+        project: &Project,
+        word: &str,
+        context: usize,
+    ) -> Vec<ConcordanceLine> {
+        let mut lines = Vec::new();
+
+        for (segment_idx, segment) in project.segments.iter().enumerate() {
+            for (token_idx, token) in segment.tokens.iter().enumerate() {
+                if token.original != word {
+                    continue;
+                }
+
+                let left_start = token_idx.saturating_sub(context);
+                let right_end = (token_idx + 1 + context).min(segment.tokens.len());
+
+                lines.push(ConcordanceLine {
+                    segment_idx,
+                    token_idx,
+                    left: segment.tokens[left_start..token_idx]
+                        .iter()
+                        .map(|t| t.original.clone())
+                        .collect(),
+                    keyword: token.original.clone(),
+                    right: segment.tokens[token_idx + 1..right_end]
+                        .iter()
+                        .map(|t| t.original.clone())
+                        .collect(),
+                });
+            }
+        }
+
+        lines
+    }
+}
+
+/// Whether a character belongs to a script usually written without spaces between words
+/// (Han, kana, Thai, Lao, Khmer, Myanmar, Tibetan)
+fn is_unspaced_script(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0E00}'..='\u{0EFF}'
+            | '\u{0F00}'..='\u{0FFF}'
+            | '\u{1000}'..='\u{109F}'
+            | '\u{1780}'..='\u{17FF}'
+            | '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{20000}'..='\u{2FA1F}'
+    )
+}
+
+/// Count the tokens, distinct token forms, and translated tokens of a segment.
+/// A token counts as translated under the same rule as [`translated_ratio`].
+#[must_use]
+pub fn segment_stats(segment: &Segment, project: &Project) -> SegmentStats {
+    let unique: HashSet<&str> = segment
+        .tokens
+        .iter()
+        .map(|token| token.original.as_str())
+        .collect();
+    SegmentStats {
+        token_count: segment_length(segment),
+        unique_count: unique.len(),
+        translated_count: TextProcessor::count_segment_translated_tokens(segment, project),
+    }
+}
+
+/// Number of tokens in a segment, the denominator of every per-segment ratio.
+/// Punctuation kept as tokens counts like any other token.
+#[must_use]
+pub fn segment_length(segment: &Segment) -> usize {
+    segment.tokens.len()
+}
+
+/// Share of a segment's tokens that are translated, from 0.0 to 1.0, and 0.0 for an empty
+/// segment.
+///
+/// Tokens are counted by [`TextProcessor::count_segment_translated_tokens`]: derived tokens
+/// inherit the gloss of their base word, and words marked as needing no gloss count as
+/// translated.
+#[must_use]
+pub fn translated_ratio(segment: &Segment, project: &Project) -> f64 {
+    ratio(
+        TextProcessor::count_segment_translated_tokens(segment, project),
+        segment_length(segment),
+    )
+}
+
+/// Align two token sequences by their surface forms along a longest common subsequence.
+/// Every token of `a` appears in exactly one `Equal` or `Delete` step and every token of
+/// `b` in one `Equal` or `Insert` step, both in order. Where a token could be dropped from
+/// either side, the one from `a` goes first.
+#[must_use]
+pub fn align_tokens(a: &[Token], b: &[Token]) -> Vec<DiffOp> {
+    let (n, m) = (a.len(), b.len());
+    // lcs[i][j] is the length of the longest common subsequence of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i].original == b[j].original {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i].original == b[j].original {
+            ops.push(DiffOp::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(DiffOp::Delete(i));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Insert(j));
+            j += 1;
+        }
+    }
+    ops.extend((i..n).map(DiffOp::Delete));
+    ops.extend((j..m).map(DiffOp::Insert));
+    ops
+}
+
+/// Guess the segmentation mode that suits `content`.
+/// Text made mostly of scripts written without spaces (CJK, Thai, ...) and with few spaces
+/// between them is split by character; everything else by whitespace. The flag is `true`
+/// when the guess is clear-cut and `false` when the text is mixed or has no letters at all.
+#[must_use]
+pub fn suggest_segmentation(content: &str) -> (SegmentationMode, bool) {
+    let mut letters = 0usize;
+    let mut unspaced = 0usize;
+    let mut words = 0usize;
+    for line in content.lines() {
+        words += line.split_whitespace().count();
+        for c in line.chars() {
+            if c.is_whitespace() || is_punctuation(c) {
+                continue;
+            }
+            letters += 1;
+            if is_unspaced_script(c) {
+                unspaced += 1;
+            }
+        }
+    }
+    if letters == 0 {
+        return (SegmentationMode::Whitespace, false);
+    }
+
+    let unspaced_share = unspaced as f64 / letters as f64;
+    let letters_per_word = letters as f64 / words.max(1) as f64;
+    let character_based = unspaced_share > 0.5 && letters_per_word >= 2.0;
+    let confident = if character_based {
+        unspaced_share >= 0.8
+    } else {
+        unspaced_share <= 0.2
+    };
+    let mode = if character_based {
+        SegmentationMode::Character
+    } else {
+        SegmentationMode::Whitespace
+    };
+    (mode, confident)
+}
+
+/// Whether a character is in one of the Unicode punctuation categories (`Pc`, `Pd`, `Ps`,
+/// `Pe`, `Pi`, `Pf`, `Po`)
+#[must_use]
+pub fn is_punctuation(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::ConnectorPunctuation
+            | GeneralCategory::DashPunctuation
+            | GeneralCategory::OpenPunctuation
+            | GeneralCategory::ClosePunctuation
+            | GeneralCategory::InitialPunctuation
+            | GeneralCategory::FinalPunctuation
+            | GeneralCategory::OtherPunctuation
+    )
+}
+
+/// Rewrite the tokens of one line according to the punctuation mode.
+/// `Keep` glues punctuation-only tokens onto the preceding token (or the following one at the
+/// start of a line), `Strip` removes punctuation and drops tokens left empty, and `Separate`
+/// splits every punctuation mark out of the tokens that contain it.
+#[must_use]
+pub fn apply_punctuation_mode(tokens: Vec<String>, mode: PunctuationMode) -> Vec<String> {
+    match mode {
+        PunctuationMode::Keep => {
+            let mut result: Vec<String> = Vec::with_capacity(tokens.len());
+            let mut leading = String::new();
+            for token in tokens {
+                let punctuation_only = token.chars().all(is_punctuation);
+                if punctuation_only && let Some(previous) = result.last_mut() {
+                    previous.push_str(&token);
+                } else if punctuation_only {
+                    leading.push_str(&token);
+                } else {
+                    let mut s = std::mem::take(&mut leading);
+                    s.push_str(&token);
+                    result.push(s);
+                }
+            }
+            if !leading.is_empty() {
+                result.push(leading);
+            }
+            result
+        }
+        PunctuationMode::Strip => tokens
+            .into_iter()
+            .map(|token| token.chars().filter(|c| !is_punctuation(*c)).collect())
+            .filter(|token: &String| !token.is_empty())
+            .collect(),
+        PunctuationMode::Separate => {
+            let mut result = Vec::with_capacity(tokens.len());
+            for token in tokens {
+                let mut run = String::new();
+                for c in token.chars() {
+                    if is_punctuation(c) {
+                        if !run.is_empty() {
+                            result.push(std::mem::take(&mut run));
+                        }
+                        result.push(c.to_string());
+                    } else {
+                        run.push(c);
+                    }
+                }
+                if !run.is_empty() {
+                    result.push(run);
+                }
+            }
+            result
+        }
+    }
 }
@@ -0,0 +1,58 @@
+use crate::enums::AppResult;
+use crate::libs::Token;
+use crate::libs::eval::TokenizationRule;
+
+/// Splits a single line of source text into tokens.
+/// Implementations only see one line at a time; blank lines are skipped before they are called.
+pub trait Tokenizer {
+    /// Tokenize `line`, returning plain (non-derived) tokens in reading order
+    fn tokenize(&self, line: &str) -> AppResult<Vec<Token>>;
+}
+
+/// Build a plain token that is not derived from any base word
+#[must_use]
+pub fn plain_token(original: String) -> Token {
+    Token {
+        original,
+        base_word: None,
+        formation_rule_indices: Vec::new(),
+    }
+}
+
+/// Splits on Unicode whitespace, for scripts that separate words with spaces
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn tokenize(&self, line: &str) -> AppResult<Vec<Token>> {
+        Ok(line
+            .split_whitespace()
+            .map(|word| plain_token(word.to_string()))
+            .collect())
+    }
+}
+
+/// Makes every character its own token, for scripts written without spaces
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CharTokenizer;
+
+impl Tokenizer for CharTokenizer {
+    fn tokenize(&self, line: &str) -> AppResult<Vec<Token>> {
+        Ok(line.chars().map(|c| plain_token(c.to_string())).collect())
+    }
+}
+
+/// Runs a user-provided Rhai tokenization script
+#[derive(Debug, Clone)]
+pub struct ScriptTokenizer(pub TokenizationRule);
+
+impl Tokenizer for ScriptTokenizer {
+    fn tokenize(&self, line: &str) -> AppResult<Vec<Token>> {
+        Ok(self
+            .0
+            .tokenize(line)?
+            .into_iter()
+            .map(plain_token)
+            .collect())
+    }
+}
@@ -101,30 +101,107 @@ fn setup_beforeunload_handler() {
 #[cfg(not(target_arch = "wasm32"))]
 fn main() -> eframe::Result<()> {
     // Native desktop entry point: Initialize and run the native application
-    use crate::consts::ui::{WINDOW_HEIGHT, WINDOW_WIDTH};
+    use crate::consts::ui::{APP_NAME, MIN_WINDOW_HEIGHT, MIN_WINDOW_WIDTH};
+    use crate::ui::window::WindowGeometry;
     use eframe::egui;
 
     // Initialize the logging system
     env_logger::init();
 
-    // Configure the main window with size and icon
-    let mut viewport =
-        egui::ViewportBuilder::default().with_inner_size([WINDOW_WIDTH, WINDOW_HEIGHT]);
+    // Headless modes skip the window entirely:
+    // `--export <format> <input.json> <output>` and `--validate <project.json>`
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("--export") => std::process::exit(run_batch_export(&args[1..])),
+        Some("--validate") => std::process::exit(run_validate(&args[1..])),
+        _ => {}
+    }
+
+    // Configure the main window with the last session's size and position, and the icon
+    let geometry = WindowGeometry::load().unwrap_or_default();
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_inner_size(geometry.inner_size)
+        .with_min_inner_size([MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT]);
+    if let Some(position) = geometry.position {
+        viewport = viewport.with_position(position);
+    }
     if let Some(icon) = load_app_icon() {
         viewport = viewport.with_icon(icon);
     }
 
+    // Window geometry is restored above rather than by eframe
     let options = eframe::NativeOptions {
         viewport,
+        persist_window: false,
         ..Default::default()
     };
 
     // Run the native eframe application
-    eframe::run_native(
-        "Text Decryption Helper",
-        options,
-        Box::new(|cc| Ok(DecryptionApp::new(cc))),
-    )
+    eframe::run_native(APP_NAME, options, Box::new(|cc| Ok(DecryptionApp::new(cc))))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run_batch_export(args: &[String]) -> i32 {
+    // Parse the export arguments, run the export, and return the process exit code
+    use crate::enums::ExportFormat;
+    use std::path::Path;
+
+    let [format, input, output] = args else {
+        eprintln!(
+            "usage: tdector --export <typst|bilingual|latex|markdown|html|csv|frequencies|dot> <input.json> <output>"
+        );
+        return 2;
+    };
+    let Some(format) = ExportFormat::from_cli_name(format) else {
+        eprintln!(
+            "unknown export format '{format}' (expected typst, bilingual, latex, markdown, html, csv, frequencies, or dot)"
+        );
+        return 2;
+    };
+
+    match io::batch::export_project_file(format, Path::new(input), Path::new(output)) {
+        Ok(()) => 0,
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn run_validate(args: &[String]) -> i32 {
+    // Report importer warnings and errors for a project file; non-zero exit on hard errors
+    use std::path::Path;
+
+    let [input] = args else {
+        eprintln!("usage: tdector --validate <project.json>");
+        return 2;
+    };
+
+    match io::batch::validate_project_file(Path::new(input)) {
+        Ok(report) => {
+            for warning in &report.issues.warnings {
+                eprintln!("warning: {warning}");
+            }
+            for error in &report.issues.errors {
+                eprintln!("error: {error}");
+            }
+            if report.has_errors() {
+                return 1;
+            }
+            println!(
+                "{input}: OK ({} segment(s), {} vocabulary word(s), {} warning(s))",
+                report.segment_count,
+                report.vocabulary_count,
+                report.issues.warnings.len()
+            );
+            0
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            1
+        }
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -1,5 +1,9 @@
 use eframe::egui;
 
+use crate::consts::ui::IMPORT_PREVIEW_SEGMENTS;
+use crate::enums::{PunctuationMode, SegmentationMode, TextDirection};
+use crate::libs::project::{ImportSettings, Segment};
+use crate::libs::text_analysis::SegmentationPreview;
 use crate::ui::states::DecryptionApp;
 
 impl DecryptionApp {
@@ -25,6 +29,57 @@ impl DecryptionApp {
         }
     }
 
+    /// Non-fatal warning for a project font whose file could not be read on open
+    pub(crate) fn render_missing_font_dialog(&mut self, ctx: &egui::Context) {
+        let Some(&slot) = self.missing_fonts.first() else {
+            return;
+        };
+        let Some(font) = self.project.fonts.get(slot) else {
+            self.missing_fonts.remove(0);
+            return;
+        };
+
+        let mut locate = false;
+        let mut clear = false;
+        let mut dismiss = false;
+        let mut open = true;
+        egui::Window::new("Missing Font")
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .collapsible(false)
+            .resizable(false)
+            .open(&mut open)
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "The font \"{}\" could not be read from {}.",
+                    font.name,
+                    font.path.as_deref().unwrap_or("an unknown location")
+                ));
+                ui.label("Sentences using it are shown with the fallback font.");
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    if ui.button("Locate Font…").clicked() {
+                        locate = true;
+                    }
+                    if ui.button("Clear Font Reference").clicked() {
+                        clear = true;
+                    }
+                    if ui.button("Ignore").clicked() {
+                        dismiss = true;
+                    }
+                });
+            });
+
+        if locate {
+            self.missing_fonts.remove(0);
+            self.relocating_font = Some(slot);
+            self.load_font_file(ctx);
+        } else if clear {
+            self.clear_font_reference(ctx, slot);
+        } else if dismiss || !open {
+            self.missing_fonts.remove(0);
+        }
+    }
+
     pub(crate) fn render_confirmation_dialog(&mut self, ctx: &egui::Context) {
         let mut confirmed_action = None;
         let mut close_dialog = false;
@@ -40,6 +95,13 @@ impl DecryptionApp {
                 .open(&mut open)
                 .show(ctx, |ui| {
                     ui.label(&msg);
+                    if !self.change_summary.is_empty() {
+                        ui.add_space(6.0);
+                        ui.label("Since the last save:");
+                        for line in self.change_summary.lines() {
+                            ui.label(format!("• {line}"));
+                        }
+                    }
                     ui.add_space(10.0);
                     ui.horizontal(|ui| {
                         if ui.button("Yes").clicked() {
@@ -66,10 +128,40 @@ impl DecryptionApp {
         }
     }
 
+    /// Install freshly imported segments. Appending keeps the current project and adds them
+    /// after its segments; otherwise they replace it as a new unsaved project.
+    pub(crate) fn install_imported_segments(
+        &mut self,
+        segments: Vec<Segment>,
+        name: String,
+        ctx: &egui::Context,
+    ) {
+        if self.import_append {
+            self.project.append_segments(segments);
+        } else {
+            self.project.segments = segments;
+            self.project.project_name = name;
+            self.reset_custom_fonts(ctx);
+            self.current_path = None;
+            self.project_filename = None;
+            self.collapsed_segments.expand_all();
+            self.segment_heights.clear();
+        }
+        self.filter_dirty = true;
+        self.lookups_dirty = true;
+        self.tfidf_dirty = true;
+        self.filter_text.clear();
+        self.clear_popups();
+        self.update_dirty_status(true, ctx);
+    }
+
     pub(crate) fn render_import_dialog(&mut self, ctx: &egui::Context) {
-        if self.pending_import.is_some() {
-            let mut choice = None;
+        if let Some((content, _)) = &self.pending_import {
+            let mut commit = false;
             let mut use_custom = false;
+            let mut mode = self.import_mode;
+            let mut punctuation = self.import_punctuation;
+            let mut direction = self.import_direction;
             let mut open = true;
             egui::Window::new("Import Options")
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
@@ -82,78 +174,139 @@ impl DecryptionApp {
                     ui.label("Select the tokenization strategy based on the language script.");
                     ui.add_space(8.0);
 
+                    if let Some((suggested, confident)) = self.import_suggestion {
+                        let hint = if confident {
+                            format!("Suggested for this text: {}", suggested.display_text())
+                        } else {
+                            format!(
+                                "Suggested for this text: {} (mixed scripts, please check)",
+                                suggested.display_text()
+                            )
+                        };
+                        ui.label(egui::RichText::new(hint).weak());
+                    }
+                    ui.horizontal(|ui| {
+                        for option in SegmentationMode::MODES {
+                            ui.radio_value(&mut mode, option, option.display_text())
+                                .on_hover_text(option.hover_text());
+                        }
+                    });
                     ui.horizontal(|ui| {
+                        for option in PunctuationMode::MODES {
+                            ui.radio_value(&mut punctuation, option, option.display_text())
+                                .on_hover_text(option.hover_text());
+                        }
+                    });
+                    if !self.import_append {
+                        ui.horizontal(|ui| {
+                            for option in TextDirection::DIRECTIONS {
+                                ui.radio_value(&mut direction, option, option.display_text());
+                            }
+                        });
+                    }
+
+                    ui.add_space(8.0);
+                    ui.separator();
+                    match &self.import_preview {
+                        Some(Ok(preview)) => {
+                            ui.label(format!(
+                                "{} segment(s), {} token(s)",
+                                preview.segment_count(),
+                                preview.token_count
+                            ));
+                            for (idx, segment) in preview
+                                .segments
+                                .iter()
+                                .take(IMPORT_PREVIEW_SEGMENTS)
+                                .enumerate()
+                            {
+                                let tokens: Vec<String> = segment
+                                    .tokens
+                                    .iter()
+                                    .map(|token| format!("[{}]", token.original))
+                                    .collect();
+                                ui.add(
+                                    egui::Label::new(format!("{}. {}", idx + 1, tokens.join(" ")))
+                                        .truncate(),
+                                );
+                            }
+                            if preview.segment_count() > IMPORT_PREVIEW_SEGMENTS {
+                                ui.label(egui::RichText::new("...").weak());
+                            }
+                        }
+                        Some(Err(e)) => {
+                            ui.colored_label(egui::Color32::RED, e);
+                        }
+                        None => {}
+                    }
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        let can_commit = matches!(self.import_preview, Some(Ok(_)));
+                        let label = if self.import_append {
+                            "Append"
+                        } else {
+                            "Import"
+                        };
                         if ui
-                            .button("Word-based (Spaces)")
-                            .on_hover_text("Split text by whitespace (e.g. English)")
+                            .add_enabled(can_commit, egui::Button::new(label))
                             .clicked()
                         {
-                            choice = Some(true);
+                            commit = true;
                         }
                         if ui
-                            .button("Character-based")
-                            .on_hover_text(
-                                "Treat each character as a token (e.g. Chinese, Japanese)",
-                            )
+                            .button("Custom Script...")
+                            .on_hover_text("Write your own Rhai tokenization script")
                             .clicked()
                         {
-                            choice = Some(false);
+                            use_custom = true;
                         }
                     });
-
-                    ui.add_space(8.0);
-                    if ui
-                        .button("Custom Script...")
-                        .on_hover_text("Write your own Rhai tokenization script")
-                        .clicked()
-                    {
-                        use_custom = true;
-                    }
                 });
 
+            self.import_direction = direction;
+            if mode != self.import_mode || punctuation != self.import_punctuation {
+                self.import_mode = mode;
+                self.import_punctuation = punctuation;
+                self.import_preview = Some(
+                    SegmentationPreview::new(content, mode, punctuation).map_err(|e| e.to_string()),
+                );
+            }
+
             if !open {
                 self.pending_import = None;
-            } else if let Some(use_whitespace) = choice {
-                if let Some((content, name)) = self.pending_import.take() {
-                    // Create the tokenization rule to use for import
-                    let rule = if use_whitespace {
-                        crate::libs::eval::TokenizationRule::default_whitespace()
-                    } else {
-                        crate::libs::eval::TokenizationRule::default_character()
-                    };
-
-                    // Use the tokenization rule to segment text
-                    let segments =
-                        crate::libs::text_analysis::TextProcessor::segment_text_with_rule(
-                            &content,
-                            Some(&rule),
-                        )
-                        .unwrap_or_else(|_| Vec::new());
-
-                    self.project.segments = segments;
-                    self.project.project_name = name;
-                    self.project.font_path = None;
-                    self.current_path = None;
-                    self.project_filename = None;
-                    self.filter_dirty = true;
-                    self.lookups_dirty = true;
-                    self.tfidf_dirty = true;
-                    self.filter_text.clear();
-                    self.clear_popups();
-                    self.update_dirty_status(true, ctx);
+                self.import_preview = None;
+                self.import_suggestion = None;
+            } else if commit {
+                self.import_suggestion = None;
+                if let Some((_, name)) = self.pending_import.take()
+                    && let Some(Ok(preview)) = self.import_preview.take()
+                {
+                    self.project.import_settings = Some(ImportSettings {
+                        mode: self.import_mode,
+                        punctuation: self.import_punctuation,
+                    });
+                    if !self.import_append {
+                        self.project.text_direction = self.import_direction;
+                    }
+                    self.install_imported_segments(preview.segments, name, ctx);
                 }
             } else if use_custom && let Some((content, name)) = self.pending_import.take() {
+                self.import_preview = None;
+                self.import_suggestion = None;
                 self.custom_tokenization_popup =
                     Some(crate::ui::states::state::CustomTokenizationDialog {
                         import_data: (content, name),
-                        command: r#"fn tokenize(line) {
-    let tokens = [];
-    // Add your tokenization logic here
-    tokens
-}"#
-                        .to_string(),
+                        command: self
+                            .import_mode
+                            .tokenization_rule()
+                            .command
+                            .trim()
+                            .to_string(),
                         test_text: String::new(),
                         preview: Vec::new(),
+                        verify_integrity: false,
+                        lossy_lines: None,
                     });
             }
         }
@@ -32,40 +32,33 @@ pub fn create_highlighted_layout(
         ..Default::default()
     };
 
-    let text_lower = text.to_lowercase();
+    // Matching runs on the original text so every section boundary is a char boundary of
+    // `text` and the job reproduces it exactly, keeping text edit cursors in place
     let query_lower = query.to_lowercase();
-    let query_len = query.len();
-
     let mut last_end = 0;
     let mut search_start = 0;
 
-    while let Some(match_pos) = text_lower[search_start..].find(&query_lower) {
-        let match_start = search_start + match_pos;
-
-        let match_byte_start = match_start;
-        let match_byte_end = match_start + query_len;
-
-        if !text.is_char_boundary(match_byte_start)
-            || !text.is_char_boundary(match_byte_end.min(text.len()))
-        {
-            search_start = match_start + 1;
+    while search_start < text.len() {
+        let Some(match_len) = lowercase_prefix_len(&text[search_start..], &query_lower) else {
+            search_start += text[search_start..]
+                .chars()
+                .next()
+                .map_or(1, char::len_utf8);
             continue;
-        }
+        };
+        let match_end = search_start + match_len;
 
-        let actual_match_end = match_byte_end.min(text.len());
-
-        if match_byte_start > last_end {
-            job.append(&text[last_end..match_byte_start], 0.0, base_format.clone());
+        if search_start > last_end {
+            job.append(&text[last_end..search_start], 0.0, base_format.clone());
         }
-
         job.append(
-            &text[match_byte_start..actual_match_end],
+            &text[search_start..match_end],
             0.0,
             highlight_format.clone(),
         );
 
-        last_end = actual_match_end;
-        search_start = actual_match_end;
+        last_end = match_end;
+        search_start = match_end;
     }
 
     if last_end < text.len() {
@@ -74,3 +67,27 @@ pub fn create_highlighted_layout(
 
     job
 }
+
+/// Byte length of the prefix of `text` whose lowercase form is exactly `query_lower`
+fn lowercase_prefix_len(text: &str, query_lower: &str) -> Option<usize> {
+    let mut rest = query_lower;
+    for (idx, c) in text.char_indices() {
+        if rest.is_empty() {
+            return (idx > 0).then_some(idx);
+        }
+        for lower in c.to_lowercase() {
+            rest = rest.strip_prefix(lower)?;
+        }
+    }
+    (rest.is_empty() && !text.is_empty()).then_some(text.len())
+}
+
+/// Toggle the soft highlight for a clicked word.
+/// Clicking the currently highlighted word clears it; any other word replaces it.
+pub fn toggle_highlight_word(current: &mut Option<String>, word: &str) {
+    if current.as_deref() == Some(word) {
+        *current = None;
+    } else {
+        *current = Some(word.to_string());
+    }
+}
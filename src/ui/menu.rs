@@ -1,16 +1,40 @@
 use eframe::egui;
 
+use crate::enums::{ExportFormat, ThemeChoice, ToolAction};
+
 #[allow(clippy::too_many_arguments)]
 pub fn render_menu_bar(
     ui: &mut egui::Ui,
     project_loaded: bool,
+    embed_fonts: bool,
+    compact_json: bool,
+    include_glossary_appendix: bool,
+    normalize_lookups: bool,
+    similarity_by_base_word: bool,
+    right_to_left: bool,
+    theme: ThemeChoice,
+    any_collapsed: bool,
+    on_new: impl FnOnce(),
     on_import: impl FnOnce(),
+    on_import_append: impl FnOnce(),
     on_open: impl FnOnce(),
     on_save: impl FnOnce(),
-    on_export: impl FnOnce(),
+    mut on_export: impl FnMut(ExportFormat),
     on_quit: impl FnOnce(),
     on_load_font: impl FnOnce(),
+    on_toggle_embed_fonts: impl FnOnce(),
+    on_toggle_compact_json: impl FnOnce(),
+    on_toggle_glossary_appendix: impl FnOnce(),
+    on_import_glossary: impl FnOnce(),
+    on_import_rules: impl FnOnce(),
+    on_import_comments: impl FnOnce(),
     on_add_word_formation_rule: impl FnOnce(),
+    on_toggle_normalize_lookups: impl FnOnce(),
+    on_toggle_similarity_by_base_word: impl FnOnce(),
+    on_toggle_right_to_left: impl FnOnce(),
+    mut on_tool: impl FnMut(ToolAction),
+    mut on_set_theme: impl FnMut(ThemeChoice),
+    mut on_set_collapsed: impl FnMut(bool),
 ) {
     let cmd = if cfg!(target_os = "macos") {
         "Cmd"
@@ -21,6 +45,13 @@ pub fn render_menu_bar(
     egui::Panel::top("top_panel").show(ui, |ui| {
         egui::MenuBar::new().ui(ui, |ui| {
             ui.menu_button("File", |ui| {
+                if ui
+                    .add(egui::Button::new("New Project").shortcut_text(format!("{cmd}+N")))
+                    .clicked()
+                {
+                    on_new();
+                    ui.close();
+                }
                 if ui
                     .add(egui::Button::new("Import Text...").shortcut_text(format!("{cmd}+I")))
                     .clicked()
@@ -28,6 +59,14 @@ pub fn render_menu_bar(
                     on_import();
                     ui.close();
                 }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Import and Append..."))
+                    .on_hover_text("Add the segments of another text after the current ones")
+                    .clicked()
+                {
+                    on_import_append();
+                    ui.close();
+                }
                 if ui
                     .add(egui::Button::new("Open Project...").shortcut_text(format!("{cmd}+O")))
                     .clicked()
@@ -42,21 +81,181 @@ pub fn render_menu_bar(
                     on_load_font();
                     ui.close();
                 }
+                let mut embed = embed_fonts;
                 if ui
-                    .add(egui::Button::new("Save Project").shortcut_text(format!("{cmd}+S")))
+                    .add_enabled(
+                        project_loaded,
+                        egui::Checkbox::new(&mut embed, "Embed Fonts in Project File"),
+                    )
+                    .on_hover_text("Store loaded fonts in the saved project so it opens anywhere")
+                    .changed()
+                {
+                    on_toggle_embed_fonts();
+                    ui.close();
+                }
+                let mut compact = compact_json;
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Checkbox::new(&mut compact, "Compact Project File"),
+                    )
+                    .on_hover_text("Save without indentation for a smaller file")
+                    .changed()
+                {
+                    on_toggle_compact_json();
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Import Glossary..."))
+                    .on_hover_text("Fill empty glosses and comments from a JSON glossary")
                     .clicked()
                 {
-                    on_save();
+                    on_import_glossary();
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Button::new("Import Formation Rules..."),
+                    )
+                    .on_hover_text(
+                        "Append rules from a JSON rule library, skipping exact duplicates",
+                    )
+                    .clicked()
+                {
+                    on_import_rules();
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Import Comments..."))
+                    .on_hover_text("Reattach comments from a JSON comments file")
+                    .clicked()
+                {
+                    on_import_comments();
                     ui.close();
                 }
-                ui.separator();
                 if ui
-                    .add(egui::Button::new("Export...").shortcut_text(format!("{cmd}+E")))
+                    .add(egui::Button::new("Save Project").shortcut_text(format!("{cmd}+S")))
                     .clicked()
                 {
-                    on_export();
+                    on_save();
                     ui.close();
                 }
+                ui.separator();
+                ui.menu_button("Export", |ui| {
+                    if ui
+                        .add(egui::Button::new("Typst...").shortcut_text(format!("{cmd}+E")))
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Typst);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("Bilingual Typst..."))
+                        .on_hover_text("Source lines beside their translations in a table")
+                        .clicked()
+                    {
+                        on_export(ExportFormat::BilingualTypst);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("LaTeX (gb4e)..."))
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Latex);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("Markdown..."))
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Markdown);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("HTML..."))
+                        .on_hover_text("A single web page that shows glosses on hover")
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Html);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("CSV..."))
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Csv);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("Frequencies (CSV)..."))
+                        .on_hover_text("Word counts with gloss status and comments")
+                        .clicked()
+                    {
+                        on_export(ExportFormat::FrequencyCsv);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(
+                            project_loaded,
+                            egui::Button::new("Untranslated Wordlist..."),
+                        )
+                        .clicked()
+                    {
+                        on_export(ExportFormat::UntranslatedWordlist);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("Glossary (JSON)..."))
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Glossary);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(
+                            project_loaded,
+                            egui::Button::new("Formation Rules (JSON)..."),
+                        )
+                        .clicked()
+                    {
+                        on_export(ExportFormat::FormationRules);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(project_loaded, egui::Button::new("Comments (JSON)..."))
+                        .on_hover_text("Only the annotations, for sharing with a reviewer")
+                        .clicked()
+                    {
+                        on_export(ExportFormat::Comments);
+                        ui.close();
+                    }
+                    if ui
+                        .add_enabled(
+                            project_loaded,
+                            egui::Button::new("Similarity Graph (DOT)..."),
+                        )
+                        .clicked()
+                    {
+                        on_export(ExportFormat::SimilarityGraph);
+                        ui.close();
+                    }
+                    ui.separator();
+                    let mut appendix = include_glossary_appendix;
+                    if ui
+                        .add_enabled(
+                            project_loaded,
+                            egui::Checkbox::new(&mut appendix, "Glossary Appendix"),
+                        )
+                        .on_hover_text(
+                            "End Typst and Markdown exports with a sorted table of every word",
+                        )
+                        .changed()
+                    {
+                        on_toggle_glossary_appendix();
+                        ui.close();
+                    }
+                });
                 if ui
                     .add(egui::Button::new("Quit").shortcut_text(format!("{cmd}+Q")))
                     .clicked()
@@ -76,14 +275,139 @@ pub fn render_menu_bar(
                     on_add_word_formation_rule();
                     ui.close();
                 }
+                ui.separator();
+                let mut normalize = normalize_lookups;
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Checkbox::new(&mut normalize, "Normalize Lookups"),
+                    )
+                    .on_hover_text("Ignore case and diacritics when looking up glosses")
+                    .changed()
+                {
+                    on_toggle_normalize_lookups();
+                    ui.close();
+                }
+                let mut by_base_word = similarity_by_base_word;
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Checkbox::new(&mut by_base_word, "Similarity by Base Word"),
+                    )
+                    .on_hover_text("Treat inflected forms of the same base word as one term")
+                    .changed()
+                {
+                    on_toggle_similarity_by_base_word();
+                    ui.close();
+                }
+                let mut rtl = right_to_left;
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Checkbox::new(&mut rtl, "Right-to-Left Script"),
+                    )
+                    .on_hover_text("Lay out segment tokens and translations from right to left")
+                    .changed()
+                {
+                    on_toggle_right_to_left();
+                    ui.close();
+                }
+            });
+            ui.menu_button("Tools", |ui| {
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Find Duplicate Segments"))
+                    .clicked()
+                {
+                    on_tool(ToolAction::FindDuplicates);
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Word Frequencies"))
+                    .clicked()
+                {
+                    on_tool(ToolAction::WordFrequencies);
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Formation Rules"))
+                    .clicked()
+                {
+                    on_tool(ToolAction::FormationRules);
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Button::new("Apply Rule to Matches..."),
+                    )
+                    .clicked()
+                {
+                    on_tool(ToolAction::ApplyRuleToMatches);
+                    ui.close();
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(
+                        project_loaded,
+                        egui::Button::new("Paste Parallel Translations..."),
+                    )
+                    .clicked()
+                {
+                    on_tool(ToolAction::PasteParallelTranslations);
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Merge Words..."))
+                    .clicked()
+                {
+                    on_tool(ToolAction::MergeWords);
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Word Categories..."))
+                    .clicked()
+                {
+                    on_tool(ToolAction::WordCategories);
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Edit Stopwords..."))
+                    .clicked()
+                {
+                    on_tool(ToolAction::EditStopwords);
+                    ui.close();
+                }
+                ui.separator();
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Project Settings..."))
+                    .clicked()
+                {
+                    on_tool(ToolAction::ProjectSettings);
+                    ui.close();
+                }
             });
-            ui.menu_button("Theme", |ui| {
-                if ui.button("Light").clicked() {
-                    ui.ctx().set_visuals(egui::Visuals::light());
+            ui.menu_button("View", |ui| {
+                ui.menu_button("Theme", |ui| {
+                    for choice in ThemeChoice::ALL {
+                        if ui.radio(theme == choice, choice.display_text()).clicked() {
+                            on_set_theme(choice);
+                            ui.close();
+                        }
+                    }
+                });
+                ui.separator();
+                if ui
+                    .add_enabled(project_loaded, egui::Button::new("Collapse All Segments"))
+                    .clicked()
+                {
+                    on_set_collapsed(true);
                     ui.close();
                 }
-                if ui.button("Dark").clicked() {
-                    ui.ctx().set_visuals(egui::Visuals::dark());
+                if ui
+                    .add_enabled(any_collapsed, egui::Button::new("Expand All Segments"))
+                    .clicked()
+                {
+                    on_set_collapsed(false);
                     ui.close();
                 }
             });
@@ -1,14 +1,21 @@
 pub(crate) mod dialogs;
-pub(crate) mod highlight;
+pub mod highlight;
 mod menu;
 mod pagination;
-pub(crate) mod panels;
+pub mod panels;
 pub mod popup_utils;
 pub(crate) mod popups;
-mod segment;
+pub mod segment;
 pub(crate) mod states;
+mod status_bar;
+pub mod sticky_menu;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod window;
 
 pub use menu::render_menu_bar;
-pub use pagination::render_pagination;
-pub use segment::{render_clickable_tokens, render_segment};
+pub use pagination::{
+    clamp_page, clamp_page_size, fit_page, page_slice, render_pagination, total_pages,
+};
+pub use segment::{CollapsedSegments, render_clickable_tokens, render_segment};
 pub use states::DecryptionApp;
+pub use status_bar::{render_status_bar, status_summary};
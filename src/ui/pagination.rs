@@ -3,20 +3,52 @@ use eframe::egui;
 use crate::consts::{
     colors::{FONT_DARK, FONT_LIGHT},
     ui::{
-        PAGINATION_BUTTON_ADJACENT_COUNT, PAGINATION_BUTTON_SIDE_COUNT,
-        PAGINATION_DEFAULT_PAGE_SIZE, PAGINATION_DRAG_SPEED, PAGINATION_ITEM_WIDTH,
-        PAGINATION_NAV_WIDTH_DEDUCTION, PAGINATION_SIZE_EXTRA_LARGE, PAGINATION_SIZE_LARGE,
-        PAGINATION_SIZE_MEDIUM, PAGINATION_SIZE_SMALL,
+        PAGINATION_BUTTON_ADJACENT_COUNT, PAGINATION_BUTTON_SIDE_COUNT, PAGINATION_DRAG_SPEED,
+        PAGINATION_ITEM_WIDTH, PAGINATION_NAV_WIDTH_DEDUCTION,
+        PAGINATION_SHOW_ALL_WARNING_THRESHOLD,
     },
 };
+use crate::enums::PageSizePreset;
+
+/// Raise a page size of zero to one, so page arithmetic never divides by zero
+pub const fn clamp_page_size(page_size: usize) -> usize {
+    if page_size == 0 { 1 } else { page_size }
+}
+
+/// Number of pages needed to show `total_items`; 0 when there are no items
+pub fn total_pages(total_items: usize, page_size: usize) -> usize {
+    total_items.div_ceil(clamp_page_size(page_size))
+}
+
+/// Clamp a page index so it points at the last non-empty page when items disappear.
+/// Returns 0 when there are no items at all.
+pub fn clamp_page(current_page: usize, total_items: usize, page_size: usize) -> usize {
+    current_page.min(total_pages(total_items, page_size).saturating_sub(1))
+}
+
+/// Page size for `total_items` items under `preset`, and `current_page` clamped to it.
+/// Used whenever the item count changes so the page shown next is never out of range.
+pub fn fit_page(current_page: usize, total_items: usize, preset: PageSizePreset) -> (usize, usize) {
+    let page_size = clamp_page_size(preset.page_size(total_items));
+    (page_size, clamp_page(current_page, total_items, page_size))
+}
+
+/// Return the items on one page, clamping the page to the last non-empty one
+pub fn page_slice<T>(items: &[T], page: usize, page_size: usize) -> &[T] {
+    let page_size = clamp_page_size(page_size);
+    let start = clamp_page(page, items.len(), page_size) * page_size;
+    let end = (start + page_size).min(items.len());
+    &items[start..end]
+}
 
 pub fn render_pagination(
     ui: &mut egui::Ui,
     current_page: usize,
     total_pages: usize,
-    page_size: &mut usize,
+    total_items: usize,
+    page_size: &mut PageSizePreset,
 ) -> Option<usize> {
-    if total_pages <= 1 && *page_size == PAGINATION_DEFAULT_PAGE_SIZE {
+    if total_pages <= 1 && *page_size == PageSizePreset::default() {
         return None;
     }
 
@@ -84,29 +116,20 @@ pub fn render_pagination(
                 ui.separator();
 
                 egui::ComboBox::from_id_salt("page_size_selector")
-                    .selected_text(format!("Show {page_size} per page"))
+                    .selected_text(page_size.display_text())
                     .show_ui(ui, |ui| {
-                        ui.selectable_value(
-                            page_size,
-                            PAGINATION_SIZE_SMALL,
-                            format!("Show {PAGINATION_SIZE_SMALL} per page"),
-                        );
-                        ui.selectable_value(
-                            page_size,
-                            PAGINATION_SIZE_MEDIUM,
-                            format!("Show {PAGINATION_SIZE_MEDIUM} per page"),
-                        );
-                        ui.selectable_value(
-                            page_size,
-                            PAGINATION_SIZE_LARGE,
-                            format!("Show {PAGINATION_SIZE_LARGE} per page"),
-                        );
-                        ui.selectable_value(
-                            page_size,
-                            PAGINATION_SIZE_EXTRA_LARGE,
-                            format!("Show {PAGINATION_SIZE_EXTRA_LARGE} per page"),
-                        );
+                        for preset in PageSizePreset::PRESETS {
+                            ui.selectable_value(page_size, preset, preset.display_text());
+                        }
                     });
+                if *page_size == PageSizePreset::All
+                    && total_items > PAGINATION_SHOW_ALL_WARNING_THRESHOLD
+                {
+                    ui.colored_label(
+                        egui::Color32::ORANGE,
+                        format!("⚠ {total_items} segments on one page may be slow"),
+                    );
+                }
 
                 ui.separator();
                 ui.label(egui::RichText::new("Jump to:").color(text_color));
@@ -1,14 +1,69 @@
 use eframe::egui;
 
 use crate::consts::{
-    colors::{FONT_DARK, FONT_LIGHT},
-    ui::PANEL_SPACING,
+    colors::{CURRENT_MATCH, FONT_DARK, FONT_LIGHT},
+    ui::{BOX_STROKE_WIDTH, PANEL_SPACING},
 };
 use crate::enums::{DictionaryPopupType, PopupRequest, SortMode, UiAction};
+use crate::io::{DEFAULT_SENTENCE_FONT, segment_font_family};
 use crate::libs::Project;
+use crate::libs::filtering::FilterOperation;
 use crate::ui;
+use crate::ui::highlight::toggle_highlight_word;
 use crate::ui::states::state::DecryptionApp;
 
+/// What a key press does to the filter box
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterKeyAction {
+    /// Move keyboard focus into the filter box
+    Focus,
+    /// Empty the filter box
+    Clear,
+}
+
+/// Decide how Cmd/Ctrl+F and Escape affect the filter box.
+/// Escape clears the filter only while the box has focus. The focus shortcut is ignored while
+/// the filter box or any other widget is being edited, so it never steals focus mid-entry.
+#[must_use]
+pub fn filter_key_action(
+    focus_shortcut: bool,
+    escape: bool,
+    filter_focused: bool,
+    editing_elsewhere: bool,
+) -> Option<FilterKeyAction> {
+    if filter_focused {
+        escape.then_some(FilterKeyAction::Clear)
+    } else if focus_shortcut && !editing_elsewhere {
+        Some(FilterKeyAction::Focus)
+    } else {
+        None
+    }
+}
+
+/// Top edge of every row when rows of the given heights are stacked with `spacing` between
+/// them. Has one more entry than `heights`: the last is where a following row would start.
+#[must_use]
+pub fn row_offsets(heights: impl IntoIterator<Item = f32>, spacing: f32) -> Vec<f32> {
+    let mut top = 0.0;
+    let mut offsets = vec![top];
+    for height in heights {
+        top += height + spacing;
+        offsets.push(top);
+    }
+    offsets
+}
+
+/// Rows from [`row_offsets`] that intersect the vertical span `min..max`
+#[must_use]
+pub fn visible_rows(offsets: &[f32], min: f32, max: f32) -> std::ops::Range<usize> {
+    let rows = offsets.len().saturating_sub(1);
+    let first = offsets[1..]
+        .partition_point(|&bottom| bottom <= min)
+        .min(rows);
+    let last = offsets[..rows].partition_point(|&top| top < max);
+    first..last.max(first)
+}
+
 impl DecryptionApp {
     pub(crate) fn render_filter_panel(&mut self, ui: &mut egui::Ui) {
         egui::Panel::top("filter_panel").show(ui, |ui| {
@@ -21,31 +76,84 @@ impl DecryptionApp {
 
                 ui.label(egui::RichText::new("Filter:").color(text_color));
 
-                let font_id = if self.project.font_path.is_some() {
+                let font_id = if self.project.has_custom_font() {
                     egui::FontId {
                         size: egui::TextStyle::Body.resolve(ui.style()).size,
-                        family: egui::FontFamily::Name("SentenceFont".into()),
+                        family: egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
                     }
                 } else {
                     egui::TextStyle::Body.resolve(ui.style())
                 };
 
-                if ui
-                    .add(
-                        egui::TextEdit::singleline(&mut self.filter_text)
-                            .font(font_id)
-                            .text_color(text_color),
-                    )
-                    .changed()
-                {
+                let filter_id = egui::Id::new("filter_box");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.filter_text)
+                        .id(filter_id)
+                        .font(font_id)
+                        .text_color(text_color)
+                        .hint_text("Ctrl+F"),
+                );
+                if response.changed() {
                     self.current_page = 0;
                     self.filter_dirty = true;
                 }
+                if response.lost_focus() {
+                    self.filter_history.push(&self.filter_text);
+                }
+
+                // Escape makes the box lose focus in the same frame, so check both states
+                let filter_focused = response.has_focus() || response.lost_focus();
+                let editing_elsewhere = !filter_focused && ui.memory(|mem| mem.focused()).is_some();
+                let (focus_shortcut, escape) = ui.input(|i| {
+                    (
+                        i.modifiers.command_only() && i.key_pressed(egui::Key::F),
+                        i.key_pressed(egui::Key::Escape),
+                    )
+                });
+                match filter_key_action(focus_shortcut, escape, filter_focused, editing_elsewhere) {
+                    Some(FilterKeyAction::Focus) => response.request_focus(),
+                    Some(FilterKeyAction::Clear) if !self.filter_text.is_empty() => {
+                        self.filter_text.clear();
+                        self.current_page = 0;
+                        self.filter_dirty = true;
+                    }
+                    _ => {}
+                }
                 if !self.filter_text.is_empty() && ui.button("X").clicked() {
                     self.filter_text.clear();
                     self.current_page = 0;
                     self.filter_dirty = true;
                 }
+                if !self.filter_history.is_empty() {
+                    let mut chosen = None;
+                    ui.menu_button("⏷", |ui| {
+                        for entry in self.filter_history.entries() {
+                            if ui.button(entry).clicked() {
+                                chosen = Some(entry.clone());
+                                ui.close();
+                            }
+                        }
+                    })
+                    .response
+                    .on_hover_text("Recent filters");
+                    if let Some(query) = chosen {
+                        self.filter_history.push(&query);
+                        self.filter_text = query;
+                        self.current_page = 0;
+                        self.filter_dirty = true;
+                    }
+                }
+
+                if ui
+                    .checkbox(&mut self.soft_highlight_mode, "Click to highlight")
+                    .on_hover_text(
+                        "Clicking a token highlights its occurrences instead of filtering",
+                    )
+                    .changed()
+                    && !self.soft_highlight_mode
+                {
+                    self.highlight_word = None;
+                }
 
                 ui.separator();
                 ui.label(egui::RichText::new("Sort by:").color(text_color));
@@ -78,57 +186,144 @@ impl DecryptionApp {
         &mut self,
         ui: &mut egui::Ui,
         any_changed: &mut bool,
+        changed_segments: &mut Vec<usize>,
         popup_request: &mut Option<PopupRequest>,
     ) {
-        let total_items = self.cached_filtered_indices.len();
-        let start = (self.current_page * self.page_size).min(total_items);
-        let end = (start + self.page_size).min(total_items);
-        let current_page_indices: &[usize] = if start < end {
-            &self.cached_filtered_indices[start..end]
-        } else {
-            &[]
-        };
-
-        let use_custom_font = self.project.font_path.is_some();
+        debug_assert!(
+            self.page_size > 0,
+            "page_size must be clamped to at least 1"
+        );
+        let current_page_indices = ui::page_slice(
+            &self.cached_filtered_indices,
+            self.current_page,
+            self.page_size,
+        )
+        .to_vec();
+        self.refresh_translation_suggestions(ui.ctx(), &current_page_indices);
+
+        let loaded_fonts = self.project.fonts.len();
+        let text_direction = self.project.text_direction;
         let filter_text = self.filter_text.as_str();
+        let normalized_index = &self.normalized_index;
+        let normalize_lookups = self.project.normalize_lookups;
+        let highlight_word = self.highlight_word.as_deref();
+        let soft_highlight = self.soft_highlight_mode;
+        let row_height = self.segment_row_height;
+        let segment_heights = &self.segment_heights;
+        let phrase_anchor = self.phrase_anchor;
+        let translation_suggestions = &self.translation_suggestions;
+        let collapsed_segments = &self.collapsed_segments;
+        let pending_gloss_focus = self.pending_gloss_focus;
+        let current_match = self.match_cursor.filter(|_| !filter_text.trim().is_empty());
+        let highlight_term = FilterOperation::text_term(filter_text);
+        let match_row = current_match
+            .filter(|_| self.pending_match_scroll)
+            .and_then(|seg_idx| current_page_indices.iter().position(|&idx| idx == seg_idx));
+        self.pending_match_scroll = false;
+        let mut measured_heights = Vec::new();
 
         let Project {
             segments,
             vocabulary,
             vocabulary_comments,
             formatted_word_comments,
+            vocabulary_categories,
+            category_colors,
+            no_gloss_words,
             formation_rules,
             ..
         } = &mut self.project;
 
         let mut new_filter = None;
+        let mut clicked_word = None;
+        let mut phrase_click = None;
+        let mut phrase_edit = None;
+        let mut toggled_collapse = None;
+        let mut submitted_gloss = None;
+        let mut focus_applied = false;
+        let mut edited_glosses = Vec::new();
+        let mut edited_translations = Vec::new();
 
         egui::CentralPanel::default().show(ui, |ui| {
             if current_page_indices.is_empty() {
                 Self::render_empty_state(ui, filter_text);
             } else {
-                egui::ScrollArea::vertical().show(ui, |ui| {
-                    for &seg_idx in current_page_indices {
+                let row_spacing = ui.spacing().item_spacing.y;
+                // Rows are placed by their measured heights, falling back to the running
+                // estimate for rows not yet shown; only rows intersecting the viewport are laid out
+                let offsets = row_offsets(
+                    current_page_indices
+                        .iter()
+                        .map(|seg_idx| segment_heights.get(seg_idx).copied().unwrap_or(row_height)),
+                    row_spacing,
+                );
+                let mut scroll_area = egui::ScrollArea::vertical();
+                if let Some(row) = match_row {
+                    scroll_area = scroll_area.vertical_scroll_offset(offsets[row]);
+                }
+                scroll_area.show_viewport(ui, |ui, viewport| {
+                    let total_height = offsets.last().copied().unwrap_or_default() - row_spacing;
+                    ui.set_height(total_height.max(0.0));
+                    let rows = visible_rows(&offsets, viewport.min.y, viewport.max.y);
+                    let top = ui.max_rect().top();
+                    let rect = egui::Rect::from_x_y_ranges(
+                        ui.max_rect().x_range(),
+                        top + offsets[rows.start]..=top + offsets[rows.end],
+                    );
+                    let mut ui = ui.new_child(egui::UiBuilder::new().max_rect(rect));
+                    ui.skip_ahead_auto_ids(rows.start);
+                    let ui = &mut ui;
+                    for &seg_idx in &current_page_indices[rows] {
+                        let row_top = ui.cursor().top();
                         if let Some(segment) = segments.get_mut(seg_idx) {
-                            let highlight = if filter_text.is_empty() {
-                                None
-                            } else {
-                                Some(filter_text)
-                            };
+                            let highlight = highlight_term.as_deref();
+                            let font_family = segment_font_family(segment.font, loaded_fonts);
                             let action = ui::render_segment(
                                 ui,
                                 segment,
                                 vocabulary,
                                 vocabulary_comments,
                                 formatted_word_comments,
+                                vocabulary_categories,
+                                category_colors,
+                                no_gloss_words,
+                                normalized_index,
+                                normalize_lookups,
                                 seg_idx + 1,
                                 highlight,
-                                use_custom_font,
+                                highlight_word,
+                                font_family.as_deref(),
                                 formation_rules,
+                                text_direction,
+                                phrase_anchor
+                                    .filter(|&(anchor_seg, _)| anchor_seg == seg_idx)
+                                    .map(|(_, word_idx)| word_idx),
+                                pending_gloss_focus
+                                    .filter(|&(focus_seg, _)| focus_seg == seg_idx)
+                                    .map(|(_, word_idx)| word_idx),
+                                translation_suggestions.get(seg_idx),
+                                collapsed_segments.is_collapsed(seg_idx),
                             );
+                            if pending_gloss_focus
+                                .is_some_and(|(focus_seg, _)| focus_seg == seg_idx)
+                            {
+                                focus_applied = true;
+                            }
 
                             match action {
-                                UiAction::Changed => *any_changed = true,
+                                UiAction::GlossChanged(word) => {
+                                    *any_changed = true;
+                                    changed_segments.push(seg_idx);
+                                    edited_glosses.push(word);
+                                }
+                                UiAction::TranslationChanged => {
+                                    *any_changed = true;
+                                    changed_segments.push(seg_idx);
+                                    edited_translations.push(seg_idx);
+                                }
+                                UiAction::Filter(text) if soft_highlight => {
+                                    clicked_word = Some(text.to_string());
+                                }
                                 UiAction::Filter(text) => {
                                     new_filter = Some(text.to_string());
                                 }
@@ -167,21 +362,93 @@ impl DecryptionApp {
                                         DictionaryPopupType::Reference,
                                     ));
                                 }
+                                UiAction::GlossSubmitted(word_idx) => {
+                                    submitted_gloss = Some((seg_idx, word_idx));
+                                }
+                                UiAction::SelectToken(word_idx) => {
+                                    phrase_click = Some((seg_idx, word_idx));
+                                }
+                                UiAction::EditPhrase(start) => {
+                                    phrase_edit = Some((seg_idx, start));
+                                }
+                                UiAction::ToggleCollapsed => {
+                                    toggled_collapse = Some(seg_idx);
+                                }
                                 UiAction::None => {}
                             }
 
+                            if current_match == Some(seg_idx) {
+                                let left = ui.max_rect().left();
+                                ui.painter().line_segment(
+                                    [
+                                        egui::pos2(left, row_top),
+                                        egui::pos2(left, ui.cursor().top()),
+                                    ],
+                                    egui::Stroke::new(BOX_STROKE_WIDTH * 2.0, CURRENT_MATCH),
+                                );
+                            }
+
                             ui.add_space(PANEL_SPACING);
                         }
+                        measured_heights.push((seg_idx, ui.cursor().top() - row_top - row_spacing));
                     }
                 });
             }
         });
 
+        if !measured_heights.is_empty() {
+            let average = measured_heights
+                .iter()
+                .map(|(_, height)| height)
+                .sum::<f32>()
+                / measured_heights.len() as f32;
+            if average > 0.0 {
+                self.segment_row_height = average;
+            }
+            self.segment_heights.extend(measured_heights);
+        }
+
+        for word in &edited_glosses {
+            self.change_summary.record_gloss(word);
+        }
+        for seg_idx in edited_translations {
+            self.change_summary.record_translation(seg_idx);
+        }
+
         if let Some(text) = new_filter {
+            self.filter_history.push(&text);
             self.filter_text = text;
             self.current_page = 0;
             self.filter_dirty = true;
         }
+
+        if let Some(seg_idx) = toggled_collapse {
+            self.collapsed_segments.toggle(seg_idx);
+        }
+
+        if let Some(word) = clicked_word {
+            toggle_highlight_word(&mut self.highlight_word, &word);
+        }
+
+        if focus_applied {
+            self.pending_gloss_focus = None;
+        }
+        if let Some((seg_idx, word_idx)) = submitted_gloss
+            && let Some((next_seg, next_word)) = self.project.next_untranslated_token(
+                seg_idx,
+                word_idx,
+                self.normalized_vocabulary(),
+            )
+        {
+            self.pending_gloss_focus = Some((next_seg, next_word));
+            self.jump_to_segment(next_seg);
+        }
+        if let Some((seg_idx, word_idx)) = phrase_click {
+            self.select_phrase_token(seg_idx, word_idx);
+        }
+        if let Some((seg_idx, start)) = phrase_edit {
+            self.open_phrase_gloss(seg_idx, start);
+        }
     }
 
     fn render_empty_state(ui: &mut egui::Ui, filter_text: &str) {
@@ -1,5 +1,10 @@
 use eframe::egui;
 
+use crate::enums::PopupRequest;
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::cross_refs::{CommentSpan, split_segment_refs};
+use crate::libs::eval::SCRIPT_HELPERS;
+
 pub fn create_popup_title(prefix: &str, word: &str, use_custom_font: bool) -> egui::WidgetText {
     if use_custom_font {
         let mut job = egui::text::LayoutJob::default();
@@ -15,7 +20,10 @@ pub fn create_popup_title(prefix: &str, word: &str, use_custom_font: bool) -> eg
             word,
             0.0,
             egui::TextFormat {
-                font_id: egui::FontId::new(14.0, egui::FontFamily::Name("SentenceFont".into())),
+                font_id: egui::FontId::new(
+                    14.0,
+                    egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
+                ),
                 ..Default::default()
             },
         );
@@ -40,7 +48,10 @@ pub fn create_pinned_title_string(prefix: &str, word: &str, use_custom_font: boo
             word,
             0.0,
             egui::TextFormat {
-                font_id: egui::FontId::new(14.0, egui::FontFamily::Name("SentenceFont".into())),
+                font_id: egui::FontId::new(
+                    14.0,
+                    egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
+                ),
                 ..Default::default()
             },
         );
@@ -49,3 +60,50 @@ pub fn create_pinned_title_string(prefix: &str, word: &str, use_custom_font: boo
         format!("{prefix}{word}")
     }
 }
+
+/// Render a comment with `[n]` references as links that jump to segment n.
+/// References to segments that do not exist are shown as plain text.
+pub fn render_comment_with_links(
+    ui: &mut egui::Ui,
+    comment: &str,
+    segment_count: usize,
+    popup_request: &mut Option<PopupRequest>,
+) {
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = 0.0;
+        for span in split_segment_refs(comment, segment_count) {
+            match span {
+                CommentSpan::Text(text) => {
+                    ui.label(egui::RichText::new(text).italics());
+                }
+                CommentSpan::SegmentLink { label, segment_idx } => {
+                    if ui
+                        .link(label)
+                        .on_hover_text(format!("Go to segment {}", segment_idx + 1))
+                        .clicked()
+                    {
+                        *popup_request = Some(PopupRequest::JumpToSegment(segment_idx));
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Collapsible cheat-sheet of the helper functions scripts can call
+pub fn render_script_helpers(ui: &mut egui::Ui) {
+    egui::CollapsingHeader::new("Helper functions")
+        .default_open(false)
+        .show(ui, |ui| {
+            egui::Grid::new("script_helpers_grid")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (signature, description) in SCRIPT_HELPERS {
+                        ui.monospace(*signature);
+                        ui.label(*description);
+                        ui.end_row();
+                    }
+                });
+        });
+}
@@ -0,0 +1,191 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_apply_rule_popup(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.apply_rule_popup.take() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut should_search = false;
+        let mut should_apply = false;
+        let mut should_close = false;
+        let word_font = if self.project.has_custom_font() {
+            egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
+        } else {
+            egui::FontFamily::Proportional
+        };
+
+        egui::Window::new("Apply Rule to Matches")
+            .id(egui::Id::new("apply_rule_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                let rules = &self.project.formation_rules;
+                let rule_text = |idx: usize| {
+                    rules
+                        .get(idx)
+                        .map(|rule| format!("{} {}", rule.rule_type.badge(), rule.description))
+                        .unwrap_or_default()
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label("Rule:");
+                    egui::ComboBox::from_id_salt("apply_rule_combo")
+                        .selected_text(dialog.selected_rule.map(rule_text).unwrap_or_default())
+                        .show_ui(ui, |ui| {
+                            for (idx, rule) in rules.iter().enumerate() {
+                                let mut response = ui.selectable_label(
+                                    dialog.selected_rule == Some(idx),
+                                    rule_text(idx),
+                                );
+                                if !rule.note.is_empty() {
+                                    response = response.on_hover_text(&rule.note);
+                                }
+                                if response.clicked() {
+                                    dialog.selected_rule = Some(idx);
+                                    should_search = true;
+                                }
+                            }
+                        });
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Pattern:");
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut dialog.pattern).hint_text("-ed or un-"),
+                    );
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        should_search = true;
+                    }
+                    if ui.button("Find").clicked() {
+                        should_search = true;
+                    }
+                });
+                ui.label(
+                    egui::RichText::new(
+                        "Base words are guessed by stripping the pattern. \
+                         Only proposals the rule reproduces exactly can be applied.",
+                    )
+                    .weak(),
+                );
+                ui.separator();
+
+                let rule = dialog
+                    .selected_rule
+                    .and_then(|idx| self.project.formation_rules.get(idx));
+                egui::ScrollArea::vertical()
+                    .max_height(POPUP_REFERENCE_HEIGHT - 160.0)
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        egui::Grid::new("apply_rule_grid")
+                            .striped(true)
+                            .num_columns(4)
+                            .show(ui, |ui| {
+                                ui.strong("Apply");
+                                ui.strong("Word");
+                                ui.strong("Base word");
+                                ui.strong("Count");
+                                ui.end_row();
+
+                                for (proposal, accepted) in
+                                    dialog.proposals.iter_mut().zip(&mut dialog.accepted)
+                                {
+                                    ui.add_enabled(
+                                        proposal.round_trips,
+                                        egui::Checkbox::without_text(accepted),
+                                    );
+                                    ui.label(
+                                        egui::RichText::new(&proposal.word)
+                                            .family(word_font.clone()),
+                                    );
+                                    let response = ui.add(
+                                        egui::TextEdit::singleline(&mut proposal.base_word)
+                                            .font(egui::FontId::new(
+                                                egui::TextStyle::Body.resolve(ui.style()).size,
+                                                word_font.clone(),
+                                            ))
+                                            .text_color_opt(
+                                                (!proposal.round_trips)
+                                                    .then_some(egui::Color32::RED),
+                                            )
+                                            .desired_width(120.0),
+                                    );
+                                    if response.changed() {
+                                        proposal.round_trips = rule.is_some_and(|rule| {
+                                            !proposal.base_word.is_empty()
+                                                && rule.round_trips(
+                                                    &proposal.base_word,
+                                                    &proposal.word,
+                                                )
+                                        });
+                                        *accepted = proposal.round_trips;
+                                    }
+                                    ui.label(proposal.count.to_string());
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                ui.separator();
+                let confirmed = dialog
+                    .proposals
+                    .iter()
+                    .zip(&dialog.accepted)
+                    .filter(|(proposal, accepted)| proposal.round_trips && **accepted)
+                    .count();
+                let skipped = dialog
+                    .proposals
+                    .iter()
+                    .filter(|proposal| !proposal.round_trips)
+                    .count();
+                ui.label(format!(
+                    "{} match(es), {confirmed} confirmed, {skipped} skipped (no round-trip)",
+                    dialog.proposals.len()
+                ));
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(confirmed > 0, egui::Button::new("Apply"))
+                        .clicked()
+                    {
+                        should_apply = true;
+                        should_close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_search && let Some(rule_idx) = dialog.selected_rule {
+            dialog.proposals = self.project.propose_rule_matches(rule_idx, &dialog.pattern);
+            dialog.accepted = dialog
+                .proposals
+                .iter()
+                .map(|proposal| proposal.round_trips)
+                .collect();
+        }
+
+        if should_apply && let Some(rule_idx) = dialog.selected_rule {
+            let mut changed = 0;
+            for (proposal, accepted) in dialog.proposals.iter().zip(&dialog.accepted) {
+                if proposal.round_trips && *accepted {
+                    changed +=
+                        self.project
+                            .derive_word(&proposal.word, &proposal.base_word, rule_idx);
+                }
+            }
+            if changed > 0 {
+                self.mark_segments_changed(ctx);
+            }
+        }
+
+        if open && !should_close {
+            self.apply_rule_popup = Some(dialog);
+        }
+    }
+}
@@ -73,7 +73,7 @@ impl DecryptionApp {
             let title = create_popup_title(
                 "Update Comment: ",
                 &dialog.word,
-                self.project.font_path.is_some(),
+                self.project.has_custom_font(),
             );
 
             let mut should_close = false;
@@ -0,0 +1,117 @@
+use eframe::egui;
+
+use crate::consts::colors::{HIGHLIGHT_BG, HIGHLIGHT_FG};
+use crate::consts::ui::{POPUP_WIDTH, TOKEN_FONT_SIZE};
+use crate::io::segment_font_family;
+use crate::libs::Segment;
+use crate::libs::normalization::resolve_vocabulary_key;
+use crate::libs::text_analysis::{DiffOp, align_tokens};
+use crate::ui::states::state::DecryptionApp;
+
+/// Picks one side's token index out of an alignment step
+type DiffSide = fn(DiffOp) -> Option<usize>;
+
+impl DecryptionApp {
+    pub(super) fn render_compare_popup(&mut self, ctx: &egui::Context) {
+        let Some((first_idx, second_idx)) = self.compare_popup else {
+            return;
+        };
+        let (Some(first), Some(second)) = (
+            self.project.segments.get(first_idx),
+            self.project.segments.get(second_idx),
+        ) else {
+            self.compare_popup = None;
+            return;
+        };
+
+        let ops = align_tokens(&first.tokens, &second.tokens);
+        let shared = ops
+            .iter()
+            .filter(|op| matches!(op, DiffOp::Equal(..)))
+            .count();
+        let loaded_fonts = self.project.fonts.len();
+        let rows: [(usize, &Segment, DiffSide); 2] = [
+            (first_idx, first, DiffOp::first),
+            (second_idx, second, DiffOp::second),
+        ];
+        let mut open = true;
+
+        egui::Window::new(format!(
+            "Compare [{}] and [{}]",
+            first_idx + 1,
+            second_idx + 1
+        ))
+        .id(egui::Id::new("compare_popup"))
+        .open(&mut open)
+        .default_width(POPUP_WIDTH)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new(format!(
+                    "{shared} shared tokens; differences are highlighted"
+                ))
+                .weak(),
+            );
+            ui.separator();
+
+            egui::ScrollArea::horizontal().show(ui, |ui| {
+                egui::Grid::new("compare_grid").show(ui, |ui| {
+                    for (seg_idx, segment, side) in rows {
+                        ui.label(egui::RichText::new(format!("[{}]", seg_idx + 1)).strong());
+                        let font_family = segment_font_family(segment.font, loaded_fonts)
+                            .map_or(egui::FontFamily::Proportional, |family| {
+                                egui::FontFamily::Name(family.into())
+                            });
+                        for op in &ops {
+                            let Some(token) = side(*op).and_then(|idx| segment.tokens.get(idx))
+                            else {
+                                ui.label("");
+                                continue;
+                            };
+                            let text = egui::RichText::new(&token.original)
+                                .family(font_family.clone())
+                                .size(TOKEN_FONT_SIZE);
+                            let text = if matches!(op, DiffOp::Equal(..)) {
+                                text.weak()
+                            } else {
+                                text.background_color(HIGHLIGHT_BG).color(HIGHLIGHT_FG)
+                            };
+                            let base_word = token.base_word.as_ref().unwrap_or(&token.original);
+                            let key = resolve_vocabulary_key(
+                                &self.project.vocabulary,
+                                self.normalized_vocabulary(),
+                                base_word,
+                            );
+                            let response = ui.label(text);
+                            if let Some(gloss) = self.project.vocabulary.get(key)
+                                && !gloss.is_empty()
+                            {
+                                response.on_hover_text(gloss);
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+
+            for (seg_idx, segment, _) in rows {
+                if !segment.translation.is_empty() {
+                    ui.add(
+                        egui::Label::new(
+                            egui::RichText::new(format!(
+                                "[{}] {}",
+                                seg_idx + 1,
+                                segment.translation
+                            ))
+                            .italics(),
+                        )
+                        .wrap(),
+                    );
+                }
+            }
+        });
+
+        if !open {
+            self.compare_popup = None;
+        }
+    }
+}
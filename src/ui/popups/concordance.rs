@@ -0,0 +1,106 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_CONCORDANCE_WIDTH, POPUP_REFERENCE_HEIGHT};
+use crate::enums::PopupRequest;
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::text_analysis::ConcordanceLine;
+use crate::ui::popup_utils::create_popup_title;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_concordance_popup(
+        &mut self,
+        ctx: &egui::Context,
+        popup_request: &mut Option<PopupRequest>,
+    ) {
+        let mut should_close = false;
+
+        if let Some((word, lines)) = self.concordance_popup.as_ref() {
+            let mut open = true;
+            let title = create_popup_title("Concordance: ", word, self.project.has_custom_font());
+            egui::Window::new(title)
+                .id(egui::Id::new("concordance_popup"))
+                .open(&mut open)
+                .default_width(POPUP_CONCORDANCE_WIDTH)
+                .default_height(POPUP_REFERENCE_HEIGHT)
+                .show(ctx, |ui| {
+                    ui.label(format!("{} occurrence(s)", lines.len()));
+                    ui.separator();
+                    self.render_concordance_content(ui, lines, popup_request);
+                });
+
+            if !open {
+                should_close = true;
+            }
+        }
+
+        if should_close {
+            self.concordance_popup = None;
+        }
+    }
+
+    fn render_concordance_content(
+        &self,
+        ui: &mut egui::Ui,
+        lines: &[ConcordanceLine],
+        popup_request: &mut Option<PopupRequest>,
+    ) {
+        let font_family = if self.project.has_custom_font() {
+            egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
+        } else {
+            egui::FontFamily::Monospace
+        };
+        let font_id = egui::FontId::new(
+            egui::TextStyle::Monospace.resolve(ui.style()).size,
+            font_family,
+        );
+
+        // Right-align left contexts so every keyword starts in the same column
+        let left_texts: Vec<String> = lines.iter().map(|line| line.left.join(" ")).collect();
+        let left_width = left_texts
+            .iter()
+            .map(|text| text.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for (line, left) in lines.iter().zip(&left_texts) {
+                    ui.horizontal(|ui| {
+                        let label_resp = ui.add(
+                            egui::Label::new(
+                                egui::RichText::new(format!("[{}]", line.segment_idx + 1))
+                                    .monospace()
+                                    .weak(),
+                            )
+                            .sense(egui::Sense::click()),
+                        );
+                        if label_resp.secondary_clicked() {
+                            let cursor_pos = ui
+                                .ctx()
+                                .input(|i| i.pointer.interact_pos())
+                                .unwrap_or_default();
+                            *popup_request =
+                                Some(PopupRequest::SentenceMenu(line.segment_idx, cursor_pos));
+                        }
+
+                        ui.spacing_mut().item_spacing.x = 0.0;
+                        ui.label(
+                            egui::RichText::new(format!(" {left:>left_width$} "))
+                                .font(font_id.clone()),
+                        );
+                        ui.label(
+                            egui::RichText::new(&line.keyword)
+                                .font(font_id.clone())
+                                .strong(),
+                        );
+                        ui.label(
+                            egui::RichText::new(format!(" {}", line.right.join(" ")))
+                                .font(font_id.clone()),
+                        );
+                    });
+                }
+            });
+    }
+}
@@ -10,7 +10,18 @@ impl DecryptionApp {
         self.definition_popup = None;
         self.reference_popup = None;
         self.similar_popup = None;
+        self.similar_pending = None;
         self.similar_tokens_popup = None;
+        self.concordance_popup = None;
+        self.duplicates_popup = None;
+        self.word_frequency_popup = None;
+        self.phrase_anchor = None;
+        self.phrase_gloss_popup = None;
+        self.word_categories_popup = None;
+        self.formation_rules_popup = None;
+        self.apply_rule_popup = None;
+        self.stopwords_popup = None;
+        self.project_settings_popup = None;
         self.word_menu_popup = None;
         self.sentence_menu_popup = None;
         self.word_formation_popup = None;
@@ -18,6 +29,12 @@ impl DecryptionApp {
         self.remove_formation_rule_popup = None;
         self.update_comment_popup = None;
         self.update_sentence_comment_popup = None;
+        self.update_tags_popup = None;
+        self.rename_word_popup = None;
+        self.merge_words_popup = None;
+        self.parallel_translations_popup = None;
+        self.segment_info_popup = None;
+        self.compare_popup = None;
         self.custom_tokenization_popup = None;
         self.pinned_popups.clear();
     }
@@ -29,10 +46,19 @@ impl DecryptionApp {
         usage_lookup: &Option<HashMap<String, Vec<usize>>>,
         popup_request: &mut Option<PopupRequest>,
     ) {
-        self.render_definition_popup(ctx, headword_lookup, popup_request);
+        self.render_definition_popup(ctx, headword_lookup, usage_lookup, popup_request);
         self.render_reference_popup(ctx, usage_lookup, popup_request);
         self.render_similar_popup(ctx, popup_request);
         self.render_similar_tokens_popup(ctx, popup_request);
+        self.render_concordance_popup(ctx, popup_request);
+        self.render_duplicates_popup(ctx, popup_request);
+        self.render_word_frequency_popup(ctx, popup_request);
+        self.render_stopwords_popup(ctx);
+        self.render_project_settings_popup(ctx);
+        self.render_phrase_gloss_popup(ctx);
+        self.render_word_categories_popup(ctx);
+        self.render_formation_rules_popup(ctx);
+        self.render_apply_rule_popup(ctx);
         self.render_word_menu_popup(ctx, popup_request);
         self.render_sentence_menu_popup(ctx, popup_request);
         self.render_formatting_chain_popup(ctx);
@@ -41,5 +67,11 @@ impl DecryptionApp {
         self.render_new_formation_rule_popup(ctx);
         self.render_update_comment_popup(ctx);
         self.render_update_sentence_comment_popup(ctx);
+        self.render_update_tags_popup(ctx);
+        self.render_rename_word_popup(ctx);
+        self.render_merge_words_popup(ctx);
+        self.render_parallel_translations_popup(ctx);
+        self.render_segment_info_popup(ctx);
+        self.render_compare_popup(ctx);
     }
 }
@@ -1,5 +1,7 @@
 use eframe::egui;
 
+use crate::libs::eval::TokenizationRule;
+use crate::ui::popup_utils::render_script_helpers;
 use crate::ui::states::DecryptionApp;
 
 impl DecryptionApp {
@@ -27,6 +29,7 @@ impl DecryptionApp {
                             .desired_rows(10)
                             .desired_width(f32::INFINITY),
                     );
+                    render_script_helpers(ui);
                     ui.add_space(8.0);
 
                     ui.separator();
@@ -38,6 +41,11 @@ impl DecryptionApp {
                             should_test = true;
                         }
                     });
+                    ui.checkbox(
+                        &mut dialog.verify_integrity,
+                        "Check that tokens reproduce every imported line",
+                    )
+                    .on_hover_text("Whitespace is ignored when comparing");
 
                     if !dialog.preview.is_empty() {
                         ui.add_space(4.0);
@@ -47,6 +55,31 @@ impl DecryptionApp {
                                 ui.label(format!("[{token}]"));
                             }
                         });
+                        if !TokenizationRule::verify(&dialog.test_text, &dialog.preview) {
+                            ui.colored_label(
+                                ui.visuals().warn_fg_color,
+                                "Tokens do not reproduce the test text",
+                            );
+                        }
+                    }
+
+                    if let Some(lossy_lines) = &dialog.lossy_lines {
+                        ui.add_space(4.0);
+                        if lossy_lines.is_empty() {
+                            ui.label("All imported lines are reproduced by their tokens.");
+                        } else {
+                            ui.colored_label(
+                                ui.visuals().warn_fg_color,
+                                format!("{} line(s) lose or alter characters:", lossy_lines.len()),
+                            );
+                            egui::ScrollArea::vertical()
+                                .max_height(80.0)
+                                .show(ui, |ui| {
+                                    for line in lossy_lines {
+                                        ui.label(format!("Line {line}"));
+                                    }
+                                });
+                        }
                     }
 
                     ui.add_space(8.0);
@@ -78,10 +111,22 @@ impl DecryptionApp {
                     dialog.preview = tokens;
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Script error: {e}"));
+                    self.error_message = Some(e.to_string());
                     dialog.preview.clear();
                 }
             }
+
+            dialog.lossy_lines = if dialog.verify_integrity {
+                match test_rule.find_lossy_lines(&dialog.import_data.0) {
+                    Ok(lines) => Some(lines),
+                    Err(e) => {
+                        self.error_message = Some(e.to_string());
+                        None
+                    }
+                }
+            } else {
+                None
+            };
         }
 
         if should_apply {
@@ -100,17 +145,7 @@ impl DecryptionApp {
                 )
                 .unwrap_or_else(|_| Vec::new());
 
-                self.project.segments = segments;
-                self.project.project_name = name;
-                self.project.font_path = None;
-                self.current_path = None;
-                self.project_filename = None;
-                self.filter_dirty = true;
-                self.lookups_dirty = true;
-                self.tfidf_dirty = true;
-                self.filter_text.clear();
-                self.clear_popups();
-                self.update_dirty_status(true, ctx);
+                self.install_imported_segments(segments, name, ctx);
             }
             should_close = true;
         }
@@ -2,10 +2,17 @@ use std::collections::HashMap;
 
 use eframe::egui;
 
-use crate::consts::ui::{POPUP_DEFINITION_HEIGHT, POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
-use crate::enums::{DictionaryPopupType, PinnedPopup, PopupRequest, UiAction};
+use crate::consts::ui::{
+    POPUP_DEFINITION_HEIGHT, POPUP_REFERENCE_HEIGHT, POPUP_SEGMENT_PAGE_SIZE, POPUP_WIDTH,
+};
+use crate::enums::{DefinitionScope, DictionaryPopupType, PinnedPopup, PopupRequest, UiAction};
+use crate::libs::filtering::FilterOperation;
+use crate::libs::normalization::resolve_vocabulary_key;
+use crate::libs::text_analysis::TextProcessor;
 use crate::ui;
-use crate::ui::popup_utils::{create_pinned_title_string, create_popup_title};
+use crate::ui::popup_utils::{
+    create_pinned_title_string, create_popup_title, render_comment_with_links,
+};
 use crate::ui::states::state::DecryptionApp;
 
 impl DecryptionApp {
@@ -13,14 +20,16 @@ impl DecryptionApp {
         &mut self,
         ctx: &egui::Context,
         headword_lookup: &Option<HashMap<String, Vec<usize>>>,
+        usage_lookup: &Option<HashMap<String, Vec<usize>>>,
         popup_request: &mut Option<PopupRequest>,
     ) {
         let mut should_close = false;
         let mut should_pin = false;
+        let mut scope = self.definition_scope;
 
         if let Some(word) = self.definition_popup.as_ref() {
             let mut open = true;
-            let title = create_popup_title("Definition: ", word, self.project.font_path.is_some());
+            let title = create_popup_title("Definition: ", word, self.project.has_custom_font());
             egui::Window::new(title)
                 .id(egui::Id::new("def_popup"))
                 .open(&mut open)
@@ -31,15 +40,22 @@ impl DecryptionApp {
                         if ui.button("📌 Pin").clicked() {
                             should_pin = true;
                         }
+                        ui.separator();
+                        for option in DefinitionScope::SCOPES {
+                            ui.selectable_value(&mut scope, option, option.display_text())
+                                .on_hover_text(option.hover_text());
+                        }
                     });
                     ui.separator();
 
+                    self.render_word_comment(ui, word, popup_request);
+
                     self.render_dictionary_content(
                         ui,
                         word,
                         DictionaryPopupType::Definition,
                         headword_lookup,
-                        &None,
+                        usage_lookup,
                         popup_request,
                         None,
                     );
@@ -53,7 +69,7 @@ impl DecryptionApp {
                 let title = create_pinned_title_string(
                     "📌 Definition: ",
                     word,
-                    self.project.font_path.is_some(),
+                    self.project.has_custom_font(),
                 );
                 self.pinned_popups.push(PinnedPopup::Dictionary(
                     word.clone(),
@@ -66,6 +82,7 @@ impl DecryptionApp {
             }
         }
 
+        self.definition_scope = scope;
         if should_close {
             self.definition_popup = None;
         }
@@ -82,7 +99,7 @@ impl DecryptionApp {
 
         if let Some(word) = self.reference_popup.as_ref() {
             let mut open = true;
-            let title = create_popup_title("References: ", word, self.project.font_path.is_some());
+            let title = create_popup_title("References: ", word, self.project.has_custom_font());
             egui::Window::new(title)
                 .id(egui::Id::new("ref_popup"))
                 .open(&mut open)
@@ -115,7 +132,7 @@ impl DecryptionApp {
                 let title = create_pinned_title_string(
                     "📌 References: ",
                     word,
-                    self.project.font_path.is_some(),
+                    self.project.has_custom_font(),
                 );
                 self.pinned_popups.push(PinnedPopup::Dictionary(
                     word.clone(),
@@ -161,7 +178,7 @@ impl DecryptionApp {
                     self.render_segment_list(
                         ui,
                         word,
-                        headword_lookup,
+                        self.definition_scope.select(headword_lookup, usage_lookup),
                         popup_request,
                         popup_id,
                         true,
@@ -180,14 +197,95 @@ impl DecryptionApp {
         is_definition: bool,
     ) {
         if let Some(map) = lookup_map {
-            if let Some(indices) = map.get(word) {
-                for &idx in indices {
+            let key = self.lookup_map_key(word);
+            if let Some(indices) = map.get(key.as_ref()) {
+                let frequency =
+                    (!is_definition).then(|| self.lookup_cache.token_frequency(key.as_ref()));
+                if let Some((total, per_segment)) = &frequency {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{total} occurrence(s) in {} segment(s)",
+                            per_segment.len()
+                        ))
+                        .strong(),
+                    );
+                    ui.separator();
+                }
+
+                let list_id = match popup_id {
+                    Some(id) => egui::Id::new(id),
+                    None => egui::Id::new("segment_list")
+                        .with(is_definition)
+                        .with(self.definition_scope)
+                        .with(word),
+                };
+                let page_id = list_id.with("segment_list_page");
+                let query_id = list_id.with("segment_list_query");
+
+                let mut query = ui
+                    .data(|d| d.get_temp::<String>(query_id))
+                    .unwrap_or_default();
+                let query_resp = ui.add(
+                    egui::TextEdit::singleline(&mut query)
+                        .hint_text("🔍 Filter by translation")
+                        .desired_width(f32::INFINITY),
+                );
+                if query_resp.changed() {
+                    ui.data_mut(|d| {
+                        d.insert_temp(query_id, query.clone());
+                        d.insert_temp(page_id, 0usize);
+                    });
+                }
+                let filtered;
+                let indices = if query.trim().is_empty() {
+                    indices.as_slice()
+                } else {
+                    filtered =
+                        FilterOperation::filter_by_translation(&self.project, indices, &query);
+                    if filtered.is_empty() {
+                        ui.label(egui::RichText::new("No segments match the filter.").weak());
+                    }
+                    filtered.as_slice()
+                };
+
+                let mut page = ui.data(|d| d.get_temp::<usize>(page_id)).unwrap_or(0);
+                page = ui::clamp_page(page, indices.len(), POPUP_SEGMENT_PAGE_SIZE);
+                let total_pages = indices.len().div_ceil(POPUP_SEGMENT_PAGE_SIZE);
+                if total_pages > 1 {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(page > 0, egui::Button::new("◀ Prev"))
+                            .clicked()
+                        {
+                            page -= 1;
+                        }
+                        ui.label(format!("Page {} / {total_pages}", page + 1));
+                        if ui
+                            .add_enabled(page + 1 < total_pages, egui::Button::new("Next ▶"))
+                            .clicked()
+                        {
+                            page += 1;
+                        }
+                    });
+                    ui.separator();
+                }
+                ui.data_mut(|d| d.insert_temp(page_id, page));
+
+                for &idx in ui::page_slice(indices, page, POPUP_SEGMENT_PAGE_SIZE) {
                     if let Some(seg) = self.project.segments.get(idx) {
+                        let occurrences = frequency.as_ref().and_then(|(_, per_segment)| {
+                            per_segment
+                                .binary_search_by_key(&idx, |&(seg_idx, _)| seg_idx)
+                                .ok()
+                                .map(|pos| per_segment[pos].1)
+                        });
                         ui.horizontal(|ui| {
-                            let mut label_resp = ui.add(
-                                egui::Label::new(format!("[{}]", idx + 1))
-                                    .sense(egui::Sense::click()),
-                            );
+                            let label_text = match occurrences {
+                                Some(count) => format!("[{}] ×{count}", idx + 1),
+                                None => format!("[{}]", idx + 1),
+                            };
+                            let mut label_resp =
+                                ui.add(egui::Label::new(label_text).sense(egui::Sense::click()));
                             if !seg.comment.is_empty() {
                                 label_resp = label_resp.on_hover_text(&seg.comment);
                             }
@@ -198,6 +296,16 @@ impl DecryptionApp {
                                     .unwrap_or_default();
                                 *popup_request = Some(PopupRequest::SentenceMenu(idx, cursor_pos));
                             }
+                            let glossed =
+                                TextProcessor::count_segment_translated_tokens(seg, &self.project);
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "({glossed}/{} glossed)",
+                                    seg.tokens.len()
+                                ))
+                                .small()
+                                .weak(),
+                            );
                             ui.vertical(|ui| {
                                 let scroll_id = match popup_id {
                                     Some(id) => egui::Id::new(id).with(idx),
@@ -207,8 +315,10 @@ impl DecryptionApp {
                                     .id_salt(scroll_id)
                                     .max_width(ui.available_width())
                                     .show(ui, |ui| {
-                                        let highlight =
-                                            if !is_definition { Some(word) } else { None };
+                                        let highlight = (!is_definition
+                                            || self.definition_scope
+                                                == DefinitionScope::AnyOccurrence)
+                                            .then_some(word);
 
                                         if let Some(action) = ui::render_clickable_tokens(
                                             ui,
@@ -216,8 +326,10 @@ impl DecryptionApp {
                                             &self.project.vocabulary,
                                             &self.project.vocabulary_comments,
                                             &self.project.formatted_word_comments,
+                                            self.normalized_vocabulary(),
                                             highlight,
-                                            self.project.font_path.is_some(),
+                                            self.highlight_word.as_deref(),
+                                            self.project.has_custom_font(),
                                             &self.project.formation_rules,
                                         ) {
                                             self.handle_ui_action(ui, action, popup_request, idx);
@@ -230,6 +342,14 @@ impl DecryptionApp {
                                     egui::RichText::new(&seg.translation).weak()
                                 };
                                 ui.add(egui::Label::new(text).wrap());
+                                if !seg.comment.is_empty() {
+                                    render_comment_with_links(
+                                        ui,
+                                        &seg.comment,
+                                        self.project.segments.len(),
+                                        popup_request,
+                                    );
+                                }
                             });
                         });
                         ui.separator();
@@ -237,7 +357,7 @@ impl DecryptionApp {
                 }
             } else {
                 ui.label(if is_definition {
-                    "No definitions found."
+                    self.definition_scope.empty_text()
                 } else {
                     "No usages found."
                 });
@@ -245,6 +365,26 @@ impl DecryptionApp {
         }
     }
 
+    /// Show the vocabulary comment for a word, with `[n]` references rendered as links
+    pub(super) fn render_word_comment(
+        &self,
+        ui: &mut egui::Ui,
+        word: &str,
+        popup_request: &mut Option<PopupRequest>,
+    ) {
+        let vocab_key =
+            resolve_vocabulary_key(&self.project.vocabulary, self.normalized_vocabulary(), word);
+        if let Some(comment) = self
+            .project
+            .vocabulary_comments
+            .get(vocab_key)
+            .filter(|comment| !comment.is_empty())
+        {
+            render_comment_with_links(ui, comment, self.project.segments.len(), popup_request);
+            ui.separator();
+        }
+    }
+
     pub(super) fn handle_ui_action(
         &self,
         ui: &egui::Ui,
@@ -0,0 +1,89 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::enums::{AppAction, PopupRequest};
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_duplicates_popup(
+        &mut self,
+        ctx: &egui::Context,
+        popup_request: &mut Option<PopupRequest>,
+    ) {
+        let Some(groups) = self.duplicates_popup.as_ref() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut delete_idx = None;
+
+        egui::Window::new("Duplicate Segments")
+            .id(egui::Id::new("duplicates_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                if groups.is_empty() {
+                    ui.label("No duplicate segments found.");
+                    return;
+                }
+
+                ui.label(format!("{} group(s) of duplicates", groups.len()));
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for (group_num, group) in groups.iter().enumerate() {
+                            let preview = self
+                                .project
+                                .segments
+                                .get(group[0])
+                                .map(|segment| {
+                                    segment
+                                        .tokens
+                                        .iter()
+                                        .map(|token| token.original.as_str())
+                                        .collect::<Vec<_>>()
+                                        .join(" ")
+                                })
+                                .unwrap_or_default();
+
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "Group {} ({} copies): {preview}",
+                                    group_num + 1,
+                                    group.len()
+                                ))
+                                .strong(),
+                            );
+
+                            for (pos, &seg_idx) in group.iter().enumerate() {
+                                ui.horizontal(|ui| {
+                                    ui.label(format!("[{}]", seg_idx + 1));
+                                    if ui.small_button("Go to").clicked() {
+                                        *popup_request = Some(PopupRequest::JumpToSegment(seg_idx));
+                                    }
+                                    // Keep the first occurrence; only later copies are offered for deletion
+                                    if pos > 0 && ui.small_button("Delete").clicked() {
+                                        delete_idx = Some(seg_idx);
+                                    }
+                                });
+                            }
+                            ui.separator();
+                        }
+                    });
+            });
+
+        if let Some(seg_idx) = delete_idx {
+            self.confirmation = Some((
+                format!("Delete segment {}? This cannot be undone.", seg_idx + 1),
+                AppAction::DeleteSegment(seg_idx),
+            ));
+        }
+
+        if !open {
+            self.duplicates_popup = None;
+        }
+    }
+}
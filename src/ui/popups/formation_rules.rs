@@ -0,0 +1,110 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::enums::FormationType;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_formation_rules_popup(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.formation_rules_popup.take() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut delete_rule = None;
+        let mut clear_and_delete_rule = None;
+
+        egui::Window::new("Formation Rules")
+            .id(egui::Id::new("formation_rules_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                let rules = &self.project.formation_rules;
+                ui.label(format!("{} rule(s)", rules.len()));
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        egui::Grid::new("formation_rules_grid")
+                            .striped(true)
+                            .num_columns(4)
+                            .show(ui, |ui| {
+                                ui.strong("Type");
+                                ui.strong("Description");
+                                ui.strong("Uses");
+                                ui.label("");
+                                ui.end_row();
+
+                                for (rule_idx, rule) in rules.iter().enumerate() {
+                                    let usage = dialog.usage.get(rule_idx).copied().unwrap_or(0);
+                                    ui.label(match rule.rule_type {
+                                        FormationType::Derivation => "Derivation",
+                                        FormationType::Inflection => "Inflection",
+                                        FormationType::Nonmorphological => "Nonmorphological",
+                                    });
+                                    let description = ui.label(&rule.description);
+                                    if !rule.note.is_empty() {
+                                        description.on_hover_text(&rule.note);
+                                    }
+                                    ui.label(usage.to_string());
+                                    if usage == 0 {
+                                        if ui.button("Delete").clicked() {
+                                            delete_rule = Some(rule_idx);
+                                        }
+                                    } else if ui
+                                        .button("Clear & Delete...")
+                                        .on_hover_text(
+                                            "Turn the tokens using this rule back into plain words, then delete it",
+                                        )
+                                        .clicked()
+                                    {
+                                        dialog.confirm_delete = Some(rule_idx);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                if let Some(rule_idx) = dialog.confirm_delete
+                    && let Some(rule) = rules.get(rule_idx)
+                {
+                    ui.separator();
+                    ui.label(format!(
+                        "'{}' is used by {} token(s). They will lose their formation chain.",
+                        rule.description,
+                        dialog.usage.get(rule_idx).copied().unwrap_or(0)
+                    ));
+                    ui.horizontal(|ui| {
+                        if ui.button("Clear and Delete").clicked() {
+                            clear_and_delete_rule = Some(rule_idx);
+                        }
+                        if ui.button("Cancel").clicked() {
+                            dialog.confirm_delete = None;
+                        }
+                    });
+                }
+            });
+
+        if let Some(rule_idx) = clear_and_delete_rule {
+            self.project.clear_formation_rule(rule_idx);
+            delete_rule = Some(rule_idx);
+            dialog.confirm_delete = None;
+        }
+
+        if let Some(rule_idx) = delete_rule
+            && self.project.remove_formation_rule(rule_idx).is_some()
+        {
+            self.mark_segments_changed(ctx);
+            dialog.usage = self.project.rule_usage_counts();
+            if dialog.confirm_delete.is_some_and(|idx| idx >= rule_idx) {
+                dialog.confirm_delete = None;
+            }
+        }
+
+        if open {
+            self.formation_rules_popup = Some(dialog);
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use eframe::egui;
 
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::eval::{FormationStep, formation_chain};
 use crate::ui::popup_utils::create_popup_title;
 use crate::ui::states::state::DecryptionApp;
 
@@ -30,11 +32,11 @@ impl DecryptionApp {
             let title_text = token_info
                 .as_ref()
                 .map(|(word, _, _)| word.as_str())
-                .unwrap_or("Formatting Chain");
+                .unwrap_or("Formation Chain");
             let title = create_popup_title(
-                "Formatting Chain: ",
+                "Formation Chain: ",
                 title_text,
-                self.project.font_path.is_some(),
+                self.project.has_custom_font(),
             );
 
             egui::Window::new(title)
@@ -44,8 +46,8 @@ impl DecryptionApp {
                 .default_height(260.0)
                 .show(ctx, |ui| {
                     if let Some((word, base_word, rule_indices)) = token_info {
-                        let font_family = if self.project.font_path.is_some() {
-                            egui::FontFamily::Name("SentenceFont".into())
+                        let font_family = if self.project.has_custom_font() {
+                            egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
                         } else {
                             egui::FontFamily::Proportional
                         };
@@ -69,59 +71,57 @@ impl DecryptionApp {
                         if rule_indices.is_empty() {
                             ui.label("No formation rules applied to this word.");
                         } else {
+                            let steps = formation_chain(
+                                &base_word,
+                                &rule_indices,
+                                &self.project.formation_rules,
+                            );
                             egui::ScrollArea::vertical()
                                 .auto_shrink([false, false])
                                 .show(ui, |ui| {
-                                    let mut current = base_word;
-                                    for (step_idx, rule_idx) in rule_indices.iter().enumerate() {
-                                        ui.group(|ui| {
-                                            if let Some(rule) =
-                                                self.project.formation_rules.get(*rule_idx)
-                                            {
-                                                let type_prefix = match rule.rule_type {
-                                                    crate::enums::FormationType::Derivation => {
-                                                        "[D]"
-                                                    }
-                                                    crate::enums::FormationType::Inflection => {
-                                                        "[I]"
-                                                    }
-                                                    crate::enums::FormationType::Nonmorphological => {
-                                                        "[N]"
-                                                    }
-                                                };
-
+                                    for (step_idx, step) in steps.iter().enumerate() {
+                                        ui.group(|ui| match step {
+                                            FormationStep::Applied {
+                                                description,
+                                                rule_type,
+                                                result,
+                                                ..
+                                            } => {
                                                 ui.label(format!(
-                                                    "Step {}: {} {}",
+                                                    "Step {}: {} {description}",
                                                     step_idx + 1,
-                                                    type_prefix,
-                                                    rule.description
+                                                    rule_type.badge()
                                                 ));
-
-                                                match rule.apply(&current) {
-                                                    Ok(next) => {
-                                                        ui.horizontal(|ui| {
-                                                            ui.label("Result: ");
-                                                            ui.label(
-                                                                egui::RichText::new(&next)
-                                                                    .family(font_family.clone()),
-                                                            );
-                                                        });
-                                                        current = next;
-                                                    }
-                                                    Err(err) => {
-                                                        ui.colored_label(
-                                                            egui::Color32::LIGHT_RED,
-                                                            format!("Error: {err}"),
-                                                        );
-                                                    }
-                                                }
-                                            } else {
+                                                ui.horizontal(|ui| {
+                                                    ui.label("Result: ");
+                                                    ui.label(
+                                                        egui::RichText::new(result)
+                                                            .family(font_family.clone()),
+                                                    );
+                                                });
+                                            }
+                                            FormationStep::Failed {
+                                                description,
+                                                rule_type,
+                                                error,
+                                                ..
+                                            } => {
+                                                ui.label(format!(
+                                                    "Step {}: {} {description}",
+                                                    step_idx + 1,
+                                                    rule_type.badge()
+                                                ));
+                                                ui.colored_label(
+                                                    egui::Color32::LIGHT_RED,
+                                                    format!("Error: {error}"),
+                                                );
+                                            }
+                                            FormationStep::MissingRule { rule_idx } => {
                                                 ui.colored_label(
                                                     egui::Color32::LIGHT_RED,
                                                     format!(
-                                                        "Step {}: Missing formation rule index {}",
-                                                        step_idx + 1,
-                                                        rule_idx
+                                                        "Step {}: Missing formation rule index {rule_idx}",
+                                                        step_idx + 1
                                                     ),
                                                 );
                                             }
@@ -130,7 +130,7 @@ impl DecryptionApp {
                                 });
                         }
                     } else {
-                        ui.label("Word not found for this formatting chain.");
+                        ui.label("Word not found for this formation chain.");
                     }
                 });
 
@@ -1,7 +1,8 @@
 use eframe::egui;
 
-use crate::enums::PopupRequest;
-use crate::ui::states::state::{DecryptionApp, UpdateSentenceCommentDialog};
+use crate::enums::{AppAction, PopupRequest};
+use crate::io::format_segment_plaintext;
+use crate::ui::states::state::{DecryptionApp, UpdateSentenceCommentDialog, UpdateTagsDialog};
 
 impl DecryptionApp {
     pub(super) fn render_sentence_menu_popup(
@@ -11,6 +12,23 @@ impl DecryptionApp {
     ) {
         if let Some((sentence_idx, cursor_pos)) = self.sentence_menu_popup.as_ref().cloned() {
             let mut should_close = false;
+            let mut merge_requested = false;
+            let mut font_choice = None;
+            let mut toggle_lock = false;
+            let mut toggle_reviewed = false;
+            let mut seed_vocabulary = false;
+            #[cfg(not(target_arch = "wasm32"))]
+            let mut export_image = false;
+            let locked = self
+                .project
+                .segments
+                .get(sentence_idx)
+                .is_some_and(|segment| segment.locked);
+            let reviewed = self
+                .project
+                .segments
+                .get(sentence_idx)
+                .is_some_and(|segment| segment.reviewed);
 
             egui::Area::new(egui::Id::new("sentence_context_menu"))
                 .order(egui::Order::Foreground)
@@ -46,9 +64,183 @@ impl DecryptionApp {
                                 });
                             should_close = true;
                         }
+
+                        if ui
+                            .add(egui::Button::new("Edit Tags").frame(false))
+                            .clicked()
+                        {
+                            let current_tags = self
+                                .project
+                                .segments
+                                .get(sentence_idx)
+                                .map(|segment| segment.tags.join(", "))
+                                .unwrap_or_default();
+
+                            self.update_tags_popup = Some(UpdateTagsDialog {
+                                segment_idx: sentence_idx,
+                                tags: current_tags,
+                            });
+                            should_close = true;
+                        }
+
+                        if ui
+                            .add(egui::Button::new("Add Tokens to Vocabulary").frame(false))
+                            .on_hover_text("Give each word of this segment an empty gloss entry")
+                            .clicked()
+                        {
+                            seed_vocabulary = true;
+                            should_close = true;
+                        }
+
+                        if ui
+                            .add(egui::Button::new("Segment Info").frame(false))
+                            .clicked()
+                        {
+                            self.segment_info_popup = Some(sentence_idx);
+                            should_close = true;
+                        }
+
+                        if ui
+                            .add(egui::Button::new("Copy as Interlinear Text").frame(false))
+                            .clicked()
+                        {
+                            if let Some(segment) = self.project.segments.get(sentence_idx) {
+                                ui.ctx().copy_text(format_segment_plaintext(
+                                    segment,
+                                    &self.project.vocabulary,
+                                    &self.project.formation_rules,
+                                ));
+                            }
+                            should_close = true;
+                        }
+
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if ui
+                            .add(egui::Button::new("Export Segment Image...").frame(false))
+                            .clicked()
+                        {
+                            export_image = true;
+                            should_close = true;
+                        }
+
+                        let lock_label = if locked {
+                            "Unlock Segment"
+                        } else {
+                            "Lock Segment"
+                        };
+                        if ui.add(egui::Button::new(lock_label).frame(false)).clicked() {
+                            toggle_lock = true;
+                            should_close = true;
+                        }
+
+                        let reviewed_label = if reviewed {
+                            "Mark Unreviewed"
+                        } else {
+                            "Mark Reviewed"
+                        };
+                        if ui
+                            .add(egui::Button::new(reviewed_label).frame(false))
+                            .clicked()
+                        {
+                            toggle_reviewed = true;
+                            should_close = true;
+                        }
+
+                        let has_next = sentence_idx + 1 < self.project.segments.len();
+                        let next_locked = self
+                            .project
+                            .segments
+                            .get(sentence_idx + 1)
+                            .is_some_and(|segment| segment.locked);
+                        if ui
+                            .add_enabled(
+                                has_next && !locked && !next_locked,
+                                egui::Button::new("Merge with Next").frame(false),
+                            )
+                            .clicked()
+                        {
+                            merge_requested = true;
+                            should_close = true;
+                        }
+
+                        if self.project.fonts.len() > 1 {
+                            ui.separator();
+                            ui.label(egui::RichText::new("Font").weak());
+                            let current = self
+                                .project
+                                .segments
+                                .get(sentence_idx)
+                                .and_then(|segment| segment.font);
+                            if ui
+                                .selectable_label(current.is_none(), "Project Default")
+                                .clicked()
+                            {
+                                font_choice = Some(None);
+                            }
+                            for (slot, font) in self.project.fonts.iter().enumerate() {
+                                if ui
+                                    .selectable_label(current == Some(slot), &font.name)
+                                    .clicked()
+                                {
+                                    font_choice = Some(Some(slot));
+                                }
+                            }
+                        }
+
+                        ui.separator();
+
+                        if ui
+                            .add(egui::Button::new("Delete Segment").frame(false))
+                            .clicked()
+                        {
+                            self.confirmation = Some((
+                                format!(
+                                    "Delete segment {}? This cannot be undone.",
+                                    sentence_idx + 1
+                                ),
+                                AppAction::DeleteSegment(sentence_idx),
+                            ));
+                            should_close = true;
+                        }
                     });
                 });
 
+            if let Some(font) = font_choice
+                && let Some(segment) = self.project.segments.get_mut(sentence_idx)
+            {
+                if segment.font != font {
+                    segment.font = font;
+                    self.update_dirty_status(true, ctx);
+                }
+                should_close = true;
+            }
+
+            if toggle_lock && let Some(segment) = self.project.segments.get_mut(sentence_idx) {
+                segment.locked = !segment.locked;
+                self.update_dirty_status(true, ctx);
+            }
+
+            if toggle_reviewed && let Some(segment) = self.project.segments.get_mut(sentence_idx) {
+                segment.reviewed = !segment.reviewed;
+                self.filter_dirty = true;
+                self.update_dirty_status(true, ctx);
+            }
+
+            if seed_vocabulary && self.project.seed_vocabulary_from_segment(sentence_idx) > 0 {
+                self.lookups_dirty = true;
+                self.update_dirty_status(true, ctx);
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if export_image {
+                self.export_segment_image(sentence_idx);
+            }
+
+            if merge_requested && self.project.merge_segments(sentence_idx) {
+                self.collapsed_segments.segment_removed(sentence_idx + 1);
+                self.mark_segments_changed(ctx);
+            }
+
             if should_close {
                 self.sentence_menu_popup = None;
             }
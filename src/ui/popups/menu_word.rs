@@ -1,7 +1,10 @@
 use eframe::egui;
 
 use crate::enums::{DictionaryPopupType, PopupRequest};
-use crate::ui::states::state::{DecryptionApp, RemoveFormationRuleDialog, WordFormationDialog};
+use crate::ui::states::state::{
+    DecryptionApp, RemoveFormationRuleDialog, RenameWordDialog, WordFormationDialog,
+};
+use crate::ui::sticky_menu::MenuEvent;
 
 impl DecryptionApp {
     pub(super) fn render_word_menu_popup(
@@ -12,7 +15,10 @@ impl DecryptionApp {
         if let Some((word, sentence_idx, word_idx, cursor_pos)) =
             self.word_menu_popup.as_ref().cloned()
         {
-            let mut should_close = false;
+            let mut action_taken = false;
+            let mut events = Vec::new();
+            let mut category_choice = None;
+            let mut no_gloss_choice = None;
 
             let (existing_base_word, existing_rule_idx) = self
                 .project
@@ -27,7 +33,7 @@ impl DecryptionApp {
                 })
                 .unwrap_or((None, None));
 
-            egui::Area::new(egui::Id::new("word_context_menu"))
+            let area_resp = egui::Area::new(egui::Id::new("word_context_menu"))
                 .order(egui::Order::Foreground)
                 .movable(false)
                 .fixed_pos(cursor_pos)
@@ -35,6 +41,21 @@ impl DecryptionApp {
                     egui::Frame::menu(ui.style()).show(ui, |ui| {
                         ui.set_min_width(180.0);
 
+                        ui.horizontal(|ui| {
+                            let pinned = self.word_menu_sticky.pinned;
+                            if ui
+                                .selectable_label(pinned, "📌")
+                                .on_hover_text("Keep this menu open after an action")
+                                .clicked()
+                            {
+                                events.push(MenuEvent::TogglePin);
+                            }
+                            if ui.small_button("✕").on_hover_text("Close").clicked() {
+                                events.push(MenuEvent::Dismiss);
+                            }
+                        });
+                        ui.separator();
+
                         if ui
                             .add(egui::Button::new("Show Definition").frame(false))
                             .clicked()
@@ -43,7 +64,7 @@ impl DecryptionApp {
                                 word.clone(),
                                 DictionaryPopupType::Definition,
                             ));
-                            should_close = true;
+                            action_taken = true;
                         }
 
                         if ui
@@ -54,7 +75,7 @@ impl DecryptionApp {
                                 word.clone(),
                                 DictionaryPopupType::Reference,
                             ));
-                            should_close = true;
+                            action_taken = true;
                         }
 
                         if ui
@@ -62,7 +83,15 @@ impl DecryptionApp {
                             .clicked()
                         {
                             *popup_request = Some(PopupRequest::SimilarTokens(word.clone()));
-                            should_close = true;
+                            action_taken = true;
+                        }
+
+                        if ui
+                            .add(egui::Button::new("Concordance").frame(false))
+                            .clicked()
+                        {
+                            *popup_request = Some(PopupRequest::Concordance(word.clone()));
+                            action_taken = true;
                         }
 
                         if ui
@@ -77,7 +106,7 @@ impl DecryptionApp {
                                 related_words: Vec::new(),
                                 rule_search_text: String::new(),
                             });
-                            should_close = true;
+                            action_taken = true;
                         }
 
                         if let Some(rule_idx) = existing_rule_idx
@@ -95,16 +124,46 @@ impl DecryptionApp {
                                     .unwrap_or_else(|| word.clone()),
                                 rule_description: rule.description.clone(),
                             });
-                            should_close = true;
+                            action_taken = true;
                         }
 
                         if ui
-                            .add(egui::Button::new("Show Formatting Chain").frame(false))
+                            .add(egui::Button::new("Show Formation Chain").frame(false))
                             .clicked()
                         {
                             *popup_request =
                                 Some(PopupRequest::FormattingChain(sentence_idx, word_idx));
-                            should_close = true;
+                            action_taken = true;
+                        }
+
+                        if ui
+                            .add(egui::Button::new("Rename Word...").frame(false))
+                            .clicked()
+                        {
+                            let target = existing_base_word.clone().unwrap_or_else(|| word.clone());
+                            self.rename_word_popup = Some(RenameWordDialog {
+                                new_word: target.clone(),
+                                word: target,
+                                confirm_merge: false,
+                            });
+                            action_taken = true;
+                        }
+
+                        let lookup_word = existing_base_word.as_ref().unwrap_or(&word);
+                        let no_gloss = self.project.no_gloss_words.contains(lookup_word);
+                        let label = if no_gloss {
+                            "Needs Gloss"
+                        } else {
+                            "Mark as No Gloss"
+                        };
+                        if ui
+                            .add(egui::Button::new(label).frame(false))
+                            .on_hover_text(
+                                "Words without a gloss, such as punctuation, are not counted as untranslated",
+                            )
+                            .clicked()
+                        {
+                            no_gloss_choice = Some(!no_gloss);
                         }
 
                         self.render_update_comment_menu_item(
@@ -112,12 +171,61 @@ impl DecryptionApp {
                             &word,
                             sentence_idx,
                             word_idx,
-                            &mut should_close,
+                            &mut action_taken,
                         );
+
+                        if !self.project.category_colors.is_empty() {
+                            ui.separator();
+                            ui.label(egui::RichText::new("Category").weak());
+                            let lookup_word = existing_base_word.as_ref().unwrap_or(&word);
+                            let current = self.project.vocabulary_categories.get(lookup_word);
+                            if ui.selectable_label(current.is_none(), "None").clicked() {
+                                category_choice = Some(None);
+                            }
+                            for (name, &[r, g, b]) in &self.project.category_colors {
+                                let label = egui::RichText::new(name)
+                                    .color(egui::Color32::from_rgb(r, g, b));
+                                if ui.selectable_label(current == Some(name), label).clicked() {
+                                    category_choice = Some(Some(name.clone()));
+                                }
+                            }
+                        }
                     });
                 });
 
-            if should_close {
+            if let Some(no_gloss) = no_gloss_choice {
+                let lookup_word = existing_base_word.clone().unwrap_or_else(|| word.clone());
+                if self.project.set_no_gloss(&lookup_word, no_gloss) {
+                    self.update_dirty_status(true, ctx);
+                }
+                action_taken = true;
+            }
+
+            if let Some(category) = category_choice {
+                let lookup_word = existing_base_word.unwrap_or_else(|| word.clone());
+                self.project.set_word_category(&lookup_word, category);
+                self.update_dirty_status(true, ctx);
+                action_taken = true;
+            }
+
+            if action_taken {
+                events.push(MenuEvent::Action);
+            }
+            let clicked_outside = ctx.input(|i| {
+                i.pointer.primary_clicked()
+                    && i.pointer
+                        .interact_pos()
+                        .is_some_and(|pos| !area_resp.response.rect.contains(pos))
+            });
+            if clicked_outside {
+                events.push(MenuEvent::ClickedOutside);
+            }
+
+            let mut keep_open = true;
+            for event in events {
+                keep_open &= self.word_menu_sticky.handle(event);
+            }
+            if !keep_open {
                 self.word_menu_popup = None;
             }
         }
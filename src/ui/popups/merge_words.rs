@@ -0,0 +1,126 @@
+use eframe::egui;
+
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::project::operations::RenameOutcome;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_merge_words_popup(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.merge_words_popup.take() else {
+            return;
+        };
+
+        let word_font = if self.project.has_custom_font() {
+            egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
+        } else {
+            egui::FontFamily::Proportional
+        };
+
+        let mut open = true;
+        let mut should_merge = false;
+        let mut should_close = false;
+
+        egui::Window::new("Merge Words")
+            .id(egui::Id::new("merge_words_popup"))
+            .open(&mut open)
+            .default_width(400.0)
+            .show(ctx, |ui| {
+                ui.label("Every occurrence of the source word becomes the target word.");
+                ui.separator();
+
+                egui::Grid::new("merge_words_grid")
+                    .num_columns(3)
+                    .show(ui, |ui| {
+                        for (label, word) in [
+                            ("Source:", &mut dialog.source),
+                            ("Target:", &mut dialog.target),
+                        ] {
+                            ui.label(label);
+                            if ui
+                                .add(
+                                    egui::TextEdit::singleline(word)
+                                        .font(egui::FontId::new(
+                                            egui::TextStyle::Body.resolve(ui.style()).size,
+                                            word_font.clone(),
+                                        ))
+                                        .desired_width(160.0),
+                                )
+                                .changed()
+                            {
+                                dialog.error = None;
+                            }
+                            let (count, _) = self
+                                .lookup_cache
+                                .token_frequency(&self.lookup_map_key(word.trim()));
+                            let gloss = self
+                                .project
+                                .vocabulary
+                                .get(word.trim())
+                                .map(String::as_str)
+                                .unwrap_or("");
+                            ui.label(
+                                egui::RichText::new(format!("{count} occurrence(s) · {gloss}"))
+                                    .weak(),
+                            );
+                            ui.end_row();
+                        }
+                    });
+
+                ui.horizontal(|ui| {
+                    ui.label("Keep gloss of:");
+                    ui.radio_value(&mut dialog.keep_source_gloss, false, "Target");
+                    ui.radio_value(&mut dialog.keep_source_gloss, true, "Source");
+                });
+                if let Some(error) = &dialog.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    let source = dialog.source.trim();
+                    let target = dialog.target.trim();
+                    let valid = !source.is_empty() && !target.is_empty() && source != target;
+                    if ui.add_enabled(valid, egui::Button::new("Merge")).clicked() {
+                        should_merge = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_merge {
+            let source = dialog.source.trim().to_string();
+            let target = dialog.target.trim().to_string();
+            let is_word = |word: &str| {
+                self.project.vocabulary.contains_key(word)
+                    || self.project.token_frequency(word).0 > 0
+            };
+            if !is_word(&target) {
+                dialog.error = Some(format!(
+                    "'{target}' is not a word in this project; use Rename Word instead."
+                ));
+            } else {
+                let source_gloss = self.project.vocabulary.get(&source).cloned();
+                match self.project.merge_words(&source, &target) {
+                    RenameOutcome::Merged(_) | RenameOutcome::Renamed(_) => {
+                        if dialog.keep_source_gloss
+                            && let Some(gloss) = source_gloss.filter(|gloss| !gloss.is_empty())
+                        {
+                            self.project.vocabulary.insert(target, gloss);
+                        }
+                        self.mark_segments_changed(ctx);
+                        return;
+                    }
+                    RenameOutcome::Unchanged | RenameOutcome::TargetExists => {
+                        dialog.error = Some(format!("'{source}' is not a word in this project."));
+                    }
+                }
+            }
+        }
+
+        if open && !should_close {
+            self.merge_words_popup = Some(dialog);
+        }
+    }
+}
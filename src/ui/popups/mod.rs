@@ -1,12 +1,27 @@
+pub(crate) mod apply_rule;
 pub(crate) mod comments;
+pub(crate) mod compare;
+pub(crate) mod concordance;
 pub(crate) mod coordinator;
 pub(crate) mod custom_tokenization;
 pub(crate) mod dictionary;
+pub(crate) mod duplicates;
+pub(crate) mod formation_rules;
 pub(crate) mod formatting_chain;
 pub(crate) mod menu_sentence;
 pub(crate) mod menu_word;
+pub(crate) mod merge_words;
+pub(crate) mod parallel_translations;
+pub(crate) mod phrase_gloss;
 pub(crate) mod pinned;
+pub(crate) mod project_settings;
 pub(crate) mod remove_formation_rule;
+pub(crate) mod rename_word;
+pub(crate) mod segment_info;
 pub(crate) mod similar_sentence;
 pub(crate) mod similar_token;
+pub(crate) mod stopwords;
+pub(crate) mod tags;
+pub(crate) mod word_categories;
 pub(crate) mod word_formation;
+pub(crate) mod word_frequency;
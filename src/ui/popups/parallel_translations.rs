@@ -0,0 +1,97 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_parallel_translations_popup(&mut self, ctx: &egui::Context) {
+        let Some(draft) = self.parallel_translations_popup.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut should_apply = false;
+        let mut should_close = false;
+        let segment_count = self.project.segments.len();
+        let line_count = draft.lines().count();
+
+        egui::Window::new("Paste Parallel Translations")
+            .id(egui::Id::new("parallel_translations_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                ui.label(
+                    "One translation per line; line 1 goes to segment [1], line 2 to [2], and so on. \
+                     Blank lines and locked segments are skipped.",
+                );
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .max_height(POPUP_REFERENCE_HEIGHT - 120.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(draft)
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(10),
+                        );
+                    });
+                ui.separator();
+                ui.label(format!(
+                    "{line_count} line(s) for {segment_count} segment(s)"
+                ));
+                if line_count > segment_count {
+                    ui.colored_label(
+                        ui.visuals().warn_fg_color,
+                        format!(
+                            "The last {} line(s) have no segment and will be ignored.",
+                            line_count - segment_count
+                        ),
+                    );
+                } else if line_count > 0 && line_count < segment_count {
+                    ui.colored_label(
+                        ui.visuals().warn_fg_color,
+                        format!(
+                            "The last {} segment(s) have no line and keep their translation.",
+                            segment_count - line_count
+                        ),
+                    );
+                }
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(line_count > 0, egui::Button::new("Apply"))
+                        .clicked()
+                    {
+                        should_apply = true;
+                        should_close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_apply {
+            let lines: Vec<&str> = draft.lines().collect();
+            let before: Vec<String> = self
+                .project
+                .segments
+                .iter()
+                .take(lines.len())
+                .map(|segment| segment.translation.clone())
+                .collect();
+            if self.project.apply_parallel_translations(&lines) > 0 {
+                let segments = self.project.segments.iter().zip(&before);
+                for (seg_idx, (segment, old)) in segments.enumerate() {
+                    if segment.translation != *old {
+                        self.change_summary.record_translation(seg_idx);
+                    }
+                }
+                self.mark_segments_changed(ctx);
+            }
+        }
+
+        if !open || should_close {
+            self.parallel_translations_popup = None;
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use eframe::egui;
+
+use crate::io::segment_font_family;
+use crate::ui::states::state::{DecryptionApp, PhraseGlossDialog};
+
+impl DecryptionApp {
+    /// Handle a shift-click on a token: the first click marks one end of a phrase,
+    /// a second click in the same segment opens the phrase gloss editor for the range
+    pub(crate) fn select_phrase_token(&mut self, seg_idx: usize, word_idx: usize) {
+        match self.phrase_anchor.take() {
+            Some((anchor_seg, anchor_idx)) if anchor_seg == seg_idx => {
+                if anchor_idx != word_idx {
+                    self.open_phrase_range(
+                        seg_idx,
+                        anchor_idx.min(word_idx),
+                        anchor_idx.max(word_idx),
+                    );
+                }
+            }
+            _ => self.phrase_anchor = Some((seg_idx, word_idx)),
+        }
+    }
+
+    /// Open the phrase gloss editor for the phrase starting at `start`
+    pub(crate) fn open_phrase_gloss(&mut self, seg_idx: usize, start: usize) {
+        if let Some(phrase) = self
+            .project
+            .segments
+            .get(seg_idx)
+            .and_then(|segment| segment.phrase_at(start))
+        {
+            let end = phrase.end;
+            self.open_phrase_range(seg_idx, start, end);
+        }
+    }
+
+    fn open_phrase_range(&mut self, seg_idx: usize, start: usize, end: usize) {
+        let existing = self.project.segments.get(seg_idx).and_then(|segment| {
+            segment
+                .phrases
+                .iter()
+                .find(|phrase| phrase.start == start && phrase.end == end)
+        });
+        self.phrase_gloss_popup = Some(PhraseGlossDialog {
+            segment_idx: seg_idx,
+            start,
+            end,
+            gloss: existing
+                .map(|phrase| phrase.gloss.clone())
+                .unwrap_or_default(),
+            existing: existing.is_some(),
+            error: None,
+        });
+    }
+
+    pub(super) fn render_phrase_gloss_popup(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.phrase_gloss_popup.take() else {
+            return;
+        };
+        let Some(segment) = self.project.segments.get(dialog.segment_idx) else {
+            return;
+        };
+
+        let phrase_text: Vec<&str> = segment
+            .tokens
+            .iter()
+            .skip(dialog.start)
+            .take(dialog.end + 1 - dialog.start)
+            .map(|token| token.original.as_str())
+            .collect();
+        let phrase_text = phrase_text.join(" ");
+        let word_font = segment_font_family(segment.font, self.project.fonts.len())
+            .map_or(egui::FontFamily::Proportional, |family| {
+                egui::FontFamily::Name(family.into())
+            });
+
+        let mut open = true;
+        let mut should_save = false;
+        let mut should_remove = false;
+        let mut should_close = false;
+
+        egui::Window::new("Phrase Gloss")
+            .id(egui::Id::new("phrase_gloss_popup"))
+            .open(&mut open)
+            .default_width(400.0)
+            .show(ctx, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label(format!(
+                        "[{}] tokens {}–{}:",
+                        dialog.segment_idx + 1,
+                        dialog.start + 1,
+                        dialog.end + 1
+                    ));
+                    ui.label(egui::RichText::new(&phrase_text).family(word_font).strong());
+                });
+                ui.separator();
+                ui.label("Gloss:");
+                ui.add(egui::TextEdit::singleline(&mut dialog.gloss).desired_width(f32::INFINITY));
+                if let Some(error) = &dialog.error {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(!dialog.gloss.trim().is_empty(), egui::Button::new("Save"))
+                        .clicked()
+                    {
+                        should_save = true;
+                    }
+                    if dialog.existing && ui.button("Remove").clicked() {
+                        should_remove = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        let mut changed = false;
+        if let Some(segment) = self.project.segments.get_mut(dialog.segment_idx) {
+            if should_save {
+                changed = segment.set_phrase_gloss(
+                    dialog.start,
+                    dialog.end,
+                    dialog.gloss.trim().to_string(),
+                );
+                if !changed {
+                    dialog.error =
+                        Some("This range overlaps another phrase in the segment.".to_string());
+                }
+            }
+            if should_remove {
+                changed |= segment.remove_phrase_gloss(dialog.start).is_some();
+            }
+        }
+        if changed {
+            self.update_dirty_status(true, ctx);
+            should_close = true;
+        }
+
+        if open && !should_close {
+            self.phrase_gloss_popup = Some(dialog);
+        }
+    }
+}
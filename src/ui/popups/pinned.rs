@@ -32,6 +32,9 @@ impl DecryptionApp {
                         .default_width(POPUP_WIDTH)
                         .default_height(height)
                         .show(ctx, |ui| {
+                            if *mode == DictionaryPopupType::Definition {
+                                self.render_word_comment(ui, word, popup_request);
+                            }
                             self.render_dictionary_content(
                                 ui,
                                 word.as_str(),
@@ -43,7 +46,7 @@ impl DecryptionApp {
                             );
                         });
                 }
-                PinnedPopup::Similar(_target_idx, similar_indices, id, title) => {
+                PinnedPopup::Similar(target_idx, similar_indices, id, title) => {
                     egui::Window::new(title.as_str())
                         .id(egui::Id::new(id))
                         .open(&mut open)
@@ -52,6 +55,7 @@ impl DecryptionApp {
                         .show(ctx, |ui| {
                             self.render_similar_content(
                                 ui,
+                                *target_idx,
                                 similar_indices.as_slice(),
                                 popup_request,
                                 Some(*id),
@@ -0,0 +1,59 @@
+use eframe::egui;
+
+use crate::consts::ui::POPUP_WIDTH;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_project_settings_popup(&mut self, ctx: &egui::Context) {
+        let Some(draft) = self.project_settings_popup.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut should_apply = false;
+        let mut should_close = false;
+
+        egui::Window::new("Project Settings")
+            .id(egui::Id::new("project_settings_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Project name:");
+                    let response = ui.add(
+                        egui::TextEdit::singleline(draft)
+                            .hint_text("Untitled")
+                            .desired_width(f32::INFINITY),
+                    );
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        should_apply = true;
+                        should_close = true;
+                    }
+                });
+                ui.label("Used for the window title and default export filenames.");
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Apply").clicked() {
+                        should_apply = true;
+                        should_close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_apply {
+            let name = draft.trim().to_string();
+            if name != self.project.project_name {
+                self.project.project_name = name;
+                self.update_dirty_status(true, ctx);
+                self.update_title(ctx);
+            }
+        }
+
+        if !open || should_close {
+            self.project_settings_popup = None;
+        }
+    }
+}
@@ -11,7 +11,7 @@ impl DecryptionApp {
             let title = create_popup_title(
                 "Remove Formation Rule: ",
                 &dialog.formatted_word,
-                self.project.font_path.is_some(),
+                self.project.has_custom_font(),
             );
 
             egui::Window::new(title)
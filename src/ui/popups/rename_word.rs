@@ -0,0 +1,113 @@
+use eframe::egui;
+
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::project::operations::RenameOutcome;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_rename_word_popup(&mut self, ctx: &egui::Context) {
+        let Some(mut dialog) = self.rename_word_popup.take() else {
+            return;
+        };
+
+        let word_font = if self.project.has_custom_font() {
+            egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
+        } else {
+            egui::FontFamily::Proportional
+        };
+        let (occurrences, _) = self
+            .lookup_cache
+            .token_frequency(&self.lookup_map_key(&dialog.word));
+
+        let mut open = true;
+        let mut should_rename = false;
+        let mut should_merge = false;
+        let mut should_close = false;
+
+        egui::Window::new("Rename Word")
+            .id(egui::Id::new("rename_word_popup"))
+            .open(&mut open)
+            .default_width(400.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Rename");
+                    ui.label(
+                        egui::RichText::new(&dialog.word)
+                            .family(word_font.clone())
+                            .strong(),
+                    );
+                    ui.label(format!("({occurrences} occurrence(s)) to:"));
+                });
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut dialog.new_word)
+                        .font(egui::FontId::new(
+                            egui::TextStyle::Body.resolve(ui.style()).size,
+                            word_font.clone(),
+                        ))
+                        .desired_width(f32::INFINITY),
+                );
+                if response.changed() {
+                    dialog.confirm_merge = false;
+                }
+                let submitted =
+                    response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+                if dialog.confirm_merge {
+                    ui.colored_label(
+                        egui::Color32::RED,
+                        format!(
+                            "'{}' already exists. Merge '{}' into it? \
+                             Its gloss, comment, and category are kept where set.",
+                            dialog.new_word.trim(),
+                            dialog.word
+                        ),
+                    );
+                }
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    let valid =
+                        !dialog.new_word.trim().is_empty() && dialog.new_word.trim() != dialog.word;
+                    if dialog.confirm_merge {
+                        if ui.add_enabled(valid, egui::Button::new("Merge")).clicked() {
+                            should_merge = true;
+                        }
+                    } else if ui.add_enabled(valid, egui::Button::new("Rename")).clicked()
+                        || (valid && submitted)
+                    {
+                        should_rename = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        let outcome = if should_merge {
+            self.project.merge_words(&dialog.word, &dialog.new_word)
+        } else if should_rename {
+            self.project.rename_word(&dialog.word, &dialog.new_word)
+        } else {
+            RenameOutcome::Unchanged
+        };
+        match outcome {
+            RenameOutcome::TargetExists => dialog.confirm_merge = true,
+            RenameOutcome::Renamed(_) | RenameOutcome::Merged(_) => {
+                if self.highlight_word.as_deref() == Some(dialog.word.as_str()) {
+                    self.highlight_word = None;
+                }
+                self.mark_segments_changed(ctx);
+                return;
+            }
+            RenameOutcome::Unchanged => {
+                if should_rename || should_merge {
+                    should_close = true;
+                }
+            }
+        }
+
+        if open && !should_close {
+            self.rename_word_popup = Some(dialog);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use eframe::egui;
+
+use crate::libs::text_analysis::segment_stats;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_segment_info_popup(&mut self, ctx: &egui::Context) {
+        let Some(seg_idx) = self.segment_info_popup else {
+            return;
+        };
+        let Some(segment) = self.project.segments.get(seg_idx) else {
+            self.segment_info_popup = None;
+            return;
+        };
+
+        let stats = segment_stats(segment, &self.project);
+        let mut open = true;
+
+        egui::Window::new(format!("Segment Info [{}]", seg_idx + 1))
+            .id(egui::Id::new("segment_info_popup"))
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("segment_info_grid")
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("Tokens");
+                        ui.label(stats.token_count.to_string());
+                        ui.end_row();
+
+                        ui.label("Unique tokens");
+                        ui.label(stats.unique_count.to_string());
+                        ui.end_row();
+
+                        ui.label("Type-token ratio")
+                            .on_hover_text("Unique tokens divided by tokens");
+                        ui.label(format!("{:.3}", stats.type_token_ratio()));
+                        ui.end_row();
+
+                        ui.label("Translated tokens");
+                        ui.label(format!(
+                            "{} ({:.0}%)",
+                            stats.translated_count,
+                            stats.translated_ratio() * 100.0
+                        ));
+                        ui.end_row();
+                    });
+            });
+
+        if !open {
+            self.segment_info_popup = None;
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use eframe::egui;
 
 use crate::consts::ui::{POPUP_SIMILAR_HEIGHT, POPUP_WIDTH};
-use crate::enums::{PinnedPopup, PopupRequest};
+use crate::enums::{PinnedPopup, PopupRequest, ScoreDisplay, SimilarityMetric};
 use crate::ui;
 use crate::ui::popup_utils::{create_pinned_title_string, create_popup_title};
 use crate::ui::states::state::DecryptionApp;
@@ -14,6 +14,8 @@ impl DecryptionApp {
     ) {
         let mut should_close = false;
         let mut should_pin = false;
+        let mut score_display = None;
+        let mut metric = self.similarity_metric;
 
         if let Some((target_idx, scores)) = self.similar_popup.as_ref() {
             let mut open = true;
@@ -25,13 +27,43 @@ impl DecryptionApp {
                 .default_width(POPUP_WIDTH)
                 .default_height(POPUP_SIMILAR_HEIGHT)
                 .show(ctx, |ui| {
+                    let pending = self.similar_pending == Some(*target_idx);
                     ui.horizontal(|ui| {
-                        if ui.button("📌 Pin").clicked() {
+                        if ui
+                            .add_enabled(!pending, egui::Button::new("📌 Pin"))
+                            .clicked()
+                        {
                             should_pin = true;
                         }
+                        ui.separator();
+                        ui.label("Metric:");
+                        for option in SimilarityMetric::METRICS {
+                            ui.selectable_value(&mut metric, option, option.display_text())
+                                .on_hover_text(option.hover_text());
+                        }
+                        ui.separator();
+                        ui.label("Scores:");
+                        for mode in [ScoreDisplay::Raw, ScoreDisplay::Relative] {
+                            if ui
+                                .selectable_label(
+                                    self.similarity_score_display == mode,
+                                    mode.display_text(),
+                                )
+                                .clicked()
+                            {
+                                score_display = Some(mode);
+                            }
+                        }
                     });
                     ui.separator();
-                    self.render_similar_content(ui, scores, popup_request, None);
+                    if pending {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Computing similarities...");
+                        });
+                    } else {
+                        self.render_similar_content(ui, *target_idx, scores, popup_request, None);
+                    }
                 });
 
             if !open {
@@ -52,18 +84,34 @@ impl DecryptionApp {
             }
         }
 
+        if let Some(mode) = score_display {
+            self.similarity_score_display = mode;
+        }
+
         if should_close {
             self.similar_popup = None;
+            self.similar_pending = None;
+        } else if metric != self.similarity_metric {
+            self.similarity_metric = metric;
+            if let Some((target_idx, _)) = self.similar_popup {
+                self.compute_similar_segments(target_idx);
+            }
         }
     }
 
     pub(super) fn render_similar_content(
         &self,
         ui: &mut egui::Ui,
+        target_idx: usize,
         similar_indices: &[(usize, f64)],
         popup_request: &mut Option<PopupRequest>,
         popup_id: Option<u64>,
     ) {
+        let top_score = similar_indices
+            .iter()
+            .map(|(_, score)| *score)
+            .fold(0.0, f64::max);
+
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .show(ui, |ui| {
@@ -74,9 +122,9 @@ impl DecryptionApp {
                                 let mut label_resp = ui.add(
                                     egui::Label::new(
                                         egui::RichText::new(format!(
-                                            "[{}] (Score: {:.2})",
+                                            "[{}] (Score: {})",
                                             idx + 1,
-                                            score
+                                            self.similarity_score_display.format(*score, top_score)
                                         ))
                                         .strong(),
                                     )
@@ -93,6 +141,17 @@ impl DecryptionApp {
                                     *popup_request =
                                         Some(PopupRequest::SentenceMenu(*idx, cursor_pos));
                                 }
+                                if ui
+                                    .small_button("Compare")
+                                    .on_hover_text(format!(
+                                        "Show [{}] and [{}] with their differences highlighted",
+                                        target_idx + 1,
+                                        idx + 1
+                                    ))
+                                    .clicked()
+                                {
+                                    *popup_request = Some(PopupRequest::Compare(target_idx, *idx));
+                                }
                             });
 
                             let scroll_id = match popup_id {
@@ -109,8 +168,10 @@ impl DecryptionApp {
                                         &self.project.vocabulary,
                                         &self.project.vocabulary_comments,
                                         &self.project.formatted_word_comments,
+                                        self.normalized_vocabulary(),
                                         None,
-                                        self.project.font_path.is_some(),
+                                        self.highlight_word.as_deref(),
+                                        self.project.has_custom_font(),
                                         &self.project.formation_rules,
                                     ) {
                                         self.handle_ui_action(ui, action, popup_request, *idx);
@@ -2,6 +2,7 @@ use eframe::egui;
 
 use crate::consts::ui::{POPUP_SIMILAR_HEIGHT, POPUP_WIDTH};
 use crate::enums::PopupRequest;
+use crate::io::DEFAULT_SENTENCE_FONT;
 use crate::libs::similarity_token::SimilarToken;
 use crate::ui::popup_utils::create_popup_title;
 use crate::ui::states::state::DecryptionApp;
@@ -19,7 +20,7 @@ impl DecryptionApp {
             let title = create_popup_title(
                 "Similar tokens: ",
                 target_word,
-                self.project.font_path.is_some(),
+                self.project.has_custom_font(),
             );
             egui::Window::new(title)
                 .id(egui::Id::new("similar_tokens_popup"))
@@ -50,9 +51,9 @@ impl DecryptionApp {
     ) {
         let custom_font_id = egui::FontId {
             size: egui::TextStyle::Body.resolve(ui.style()).size,
-            family: egui::FontFamily::Name("SentenceFont".into()),
+            family: egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
         };
-        let has_custom_font = self.project.font_path.is_some();
+        let has_custom_font = self.project.has_custom_font();
 
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
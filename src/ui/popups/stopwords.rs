@@ -0,0 +1,62 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::libs::similarity_sentence::parse_stopwords;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_stopwords_popup(&mut self, ctx: &egui::Context) {
+        let Some(draft) = self.stopwords_popup.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut should_apply = false;
+        let mut should_close = false;
+
+        egui::Window::new("Stopwords")
+            .id(egui::Id::new("stopwords_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                ui.label(
+                    "Words excluded from similarity search, separated by spaces or new lines.",
+                );
+                ui.separator();
+                egui::ScrollArea::vertical()
+                    .max_height(POPUP_REFERENCE_HEIGHT - 100.0)
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(draft)
+                                .desired_width(f32::INFINITY)
+                                .desired_rows(10),
+                        );
+                    });
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Apply").clicked() {
+                        should_apply = true;
+                        should_close = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        should_close = true;
+                    }
+                });
+            });
+
+        if should_apply {
+            let stopwords = parse_stopwords(draft);
+            if stopwords != self.project.stopwords {
+                self.project.stopwords = stopwords;
+                self.tfidf_dirty = true;
+                self.tfidf_cache.invalidate();
+                self.update_dirty_status(true, ctx);
+            }
+        }
+
+        if !open || should_close {
+            self.stopwords_popup = None;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use eframe::egui;
+
+use crate::libs::project::operations::parse_tags;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_update_tags_popup(&mut self, ctx: &egui::Context) {
+        if let Some(mut dialog) = self.update_tags_popup.take() {
+            let mut open = true;
+
+            let mut should_close = false;
+            egui::Window::new("Edit Segment Tags")
+                .id(egui::Id::new("update_tags_popup"))
+                .open(&mut open)
+                .default_width(400.0)
+                .show(ctx, |ui| {
+                    ui.label("Tags (separated by commas or spaces):");
+                    ui.text_edit_singleline(&mut dialog.tags);
+                    ui.label(
+                        egui::RichText::new("Filter by a tag with tag:name in the search box.")
+                            .weak(),
+                    );
+
+                    ui.separator();
+
+                    ui.horizontal(|ui| {
+                        if ui.button("Save").clicked() {
+                            if let Some(segment) = self.project.segments.get_mut(dialog.segment_idx)
+                            {
+                                let tags = parse_tags(&dialog.tags);
+                                if tags != segment.tags {
+                                    segment.tags = tags;
+                                    self.filter_dirty = true;
+                                    self.update_dirty_status(true, ctx);
+                                }
+                            }
+                            should_close = true;
+                        }
+                        if ui.button("Cancel").clicked() {
+                            should_close = true;
+                        }
+                    });
+                });
+
+            if open && !should_close {
+                self.update_tags_popup = Some(dialog);
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use eframe::egui;
+
+use crate::consts::colors::CATEGORY_PALETTE;
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_word_categories_popup(&mut self, ctx: &egui::Context) {
+        let Some(mut draft) = self.word_categories_popup.take() else {
+            return;
+        };
+
+        let mut open = true;
+        let mut changed = false;
+        let mut removed = None;
+        let mut add_requested = false;
+        let counts: Vec<(String, usize)> = self
+            .project
+            .category_word_counts()
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+
+        egui::Window::new("Word Categories")
+            .id(egui::Id::new("word_categories_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                ui.label("Tokens are tinted by their word's category. Assign categories from the word menu.");
+                ui.separator();
+
+                if self.project.category_colors.is_empty() {
+                    ui.label(egui::RichText::new("No categories yet.").weak());
+                }
+                egui::Grid::new("word_categories_grid")
+                    .striped(true)
+                    .num_columns(4)
+                    .show(ui, |ui| {
+                        for (name, color) in &mut self.project.category_colors {
+                            changed |= ui.color_edit_button_srgb(color).changed();
+                            ui.label(
+                                egui::RichText::new(name.as_str())
+                                    .color(egui::Color32::from_rgb(color[0], color[1], color[2])),
+                            );
+                            let count = counts
+                                .iter()
+                                .find(|(category, _)| category == name)
+                                .map_or(0, |(_, count)| *count);
+                            ui.label(format!("{count} word(s)"));
+                            if ui.button("Remove").clicked() {
+                                removed = Some(name.clone());
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut draft).hint_text("New category"),
+                    );
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        add_requested = true;
+                    }
+                    if ui
+                        .add_enabled(!draft.trim().is_empty(), egui::Button::new("Add"))
+                        .clicked()
+                    {
+                        add_requested = true;
+                    }
+                });
+            });
+
+        if add_requested {
+            let color =
+                CATEGORY_PALETTE[self.project.category_colors.len() % CATEGORY_PALETTE.len()];
+            if self.project.add_category(&draft, color) {
+                draft.clear();
+                changed = true;
+            }
+        }
+        if let Some(name) = removed {
+            self.project.remove_category(&name);
+            changed = true;
+        }
+        if changed {
+            self.update_dirty_status(true, ctx);
+        }
+
+        if open {
+            self.word_categories_popup = Some(draft);
+        }
+    }
+}
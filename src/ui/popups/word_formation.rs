@@ -2,7 +2,9 @@ use eframe::egui;
 
 use crate::consts::ui::WORD_FORMATION_SCRIPT_ROWS;
 use crate::enums::FormationType;
-use crate::ui::popup_utils::create_popup_title;
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::eval::is_noop_result;
+use crate::ui::popup_utils::{create_popup_title, render_script_helpers};
 use crate::ui::states::state::DecryptionApp;
 
 impl DecryptionApp {
@@ -12,7 +14,7 @@ impl DecryptionApp {
             let title = create_popup_title(
                 "Set Formation Rule: ",
                 &dialog.selected_word,
-                self.project.font_path.is_some(),
+                self.project.has_custom_font(),
             );
 
             let mut should_keep = false;
@@ -23,9 +25,9 @@ impl DecryptionApp {
                 .show(ctx, |ui| {
                     let custom_font_id = egui::FontId {
                         size: egui::TextStyle::Body.resolve(ui.style()).size,
-                        family: egui::FontFamily::Name("SentenceFont".into()),
+                        family: egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
                     };
-                    let has_custom_font = self.project.font_path.is_some();
+                    let has_custom_font = self.project.has_custom_font();
 
                     if dialog.preview.is_empty()
                         && let (Some(rule_idx), false) =
@@ -92,14 +94,7 @@ impl DecryptionApp {
                     let selected_text = dialog
                         .selected_rule
                         .and_then(|idx| self.project.formation_rules.get(idx))
-                        .map(|rule| {
-                            let type_prefix = match rule.rule_type {
-                                FormationType::Derivation => "[D]",
-                                FormationType::Inflection => "[I]",
-                                FormationType::Nonmorphological => "[N]",
-                            };
-                            format!("{} {}", type_prefix, rule.description)
-                        })
+                        .map(|rule| format!("{} {}", rule.rule_type.badge(), rule.description))
                         .unwrap_or_default();
 
                     let combo_id = egui::Id::new("formation_rule_combo");
@@ -129,15 +124,15 @@ impl DecryptionApp {
                                     any_visible = true;
                                     let is_selected = dialog.selected_rule == Some(rule_idx);
 
-                                    let type_prefix = match rule.rule_type {
-                                        FormationType::Derivation => "[D]",
-                                        FormationType::Inflection => "[I]",
-                                        FormationType::Nonmorphological => "[N]",
-                                    };
                                     let display_text =
-                                        format!("{} {}", type_prefix, rule.description);
+                                        format!("{} {}", rule.rule_type.badge(), rule.description);
 
-                                    if ui.selectable_label(is_selected, display_text).clicked() {
+                                    let mut response =
+                                        ui.selectable_label(is_selected, display_text);
+                                    if !rule.note.is_empty() {
+                                        response = response.on_hover_text(&rule.note);
+                                    }
+                                    if response.clicked() {
                                         dialog.selected_rule = Some(rule_idx);
                                         if !dialog.base_word.is_empty() {
                                             dialog.preview = rule
@@ -230,6 +225,12 @@ impl DecryptionApp {
                         } else {
                             ui.colored_label(egui::Color32::GREEN, "Preview matches selected word");
                         }
+                        if is_noop_result(&dialog.base_word, &dialog.preview) {
+                            ui.colored_label(
+                                egui::Color32::ORANGE,
+                                "⚠ This rule leaves the base word unchanged; check that it is the right rule for this word",
+                            );
+                        }
 
                         ui.separator();
 
@@ -268,7 +269,15 @@ impl DecryptionApp {
                                     base_rule_chain = chain;
                                 }
 
-                                if base_word_exists
+                                if self
+                                    .project
+                                    .derivation_cycles(&dialog.preview, &base_word_for_lookup)
+                                {
+                                    self.error_message = Some(format!(
+                                        "'{}' cannot be derived from '{}': its base word would resolve back to itself.",
+                                        dialog.preview, dialog.base_word
+                                    ));
+                                } else if base_word_exists
                                     && self.project.vocabulary.contains_key(&base_word_for_lookup)
                                 {
                                     let original_word = dialog.selected_word.clone();
@@ -322,9 +331,9 @@ impl DecryptionApp {
                 .show(ctx, |ui| {
                     let custom_font_id = egui::FontId {
                         size: egui::TextStyle::Body.resolve(ui.style()).size,
-                        family: egui::FontFamily::Name("SentenceFont".into()),
+                        family: egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into()),
                     };
-                    let has_custom_font = self.project.font_path.is_some();
+                    let has_custom_font = self.project.has_custom_font();
 
                     ui.label("Description:");
                     ui.text_edit_singleline(&mut dialog.description);
@@ -362,6 +371,16 @@ impl DecryptionApp {
                             .desired_rows(WORD_FORMATION_SCRIPT_ROWS)
                             .desired_width(f32::INFINITY),
                     );
+                    render_script_helpers(ui);
+
+                    ui.separator();
+                    ui.label("Note (optional):");
+                    ui.add(
+                        egui::TextEdit::multiline(&mut dialog.note)
+                            .hint_text("Rationale or linguistic source")
+                            .desired_rows(2)
+                            .desired_width(f32::INFINITY),
+                    );
 
                     ui.separator();
                     ui.label("Test Word:");
@@ -381,7 +400,9 @@ impl DecryptionApp {
                                 dialog.command, dialog.test_word
                             ))
                         });
-                        dialog.preview = result.unwrap_or_else(|e| format!("Error: {e}"));
+                        dialog.preview = result.unwrap_or_else(|e| {
+                            format!("Error: {}", crate::libs::eval::eval_error("Transform", &e))
+                        });
                     }
 
                     if !dialog.test_word.is_empty() && !dialog.preview.is_empty() {
@@ -396,6 +417,12 @@ impl DecryptionApp {
                             };
                             ui.label(preview_text);
                         });
+                        if is_noop_result(&dialog.test_word, &dialog.preview) {
+                            ui.colored_label(
+                                egui::Color32::ORANGE,
+                                "⚠ The rule leaves the test word unchanged",
+                            );
+                        }
                     }
 
                     ui.separator();
@@ -413,8 +440,10 @@ impl DecryptionApp {
                                 description: dialog.description.clone(),
                                 rule_type: dialog.rule_type,
                                 command: dialog.command.clone(),
+                                note: dialog.note.trim().to_string(),
                                 cached_ast: crate::libs::eval::default_cached_ast(),
                             });
+                        self.change_summary.record_rule_added();
                         self.update_dirty_status(true, ctx);
                         should_close = true;
                     }
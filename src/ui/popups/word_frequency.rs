@@ -0,0 +1,100 @@
+use eframe::egui;
+
+use crate::consts::ui::{POPUP_REFERENCE_HEIGHT, POPUP_WIDTH};
+use crate::enums::PopupRequest;
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::ui::states::state::DecryptionApp;
+
+impl DecryptionApp {
+    pub(super) fn render_word_frequency_popup(
+        &mut self,
+        ctx: &egui::Context,
+        popup_request: &mut Option<PopupRequest>,
+    ) {
+        let use_custom_font = self.project.has_custom_font();
+        let stopwords = &self.project.stopwords;
+        let Some(dialog) = self.word_frequency_popup.as_mut() else {
+            return;
+        };
+
+        let mut open = true;
+        let word_font = if use_custom_font {
+            egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
+        } else {
+            egui::FontFamily::Proportional
+        };
+
+        egui::Window::new("Word Frequencies")
+            .id(egui::Id::new("word_frequency_popup"))
+            .open(&mut open)
+            .default_width(POPUP_WIDTH)
+            .default_height(POPUP_REFERENCE_HEIGHT)
+            .show(ctx, |ui| {
+                let glossed = dialog.rows.iter().filter(|row| row.glossed).count();
+                ui.label(format!(
+                    "{} unique word(s), {glossed} glossed",
+                    dialog.rows.len()
+                ));
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut dialog.unglossed_only, "Only unglossed words");
+                    ui.add_enabled(
+                        !stopwords.is_empty(),
+                        egui::Checkbox::new(&mut dialog.hide_stopwords, "Hide stopwords"),
+                    );
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        egui::Grid::new("word_frequency_grid")
+                            .striped(true)
+                            .num_columns(3)
+                            .show(ui, |ui| {
+                                ui.strong("Word");
+                                let arrow = if dialog.descending { "⏷" } else { "⏶" };
+                                if ui.button(format!("Count {arrow}")).clicked() {
+                                    dialog.descending = !dialog.descending;
+                                    dialog.rows.reverse();
+                                }
+                                ui.strong("Glossed");
+                                ui.end_row();
+
+                                for row in dialog
+                                    .rows
+                                    .iter()
+                                    .filter(|row| !dialog.unglossed_only || !row.glossed)
+                                    .filter(|row| {
+                                        !dialog.hide_stopwords || !stopwords.contains(&row.word)
+                                    })
+                                {
+                                    let font_id = egui::FontId::new(
+                                        egui::TextStyle::Body.resolve(ui.style()).size,
+                                        word_font.clone(),
+                                    );
+                                    if ui
+                                        .add(
+                                            egui::Label::new(
+                                                egui::RichText::new(&row.word).font(font_id),
+                                            )
+                                            .sense(egui::Sense::click()),
+                                        )
+                                        .on_hover_text("Filter segments by this word")
+                                        .clicked()
+                                    {
+                                        *popup_request =
+                                            Some(PopupRequest::Filter(row.word.clone()));
+                                    }
+                                    ui.label(row.count.to_string());
+                                    ui.label(if row.glossed { "✔" } else { "" });
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+
+        if !open {
+            self.word_frequency_popup = None;
+        }
+    }
+}
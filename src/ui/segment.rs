@@ -1,23 +1,28 @@
 use crate::libs::eval::FormationRule;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
 use eframe::egui;
 
 use crate::consts::{
     colors::{
-        FONT_DARK, FONT_LIGHT, GLOSSBOX, GLOSSBOX_BYFORMATION, HIGHLIGHT_BG, HIGHLIGHT_FG,
-        SENTENCEBOX,
+        FONT_DARK, FONT_LIGHT, GLOSSBOX, GLOSSBOX_BYFORMATION, GLOSSBOX_MISSING, GLOSSBOX_NO_GLOSS,
+        HIGHLIGHT_BG, HIGHLIGHT_FG, PHRASEBRACKET, SENTENCEBOX, TAG_CHIP,
     },
     ui::{
         BOX_STROKE_WIDTH, GLOSS_BOX_EXTRA_WIDTH, GLOSS_BOX_INNER_MARGIN, GLOSS_BOX_LAYOUT_EXTRA,
-        GLOSS_BOX_MIN_WIDTH, GLOSS_BOX_ROUNDING, GLOSS_FONT_SIZE, SEGMENT_SPACING_X,
-        SEGMENT_VERTICAL_SPACING, TOKEN_FONT_SIZE, TOKEN_SPACING_X, TOKEN_SPACING_Y,
-        TRANSLATION_BOX_INNER_MARGIN, TRANSLATION_BOX_ROUNDING, TRANSLATION_BOX_ROWS,
-        TRANSLATION_BOX_STROKE_WIDTH,
+        GLOSS_BOX_MAX_WIDTH, GLOSS_BOX_MIN_WIDTH, GLOSS_BOX_ROUNDING, GLOSS_FONT_SIZE,
+        PHRASE_BRACKET_HEIGHT, RULE_BADGE_FONT_SIZE, RULE_BADGE_INSET, SEGMENT_SPACING_X,
+        SEGMENT_VERTICAL_SPACING, TAG_CHIP_INNER_MARGIN, TAG_CHIP_ROUNDING, TAG_FONT_SIZE,
+        TOKEN_FONT_SIZE, TOKEN_SPACING_X, TOKEN_SPACING_Y, TRANSLATION_BOX_INNER_MARGIN,
+        TRANSLATION_BOX_ROUNDING, TRANSLATION_BOX_ROWS, TRANSLATION_BOX_STROKE_WIDTH,
     },
 };
-use crate::enums::UiAction;
+use crate::enums::{TextDirection, UiAction};
+use crate::io::DEFAULT_SENTENCE_FONT;
+use crate::libs::normalization::{NormalizedIndex, resolve_vocabulary_key, suggest_gloss_key};
+use crate::libs::project::operations::category_color;
+use crate::libs::similarity_sentence::TranslationSuggestion;
 use crate::libs::{Segment, Token};
 use crate::ui::highlight::create_highlighted_layout;
 
@@ -28,14 +33,16 @@ pub fn render_clickable_tokens(
     vocabulary: &HashMap<String, String>,
     vocabulary_comments: &HashMap<String, String>,
     formatted_word_comments: &HashMap<String, String>,
+    normalized_index: Option<&NormalizedIndex>,
     highlight_token: Option<&str>,
+    highlight_word: Option<&str>,
     use_custom_font: bool,
     formation_rules: &[FormationRule],
 ) -> Option<UiAction> {
     let mut clicked_action = None;
 
     let font_family = if use_custom_font {
-        egui::FontFamily::Name("SentenceFont".into())
+        egui::FontFamily::Name(DEFAULT_SENTENCE_FONT.into())
     } else {
         egui::FontFamily::Proportional
     };
@@ -50,13 +57,15 @@ pub fn render_clickable_tokens(
         ui.spacing_mut().item_spacing.x = TOKEN_SPACING_X;
         ui.spacing_mut().item_spacing.y = TOKEN_SPACING_Y;
         for (word_idx, token) in tokens.iter().enumerate() {
-            let is_highlighted = highlight_token.is_some_and(|h| h == token.original);
+            let is_highlighted = highlight_token.is_some_and(|h| h == token.original)
+                || highlight_word.is_some_and(|h| h == token.original);
             let text = &token.original;
 
             let base_word = token.base_word.as_ref().unwrap_or(text);
-            let base_gloss = vocabulary.get(base_word).map(|s| s.as_str()).unwrap_or("");
+            let vocab_key = resolve_vocabulary_key(vocabulary, normalized_index, base_word);
+            let base_gloss = vocabulary.get(vocab_key).map(|s| s.as_str()).unwrap_or("");
             let base_comment = vocabulary_comments
-                .get(base_word)
+                .get(vocab_key)
                 .map(|s| s.as_str())
                 .unwrap_or("");
             let formatted_comment = if !token.formation_rule_indices.is_empty() {
@@ -134,6 +143,72 @@ pub fn render_clickable_tokens(
     clicked_action
 }
 
+/// Id scope of a segment's horizontal token scroll area.
+/// Derived only from the segment's index in the project, independent of the page or row
+/// it is drawn in, so egui restores the scroll offset when the segment is shown again.
+#[must_use]
+pub fn segment_scroll_id(seg_idx: usize) -> egui::Id {
+    egui::Id::new(("segment_scroll", seg_idx))
+}
+
+/// Width of a token column holding a token and gloss of the given measured widths.
+/// Clamped between `GLOSS_BOX_MIN_WIDTH` and `GLOSS_BOX_MAX_WIDTH`, so a malformed import
+/// that yields a paragraph-long token cannot blow out the segment layout.
+#[must_use]
+pub fn token_column_width(original_width: f32, gloss_width: f32) -> f32 {
+    (original_width.max(gloss_width) + GLOSS_BOX_EXTRA_WIDTH)
+        .clamp(GLOSS_BOX_MIN_WIDTH, GLOSS_BOX_MAX_WIDTH)
+}
+
+/// Segments folded down to their number and translation in the segment list.
+/// Pure view state keyed by segment index; it is never saved with the project.
+#[derive(Debug, Clone, Default)]
+pub struct CollapsedSegments {
+    collapsed: HashSet<usize>,
+}
+
+impl CollapsedSegments {
+    /// Whether the segment at `seg_idx` is folded
+    #[must_use]
+    pub fn is_collapsed(&self, seg_idx: usize) -> bool {
+        self.collapsed.contains(&seg_idx)
+    }
+
+    /// Fold the segment if it is expanded, or expand it if it is folded
+    pub fn toggle(&mut self, seg_idx: usize) {
+        if !self.collapsed.remove(&seg_idx) {
+            self.collapsed.insert(seg_idx);
+        }
+    }
+
+    /// Fold every one of `segment_count` segments
+    pub fn collapse_all(&mut self, segment_count: usize) {
+        self.collapsed.extend(0..segment_count);
+    }
+
+    /// Expand every segment
+    pub fn expand_all(&mut self) {
+        self.collapsed.clear();
+    }
+
+    /// Whether no segment is folded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.collapsed.is_empty()
+    }
+
+    /// Follow the removal of segment `seg_idx`: forget its state and shift later
+    /// segments down by one
+    pub fn segment_removed(&mut self, seg_idx: usize) {
+        self.collapsed = self
+            .collapsed
+            .drain()
+            .filter(|&idx| idx != seg_idx)
+            .map(|idx| if idx > seg_idx { idx - 1 } else { idx })
+            .collect();
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn render_segment(
     ui: &mut egui::Ui,
@@ -141,63 +216,180 @@ pub fn render_segment(
     vocabulary: &mut HashMap<String, String>,
     vocabulary_comments: &HashMap<String, String>,
     formatted_word_comments: &HashMap<String, String>,
+    vocabulary_categories: &HashMap<String, String>,
+    category_colors: &BTreeMap<String, [u8; 3]>,
+    no_gloss_words: &BTreeSet<String>,
+    normalized_index: &NormalizedIndex,
+    normalize_lookups: bool,
     seg_num: usize,
     highlight: Option<&str>,
-    use_custom_font: bool,
+    highlight_word: Option<&str>,
+    font_family: Option<&str>,
     formation_rules: &[FormationRule],
+    text_direction: TextDirection,
+    selected_token: Option<usize>,
+    focus_token: Option<usize>,
+    translation_suggestions: Option<&[TranslationSuggestion]>,
+    collapsed: bool,
 ) -> UiAction {
     let mut action = UiAction::None;
     ui.group(|ui| {
-        let title = egui::RichText::new(format!("[{seg_num}]")).weak();
-        let mut title_resp = ui.add(egui::Label::new(title).sense(egui::Sense::click()));
+        let lock_mark = if segment.locked { " 🔒" } else { "" };
+        let review_mark = if segment.reviewed { " ✔" } else { "" };
+        let title = egui::RichText::new(format!("[{seg_num}]{lock_mark}{review_mark}")).weak();
+        ui.horizontal(|ui| {
+            let (arrow, hint) = if collapsed {
+                ("⏵", "Expand segment")
+            } else {
+                ("⏷", "Collapse segment")
+            };
+            if ui
+                .add(egui::Button::new(arrow).small().frame(false))
+                .on_hover_text(hint)
+                .clicked()
+            {
+                action = UiAction::ToggleCollapsed;
+            }
 
-        if !segment.comment.is_empty() {
-            title_resp = title_resp.on_hover_text(&segment.comment);
-        }
+            let mut title_resp = ui.add(egui::Label::new(title).sense(egui::Sense::click()));
 
-        if title_resp.secondary_clicked() {
-            action = UiAction::ShowSentenceMenu(seg_num - 1);
-        }
+            if !segment.comment.is_empty() {
+                title_resp = title_resp.on_hover_text(&segment.comment);
+            }
 
-        egui::ScrollArea::horizontal()
-            .id_salt(seg_num)
-            .show(ui, |ui| {
-                ui.horizontal_top(|ui| {
-                    ui.spacing_mut().item_spacing.x = SEGMENT_SPACING_X;
-                    for (word_idx, token) in segment.tokens.iter_mut().enumerate() {
-                        let token_action = render_token_column(
-                            ui,
-                            token,
-                            vocabulary,
-                            vocabulary_comments,
-                            formatted_word_comments,
-                            highlight,
-                            use_custom_font,
-                            word_idx,
-                            formation_rules,
-                        );
+            if title_resp.secondary_clicked() {
+                action = UiAction::ShowSentenceMenu(seg_num - 1);
+            }
 
-                        match token_action {
-                            UiAction::Changed => action = UiAction::Changed,
-                            UiAction::Filter(_) => action = token_action,
-                            UiAction::ShowSimilar(_) => action = token_action,
-                            UiAction::ShowDefinition(_) => action = token_action,
-                            UiAction::ShowReference(_) => action = token_action,
-                            UiAction::ShowSentenceMenu(_) => action = token_action,
-                            UiAction::ShowWordMenu(word, _) => {
-                                action = UiAction::ShowWordMenu(word, word_idx);
+            for tag in &segment.tags {
+                render_tag_chip(ui, tag);
+            }
+
+            if collapsed {
+                let preview = match segment.translation.lines().find(|l| !l.trim().is_empty()) {
+                    Some(line) => egui::RichText::new(line.trim()),
+                    None => egui::RichText::new("(untranslated)").weak().italics(),
+                };
+                ui.add(egui::Label::new(preview).truncate());
+            }
+        });
+
+        if collapsed {
+            return;
+        }
+
+        ui.scope_builder(
+            egui::UiBuilder::new().id(segment_scroll_id(seg_num - 1)),
+            |ui| {
+                egui::ScrollArea::horizontal().show(ui, |ui| {
+                    ui.horizontal_top(|ui| {
+                        ui.spacing_mut().item_spacing.x = SEGMENT_SPACING_X;
+                        let Segment {
+                            tokens,
+                            phrases,
+                            locked,
+                            ..
+                        } = &mut *segment;
+                        let editable = !*locked;
+                        let order = text_direction.display_order(tokens.len());
+                        let mut edited_phrase = None;
+                        let mut render_column = |ui: &mut egui::Ui, word_idx: usize| {
+                            let token_action = render_token_column(
+                                ui,
+                                &mut tokens[word_idx],
+                                vocabulary,
+                                vocabulary_comments,
+                                formatted_word_comments,
+                                vocabulary_categories,
+                                category_colors,
+                                no_gloss_words,
+                                normalized_index,
+                                normalize_lookups,
+                                highlight,
+                                highlight_word,
+                                font_family,
+                                word_idx,
+                                formation_rules,
+                                selected_token == Some(word_idx),
+                                focus_token == Some(word_idx),
+                                editable,
+                            );
+
+                            match token_action {
+                                UiAction::GlossChanged(_) => action = token_action,
+                                UiAction::TranslationChanged => action = token_action,
+                                UiAction::Filter(_) => action = token_action,
+                                UiAction::ShowSimilar(_) => action = token_action,
+                                UiAction::ShowDefinition(_) => action = token_action,
+                                UiAction::ShowReference(_) => action = token_action,
+                                UiAction::ShowSentenceMenu(_) => action = token_action,
+                                UiAction::GlossSubmitted(_) => action = token_action,
+                                UiAction::SelectToken(_) if editable => action = token_action,
+                                UiAction::SelectToken(_) => {}
+                                UiAction::EditPhrase(_) => action = token_action,
+                                UiAction::ToggleCollapsed => {}
+                                UiAction::ShowWordMenu(word, _) => {
+                                    action = UiAction::ShowWordMenu(word, word_idx);
+                                }
+                                UiAction::None => {}
                             }
-                            UiAction::None => {}
+                        };
+
+                        let mut pos = 0;
+                        while pos < order.len() {
+                            let Some(phrase) =
+                                phrases.iter().find(|phrase| phrase.contains(order[pos]))
+                            else {
+                                render_column(ui, order[pos]);
+                                pos += 1;
+                                continue;
+                            };
+
+                            // A phrase is contiguous in either reading direction,
+                            // so its tokens form one run of the display order
+                            let run = order[pos..]
+                                .iter()
+                                .take_while(|&&idx| phrase.contains(idx))
+                                .count();
+                            ui.vertical(|ui| {
+                                let row = ui
+                                    .horizontal_top(|ui| {
+                                        ui.spacing_mut().item_spacing.x = SEGMENT_SPACING_X;
+                                        for &word_idx in &order[pos..pos + run] {
+                                            render_column(ui, word_idx);
+                                        }
+                                    })
+                                    .response
+                                    .rect;
+                                if render_phrase_bracket(ui, &phrase.gloss, row.width()) {
+                                    edited_phrase = Some(phrase.start);
+                                }
+                            });
+                            pos += run;
                         }
-                    }
+
+                        if let Some(start) = edited_phrase.filter(|_| editable) {
+                            action = UiAction::EditPhrase(start);
+                        }
+                    });
                 });
-            });
+            },
+        );
 
         ui.add_space(SEGMENT_VERTICAL_SPACING);
 
-        let editbox_highlight = None;
-        if render_translation_box(ui, segment, editbox_highlight) && action == UiAction::None {
-            action = UiAction::Changed;
+        if render_translation_box(ui, segment, highlight, text_direction)
+            && action == UiAction::None
+        {
+            action = UiAction::TranslationChanged;
+        }
+
+        if let Some(suggestions) = translation_suggestions.filter(|s| !s.is_empty())
+            && segment.translation.trim().is_empty()
+            && render_translation_suggestions(ui, segment, suggestions, seg_num)
+            && action == UiAction::None
+        {
+            action = UiAction::TranslationChanged;
         }
     });
 
@@ -211,15 +403,27 @@ fn render_token_column(
     vocabulary: &mut HashMap<String, String>,
     vocabulary_comments: &HashMap<String, String>,
     formatted_word_comments: &HashMap<String, String>,
+    vocabulary_categories: &HashMap<String, String>,
+    category_colors: &BTreeMap<String, [u8; 3]>,
+    no_gloss_words: &BTreeSet<String>,
+    normalized_index: &NormalizedIndex,
+    normalize_lookups: bool,
     highlight: Option<&str>,
-    use_custom_font: bool,
+    highlight_word: Option<&str>,
+    font_family: Option<&str>,
     word_idx: usize,
     formation_rules: &[FormationRule],
+    selected: bool,
+    focus: bool,
+    editable: bool,
 ) -> UiAction {
     let base_word = token.base_word.as_ref().unwrap_or(&token.original);
-    let base_gloss = vocabulary.get(base_word).cloned().unwrap_or_default();
+    let lookup_index = normalize_lookups.then_some(normalized_index);
+    let vocab_key = resolve_vocabulary_key(vocabulary, lookup_index, base_word).to_string();
+    let no_gloss = no_gloss_words.contains(&vocab_key);
+    let base_gloss = vocabulary.get(&vocab_key).cloned().unwrap_or_default();
     let base_comment = vocabulary_comments
-        .get(base_word)
+        .get(&vocab_key)
         .cloned()
         .unwrap_or_default();
     let formatted_comment = if !token.formation_rule_indices.is_empty() {
@@ -255,10 +459,10 @@ fn render_token_column(
     };
 
     let default_font_id = egui::TextStyle::Body.resolve(ui.style());
-    let token_font_id = if use_custom_font {
+    let token_font_id = if let Some(family) = font_family {
         egui::FontId {
             size: TOKEN_FONT_SIZE,
-            family: egui::FontFamily::Name("SentenceFont".into()),
+            family: egui::FontFamily::Name(family.into()),
         }
     } else {
         egui::FontId {
@@ -286,7 +490,8 @@ fn render_token_column(
         .rect
         .width();
 
-    let width = (original_width.max(gloss_width) + GLOSS_BOX_EXTRA_WIDTH).max(GLOSS_BOX_MIN_WIDTH);
+    let width = token_column_width(original_width, gloss_width);
+    let truncated = original_width > width;
 
     let text_color = if ui.visuals().dark_mode {
         FONT_DARK
@@ -300,20 +505,22 @@ fn render_token_column(
         egui::vec2(width + GLOSS_BOX_LAYOUT_EXTRA, 0.0),
         egui::Layout::top_down(egui::Align::LEFT),
         |ui| {
-            let box_color = if gloss.is_empty() {
-                ui.visuals().text_color()
+            let box_color = if gloss.is_empty() && no_gloss {
+                GLOSSBOX_NO_GLOSS
+            } else if gloss.is_empty() {
+                GLOSSBOX_MISSING
             } else if has_rule {
                 GLOSSBOX_BYFORMATION
             } else {
                 GLOSSBOX
             };
 
-            egui::Frame::NONE
+            let gloss_box = egui::Frame::NONE
                 .stroke(egui::Stroke::new(BOX_STROKE_WIDTH, box_color))
                 .inner_margin(GLOSS_BOX_INNER_MARGIN)
                 .corner_radius(GLOSS_BOX_ROUNDING)
                 .show(ui, |ui| {
-                    if has_rule {
+                    if has_rule || !editable {
                         let label_resp = ui.add_sized(
                             egui::vec2(width, ui.text_style_height(&egui::TextStyle::Body)),
                             egui::Label::new(egui::RichText::new(&gloss).color(text_color))
@@ -324,7 +531,7 @@ fn render_token_column(
                             label_resp.on_hover_text(&comment);
                         }
                     } else {
-                        let lookup_word = base_word.clone();
+                        let lookup_word = vocab_key.clone();
                         let mut current_gloss =
                             vocabulary.get(&lookup_word).cloned().unwrap_or_default();
 
@@ -332,29 +539,81 @@ fn render_token_column(
                             egui::vec2(width, ui.text_style_height(&egui::TextStyle::Body)),
                             egui::TextEdit::singleline(&mut current_gloss)
                                 .text_color(text_color)
+                                .hint_text(if no_gloss { "no gloss" } else { "" })
                                 .frame(egui::Frame::NONE),
                         );
 
+                        if focus {
+                            edit_resp.request_focus();
+                            edit_resp.scroll_to_me(Some(egui::Align::Center));
+                        }
+                        let suggestion =
+                            suggest_gloss_key(vocabulary, normalized_index, &lookup_word)
+                                .and_then(|key| Some((key, vocabulary.get(key)?.clone())));
                         if edit_resp.changed() {
-                            vocabulary.insert(lookup_word, current_gloss);
-                            action = UiAction::Changed;
+                            action = UiAction::GlossChanged(lookup_word.as_str().into());
+                            vocabulary.insert(lookup_word.clone(), current_gloss);
+                        } else if edit_resp.lost_focus()
+                            && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                        {
+                            action = UiAction::GlossSubmitted(word_idx);
                         }
 
                         if !comment.is_empty() {
                             edit_resp.on_hover_text(&comment);
                         }
+
+                        if let Some((key, suggested)) = suggestion
+                            && ui
+                                .add(
+                                    egui::Label::new(
+                                        egui::RichText::new(format!("⤷ {suggested}"))
+                                            .size(GLOSS_FONT_SIZE)
+                                            .weak()
+                                            .italics(),
+                                    )
+                                    .truncate()
+                                    .sense(egui::Sense::click()),
+                                )
+                                .on_hover_text(format!("Use the gloss of '{key}'"))
+                                .clicked()
+                        {
+                            action = UiAction::GlossChanged(lookup_word.as_str().into());
+                            vocabulary.insert(lookup_word, suggested);
+                        }
                     }
-                });
+                })
+                .response
+                .rect;
 
-            let layout_job =
-                create_highlighted_layout(&token.original, highlight, token_font_id, text_color);
+            if let Some(badge) = rule_type_badge(token, formation_rules) {
+                paint_rule_badge(ui, gloss_box, badge);
+            }
+
+            let highlight = if selected || highlight_word.is_some_and(|h| h == token.original) {
+                Some(token.original.as_str())
+            } else {
+                highlight
+            };
+            let token_color = category_color(vocabulary_categories, category_colors, &vocab_key)
+                .map_or(text_color, |[r, g, b]| egui::Color32::from_rgb(r, g, b));
+            let mut layout_job =
+                create_highlighted_layout(&token.original, highlight, token_font_id, token_color);
+            if truncated {
+                layout_job.wrap = egui::text::TextWrapping::truncate_at_width(width);
+            }
             let mut label_resp = ui.add(egui::Label::new(layout_job).sense(egui::Sense::click()));
 
+            if truncated {
+                label_resp = label_resp.on_hover_text(&token.original);
+            }
             if !comment.is_empty() {
                 label_resp = label_resp.on_hover_text(&comment);
             }
 
-            if label_resp.clicked() {
+            if label_resp.clicked() && ui.input(|i| i.modifiers.shift) {
+                action = UiAction::SelectToken(word_idx);
+            } else if label_resp.clicked() {
                 action = UiAction::Filter(Arc::from(token.original.as_str()));
             } else if label_resp.secondary_clicked() {
                 action = UiAction::ShowWordMenu(Arc::from(token.original.as_str()), word_idx);
@@ -364,11 +623,115 @@ fn render_token_column(
     action
 }
 
+/// Badge naming the type of the last formation rule applied to a derived token,
+/// such as `[I]` for an inflection. `None` for underived tokens or unknown rules.
+#[must_use]
+pub fn rule_type_badge(token: &Token, formation_rules: &[FormationRule]) -> Option<&'static str> {
+    let last = *token.formation_rule_indices.last()?;
+    formation_rules.get(last).map(|rule| rule.rule_type.badge())
+}
+
+/// Draw `badge` over the top border of a gloss box, near its right edge
+fn paint_rule_badge(ui: &egui::Ui, gloss_box: egui::Rect, badge: &str) {
+    let galley = ui.painter().layout_no_wrap(
+        badge.to_string(),
+        egui::FontId::proportional(RULE_BADGE_FONT_SIZE),
+        GLOSSBOX_BYFORMATION,
+    );
+    let anchor = gloss_box.right_top() - egui::vec2(RULE_BADGE_INSET, 0.0);
+    let rect = egui::Align2::RIGHT_CENTER.anchor_size(anchor, galley.size());
+    ui.painter()
+        .rect_filled(rect.expand(1.0), 0.0, ui.visuals().panel_fill);
+    ui.painter().galley(rect.min, galley, GLOSSBOX_BYFORMATION);
+}
+
+fn render_tag_chip(ui: &mut egui::Ui, tag: &str) {
+    egui::Frame::NONE
+        .stroke(egui::Stroke::new(BOX_STROKE_WIDTH, TAG_CHIP))
+        .inner_margin(TAG_CHIP_INNER_MARGIN)
+        .corner_radius(TAG_CHIP_ROUNDING)
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new(tag).size(TAG_FONT_SIZE).color(TAG_CHIP));
+        });
+}
+
+/// Draw a bracket under a phrase's tokens with its gloss beneath.
+/// Returns whether the gloss was clicked.
+fn render_phrase_bracket(ui: &mut egui::Ui, gloss: &str, width: f32) -> bool {
+    let (rect, _) = ui.allocate_exact_size(
+        egui::vec2(width, PHRASE_BRACKET_HEIGHT),
+        egui::Sense::hover(),
+    );
+    let stroke = egui::Stroke::new(BOX_STROKE_WIDTH, PHRASEBRACKET);
+    let painter = ui.painter();
+    painter.line_segment([rect.left_top(), rect.left_bottom()], stroke);
+    painter.line_segment([rect.left_bottom(), rect.right_bottom()], stroke);
+    painter.line_segment([rect.right_top(), rect.right_bottom()], stroke);
+
+    let text_color = if ui.visuals().dark_mode {
+        FONT_DARK
+    } else {
+        FONT_LIGHT
+    };
+    ui.add(
+        egui::Label::new(
+            egui::RichText::new(gloss)
+                .size(GLOSS_FONT_SIZE)
+                .color(text_color),
+        )
+        .sense(egui::Sense::click()),
+    )
+    .on_hover_text("Click to edit the phrase gloss")
+    .clicked()
+}
+
+/// Collapsible list of translations borrowed from similar segments.
+/// Clicking one copies it into the segment; returns whether the translation changed.
+fn render_translation_suggestions(
+    ui: &mut egui::Ui,
+    segment: &mut Segment,
+    suggestions: &[TranslationSuggestion],
+    seg_num: usize,
+) -> bool {
+    let mut changed = false;
+    egui::CollapsingHeader::new(egui::RichText::new("Suggestions").weak())
+        .id_salt(("translation_suggestions", seg_num))
+        .show(ui, |ui| {
+            for suggestion in suggestions {
+                let hover = format!(
+                    "From segment [{}], {:.0}% similar",
+                    suggestion.segment_idx + 1,
+                    suggestion.score * 100.0
+                );
+                if ui
+                    .add(
+                        egui::Label::new(format!("⤷ {}", suggestion.translation))
+                            .truncate()
+                            .sense(egui::Sense::click()),
+                    )
+                    .on_hover_text(hover)
+                    .clicked()
+                {
+                    changed |= segment.set_translation(suggestion.translation.clone());
+                }
+            }
+        });
+    changed
+}
+
 fn render_translation_box(
     ui: &mut egui::Ui,
     segment: &mut Segment,
     highlight: Option<&str>,
+    text_direction: TextDirection,
 ) -> bool {
+    let editable = !segment.locked;
+    let align = if text_direction.is_rtl() {
+        egui::Align::RIGHT
+    } else {
+        egui::Align::LEFT
+    };
+
     egui::Frame::NONE
         .stroke(egui::Stroke::new(TRANSLATION_BOX_STROKE_WIDTH, SENTENCEBOX))
         .inner_margin(TRANSLATION_BOX_INNER_MARGIN)
@@ -385,17 +748,23 @@ fn render_translation_box(
                 let mut layout_job =
                     create_highlighted_layout(string, highlight, font_id, text_color);
                 layout_job.wrap.max_width = wrap_width;
+                layout_job.halign = align;
                 ui.painter().layout_job(layout_job)
             };
 
-            ui.add(
-                egui::TextEdit::multiline(&mut segment.translation)
-                    .desired_width(f32::INFINITY)
-                    .desired_rows(TRANSLATION_BOX_ROWS)
-                    .frame(egui::Frame::NONE)
-                    .layouter(&mut layouter),
-            )
-            .changed()
+            let mut translation = segment.translation.clone();
+            let changed = ui
+                .add(
+                    egui::TextEdit::multiline(&mut translation)
+                        .desired_width(f32::INFINITY)
+                        .desired_rows(TRANSLATION_BOX_ROWS)
+                        .frame(egui::Frame::NONE)
+                        .horizontal_align(align)
+                        .interactive(editable)
+                        .layouter(&mut layouter),
+                )
+                .changed();
+            changed && segment.set_translation(translation)
         })
         .inner
 }
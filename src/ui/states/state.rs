@@ -1,19 +1,40 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
 use eframe::egui;
 
-use crate::enums::{AppAction, CommentTarget, FormationType, PinnedPopup, SortMode};
+use crate::consts::ui::{PAGINATION_DEFAULT_PAGE_SIZE, SEGMENT_ROW_HEIGHT_ESTIMATE};
+use crate::enums::{
+    AppAction, AppResult, CommentTarget, DefinitionScope, FormationType, PageSizePreset,
+    PinnedPopup, PinnedPopupDescriptor, PunctuationMode, ScoreDisplay, SegmentationMode,
+    SimilarityMetric, SortMode, TextDirection, ThemeChoice,
+};
+use crate::io::default_font_definitions;
 use crate::libs::{
     Project,
-    cache::{CachedTfidf, LookupCache},
+    cache::{BackgroundTfidf, CachedTfidf, LookupCache, SuggestionCache},
+    change_summary::ChangeSummary,
+    filtering::FilterHistory,
+    normalization::NormalizedIndex,
+    project::operations::{RuleMatch, WordFrequency},
+    text_analysis::{ConcordanceLine, SegmentationPreview},
 };
+use crate::ui::CollapsedSegments;
+use crate::ui::sticky_menu::StickyMenu;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ui::window::WindowGeometry;
 
 type AsyncFileResult<T> = Arc<Mutex<Option<Result<T, String>>>>;
+/// Like `AsyncFileResult`, but keeps the error kind so a cancelled dialog can be told apart
+type AsyncAppResult<T> = Arc<Mutex<Option<AppResult<T>>>>;
 type PendingTextFile = AsyncFileResult<(String, String)>;
 type PendingProjectFile = AsyncFileResult<(String, String, Option<String>)>;
-type PendingFontFile = AsyncFileResult<(Vec<u8>, String)>;
-type PendingSaveResult = AsyncFileResult<()>;
+type PendingFontFile = AsyncAppResult<(Vec<u8>, String, Option<String>)>;
+type PendingSaveResult = AsyncAppResult<()>;
+type PendingGlossaryFile = AsyncFileResult<String>;
+type PendingRulesFile = AsyncFileResult<String>;
+type PendingCommentsFile = AsyncFileResult<String>;
 
 /// Dialog for creating a new word formation rule
 #[derive(Debug, Clone)]
@@ -24,6 +45,8 @@ pub struct NewFormationRuleDialog {
     pub rule_type: FormationType,
     /// Rhai script implementing the transformation
     pub command: String,
+    /// Optional note documenting the rule's rationale or source
+    pub note: String,
     /// Test word to preview the rule's effect
     pub test_word: String,
     /// Preview of the rule applied to the test word
@@ -91,6 +114,91 @@ pub struct UpdateSentenceCommentDialog {
     pub comment: String,
 }
 
+/// Dialog for editing a segment's tags
+#[derive(Debug, Clone)]
+pub struct UpdateTagsDialog {
+    /// Index of the segment being tagged
+    pub segment_idx: usize,
+    /// Tags separated by commas or spaces
+    pub tags: String,
+}
+
+/// Dialog for renaming a word across the whole project
+#[derive(Debug, Clone)]
+pub struct RenameWordDialog {
+    /// The word being renamed
+    pub word: String,
+    /// The new spelling
+    pub new_word: String,
+    /// Whether the new spelling is already a word, so renaming would merge the two
+    pub confirm_merge: bool,
+}
+
+/// Dialog for folding one vocabulary word into another
+#[derive(Debug, Clone, Default)]
+pub struct MergeWordsDialog {
+    /// Word whose occurrences are retargeted and whose entry is dropped
+    pub source: String,
+    /// Word that remains
+    pub target: String,
+    /// Whether the merged word takes the source's gloss instead of the target's
+    pub keep_source_gloss: bool,
+    /// Why the last merge attempt was refused
+    pub error: Option<String>,
+}
+
+/// Word frequency table, computed once when the dialog opens
+#[derive(Debug, Clone)]
+pub struct WordFrequencyDialog {
+    /// One row per unique token
+    pub rows: Vec<WordFrequency>,
+    /// Whether rows are listed from most to least frequent
+    pub descending: bool,
+    /// Only list words without a gloss
+    pub unglossed_only: bool,
+    /// Leave the project's stopwords out of the ranking
+    pub hide_stopwords: bool,
+}
+
+/// Dialog for glossing a contiguous range of tokens as one phrase
+#[derive(Debug, Clone)]
+pub struct PhraseGlossDialog {
+    /// Segment containing the phrase
+    pub segment_idx: usize,
+    /// First token of the phrase
+    pub start: usize,
+    /// Last token of the phrase (inclusive)
+    pub end: usize,
+    /// Gloss being edited
+    pub gloss: String,
+    /// Whether the range already has a phrase gloss that can be removed
+    pub existing: bool,
+    /// Why the last save was rejected
+    pub error: Option<String>,
+}
+
+/// Formation rule manager listing every rule with its usage count
+#[derive(Debug, Clone, Default)]
+pub struct FormationRulesDialog {
+    /// Tokens referencing each rule, refreshed after every change
+    pub usage: Vec<usize>,
+    /// Used rule awaiting confirmation to be cleared from its tokens and deleted
+    pub confirm_delete: Option<usize>,
+}
+
+/// Bulk application of one formation rule to tokens matching an affix pattern
+#[derive(Debug, Clone, Default)]
+pub struct ApplyRuleToMatchesDialog {
+    /// Index of the rule to apply
+    pub selected_rule: Option<usize>,
+    /// Affix pattern such as `-ed` or `un-`
+    pub pattern: String,
+    /// Proposed derivations from the last search
+    pub proposals: Vec<RuleMatch>,
+    /// Per-proposal confirmation, parallel to `proposals`
+    pub accepted: Vec<bool>,
+}
+
 /// Dialog for creating a custom tokenization rule during import
 #[derive(Debug, Clone)]
 pub struct CustomTokenizationDialog {
@@ -102,6 +210,10 @@ pub struct CustomTokenizationDialog {
     pub test_text: String,
     /// Preview of tokens generated from test text
     pub preview: Vec<String>,
+    /// Whether testing also checks every imported line for lost or altered characters
+    pub verify_integrity: bool,
+    /// 1-based lines whose tokens don't reproduce the source, from the last integrity check
+    pub lossy_lines: Option<Vec<usize>>,
 }
 
 /// Main application state for the decryption UI
@@ -114,24 +226,64 @@ pub struct DecryptionApp {
     pub(crate) project_filename: Option<String>,
     /// Current page being displayed (0-indexed)
     pub(crate) current_page: usize,
-    /// Number of segments per page
+    /// Number of segments per page, derived from `page_size_preset` every frame
     pub(crate) page_size: usize,
+    /// Selected page size, persisted through `eframe` storage
+    pub(crate) page_size_preset: PageSizePreset,
+    /// Segments folded in the segment list; view state only, never saved
+    pub(crate) collapsed_segments: CollapsedSegments,
+    /// Average height of the segments rendered last frame, used for rows not yet measured
+    pub(crate) segment_row_height: f32,
+    /// Last measured height of each rendered segment, keyed by segment index
+    pub(crate) segment_heights: HashMap<usize, f32>,
     /// Whether the project has unsaved changes
     pub(crate) is_dirty: bool,
+    /// Edits made since the last save or load, shown when confirming a discard
+    pub(crate) change_summary: ChangeSummary,
     /// Pending text content to import (text content, tokenization flag)
     pub(crate) pending_import: Option<(String, String)>,
+    /// Whether the pending import is added after the current segments instead of replacing them
+    pub(crate) import_append: bool,
+    /// Segmentation mode selected in the import dialog
+    pub(crate) import_mode: SegmentationMode,
+    /// Mode guessed from the pending import's text, and whether the guess is clear-cut
+    pub(crate) import_suggestion: Option<(SegmentationMode, bool)>,
+    /// Punctuation handling selected in the import dialog
+    pub(crate) import_punctuation: PunctuationMode,
+    /// Reading direction selected in the import dialog
+    pub(crate) import_direction: TextDirection,
+    /// Segments the pending import would create with `import_mode`, or the tokenization error
+    pub(crate) import_preview: Option<Result<SegmentationPreview, String>>,
     /// Result of async text file load operation
     pub(crate) pending_text_file: PendingTextFile,
     /// Result of async project file load operation
     pub(crate) pending_project_file: PendingProjectFile,
+    /// Font definitions including every loaded custom sentence font
+    pub(crate) font_definitions: egui::FontDefinitions,
     /// Result of async font file load operation
     pub(crate) pending_font_file: PendingFontFile,
+    /// Project font slot the pending font file replaces, when locating a missing font
+    pub(crate) relocating_font: Option<usize>,
+    /// Project font slots whose files could not be read on open, awaiting user action
+    pub(crate) missing_fonts: Vec<usize>,
     /// Result of async save operation
     pub(crate) pending_save_result: PendingSaveResult,
+    /// Result of async glossary file load operation
+    pub(crate) pending_glossary_file: PendingGlossaryFile,
+    /// Result of async formation rule library load operation
+    pub(crate) pending_rules_file: PendingRulesFile,
+    /// Result of async comment sidecar load operation
+    pub(crate) pending_comments_file: PendingCommentsFile,
     /// Current filter query text
     pub(crate) filter_text: String,
+    /// Recently used filter queries offered under the filter box
+    pub(crate) filter_history: FilterHistory,
     /// Current sort mode
     pub(crate) sort_mode: SortMode,
+    /// Whether clicking a token highlights its occurrences instead of filtering
+    pub(crate) soft_highlight_mode: bool,
+    /// Word whose occurrences are highlighted on the visible page
+    pub(crate) highlight_word: Option<String>,
     /// Error message to display in error dialog (if any)
     pub(crate) error_message: Option<String>,
     /// Pending confirmation dialog with question and action to confirm
@@ -139,15 +291,53 @@ pub struct DecryptionApp {
 
     /// Currently open definition popup word
     pub(crate) definition_popup: Option<String>,
+    /// Whether Definition popups list headword segments or every occurrence
+    pub(crate) definition_scope: DefinitionScope,
     /// Currently open reference popup word
     pub(crate) reference_popup: Option<String>,
+    /// Selected color theme, persisted through `eframe` storage
+    pub(crate) theme: ThemeChoice,
+    /// How scores are shown in similarity popups (stored scores stay raw)
+    pub(crate) similarity_score_display: ScoreDisplay,
+    /// Metric used to rank segments in the Similar popup
+    pub(crate) similarity_metric: SimilarityMetric,
     /// Currently open similarity search popup
     pub(crate) similar_popup: Option<(usize, Vec<(usize, f64)>)>,
+    /// Segment whose Similar popup waits for the background TF-IDF rebuild
+    pub(crate) similar_pending: Option<usize>,
     /// Currently open similar tokens popup
     pub(crate) similar_tokens_popup:
         Option<(String, Vec<crate::libs::similarity_token::SimilarToken>)>,
+    /// Currently open concordance (keyword-in-context) popup
+    pub(crate) concordance_popup: Option<(String, Vec<ConcordanceLine>)>,
+    /// Currently open duplicate segments popup (groups of segment indices)
+    pub(crate) duplicates_popup: Option<Vec<Vec<usize>>>,
+    /// Currently open word frequency ranking
+    pub(crate) word_frequency_popup: Option<WordFrequencyDialog>,
+    /// Gloss box to focus once its segment is laid out: (`segment_idx`, `token_idx`)
+    pub(crate) pending_gloss_focus: Option<(usize, usize)>,
+    /// First token shift-clicked when selecting a phrase: (`segment_idx`, `token_idx`)
+    pub(crate) phrase_anchor: Option<(usize, usize)>,
+    /// Segment selected by stepping through filter matches with F3/Shift+F3
+    pub(crate) match_cursor: Option<usize>,
+    /// Whether the central panel should scroll to `match_cursor` on the next frame
+    pub(crate) pending_match_scroll: bool,
+    /// Currently open phrase gloss editor
+    pub(crate) phrase_gloss_popup: Option<PhraseGlossDialog>,
+    /// Currently open word category legend (name draft for a new category)
+    pub(crate) word_categories_popup: Option<String>,
+    /// Currently open formation rule manager
+    pub(crate) formation_rules_popup: Option<FormationRulesDialog>,
+    /// Currently open bulk rule application dialog
+    pub(crate) apply_rule_popup: Option<ApplyRuleToMatchesDialog>,
+    /// Currently open stopword editor (whitespace-separated draft)
+    pub(crate) stopwords_popup: Option<String>,
+    /// Currently open project settings dialog (project name draft)
+    pub(crate) project_settings_popup: Option<String>,
     /// Currently open word context menu
     pub(crate) word_menu_popup: Option<(String, usize, usize, egui::Pos2)>,
+    /// Pin state of the word context menu
+    pub(crate) word_menu_sticky: StickyMenu,
     /// Currently open segment context menu
     pub(crate) sentence_menu_popup: Option<(usize, egui::Pos2)>,
     /// Word formation rule application dialog
@@ -162,29 +352,82 @@ pub struct DecryptionApp {
     pub(crate) update_comment_popup: Option<UpdateCommentDialog>,
     /// Segment comment editing dialog
     pub(crate) update_sentence_comment_popup: Option<UpdateSentenceCommentDialog>,
+    /// Segment tag editing dialog
+    pub(crate) update_tags_popup: Option<UpdateTagsDialog>,
+    /// Word rename dialog
+    pub(crate) rename_word_popup: Option<RenameWordDialog>,
+    /// Word merge dialog
+    pub(crate) merge_words_popup: Option<MergeWordsDialog>,
+    /// Draft of a line-aligned translation text to paste into segments
+    pub(crate) parallel_translations_popup: Option<String>,
+    /// Segment whose token statistics are shown
+    pub(crate) segment_info_popup: Option<usize>,
+    /// Pair of segments shown side by side: (target, similar neighbor)
+    pub(crate) compare_popup: Option<(usize, usize)>,
     /// Custom tokenization rule creation dialog during import
     pub(crate) custom_tokenization_popup: Option<CustomTokenizationDialog>,
     /// Popups pinned to remain visible (not auto-closing)
     pub(crate) pinned_popups: Vec<PinnedPopup>,
     /// Counter for generating unique popup IDs
     pub(crate) next_popup_id: u64,
+    /// Popups pinned in the previous session, restored once a project is opened
+    pub(crate) pending_pinned_popups: Vec<PinnedPopupDescriptor>,
 
     /// Cached list of segment indices matching current filter
     pub(crate) cached_filtered_indices: Vec<usize>,
     /// Cache for quick token lookups
     pub(crate) lookup_cache: LookupCache,
+    /// Normalized word -> glossed vocabulary key, for normalized lookups and gloss suggestions
+    pub(crate) normalized_index: NormalizedIndex,
     /// Cache for TF-IDF matrix (similarity search)
     pub(crate) tfidf_cache: CachedTfidf,
+    /// Translations offered under untranslated segments, drawn from similar segments
+    pub(crate) translation_suggestions: SuggestionCache,
+    /// TF-IDF rebuild running in the background for the Similar popup
+    pub(crate) tfidf_build: BackgroundTfidf,
 
     /// Whether filtered indices cache needs recalculation
     pub(crate) filter_dirty: bool,
-    /// Whether lookup maps need recalculation
+    /// Whether lookup maps need a full rebuild
     pub(crate) lookups_dirty: bool,
+    /// Segments whose tokens changed since the lookup maps were last updated
+    pub(crate) dirty_lookup_segments: Vec<usize>,
     /// Whether TF-IDF matrix needs recalculation
     pub(crate) tfidf_dirty: bool,
+
+    /// Latest size and position of the main window, saved for the next session
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) window_geometry: Option<WindowGeometry>,
+    /// Geometry the window was restored with, checked against the monitor on the first frame
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) restored_window: Option<WindowGeometry>,
 }
 
 impl DecryptionApp {
+    /// The open project
+    #[allow(dead_code)]
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    /// Mutable access to the open project; callers are responsible for marking it dirty
+    #[allow(dead_code)]
+    pub fn project_mut(&mut self) -> &mut Project {
+        &mut self.project
+    }
+
+    /// Whether the project has unsaved changes
+    #[allow(dead_code)]
+    pub fn is_dirty(&self) -> bool {
+        self.is_dirty
+    }
+
+    /// Action waiting for the user to confirm discarding unsaved changes
+    #[allow(dead_code)]
+    pub fn pending_confirmation(&self) -> Option<AppAction> {
+        self.confirmation.as_ref().map(|(_, action)| *action)
+    }
+
     /// Recalculate the cached list of segment indices based on current filter and sort settings
     pub(crate) fn recalculate_filtered_indices(&mut self) {
         use crate::libs::filtering::FilterOperation;
@@ -192,13 +435,13 @@ impl DecryptionApp {
 
         let mut indices = FilterOperation::apply_filter(&self.project, &self.filter_text);
         SortOperation::apply_sort(&self.project, &mut indices, self.sort_mode);
+        (self.page_size, self.current_page) =
+            crate::ui::fit_page(self.current_page, indices.len(), self.page_size_preset);
         self.cached_filtered_indices = indices;
     }
 
     /// Ensure the TF-IDF matrix cache is up-to-date
     pub(crate) fn ensure_tfidf_cache_impl(&mut self) {
-        use crate::libs::similarity_sentence::SimilarityEngine;
-
         if !self.tfidf_dirty && !self.tfidf_cache.is_dirty() {
             return;
         }
@@ -209,34 +452,168 @@ impl DecryptionApp {
             return;
         }
 
-        if let Some(matrix) = SimilarityEngine::compute_tfidf_matrix(&self.project) {
-            self.tfidf_cache.set_matrix(matrix);
+        // A background rebuild started earlier would only replace this one
+        self.tfidf_build = BackgroundTfidf::default();
+        self.tfidf_cache.rebuild(&self.project);
+        self.tfidf_dirty = false;
+    }
+
+    /// Rebuild the TF-IDF cache on a background thread, unless a rebuild is already running
+    pub(crate) fn start_tfidf_rebuild(&mut self) {
+        if self.tfidf_build.is_running() {
+            return;
         }
+        self.tfidf_cache.invalidate();
         self.tfidf_dirty = false;
+        self.tfidf_build.start(&self.project);
     }
 
-    /// Compute similar segments to a target segment and update the UI
+    /// Compute similar segments to a target segment and update the UI.
+    /// When the TF-IDF cache is stale, the popup opens with a spinner and the scores
+    /// are filled in by `process_pending_similarity` once the background rebuild is done.
     pub(crate) fn compute_similar_segments(&mut self, target_idx: usize) {
+        if target_idx >= self.project.segments.len() {
+            return;
+        }
+        if self.similarity_metric == SimilarityMetric::Cosine
+            && (self.tfidf_dirty || self.tfidf_cache.is_dirty())
+        {
+            self.start_tfidf_rebuild();
+            self.similar_pending = Some(target_idx);
+            self.similar_popup = Some((target_idx, Vec::new()));
+            return;
+        }
+
+        self.similar_pending = None;
+        if let Some(scores) = self.similar_segment_scores(target_idx) {
+            self.similar_popup = Some((target_idx, scores));
+        }
+    }
+
+    /// Install a finished background TF-IDF rebuild and fill in the waiting Similar popup.
+    /// A rebuild that finished after the project changed again is stale: it is redone
+    /// when a popup still waits for it, and dropped otherwise.
+    pub(crate) fn process_pending_similarity(&mut self) {
+        let Some(cache) = self.tfidf_build.poll() else {
+            return;
+        };
+        if self.tfidf_dirty {
+            if self.similar_pending.is_some() {
+                self.start_tfidf_rebuild();
+            }
+            return;
+        }
+        self.tfidf_cache = cache;
+
+        if let Some(target_idx) = self.similar_pending.take()
+            && self
+                .similar_popup
+                .as_ref()
+                .is_some_and(|(idx, _)| *idx == target_idx)
+        {
+            self.compute_similar_segments(target_idx);
+        }
+    }
+
+    /// Rank the segments most similar to `target_idx`, or `None` if it doesn't exist
+    fn similar_segment_scores(&mut self, target_idx: usize) -> Option<Vec<(usize, f64)>> {
         use crate::consts::domain::DEFAULT_SIMILARITY_RESULTS;
         use crate::libs::similarity_sentence::SimilarityEngine;
 
         if target_idx >= self.project.segments.len() {
-            return;
+            return None;
+        }
+        if self.similarity_metric == SimilarityMetric::Jaccard {
+            return Some(SimilarityEngine::find_similar_jaccard(
+                &self.project,
+                target_idx,
+                DEFAULT_SIMILARITY_RESULTS,
+            ));
         }
 
         self.ensure_tfidf_cache_impl();
 
-        let matrix = match self.tfidf_cache.get_matrix() {
-            Some(m) => m,
-            None => return,
-        };
+        let matrix = self.tfidf_cache.get_matrix()?;
 
         let similarities =
             SimilarityEngine::find_similar(matrix, target_idx, DEFAULT_SIMILARITY_RESULTS);
 
-        let scores: Vec<(usize, f64)> = similarities.into_iter().collect();
+        Some(similarities.into_iter().collect())
+    }
+
+    /// Fill in translation suggestions for the untranslated segments among `indices`.
+    /// Only uses an already-built TF-IDF matrix, and waits for edits to pause first.
+    pub(crate) fn refresh_translation_suggestions(
+        &mut self,
+        ctx: &egui::Context,
+        indices: &[usize],
+    ) {
+        use crate::consts::domain::{
+            TRANSLATION_SUGGESTION_CANDIDATES, TRANSLATION_SUGGESTION_RESULTS,
+        };
+        use crate::consts::ui::TRANSLATION_SUGGESTION_DEBOUNCE_SECS;
+        use crate::libs::similarity_sentence::{SimilarityEngine, translation_suggestions};
+
+        if self.tfidf_dirty {
+            self.translation_suggestions.clear();
+            return;
+        }
+        let Some(matrix) = self.tfidf_cache.get_matrix() else {
+            return;
+        };
+        let now = ctx.input(|i| i.time);
+        if let Some(wait) = self
+            .translation_suggestions
+            .remaining_delay(now, TRANSLATION_SUGGESTION_DEBOUNCE_SECS)
+        {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(wait));
+            return;
+        }
+
+        for &seg_idx in indices {
+            let Some(segment) = self.project.segments.get(seg_idx) else {
+                continue;
+            };
+            if segment.locked
+                || !segment.translation.trim().is_empty()
+                || self.translation_suggestions.contains(seg_idx)
+            {
+                continue;
+            }
+            let similar =
+                SimilarityEngine::find_similar(matrix, seg_idx, TRANSLATION_SUGGESTION_CANDIDATES);
+            let suggestions =
+                translation_suggestions(&self.project, &similar, TRANSLATION_SUGGESTION_RESULTS);
+            self.translation_suggestions.insert(seg_idx, suggestions);
+        }
+    }
 
-        self.similar_popup = Some((target_idx, scores));
+    /// Reopen the popups pinned in the previous session against the freshly loaded project.
+    /// Popups whose word or segment no longer exists are dropped.
+    pub(crate) fn restore_pinned_popups(&mut self) {
+        for descriptor in std::mem::take(&mut self.pending_pinned_popups) {
+            let popup = match descriptor {
+                PinnedPopupDescriptor::Similar { segment_idx, title } => {
+                    self.similar_segment_scores(segment_idx).map(|scores| {
+                        PinnedPopup::Similar(segment_idx, scores, self.next_popup_id, title)
+                    })
+                }
+                PinnedPopupDescriptor::Dictionary { word, mode, title } => {
+                    let exists = self.project.vocabulary.contains_key(&word)
+                        || self.project.token_frequency(&word).0 > 0;
+                    exists.then_some(PinnedPopup::Dictionary(
+                        word,
+                        mode,
+                        self.next_popup_id,
+                        title,
+                    ))
+                }
+            };
+            if let Some(popup) = popup {
+                self.pinned_popups.push(popup);
+                self.next_popup_id += 1;
+            }
+        }
     }
 }
 
@@ -248,22 +625,61 @@ impl Default for DecryptionApp {
             current_path: None,
             project_filename: None,
             current_page: 0,
-            page_size: 10,
+            page_size: PAGINATION_DEFAULT_PAGE_SIZE,
+            page_size_preset: PageSizePreset::default(),
+            collapsed_segments: CollapsedSegments::default(),
+            segment_row_height: SEGMENT_ROW_HEIGHT_ESTIMATE,
+            segment_heights: HashMap::new(),
             is_dirty: false,
+            change_summary: ChangeSummary::default(),
             pending_import: None,
+            import_append: false,
+            import_mode: SegmentationMode::default(),
+            import_suggestion: None,
+            import_punctuation: PunctuationMode::default(),
+            import_direction: TextDirection::default(),
+            import_preview: None,
             pending_text_file: Arc::new(Mutex::new(None)),
             pending_project_file: Arc::new(Mutex::new(None)),
+            font_definitions: default_font_definitions(),
             pending_font_file: Arc::new(Mutex::new(None)),
+            relocating_font: None,
+            missing_fonts: Vec::new(),
             pending_save_result: Arc::new(Mutex::new(None)),
+            pending_glossary_file: Arc::new(Mutex::new(None)),
+            pending_rules_file: Arc::new(Mutex::new(None)),
+            pending_comments_file: Arc::new(Mutex::new(None)),
             filter_text: String::new(),
+            filter_history: FilterHistory::default(),
             sort_mode: SortMode::DEFAULT,
+            soft_highlight_mode: false,
+            highlight_word: None,
             error_message: None,
             confirmation: None,
             definition_popup: None,
+            definition_scope: DefinitionScope::default(),
             reference_popup: None,
+            theme: ThemeChoice::default(),
+            similarity_score_display: ScoreDisplay::default(),
+            similarity_metric: SimilarityMetric::default(),
             similar_popup: None,
+            similar_pending: None,
             similar_tokens_popup: None,
+            concordance_popup: None,
+            duplicates_popup: None,
+            word_frequency_popup: None,
+            pending_gloss_focus: None,
+            phrase_anchor: None,
+            match_cursor: None,
+            pending_match_scroll: false,
+            phrase_gloss_popup: None,
+            word_categories_popup: None,
+            formation_rules_popup: None,
+            apply_rule_popup: None,
+            stopwords_popup: None,
+            project_settings_popup: None,
             word_menu_popup: None,
+            word_menu_sticky: StickyMenu::default(),
             sentence_menu_popup: None,
             word_formation_popup: None,
             formatting_chain_popup: None,
@@ -271,15 +687,30 @@ impl Default for DecryptionApp {
             new_formation_rule_popup: None,
             update_comment_popup: None,
             update_sentence_comment_popup: None,
+            update_tags_popup: None,
+            rename_word_popup: None,
+            parallel_translations_popup: None,
+            segment_info_popup: None,
+            compare_popup: None,
+            merge_words_popup: None,
             custom_tokenization_popup: None,
             pinned_popups: Vec::new(),
             next_popup_id: 0,
+            pending_pinned_popups: Vec::new(),
             cached_filtered_indices: Vec::new(),
             lookup_cache: LookupCache::default(),
+            normalized_index: NormalizedIndex::new(),
             tfidf_cache: CachedTfidf::default(),
+            translation_suggestions: SuggestionCache::default(),
+            tfidf_build: BackgroundTfidf::default(),
             filter_dirty: false,
             lookups_dirty: false,
+            dirty_lookup_segments: Vec::new(),
             tfidf_dirty: false,
+            #[cfg(not(target_arch = "wasm32"))]
+            window_geometry: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            restored_window: None,
         }
     }
 }
@@ -1,11 +1,29 @@
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
 
 use eframe::egui;
 
-use crate::enums::{AppAction, DictionaryPopupType, FormationType, PopupRequest};
-use crate::libs::project::load_project_from_json;
+use crate::consts::domain::DEFAULT_CONCORDANCE_CONTEXT;
+use crate::consts::ui::{
+    FILTER_HISTORY_STORAGE_KEY, PAGE_SIZE_STORAGE_KEY, PINNED_POPUPS_STORAGE_KEY, THEME_STORAGE_KEY,
+};
+use crate::enums::{
+    AppAction, AppError, DictionaryPopupType, ExportFormat, FormationType, PageSizePreset,
+    PinnedPopup, PinnedPopupDescriptor, PopupRequest, TextDirection, ThemeChoice, ToolAction,
+};
+use crate::io::comments::{apply_comment_sidecar, parse_comments_json};
+use crate::io::glossary::{apply_glossary, parse_glossary_json};
+use crate::io::jsonl::{is_jsonl_filename, parse_jsonl_segments};
+use crate::io::rules::{import_rules, parse_rules_json};
+use crate::libs::cache::lookup_key;
+use crate::libs::filtering::FilterOperation;
+use crate::libs::normalization::{NormalizedIndex, build_normalized_index};
+use crate::libs::project::{load_project_from_json, parse_project_json};
 use crate::libs::similarity_token::find_similar_tokens;
+use crate::libs::text_analysis::{SegmentationPreview, TextProcessor, suggest_segmentation};
 use crate::ui;
+use crate::ui::sticky_menu::StickyMenu;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ui::window::WindowGeometry;
 
 use crate::ui::states::state::DecryptionApp;
 
@@ -13,25 +31,116 @@ impl DecryptionApp {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(cc: &eframe::CreationContext<'_>) -> Box<dyn eframe::App> {
         Self::initialize_fonts(&cc.egui_ctx);
-        Box::new(Self::default())
+        let theme: ThemeChoice = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, THEME_STORAGE_KEY))
+            .unwrap_or_default();
+        theme.apply(&cc.egui_ctx);
+        let page_size_preset: PageSizePreset = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, PAGE_SIZE_STORAGE_KEY))
+            .unwrap_or_default();
+        let pending_pinned_popups = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, PINNED_POPUPS_STORAGE_KEY))
+            .unwrap_or_default();
+        let filter_history = cc
+            .storage
+            .and_then(|storage| eframe::get_value(storage, FILTER_HISTORY_STORAGE_KEY))
+            .unwrap_or_default();
+        Box::new(Self {
+            theme,
+            page_size_preset,
+            pending_pinned_popups,
+            filter_history,
+            #[cfg(not(target_arch = "wasm32"))]
+            restored_window: WindowGeometry::load(),
+            ..Self::default()
+        })
+    }
+
+    /// Record the window geometry for the next session. On the first frame the monitor
+    /// is known, a window restored onto a monitor of a different size, such as after its
+    /// display was disconnected, is moved back to the default size at the screen center.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn track_window_geometry(&mut self, ctx: &egui::Context) {
+        let (geometry, monitor_size) = ctx.input(|i| {
+            (
+                WindowGeometry::from_viewport(i.viewport()),
+                i.viewport().monitor_size,
+            )
+        });
+        if let Some(monitor_size) = monitor_size
+            && let Some(restored) = self.restored_window.take()
+            && restored.monitor_changed(monitor_size)
+        {
+            ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(
+                WindowGeometry::default().inner_size.into(),
+            ));
+            if let Some(center) = egui::ViewportCommand::center_on_screen(ctx) {
+                ctx.send_viewport_cmd(center);
+            }
+            return;
+        }
+        if geometry.is_some() {
+            self.window_geometry = geometry;
+        }
     }
 }
 
 impl eframe::App for DecryptionApp {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, THEME_STORAGE_KEY, &self.theme);
+        eframe::set_value(storage, PAGE_SIZE_STORAGE_KEY, &self.page_size_preset);
+        // Until a project is opened, keep the previous session's popups for the next launch
+        let pinned: Vec<PinnedPopupDescriptor> = if self.project.segments.is_empty() {
+            self.pending_pinned_popups.clone()
+        } else {
+            self.pinned_popups
+                .iter()
+                .map(PinnedPopup::descriptor)
+                .collect()
+        };
+        eframe::set_value(storage, PINNED_POPUPS_STORAGE_KEY, &pinned);
+        eframe::set_value(storage, FILTER_HISTORY_STORAGE_KEY, &self.filter_history);
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(geometry) = self.window_geometry {
+            geometry.store();
+        }
+    }
+
     fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
         let ctx = ui.ctx().clone();
+        #[cfg(not(target_arch = "wasm32"))]
+        self.track_window_geometry(&ctx);
         self.process_pending_file_operations(&ctx);
+        self.process_pending_similarity();
 
+        let mut do_new = false;
         let mut do_import = false;
+        let mut do_import_append = false;
         let mut do_open = false;
         let mut do_save = false;
-        let mut do_export = false;
+        let mut do_export = None;
         let mut do_quit = false;
         let mut do_load_font = false;
+        let mut do_toggle_embed_fonts = false;
+        let mut do_toggle_compact_json = false;
+        let mut do_toggle_glossary_appendix = false;
+        let mut do_import_glossary = false;
+        let mut do_import_rules = false;
+        let mut do_import_comments = false;
         let mut do_add_word_formation_rule = false;
+        let mut do_toggle_normalize_lookups = false;
+        let mut do_toggle_similarity_by_base_word = false;
+        let mut do_toggle_right_to_left = false;
+        let mut do_tool = None;
+        let mut do_set_theme = None;
+        let mut do_set_collapsed = None;
 
         self.handle_keyboard_shortcuts(
             &ctx,
+            &mut do_new,
             &mut do_import,
             &mut do_open,
             &mut do_save,
@@ -42,13 +151,35 @@ impl eframe::App for DecryptionApp {
         ui::render_menu_bar(
             ui,
             !self.project.segments.is_empty(),
+            self.project.embed_fonts,
+            self.project.compact_json,
+            self.project.include_glossary_appendix,
+            self.project.normalize_lookups,
+            self.project.similarity_by_base_word,
+            self.project.text_direction.is_rtl(),
+            self.theme,
+            !self.collapsed_segments.is_empty(),
+            || do_new = true,
             || do_import = true,
+            || do_import_append = true,
             || do_open = true,
             || do_save = true,
-            || do_export = true,
+            |format| do_export = Some(format),
             || do_quit = true,
             || do_load_font = true,
+            || do_toggle_embed_fonts = true,
+            || do_toggle_compact_json = true,
+            || do_toggle_glossary_appendix = true,
+            || do_import_glossary = true,
+            || do_import_rules = true,
+            || do_import_comments = true,
             || do_add_word_formation_rule = true,
+            || do_toggle_normalize_lookups = true,
+            || do_toggle_similarity_by_base_word = true,
+            || do_toggle_right_to_left = true,
+            |tool| do_tool = Some(tool),
+            |theme| do_set_theme = Some(theme),
+            |collapsed| do_set_collapsed = Some(collapsed),
         );
 
         if !self.project.segments.is_empty() {
@@ -69,21 +200,36 @@ impl eframe::App for DecryptionApp {
         }
 
         let total_items = self.cached_filtered_indices.len();
-        let total_pages = self.calculate_total_pages(total_items);
+        (self.page_size, self.current_page) =
+            ui::fit_page(self.current_page, total_items, self.page_size_preset);
+        self.handle_match_navigation(&ctx);
 
-        if self.current_page >= total_pages && total_pages > 0 {
-            self.current_page = total_pages - 1;
-        }
+        let total_pages = self.calculate_total_pages(total_items);
+        self.current_page = ui::clamp_page(self.current_page, total_items, self.page_size);
 
         self.process_actions(
             &ctx,
+            do_new,
             do_import,
+            do_import_append,
             do_open,
             do_save,
             do_export,
             do_quit,
             do_load_font,
+            do_toggle_embed_fonts,
+            do_toggle_compact_json,
+            do_toggle_glossary_appendix,
+            do_import_glossary,
+            do_import_rules,
+            do_import_comments,
             do_add_word_formation_rule,
+            do_toggle_normalize_lookups,
+            do_toggle_similarity_by_base_word,
+            do_toggle_right_to_left,
+            do_tool,
+            do_set_theme,
+            do_set_collapsed,
         );
 
         if ctx.input(|i| i.viewport().close_requested()) && self.is_dirty {
@@ -91,13 +237,28 @@ impl eframe::App for DecryptionApp {
             self.trigger_action(AppAction::Quit, &ctx);
         }
 
-        if let Some(new_page) =
-            ui::render_pagination(ui, self.current_page, total_pages, &mut self.page_size)
-        {
+        if !self.project.segments.is_empty() {
+            let summary = ui::status_summary(
+                total_items,
+                self.project.segments.len(),
+                self.sort_mode,
+                &self.filter_text,
+            );
+            ui::render_status_bar(ui, &summary);
+        }
+
+        if let Some(new_page) = ui::render_pagination(
+            ui,
+            self.current_page,
+            total_pages,
+            total_items,
+            &mut self.page_size_preset,
+        ) {
             self.current_page = new_page;
         }
 
         self.render_error_dialog(&ctx);
+        self.render_missing_font_dialog(&ctx);
         self.render_confirmation_dialog(&ctx);
         self.render_import_dialog(&ctx);
         self.render_custom_tokenization_popup(&ctx);
@@ -105,47 +266,25 @@ impl eframe::App for DecryptionApp {
         if self.lookups_dirty {
             self.recalculate_lookup_maps();
             self.lookups_dirty = false;
+        } else if !self.dirty_lookup_segments.is_empty() {
+            self.update_lookup_maps_incrementally();
         }
 
         let (headword_lookup, usage_lookup) = self.lookup_cache.take();
 
         let mut any_changed = false;
+        let mut changed_segments = Vec::new();
         let mut popup_request = None;
 
-        self.render_central_panel(ui, &mut any_changed, &mut popup_request);
+        self.render_central_panel(
+            ui,
+            &mut any_changed,
+            &mut changed_segments,
+            &mut popup_request,
+        );
 
         if let Some(req) = popup_request.take() {
-            match req {
-                PopupRequest::Dictionary(word, mode) => match mode {
-                    DictionaryPopupType::Definition => self.definition_popup = Some(word),
-                    DictionaryPopupType::Reference => self.reference_popup = Some(word),
-                },
-                PopupRequest::Similar(idx) => {
-                    self.compute_similar_segments(idx);
-                }
-                PopupRequest::SimilarTokens(word) => {
-                    let similar_indices = find_similar_tokens(&self.project, &word);
-                    self.similar_tokens_popup = Some((word, similar_indices));
-                }
-                PopupRequest::WordMenu(word, sentence_idx, word_idx, cursor_pos) => {
-                    self.word_menu_popup = Some((word, sentence_idx, word_idx, cursor_pos));
-                }
-                PopupRequest::SentenceMenu(sentence_idx, cursor_pos) => {
-                    self.sentence_menu_popup = Some((sentence_idx, cursor_pos));
-                }
-                PopupRequest::FormattingChain(sentence_idx, word_idx) => {
-                    self.formatting_chain_popup =
-                        Some(crate::ui::states::state::FormattingChainDialog {
-                            sentence_idx,
-                            word_idx,
-                        });
-                }
-                PopupRequest::Filter(text) => {
-                    self.filter_text = text;
-                    self.current_page = 0;
-                    self.filter_dirty = true;
-                }
-            }
+            self.apply_popup_request(req);
         }
 
         self.render_popups(&ctx, &headword_lookup, &usage_lookup, &mut popup_request);
@@ -155,60 +294,34 @@ impl eframe::App for DecryptionApp {
         self.lookup_cache.restore(headword_lookup, usage_lookup);
 
         if let Some(req) = popup_request {
-            match req {
-                PopupRequest::Dictionary(word, mode) => match mode {
-                    DictionaryPopupType::Definition => self.definition_popup = Some(word),
-                    DictionaryPopupType::Reference => self.reference_popup = Some(word),
-                },
-                PopupRequest::Similar(idx) => {
-                    self.compute_similar_segments(idx);
-                }
-                PopupRequest::SimilarTokens(word) => {
-                    let similar_indices = find_similar_tokens(&self.project, &word);
-                    self.similar_tokens_popup = Some((word, similar_indices));
-                }
-                PopupRequest::WordMenu(word, sentence_idx, word_idx, cursor_pos) => {
-                    self.word_menu_popup = Some((word, sentence_idx, word_idx, cursor_pos));
-                }
-                PopupRequest::SentenceMenu(sentence_idx, cursor_pos) => {
-                    self.sentence_menu_popup = Some((sentence_idx, cursor_pos));
-                }
-                PopupRequest::FormattingChain(sentence_idx, word_idx) => {
-                    self.formatting_chain_popup =
-                        Some(crate::ui::states::state::FormattingChainDialog {
-                            sentence_idx,
-                            word_idx,
-                        });
-                }
-                PopupRequest::Filter(text) => {
-                    self.filter_text = text;
-                    self.current_page = 0;
-                    self.filter_dirty = true;
-                }
-            }
+            self.apply_popup_request(req);
         }
 
         if any_changed {
             self.update_dirty_status(true, &ctx);
             self.filter_dirty = true;
-            self.lookups_dirty = true;
-            self.tfidf_dirty = true;
-            self.tfidf_cache.invalidate();
+            self.update_tfidf_incrementally(&changed_segments);
+            self.dirty_lookup_segments.extend(changed_segments);
             ctx.request_repaint();
         }
     }
 }
 
 impl DecryptionApp {
+    #[allow(clippy::too_many_arguments)]
     fn handle_keyboard_shortcuts(
         &self,
         ctx: &egui::Context,
+        do_new: &mut bool,
         do_import: &mut bool,
         do_open: &mut bool,
         do_save: &mut bool,
-        do_export: &mut bool,
+        do_export: &mut Option<ExportFormat>,
         do_quit: &mut bool,
     ) {
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::N)) {
+            *do_new = true;
+        }
         if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::I)) {
             *do_import = true;
         }
@@ -219,47 +332,135 @@ impl DecryptionApp {
             *do_save = true;
         }
         if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::E)) {
-            *do_export = true;
+            *do_export = Some(ExportFormat::Typst);
         }
         if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::Q)) {
             *do_quit = true;
         }
     }
 
-    fn calculate_total_pages(&self, total_items: usize) -> usize {
-        if total_items > 0 {
-            total_items.div_ceil(self.page_size)
-        } else {
-            0
+    fn apply_popup_request(&mut self, req: PopupRequest) {
+        match req {
+            PopupRequest::Dictionary(word, mode) => match mode {
+                DictionaryPopupType::Definition => self.definition_popup = Some(word),
+                DictionaryPopupType::Reference => self.reference_popup = Some(word),
+            },
+            PopupRequest::Similar(idx) => {
+                self.compute_similar_segments(idx);
+            }
+            PopupRequest::Compare(target_idx, other_idx) => {
+                self.compare_popup = Some((target_idx, other_idx));
+            }
+            PopupRequest::SimilarTokens(word) => {
+                let similar_indices = find_similar_tokens(&self.project, &word);
+                self.similar_tokens_popup = Some((word, similar_indices));
+            }
+            PopupRequest::Concordance(word) => {
+                let lines = TextProcessor::concordance_lines(
+                    &self.project,
+                    &word,
+                    DEFAULT_CONCORDANCE_CONTEXT,
+                );
+                self.concordance_popup = Some((word, lines));
+            }
+            PopupRequest::WordMenu(word, sentence_idx, word_idx, cursor_pos) => {
+                self.word_menu_popup = Some((word, sentence_idx, word_idx, cursor_pos));
+                self.word_menu_sticky = StickyMenu::default();
+            }
+            PopupRequest::SentenceMenu(sentence_idx, cursor_pos) => {
+                self.sentence_menu_popup = Some((sentence_idx, cursor_pos));
+            }
+            PopupRequest::FormattingChain(sentence_idx, word_idx) => {
+                self.formatting_chain_popup =
+                    Some(crate::ui::states::state::FormattingChainDialog {
+                        sentence_idx,
+                        word_idx,
+                    });
+            }
+            PopupRequest::Filter(text) => {
+                self.filter_text = text;
+                self.current_page = 0;
+                self.filter_dirty = true;
+            }
+            PopupRequest::JumpToSegment(seg_idx) => self.jump_to_segment(seg_idx),
         }
     }
 
+    fn calculate_total_pages(&self, total_items: usize) -> usize {
+        debug_assert!(
+            self.page_size > 0,
+            "page_size must be clamped to at least 1"
+        );
+        ui::total_pages(total_items, self.page_size)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn process_actions(
         &mut self,
         ctx: &egui::Context,
+        do_new: bool,
         do_import: bool,
+        do_import_append: bool,
         do_open: bool,
         do_save: bool,
-        do_export: bool,
+        do_export: Option<ExportFormat>,
         do_quit: bool,
         do_load_font: bool,
+        do_toggle_embed_fonts: bool,
+        do_toggle_compact_json: bool,
+        do_toggle_glossary_appendix: bool,
+        do_import_glossary: bool,
+        do_import_rules: bool,
+        do_import_comments: bool,
         do_add_word_formation_rule: bool,
+        do_toggle_normalize_lookups: bool,
+        do_toggle_similarity_by_base_word: bool,
+        do_toggle_right_to_left: bool,
+        do_tool: Option<ToolAction>,
+        do_set_theme: Option<ThemeChoice>,
+        do_set_collapsed: Option<bool>,
     ) {
+        if do_new {
+            self.trigger_action(AppAction::New, ctx);
+        }
         if do_import {
             self.trigger_action(AppAction::Import, ctx);
         }
+        if do_import_append {
+            self.load_text_file(ctx, true);
+        }
         if do_open {
             self.trigger_action(AppAction::Open, ctx);
         }
         if do_load_font {
             self.load_font_file(ctx);
         }
+        if do_toggle_embed_fonts {
+            self.project.embed_fonts = !self.project.embed_fonts;
+            self.update_dirty_status(true, ctx);
+        }
+        if do_toggle_compact_json {
+            self.project.compact_json = !self.project.compact_json;
+            self.update_dirty_status(true, ctx);
+        }
+        if do_toggle_glossary_appendix {
+            self.project.include_glossary_appendix = !self.project.include_glossary_appendix;
+            self.update_dirty_status(true, ctx);
+        }
+        if do_import_glossary {
+            self.load_glossary_file(ctx);
+        }
+        if do_import_rules {
+            self.load_rules_file(ctx);
+        }
+        if do_import_comments {
+            self.load_comments_file(ctx);
+        }
         if do_save {
             self.save_project(ctx);
         }
-        if do_export {
-            self.trigger_action(AppAction::Export, ctx);
+        if let Some(format) = do_export {
+            self.trigger_action(AppAction::Export(format), ctx);
         }
         if do_quit {
             self.trigger_action(AppAction::Quit, ctx);
@@ -269,46 +470,247 @@ impl DecryptionApp {
                 description: String::new(),
                 rule_type: FormationType::Derivation,
                 command: "fn transform(word) { word }".to_string(),
+                note: String::new(),
                 test_word: String::new(),
                 preview: String::new(),
             });
         }
+        if do_toggle_normalize_lookups {
+            self.project.normalize_lookups = !self.project.normalize_lookups;
+            self.lookups_dirty = true;
+            self.update_dirty_status(true, ctx);
+        }
+        if do_toggle_similarity_by_base_word {
+            self.project.similarity_by_base_word = !self.project.similarity_by_base_word;
+            self.tfidf_dirty = true;
+            self.tfidf_cache.invalidate();
+            self.update_dirty_status(true, ctx);
+        }
+        if do_toggle_right_to_left {
+            self.project.text_direction = if self.project.text_direction.is_rtl() {
+                TextDirection::Ltr
+            } else {
+                TextDirection::Rtl
+            };
+            self.update_dirty_status(true, ctx);
+        }
+        if let Some(tool) = do_tool {
+            self.run_tool(tool);
+        }
+        if let Some(theme) = do_set_theme {
+            self.theme = theme;
+            theme.apply(ctx);
+        }
+        match do_set_collapsed {
+            Some(true) => self
+                .collapsed_segments
+                .collapse_all(self.project.segments.len()),
+            Some(false) => self.collapsed_segments.expand_all(),
+            None => {}
+        }
     }
 
-    fn recalculate_lookup_maps(&mut self) {
-        if self.project.segments.is_empty() {
-            self.lookup_cache.invalidate();
+    fn run_tool(&mut self, tool: ToolAction) {
+        match tool {
+            ToolAction::FindDuplicates => {
+                self.duplicates_popup = Some(self.project.find_duplicate_segments());
+            }
+            ToolAction::WordFrequencies => {
+                self.word_frequency_popup = Some(super::state::WordFrequencyDialog {
+                    rows: self.project.word_frequencies(),
+                    descending: true,
+                    unglossed_only: false,
+                    hide_stopwords: false,
+                });
+            }
+            ToolAction::MergeWords => {
+                self.merge_words_popup = Some(super::state::MergeWordsDialog::default());
+            }
+            ToolAction::PasteParallelTranslations => {
+                self.parallel_translations_popup = Some(String::new());
+            }
+            ToolAction::EditStopwords => {
+                let draft: Vec<&str> = self.project.stopwords.iter().map(String::as_str).collect();
+                self.stopwords_popup = Some(draft.join("\n"));
+            }
+            ToolAction::WordCategories => {
+                self.word_categories_popup = Some(String::new());
+            }
+            ToolAction::FormationRules => {
+                self.formation_rules_popup = Some(super::state::FormationRulesDialog {
+                    usage: self.project.rule_usage_counts(),
+                    confirm_delete: None,
+                });
+            }
+            ToolAction::ApplyRuleToMatches => {
+                self.apply_rule_popup = Some(super::state::ApplyRuleToMatchesDialog::default());
+            }
+            ToolAction::ProjectSettings => {
+                self.project_settings_popup = Some(self.project.project_name.clone());
+            }
+        }
+    }
+
+    /// Step the match cursor through the filtered segments on F3 (next) and Shift+F3
+    /// (previous), moving to the page that holds the new match
+    fn handle_match_navigation(&mut self, ctx: &egui::Context) {
+        let forward = if ctx.input_mut(|i| i.consume_key(egui::Modifiers::SHIFT, egui::Key::F3)) {
+            false
+        } else if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::F3)) {
+            true
+        } else {
+            return;
+        };
+        if self.filter_text.trim().is_empty() {
             return;
         }
 
-        let mut headmap: HashMap<String, Vec<usize>> = HashMap::new();
-        let mut usagemap: HashMap<String, Vec<usize>> = HashMap::new();
+        let current = self.match_cursor.and_then(|seg_idx| {
+            self.cached_filtered_indices
+                .iter()
+                .position(|&idx| idx == seg_idx)
+        });
+        if let Some(pos) =
+            FilterOperation::step_match(current, self.cached_filtered_indices.len(), forward)
+            && let Some(&seg_idx) = self.cached_filtered_indices.get(pos)
+        {
+            self.match_cursor = Some(seg_idx);
+            self.current_page = pos / ui::clamp_page_size(self.page_size);
+            self.pending_match_scroll = true;
+        }
+    }
+
+    /// Show the page containing a segment, clearing the filter if it hides that segment
+    pub(crate) fn jump_to_segment(&mut self, seg_idx: usize) {
+        if seg_idx >= self.project.segments.len() {
+            return;
+        }
+
+        if self.filter_dirty {
+            self.recalculate_filtered_indices();
+            self.filter_dirty = false;
+        }
+        if !self.cached_filtered_indices.contains(&seg_idx) {
+            self.filter_text.clear();
+            self.recalculate_filtered_indices();
+        }
+
+        if let Some(pos) = self
+            .cached_filtered_indices
+            .iter()
+            .position(|&idx| idx == seg_idx)
+        {
+            self.current_page = pos / ui::clamp_page_size(self.page_size);
+        }
+    }
+
+    fn recalculate_lookup_maps(&mut self) {
+        self.refresh_normalized_vocabulary();
+        self.lookup_cache.rebuild(&self.project);
+        self.dirty_lookup_segments.clear();
+    }
 
-        for (idx, segment) in self.project.segments.iter().enumerate() {
-            if let Some(first) = segment.tokens.first() {
-                headmap.entry(first.original.clone()).or_default().push(idx);
+    /// Re-index only the segments whose tokens were edited, falling back to a full rebuild
+    /// when the cache cannot be patched
+    fn update_lookup_maps_incrementally(&mut self) {
+        self.refresh_normalized_vocabulary();
+        let mut dirty_segments = std::mem::take(&mut self.dirty_lookup_segments);
+        dirty_segments.sort_unstable();
+        dirty_segments.dedup();
+
+        for seg_idx in dirty_segments {
+            if !self.lookup_cache.update_segment(&self.project, seg_idx) {
+                self.lookup_cache.rebuild(&self.project);
+                return;
             }
+        }
+    }
 
-            let mut seen = HashSet::new();
-            for token in &segment.tokens {
-                if seen.insert(&token.original) {
-                    usagemap
-                        .entry(token.original.clone())
-                        .or_default()
-                        .push(idx);
-                }
+    /// Patch the cached TF-IDF matrix for edited segments.
+    /// A cache that is already stale, or cannot be patched, is left for a full rebuild.
+    fn update_tfidf_incrementally(&mut self, changed_segments: &[usize]) {
+        if self.tfidf_dirty || self.tfidf_cache.is_dirty() {
+            self.tfidf_dirty = true;
+            return;
+        }
+        for &seg_idx in changed_segments {
+            if !self.tfidf_cache.update_segment(&self.project, seg_idx) {
+                self.tfidf_dirty = true;
+                self.tfidf_cache.invalidate();
+                return;
             }
         }
+    }
+
+    fn refresh_normalized_vocabulary(&mut self) {
+        self.normalized_index = build_normalized_index(&self.project.vocabulary);
+    }
 
-        self.lookup_cache.restore(Some(headmap), Some(usagemap));
+    /// The normalized index when the project routes lookups through it
+    pub(crate) fn normalized_vocabulary(&self) -> Option<&NormalizedIndex> {
+        self.project
+            .normalize_lookups
+            .then_some(&self.normalized_index)
+    }
+
+    /// Refresh every segment-derived cache after segments were added, removed or reordered.
+    /// Popups holding segment indices are closed since those indices may now be stale.
+    pub(crate) fn mark_segments_changed(&mut self, ctx: &egui::Context) {
+        self.clear_popups();
+        self.filter_dirty = true;
+        self.lookups_dirty = true;
+        self.tfidf_dirty = true;
+        self.tfidf_cache.invalidate();
+        self.segment_heights.clear();
+        self.update_dirty_status(true, ctx);
+    }
+
+    /// Key used for the headword/usage lookup maps, normalized when the project asks for it
+    pub(crate) fn lookup_map_key<'a>(&self, word: &'a str) -> Cow<'a, str> {
+        lookup_key(self.project.normalize_lookups, word)
     }
 
     fn process_pending_file_operations(&mut self, ctx: &egui::Context) {
-        if let Ok(mut guard) = self.pending_text_file.try_lock()
-            && let Some(result) = guard.take()
-        {
+        let text_result = self
+            .pending_text_file
+            .try_lock()
+            .ok()
+            .and_then(|mut guard| guard.take());
+        if let Some(result) = text_result {
             match result {
+                Ok((content, name)) if is_jsonl_filename(&name) => {
+                    let import = parse_jsonl_segments(&content);
+                    if !import.errors.is_empty() {
+                        let lines = import
+                            .errors
+                            .iter()
+                            .map(|e| format!("line {}: {}", e.line, e.message))
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        self.error_message = Some(format!(
+                            "Skipped {} malformed JSON Lines entries:\n{lines}",
+                            import.errors.len()
+                        ));
+                    }
+                    if !self.import_append {
+                        self.project.import_settings = None;
+                    }
+                    self.install_imported_segments(import.segments, name, ctx);
+                }
                 Ok((content, name)) => {
+                    let defaults = self.project.import_defaults(&content);
+                    self.import_mode = defaults.mode;
+                    self.import_punctuation = defaults.punctuation;
+                    self.import_direction = self.project.text_direction;
+                    self.import_suggestion = Some(suggest_segmentation(&content));
+                    self.import_preview = Some(
+                        SegmentationPreview::new(
+                            &content,
+                            self.import_mode,
+                            self.import_punctuation,
+                        )
+                        .map_err(|e| e.to_string()),
+                    );
                     self.pending_import = Some((content, name));
                 }
                 Err(e) => {
@@ -326,10 +728,10 @@ impl DecryptionApp {
         if let Some(result) = project_result {
             match result {
                 Ok((content, name, full_path)) => {
-                    let value: serde_json::Value = match serde_json::from_str(&content) {
+                    let value = match parse_project_json(&content) {
                         Ok(parsed) => parsed,
                         Err(e) => {
-                            self.error_message = Some(format!("Failed to parse project file: {e}"));
+                            self.error_message = Some(e);
                             return;
                         }
                     };
@@ -337,6 +739,8 @@ impl DecryptionApp {
                     match load_project_from_json(value) {
                         Ok(project) => {
                             self.project = project;
+                            self.resolve_project_fonts();
+                            self.register_project_fonts(ctx);
                             self.current_path = None;
 
                             self.project_filename = full_path.or(Some(name));
@@ -344,8 +748,18 @@ impl DecryptionApp {
                             self.lookups_dirty = true;
                             self.tfidf_dirty = true;
                             self.filter_text.clear();
+                            self.collapsed_segments.expand_all();
+                            self.segment_heights.clear();
                             self.clear_popups();
+                            self.restore_pinned_popups();
                             self.update_dirty_status(false, ctx);
+                            let cyclic = self.project.cyclic_derived_words();
+                            if !cyclic.is_empty() {
+                                self.error_message = Some(format!(
+                                    "These derived words resolve back to themselves through their base words: {}. Check their formation rules.",
+                                    cyclic.join(", ")
+                                ));
+                            }
                         }
                         Err(e) => {
                             self.error_message = Some(e);
@@ -369,10 +783,9 @@ impl DecryptionApp {
                 Ok(()) => {
                     self.update_dirty_status(false, ctx);
                 }
+                Err(AppError::OperationCancelled) => {}
                 Err(e) => {
-                    if !e.contains("cancelled") && !e.contains("Cancelled") {
-                        self.error_message = Some(format!("Failed to save project: {e}"));
-                    }
+                    self.error_message = Some(format!("Failed to save project: {e}"));
                 }
             }
         }
@@ -385,11 +798,115 @@ impl DecryptionApp {
 
         if let Some(result) = font_result {
             match result {
-                Ok((data, name)) => {
-                    self.load_custom_font_from_bytes(ctx, data, &name);
+                Ok((data, name, path)) => {
+                    self.load_custom_font_from_bytes(ctx, data, &name, path);
+                }
+                Err(e) => {
+                    // Re-raise the missing font warning the pick was meant to resolve
+                    if let Some(slot) = self.relocating_font.take() {
+                        self.missing_fonts.insert(0, slot);
+                    }
+                    if !matches!(e, AppError::OperationCancelled) {
+                        self.error_message = Some(format!("Failed to load font file: {e}"));
+                    }
+                }
+            }
+        }
+
+        let glossary_result = if let Ok(mut guard) = self.pending_glossary_file.try_lock() {
+            guard.take()
+        } else {
+            None
+        };
+
+        if let Some(result) = glossary_result {
+            match result
+                .and_then(|content| parse_glossary_json(&content).map_err(|e| e.to_string()))
+            {
+                Ok(entries) => {
+                    if apply_glossary(&mut self.project, &entries) > 0 {
+                        self.lookups_dirty = true;
+                        self.filter_dirty = true;
+                        self.update_dirty_status(true, ctx);
+                    }
+                }
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to load glossary: {e}"));
+                }
+            }
+        }
+
+        let rules_result = if let Ok(mut guard) = self.pending_rules_file.try_lock() {
+            guard.take()
+        } else {
+            None
+        };
+
+        if let Some(result) = rules_result {
+            match result.and_then(|content| parse_rules_json(&content).map_err(|e| e.to_string())) {
+                Ok(rules) => {
+                    let import = import_rules(&mut self.project.formation_rules, rules);
+                    if import.added > 0 {
+                        self.update_dirty_status(true, ctx);
+                    }
+                    if !import.duplicate_descriptions.is_empty() {
+                        self.error_message = Some(format!(
+                            "Imported {} rule(s), skipped {} identical. These descriptions are now shared by different rules: {}",
+                            import.added,
+                            import.skipped,
+                            import.duplicate_descriptions.join(", ")
+                        ));
+                    }
+                }
+                Err(e) => {
+                    self.error_message = Some(format!("Failed to load formation rules: {e}"));
+                }
+            }
+        }
+
+        let comments_result = if let Ok(mut guard) = self.pending_comments_file.try_lock() {
+            guard.take()
+        } else {
+            None
+        };
+
+        if let Some(result) = comments_result {
+            match result
+                .and_then(|content| parse_comments_json(&content).map_err(|e| e.to_string()))
+            {
+                Ok(sidecar) => {
+                    let import = apply_comment_sidecar(&mut self.project, &sidecar);
+                    if import.applied > 0 {
+                        self.lookups_dirty = true;
+                        self.filter_dirty = true;
+                        self.update_dirty_status(true, ctx);
+                    }
+                    if !import.mismatched.is_empty() || !import.missing.is_empty() {
+                        let numbers = |indices: &[usize]| {
+                            indices
+                                .iter()
+                                .map(|idx| format!("[{}]", idx + 1))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        };
+                        let mut message = format!("Reattached {} comment(s).", import.applied);
+                        if !import.mismatched.is_empty() {
+                            message.push_str(&format!(
+                                " Skipped segments whose text changed since export: {}.",
+                                numbers(&import.mismatched)
+                            ));
+                        }
+                        if !import.missing.is_empty() {
+                            message.push_str(&format!(
+                                " Skipped segments that no longer exist: {}.",
+                                numbers(&import.missing)
+                            ));
+                        }
+                        self.error_message = Some(message);
+                    }
                 }
                 Err(e) => {
-                    self.error_message = Some(format!("Failed to load font file: {e}"));
+                    self.error_message = Some(format!("Failed to load comments: {e}"));
                 }
             }
         }
@@ -0,0 +1,31 @@
+use eframe::egui;
+
+use crate::consts::colors::{FONT_DARK, FONT_LIGHT};
+use crate::enums::SortMode;
+
+/// Build the status bar text, e.g. `Showing 3 of 10 segments · Sort: Index (Asc) · Filter: "ka"`.
+/// The filter part is omitted when no filter is active.
+#[must_use]
+pub fn status_summary(shown: usize, total: usize, sort_mode: SortMode, filter: &str) -> String {
+    let noun = if total == 1 { "segment" } else { "segments" };
+    let mut summary = format!(
+        "Showing {shown} of {total} {noun} · Sort: {}",
+        sort_mode.display_text()
+    );
+    let filter = filter.trim();
+    if !filter.is_empty() {
+        summary.push_str(&format!(" · Filter: \"{filter}\""));
+    }
+    summary
+}
+
+pub fn render_status_bar(ui: &mut egui::Ui, summary: &str) {
+    egui::Panel::bottom("status_bar").show(ui, |ui| {
+        let text_color = if ui.visuals().dark_mode {
+            FONT_DARK
+        } else {
+            FONT_LIGHT
+        };
+        ui.label(egui::RichText::new(summary).small().color(text_color));
+    });
+}
@@ -0,0 +1,38 @@
+/// Events that can affect whether a context menu stays open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuEvent {
+    /// A menu item was activated
+    Action,
+    /// The primary button was clicked outside the menu
+    ClickedOutside,
+    /// The pin toggle was clicked
+    TogglePin,
+    /// The menu's close button was clicked
+    Dismiss,
+}
+
+/// Open/pinned state of a context menu that can be kept open across actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StickyMenu {
+    /// Whether the menu survives actions
+    pub pinned: bool,
+}
+
+impl StickyMenu {
+    /// Apply an event and return whether the menu should stay open.
+    /// Unpinned menus close on any action; pinned menus stay open until dismissed.
+    /// An outside click closes and unpins either kind.
+    pub fn handle(&mut self, event: MenuEvent) -> bool {
+        match event {
+            MenuEvent::TogglePin => {
+                self.pinned = !self.pinned;
+                true
+            }
+            MenuEvent::Dismiss | MenuEvent::ClickedOutside => {
+                self.pinned = false;
+                false
+            }
+            MenuEvent::Action => self.pinned,
+        }
+    }
+}
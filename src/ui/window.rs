@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::consts::ui::{
+    APP_NAME, MAX_WINDOW_COORD, MIN_WINDOW_HEIGHT, MIN_WINDOW_WIDTH, WINDOW_GEOMETRY_FILE,
+    WINDOW_HEIGHT, WINDOW_WIDTH,
+};
+
+/// Size and position of the main window, remembered between sessions.
+///
+/// Stored as a small JSON file next to the `eframe` storage so `main` can read it before
+/// the window exists; the regular storage only becomes available once it does.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    /// Inner size in points
+    pub inner_size: [f32; 2],
+
+    /// Outer top-left corner in points, when the platform reports it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<[f32; 2]>,
+
+    /// Size of the monitor the window was on, used to notice a disconnected display
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor_size: Option<[f32; 2]>,
+}
+
+impl Default for WindowGeometry {
+    fn default() -> Self {
+        Self {
+            inner_size: [WINDOW_WIDTH, WINDOW_HEIGHT],
+            position: None,
+            monitor_size: None,
+        }
+    }
+}
+
+impl WindowGeometry {
+    /// Capture the geometry of a viewport. `None` until its size is known, and while it is
+    /// minimized, maximized, or fullscreen, so those states are never restored as a size.
+    #[must_use]
+    pub fn from_viewport(info: &egui::ViewportInfo) -> Option<Self> {
+        if info.minimized == Some(true)
+            || info.maximized == Some(true)
+            || info.fullscreen == Some(true)
+        {
+            return None;
+        }
+        let inner = info.inner_rect?;
+        Some(Self {
+            inner_size: [inner.width(), inner.height()],
+            position: info.outer_rect.map(|rect| [rect.min.x, rect.min.y]),
+            monitor_size: info.monitor_size.map(|size| [size.x, size.y]),
+        })
+    }
+
+    /// Make a stored geometry safe to apply: a size that is not finite falls back to the
+    /// default and is otherwise raised to the minimum, and a position that is not finite or
+    /// lies beyond any plausible desktop is dropped so the platform picks one.
+    #[must_use]
+    pub fn clamped(self) -> Self {
+        let [width, height] = self.inner_size;
+        let inner_size = if width.is_finite() && height.is_finite() {
+            [width.max(MIN_WINDOW_WIDTH), height.max(MIN_WINDOW_HEIGHT)]
+        } else {
+            Self::default().inner_size
+        };
+        let position = self.position.filter(|coords| {
+            coords
+                .iter()
+                .all(|c| c.is_finite() && c.abs() <= MAX_WINDOW_COORD)
+        });
+        Self {
+            inner_size,
+            position,
+            monitor_size: self.monitor_size,
+        }
+    }
+
+    /// Whether the window now sits on a monitor of a different size than the one it was
+    /// saved on, which usually means that display was disconnected and the restored
+    /// position may be off-screen
+    #[must_use]
+    pub fn monitor_changed(&self, current: egui::Vec2) -> bool {
+        self.monitor_size
+            .is_some_and(|[width, height]| width != current.x || height != current.y)
+    }
+
+    /// Read the geometry saved by the previous session, clamped for use
+    #[must_use]
+    pub fn load() -> Option<Self> {
+        let text = std::fs::read_to_string(geometry_path()?).ok()?;
+        serde_json::from_str::<Self>(&text).ok().map(Self::clamped)
+    }
+
+    /// Save the geometry for the next session. Failures are logged and otherwise ignored.
+    pub fn store(&self) {
+        let Some(path) = geometry_path() else {
+            return;
+        };
+        let result = serde_json::to_string(self)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+                }
+                std::fs::write(&path, json).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            log::warn!("Failed to save window geometry: {e}");
+        }
+    }
+}
+
+fn geometry_path() -> Option<PathBuf> {
+    eframe::storage_dir(APP_NAME).map(|dir| dir.join(WINDOW_GEOMETRY_FILE))
+}
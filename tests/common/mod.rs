@@ -0,0 +1,50 @@
+//! Fixture builders shared by the test modules
+
+use tdector::enums::FormationType;
+use tdector::libs::eval::{FormationRule, default_cached_ast};
+use tdector::libs::{Project, Segment, Token};
+
+/// A plain token with no base word
+pub fn token(original: &str) -> Token {
+    Token {
+        original: original.to_string(),
+        base_word: None,
+        formation_rule_indices: Vec::new(),
+    }
+}
+
+/// A token derived from `base` through the formation rules at `rules`
+pub fn derived(original: &str, base: &str, rules: &[usize]) -> Token {
+    Token {
+        original: original.to_string(),
+        base_word: Some(base.to_string()),
+        formation_rule_indices: rules.to_vec(),
+    }
+}
+
+/// A segment of plain tokens
+pub fn segment(words: &[&str]) -> Segment {
+    Segment {
+        tokens: words.iter().map(|w| token(w)).collect(),
+        ..Default::default()
+    }
+}
+
+/// A project with one segment of plain tokens per word list
+pub fn project(segments: &[&[&str]]) -> Project {
+    Project {
+        segments: segments.iter().map(|words| segment(words)).collect(),
+        ..Default::default()
+    }
+}
+
+/// A formation rule with no note
+pub fn formation_rule(description: &str, rule_type: FormationType, command: &str) -> FormationRule {
+    FormationRule {
+        description: description.to_string(),
+        rule_type,
+        command: command.to_string(),
+        note: String::new(),
+        cached_ast: default_cached_ast(),
+    }
+}
@@ -0,0 +1,28 @@
+use tdector::enums::{AppAction, ExportFormat};
+
+#[test]
+fn test_import_prompt_mentions_replacing_project() {
+    let prompt = AppAction::Import.unsaved_changes_prompt();
+    assert!(prompt.contains("Importing will replace the current project. Continue?"));
+}
+
+#[test]
+fn test_prompts_are_specific_per_action() {
+    assert!(AppAction::Quit.unsaved_changes_prompt().contains("quit"));
+    assert!(AppAction::Open.unsaved_changes_prompt().contains("Opening"));
+    assert_eq!(
+        AppAction::Export(ExportFormat::Typst).unsaved_changes_prompt(),
+        "You have unsaved changes. Continue anyway?"
+    );
+    assert_ne!(
+        AppAction::Import.unsaved_changes_prompt(),
+        AppAction::Open.unsaved_changes_prompt()
+    );
+}
+
+#[test]
+fn test_new_prompt_mentions_discarding_changes() {
+    let prompt = AppAction::New.unsaved_changes_prompt();
+    assert!(prompt.contains("new project"));
+    assert!(prompt.contains("discard"));
+}
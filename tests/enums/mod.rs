@@ -0,0 +1,6 @@
+mod app_action;
+mod page_size;
+mod popups;
+mod score_display;
+mod text_direction;
+mod theme;
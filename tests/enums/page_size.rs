@@ -0,0 +1,33 @@
+use tdector::enums::PageSizePreset;
+
+#[test]
+fn test_fixed_presets_ignore_filtered_count() {
+    let sizes: Vec<usize> = PageSizePreset::PRESETS
+        .iter()
+        .filter(|preset| **preset != PageSizePreset::All)
+        .map(|preset| preset.page_size(1234))
+        .collect();
+    assert_eq!(sizes, vec![10, 25, 50, 100]);
+}
+
+#[test]
+fn test_all_preset_uses_filtered_count() {
+    assert_eq!(PageSizePreset::All.page_size(37), 37);
+    // An empty filter result still yields a usable page size
+    assert_eq!(PageSizePreset::All.page_size(0), 1);
+}
+
+#[test]
+fn test_preset_round_trips_through_json() {
+    for preset in PageSizePreset::PRESETS {
+        let json = serde_json::to_string(&preset).expect("preset serializes");
+        let parsed: PageSizePreset = serde_json::from_str(&json).expect("preset deserializes");
+        assert_eq!(parsed, preset);
+    }
+}
+
+#[test]
+fn test_preset_labels() {
+    assert_eq!(PageSizePreset::Medium.display_text(), "Show 25 per page");
+    assert_eq!(PageSizePreset::All.display_text(), "Show all");
+}
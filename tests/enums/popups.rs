@@ -0,0 +1,48 @@
+use tdector::enums::{DictionaryPopupType, PinnedPopup, PinnedPopupDescriptor};
+
+#[test]
+fn test_descriptor_drops_computed_contents() {
+    let similar = PinnedPopup::Similar(4, vec![(1, 0.5), (7, 0.25)], 12, "📌 Similar".to_string());
+    assert_eq!(
+        similar.descriptor(),
+        PinnedPopupDescriptor::Similar {
+            segment_idx: 4,
+            title: "📌 Similar".to_string(),
+        }
+    );
+
+    let dictionary = PinnedPopup::Dictionary(
+        "ωμέγα".to_string(),
+        DictionaryPopupType::Reference,
+        3,
+        "📌 References: ωμέγα".to_string(),
+    );
+    assert_eq!(
+        dictionary.descriptor(),
+        PinnedPopupDescriptor::Dictionary {
+            word: "ωμέγα".to_string(),
+            mode: DictionaryPopupType::Reference,
+            title: "📌 References: ωμέγα".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_descriptors_round_trip_through_json() {
+    let descriptors = vec![
+        PinnedPopupDescriptor::Similar {
+            segment_idx: 0,
+            title: "Similar to [1]".to_string(),
+        },
+        PinnedPopupDescriptor::Dictionary {
+            word: "ka".to_string(),
+            mode: DictionaryPopupType::Definition,
+            title: "Definition: ka".to_string(),
+        },
+    ];
+
+    let json = serde_json::to_string(&descriptors).expect("descriptors serialize");
+    let parsed: Vec<PinnedPopupDescriptor> =
+        serde_json::from_str(&json).expect("descriptors deserialize");
+    assert_eq!(parsed, descriptors);
+}
@@ -0,0 +1,20 @@
+use tdector::enums::ScoreDisplay;
+
+#[test]
+fn test_raw_scores_unchanged() {
+    assert_eq!(ScoreDisplay::Raw.display_value(0.42, 0.8), 0.42);
+    assert_eq!(ScoreDisplay::Raw.format(0.426, 0.8), "0.43");
+}
+
+#[test]
+fn test_relative_scores_scale_to_top_result() {
+    assert_eq!(ScoreDisplay::Relative.display_value(0.8, 0.8), 100.0);
+    assert_eq!(ScoreDisplay::Relative.display_value(0.4, 0.8), 50.0);
+    assert_eq!(ScoreDisplay::Relative.format(0.2, 0.8), "25");
+}
+
+#[test]
+fn test_relative_scores_all_zero() {
+    assert_eq!(ScoreDisplay::Relative.display_value(0.0, 0.0), 0.0);
+    assert_eq!(ScoreDisplay::Relative.format(0.0, 0.0), "0");
+}
@@ -0,0 +1,39 @@
+use tdector::enums::TextDirection;
+use tdector::libs::Project;
+use tdector::libs::project::{convert_to_saved_project, load_project_from_json};
+
+use crate::common::segment;
+
+#[test]
+fn test_ltr_keeps_token_order() {
+    assert_eq!(TextDirection::Ltr.display_order(3), vec![0, 1, 2]);
+}
+
+#[test]
+fn test_rtl_reverses_token_order() {
+    assert_eq!(TextDirection::Rtl.display_order(3), vec![2, 1, 0]);
+    assert!(TextDirection::Rtl.display_order(0).is_empty());
+}
+
+#[test]
+fn test_text_direction_persists_in_saved_project() {
+    let project = Project {
+        segments: vec![segment(&["word"])],
+        text_direction: TextDirection::Rtl,
+        ..Default::default()
+    };
+
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+    assert_eq!(json["text_direction"], "Rtl");
+
+    let loaded = load_project_from_json(json).expect("project loads");
+    assert_eq!(loaded.text_direction, TextDirection::Rtl);
+}
+
+#[test]
+fn test_ltr_is_omitted_from_saved_project() {
+    let saved = convert_to_saved_project(&Project::default()).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+    assert!(json.get("text_direction").is_none());
+}
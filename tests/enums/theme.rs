@@ -0,0 +1,27 @@
+use tdector::enums::ThemeChoice;
+
+#[test]
+fn test_theme_round_trips_through_json() {
+    for theme in ThemeChoice::ALL {
+        let json = serde_json::to_string(&theme).expect("theme serializes");
+        let parsed: ThemeChoice = serde_json::from_str(&json).expect("theme deserializes");
+        assert_eq!(parsed, theme);
+    }
+}
+
+#[test]
+fn test_theme_serialized_form_is_stable() {
+    assert_eq!(
+        serde_json::to_string(&ThemeChoice::Dark).expect("theme serializes"),
+        "\"Dark\""
+    );
+    assert_eq!(
+        serde_json::from_str::<ThemeChoice>("\"Light\"").expect("theme deserializes"),
+        ThemeChoice::Light
+    );
+}
+
+#[test]
+fn test_theme_defaults_to_system() {
+    assert_eq!(ThemeChoice::default(), ThemeChoice::System);
+}
@@ -0,0 +1,215 @@
+use std::path::PathBuf;
+
+use tdector::enums::ExportFormat;
+use tdector::io::batch::{export_project_file, validate_project_file};
+use tdector::io::{generate_csv_content, generate_markdown_content};
+use tdector::libs::project::convert_to_saved_project;
+use tdector::libs::{Project, Segment};
+
+use crate::common::token;
+
+fn sample_project() -> Project {
+    let mut project = Project {
+        project_name: "Sample".to_string(),
+        ..Default::default()
+    };
+    project.segments.push(Segment {
+        tokens: vec![token("ka"), token("tu|ri")],
+        translation: "The \"cat\", sleeping".to_string(),
+        ..Default::default()
+    });
+    project.segments.push(Segment {
+        tokens: vec![token("ka")],
+        ..Default::default()
+    });
+    project
+        .vocabulary
+        .insert("ka".to_string(), "cat".to_string());
+    project
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("tdector_batch_{}_{name}", std::process::id()))
+}
+
+#[test]
+fn test_cli_format_names() {
+    assert_eq!(
+        ExportFormat::from_cli_name("typst"),
+        Some(ExportFormat::Typst)
+    );
+    assert_eq!(
+        ExportFormat::from_cli_name("Markdown"),
+        Some(ExportFormat::Markdown)
+    );
+    assert_eq!(ExportFormat::from_cli_name("csv"), Some(ExportFormat::Csv));
+    assert_eq!(
+        ExportFormat::from_cli_name("frequencies"),
+        Some(ExportFormat::FrequencyCsv)
+    );
+    assert_eq!(
+        ExportFormat::from_cli_name("bilingual"),
+        Some(ExportFormat::BilingualTypst)
+    );
+    assert_eq!(
+        ExportFormat::from_cli_name("HTML"),
+        Some(ExportFormat::Html)
+    );
+    assert_eq!(ExportFormat::from_cli_name("pdf"), None);
+}
+
+#[test]
+fn test_markdown_escapes_table_cells() {
+    let content = generate_markdown_content(&sample_project());
+    assert!(content.starts_with("# Sample\n"));
+    assert!(content.contains("## [1]\n\n| ka | tu\\|ri |\n| --- | --- |\n| cat |  |\n"));
+    assert!(content.contains("> The \"cat\", sleeping\n"));
+}
+
+#[test]
+fn test_csv_quotes_fields() {
+    let content = generate_csv_content(&sample_project());
+    let lines: Vec<&str> = content.split("\r\n").collect();
+    assert_eq!(lines[0], "segment,text,gloss,translation");
+    assert_eq!(lines[1], "1,ka tu|ri,cat | ,\"The \"\"cat\"\", sleeping\"");
+    assert_eq!(lines[2], "2,ka,cat,");
+}
+
+#[test]
+fn test_export_project_file_writes_each_format() {
+    let saved = convert_to_saved_project(&sample_project()).expect("project should convert");
+    let input = temp_path("input.json");
+    std::fs::write(
+        &input,
+        serde_json::to_string(&saved).expect("project should serialize"),
+    )
+    .expect("input should be written");
+
+    for (format, name, expected) in [
+        (ExportFormat::Typst, "out.typ", "= Sample"),
+        (ExportFormat::Markdown, "out.md", "# Sample"),
+        (ExportFormat::Html, "out.html", "<h1>Sample</h1>"),
+        (ExportFormat::Csv, "out.csv", "2,ka,cat,"),
+    ] {
+        let output = temp_path(name);
+        export_project_file(format, &input, &output).expect("export should succeed");
+        let content = std::fs::read_to_string(&output).expect("output should exist");
+        assert!(content.contains(expected), "{name}: {content}");
+        std::fs::remove_file(&output).ok();
+    }
+    std::fs::remove_file(&input).ok();
+}
+
+#[test]
+fn test_export_project_file_reports_missing_input() {
+    let result = export_project_file(
+        ExportFormat::Csv,
+        &temp_path("missing.json"),
+        &temp_path("missing.csv"),
+    );
+    assert!(result.is_err());
+}
+
+const CORRUPTED_PROJECT: &str = r#"{
+    "version": 2,
+    "vocabulary": {
+        "original": [
+            { "word": "ka", "meaning": "cat" },
+            { "word": "ka", "meaning": "dog" }
+        ],
+        "formatted": [{ "word": [7] }]
+    },
+    "sentences": [{ "words": [0, 5], "meaning": "" }]
+}"#;
+
+#[test]
+fn test_validate_reports_warnings_and_errors() {
+    let input = temp_path("corrupted.json");
+    std::fs::write(&input, CORRUPTED_PROJECT).expect("input should be written");
+
+    let report = validate_project_file(&input).expect("file should parse");
+    std::fs::remove_file(&input).ok();
+
+    assert!(report.has_errors());
+    assert_eq!(report.segment_count, 1);
+    assert_eq!(report.vocabulary_count, 2);
+    assert_eq!(report.issues.warnings.len(), 2);
+    assert_eq!(
+        report.issues.errors,
+        vec!["Segment 1 refers to missing word 5".to_string()]
+    );
+}
+
+const BAD_PHRASES_PROJECT: &str = r#"{
+    "version": 2,
+    "vocabulary": { "original": [{ "word": "ka", "meaning": "cat" }] },
+    "sentences": [{
+        "words": [0, 0, 0],
+        "meaning": "",
+        "phrases": [
+            { "start": 1, "end": 2, "gloss": "b" },
+            { "start": 0, "end": 1, "gloss": "a" },
+            { "start": 2, "end": 3, "gloss": "c" }
+        ]
+    }]
+}"#;
+
+#[test]
+fn test_validate_warns_about_dropped_phrases() {
+    let input = temp_path("bad_phrases.json");
+    std::fs::write(&input, BAD_PHRASES_PROJECT).expect("input should be written");
+
+    let report = validate_project_file(&input).expect("file should parse");
+    std::fs::remove_file(&input).ok();
+
+    assert!(!report.has_errors());
+    assert_eq!(
+        report.issues.warnings,
+        vec![
+            "Segment 1 has a phrase over tokens 2–3 overlapping the phrase over tokens 1–2; the phrase is dropped"
+                .to_string(),
+            "Segment 1 has a phrase over tokens 3–4, which is not a range of at least two of its 3 tokens; the phrase is dropped"
+                .to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_validate_rejects_newer_version() {
+    let input = temp_path("future.json");
+    std::fs::write(&input, r#"{ "version": 99 }"#).expect("input should be written");
+
+    let result = validate_project_file(&input);
+    std::fs::remove_file(&input).ok();
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_validate_command_exit_codes() {
+    let corrupted = temp_path("cli_corrupted.json");
+    std::fs::write(&corrupted, CORRUPTED_PROJECT).expect("input should be written");
+    let saved = convert_to_saved_project(&sample_project()).expect("project should convert");
+    let valid = temp_path("cli_valid.json");
+    std::fs::write(
+        &valid,
+        serde_json::to_string(&saved).expect("project should serialize"),
+    )
+    .expect("input should be written");
+
+    let run = |path: &PathBuf| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_tdector"))
+            .arg("--validate")
+            .arg(path)
+            .output()
+            .expect("binary should run")
+    };
+    let failed = run(&corrupted);
+    let passed = run(&valid);
+    std::fs::remove_file(&corrupted).ok();
+    std::fs::remove_file(&valid).ok();
+
+    assert!(!failed.status.success());
+    assert!(passed.status.success());
+    assert!(String::from_utf8_lossy(&passed.stdout).contains("2 segment(s)"));
+}
@@ -0,0 +1,111 @@
+use tdector::io::comments::{
+    apply_comment_sidecar, build_comment_sidecar, generate_comments_json, parse_comments_json,
+    segment_content_hash,
+};
+use tdector::libs::{Project, Segment};
+
+use crate::common;
+
+fn segment(words: &[&str], comment: &str) -> Segment {
+    Segment {
+        comment: comment.to_string(),
+        ..common::segment(words)
+    }
+}
+
+fn annotated_project() -> Project {
+    let mut project = Project {
+        segments: vec![
+            segment(&["kaa", "mur"], "opening formula"),
+            segment(&["tel", "kaa"], ""),
+            segment(&["mur", "sa"], "possibly a name"),
+        ],
+        ..Default::default()
+    };
+    project
+        .vocabulary_comments
+        .insert("kaa".to_string(), "frequent".to_string());
+    project
+        .formatted_word_comments
+        .insert("kaas".to_string(), "plural?".to_string());
+    project
+}
+
+/// The same project with every comment stripped, as a reviewer's copy would start out
+fn stripped(project: &Project) -> Project {
+    let mut project = project.clone();
+    project.vocabulary_comments.clear();
+    project.formatted_word_comments.clear();
+    for segment in &mut project.segments {
+        segment.comment.clear();
+    }
+    project
+}
+
+#[test]
+fn test_hash_depends_only_on_tokens() {
+    let mut a = segment(&["ab", "c"], "");
+    let b = segment(&["a", "bc"], "");
+    assert_ne!(segment_content_hash(&a), segment_content_hash(&b));
+
+    let before = segment_content_hash(&a);
+    a.translation = "changed".to_string();
+    a.comment = "changed".to_string();
+    assert_eq!(segment_content_hash(&a), before);
+}
+
+#[test]
+fn test_sidecar_json_round_trip() {
+    let project = annotated_project();
+    let json = generate_comments_json(&project).expect("comments should serialize");
+    let parsed = parse_comments_json(&json).expect("comments should parse");
+    assert_eq!(parsed, build_comment_sidecar(&project));
+    assert_eq!(parsed.segments.len(), 2);
+}
+
+#[test]
+fn test_matched_segments_reattach_comments() {
+    let project = annotated_project();
+    let sidecar = build_comment_sidecar(&project);
+    let mut target = stripped(&project);
+
+    let import = apply_comment_sidecar(&mut target, &sidecar);
+
+    assert_eq!(import.applied, 4);
+    assert!(import.mismatched.is_empty());
+    assert!(import.missing.is_empty());
+    assert_eq!(target.segments[0].comment, "opening formula");
+    assert_eq!(target.segments[2].comment, "possibly a name");
+    assert_eq!(target.vocabulary_comments["kaa"], "frequent");
+    assert_eq!(target.formatted_word_comments["kaas"], "plural?");
+}
+
+#[test]
+fn test_mismatched_segments_are_reported_and_skipped() {
+    let project = annotated_project();
+    let sidecar = build_comment_sidecar(&project);
+
+    // The third segment was re-tokenized after export
+    let mut retokenized = stripped(&project);
+    retokenized.segments[2] = segment(&["mursa"], "");
+    let import = apply_comment_sidecar(&mut retokenized, &sidecar);
+    assert_eq!(import.mismatched, vec![2]);
+    assert!(import.missing.is_empty());
+    assert_eq!(import.applied, 3);
+    assert_eq!(retokenized.segments[0].comment, "opening formula");
+    assert!(retokenized.segments[2].comment.is_empty());
+
+    // Segments were reordered, so neither comment may land on the wrong sentence
+    let mut reordered = stripped(&project);
+    reordered.segments.swap(0, 2);
+    let import = apply_comment_sidecar(&mut reordered, &sidecar);
+    assert_eq!(import.mismatched, vec![0, 2]);
+    assert!(reordered.segments.iter().all(|s| s.comment.is_empty()));
+
+    // The last segment was deleted
+    let mut truncated = stripped(&project);
+    truncated.segments.truncate(2);
+    let import = apply_comment_sidecar(&mut truncated, &sidecar);
+    assert_eq!(import.missing, vec![2]);
+    assert!(import.mismatched.is_empty());
+}
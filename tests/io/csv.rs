@@ -0,0 +1,59 @@
+use tdector::io::generate_frequency_csv;
+use tdector::libs::{Project, Segment};
+
+use crate::common::{derived, token};
+
+#[test]
+fn test_frequency_csv_counts_derived_tokens_under_base_word() {
+    let mut project = Project {
+        segments: vec![
+            Segment {
+                tokens: vec![token("kaa"), derived("kaas", "kaa", &[0])],
+                ..Default::default()
+            },
+            Segment {
+                tokens: vec![token("mur")],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+    project
+        .vocabulary
+        .insert("kaa".to_string(), "water".to_string());
+    project.vocabulary.insert("mur".to_string(), String::new());
+    project.vocabulary.insert("tel".to_string(), String::new());
+    project
+        .vocabulary_comments
+        .insert("kaa".to_string(), "see \"kaas\", plural".to_string());
+
+    let csv = generate_frequency_csv(&project);
+
+    assert_eq!(
+        csv,
+        concat!(
+            "word,count,glossed,comment\r\n",
+            "kaa,2,true,\"see \"\"kaas\"\", plural\"\r\n",
+            "mur,1,false,\r\n",
+            "tel,0,false,\r\n",
+        )
+    );
+}
+
+#[test]
+fn test_frequency_csv_quotes_words_with_line_breaks() {
+    let mut project = Project::default();
+    project
+        .vocabulary
+        .insert("a,b".to_string(), "gloss".to_string());
+    project
+        .vocabulary_comments
+        .insert("a,b".to_string(), "two\nlines".to_string());
+
+    let csv = generate_frequency_csv(&project);
+
+    assert_eq!(
+        csv,
+        "word,count,glossed,comment\r\n\"a,b\",0,true,\"two\nlines\"\r\n"
+    );
+}
@@ -0,0 +1,24 @@
+use tdector::io::export_filename;
+use tdector::libs::Project;
+
+#[test]
+fn test_unnamed_project_falls_back_to_export() {
+    assert_eq!(export_filename("", "md"), "export.md");
+    assert_eq!(export_filename("   ", "tex"), "export.tex");
+}
+
+#[test]
+fn test_renaming_project_changes_default_export_filename() {
+    let mut project = Project::default();
+    assert_eq!(export_filename(&project.project_name, "md"), "export.md");
+
+    project.project_name = "voynich".to_string();
+    assert_eq!(export_filename(&project.project_name, "md"), "voynich.md");
+    assert_eq!(
+        export_filename(&project.project_name, "glossary.json"),
+        "voynich.glossary.json"
+    );
+
+    project.project_name = "rohonc".to_string();
+    assert_eq!(export_filename(&project.project_name, "md"), "rohonc.md");
+}
@@ -0,0 +1,145 @@
+use std::path::{Path, PathBuf};
+
+use eframe::egui::{FontDefinitions, FontFamily};
+use tdector::enums::FontSource;
+use tdector::io::{
+    DEFAULT_SENTENCE_FONT, default_font_definitions, register_custom_font,
+    register_placeholder_font, resolve_font_source, segment_font_family, sentence_font_family,
+    set_default_sentence_font,
+};
+use tdector::libs::project::CustomFont;
+
+fn family(fonts: &FontDefinitions, name: &str) -> Vec<String> {
+    fonts
+        .families
+        .get(&FontFamily::Name(name.into()))
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_sentence_font_families_are_numbered_from_one() {
+    assert_eq!(sentence_font_family(0), "SentenceFont1");
+    assert_eq!(sentence_font_family(2), "SentenceFont3");
+}
+
+#[test]
+fn test_register_keeps_each_font_in_its_own_family() {
+    let mut fonts = default_font_definitions();
+    let proportional = fonts.families[&FontFamily::Proportional].clone();
+
+    register_custom_font(&mut fonts, &sentence_font_family(0), vec![1]);
+    register_custom_font(&mut fonts, &sentence_font_family(1), vec![2]);
+
+    let first = family(&fonts, "SentenceFont1");
+    let second = family(&fonts, "SentenceFont2");
+    assert_eq!(first[0], "SentenceFont1");
+    assert_eq!(second[0], "SentenceFont2");
+    assert_eq!(first[1..], proportional[..]);
+    assert_eq!(second[1..], proportional[..]);
+    assert!(fonts.font_data.contains_key("SentenceFont1"));
+    assert!(fonts.font_data.contains_key("SentenceFont2"));
+}
+
+#[test]
+fn test_register_same_family_replaces_font() {
+    let mut fonts = default_font_definitions();
+    let font_count = fonts.font_data.len();
+
+    register_custom_font(&mut fonts, "SentenceFont1", vec![1]);
+    register_custom_font(&mut fonts, "SentenceFont1", vec![2]);
+
+    assert_eq!(fonts.font_data.len(), font_count + 1);
+    assert_eq!(family(&fonts, "SentenceFont1")[0], "SentenceFont1");
+}
+
+#[test]
+fn test_default_sentence_font_follows_first_font() {
+    let mut fonts = default_font_definitions();
+    assert_eq!(
+        family(&fonts, DEFAULT_SENTENCE_FONT),
+        fonts.families[&FontFamily::Proportional]
+    );
+
+    register_custom_font(&mut fonts, "SentenceFont1", vec![1]);
+    set_default_sentence_font(&mut fonts, "SentenceFont1");
+
+    assert_eq!(
+        family(&fonts, DEFAULT_SENTENCE_FONT),
+        family(&fonts, "SentenceFont1")
+    );
+}
+
+#[test]
+fn test_segment_font_family_resolution() {
+    assert_eq!(segment_font_family(None, 0), None);
+    assert_eq!(segment_font_family(Some(0), 0), None);
+    assert_eq!(
+        segment_font_family(None, 2).as_deref(),
+        Some(DEFAULT_SENTENCE_FONT)
+    );
+    assert_eq!(
+        segment_font_family(Some(1), 2).as_deref(),
+        Some("SentenceFont2")
+    );
+    assert_eq!(
+        segment_font_family(Some(5), 2).as_deref(),
+        Some(DEFAULT_SENTENCE_FONT)
+    );
+}
+
+#[test]
+fn test_placeholder_font_aliases_proportional() {
+    let mut fonts = default_font_definitions();
+    register_custom_font(&mut fonts, "SentenceFont1", vec![1]);
+    register_placeholder_font(&mut fonts, "SentenceFont1");
+
+    assert_eq!(
+        family(&fonts, "SentenceFont1"),
+        fonts.families[&FontFamily::Proportional]
+    );
+    assert!(!fonts.font_data.contains_key("SentenceFont1"));
+}
+
+fn font(path: Option<&Path>, data: Vec<u8>) -> CustomFont {
+    CustomFont {
+        name: "script.ttf".to_string(),
+        path: path.map(|p| p.to_string_lossy().to_string()),
+        data,
+    }
+}
+
+#[test]
+fn test_embedded_font_needs_no_file() {
+    let missing = Path::new("/nonexistent/script.ttf");
+    assert_eq!(
+        resolve_font_source(&font(Some(missing), vec![1, 2]), Path::is_file),
+        FontSource::Embedded
+    );
+}
+
+#[test]
+fn test_present_font_path_is_read() {
+    let path: PathBuf = std::env::temp_dir().join("tdector_test_present_font.ttf");
+    std::fs::write(&path, [0u8; 4]).expect("temp font written");
+
+    let source = resolve_font_source(&font(Some(&path), Vec::new()), Path::is_file);
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(source, FontSource::Path(path));
+}
+
+#[test]
+fn test_absent_font_path_is_missing() {
+    let path = std::env::temp_dir().join("tdector_test_absent_font.ttf");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(
+        resolve_font_source(&font(Some(&path), Vec::new()), Path::is_file),
+        FontSource::Missing
+    );
+    assert_eq!(
+        resolve_font_source(&font(None, Vec::new()), |_| true),
+        FontSource::Missing
+    );
+}
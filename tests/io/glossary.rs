@@ -0,0 +1,167 @@
+use tdector::enums::FormationType;
+use tdector::io::glossary::{
+    GlossaryEntry, apply_glossary, build_glossary, build_glossary_appendix, generate_glossary_json,
+    parse_glossary_json,
+};
+use tdector::io::{generate_markdown_content, generate_typst_content};
+use tdector::libs::eval::FormationRule;
+use tdector::libs::{Project, Segment, Token};
+
+use crate::common::{formation_rule, token};
+
+fn sample_project() -> Project {
+    let mut project = Project::default();
+    project
+        .vocabulary
+        .insert("ωμέγα".to_string(), "omega".to_string());
+    project
+        .vocabulary
+        .insert("alpha".to_string(), "first".to_string());
+    project
+        .vocabulary
+        .insert("empty".to_string(), String::new());
+    project
+        .vocabulary_comments
+        .insert("alpha".to_string(), "note with \"quotes\"".to_string());
+    project
+        .vocabulary_comments
+        .insert("日本".to_string(), "comment only".to_string());
+    project
+}
+
+#[test]
+fn test_build_glossary_sorted_and_skips_blank_entries() {
+    let glossary = build_glossary(&sample_project());
+    let words: Vec<&str> = glossary.iter().map(|e| e.word.as_str()).collect();
+    assert_eq!(words, vec!["alpha", "ωμέγα", "日本"]);
+    assert_eq!(glossary[2].gloss, "");
+    assert_eq!(glossary[2].comment, "comment only");
+}
+
+#[test]
+fn test_glossary_json_round_trip() {
+    let project = sample_project();
+    let json = generate_glossary_json(&project).expect("glossary should serialize");
+    let parsed = parse_glossary_json(&json).expect("glossary should parse");
+    assert_eq!(parsed, build_glossary(&project));
+
+    let mut restored = Project::default();
+    assert_eq!(apply_glossary(&mut restored, &parsed), 3);
+    assert_eq!(
+        restored.vocabulary.get("ωμέγα").map(String::as_str),
+        Some("omega")
+    );
+    assert_eq!(
+        restored
+            .vocabulary_comments
+            .get("alpha")
+            .map(String::as_str),
+        Some("note with \"quotes\"")
+    );
+    assert_eq!(
+        restored.vocabulary_comments.get("日本").map(String::as_str),
+        Some("comment only")
+    );
+    assert!(!restored.vocabulary.contains_key("日本"));
+}
+
+#[test]
+fn test_apply_glossary_keeps_existing_glosses() {
+    let mut project = Project::default();
+    project
+        .vocabulary
+        .insert("alpha".to_string(), "mine".to_string());
+    let entries = vec![GlossaryEntry {
+        word: "alpha".to_string(),
+        gloss: "theirs".to_string(),
+        comment: String::new(),
+    }];
+
+    assert_eq!(apply_glossary(&mut project, &entries), 0);
+    assert_eq!(
+        project.vocabulary.get("alpha").map(String::as_str),
+        Some("mine")
+    );
+}
+
+#[test]
+fn test_parse_glossary_comment_optional() {
+    let parsed = parse_glossary_json(r#"[{"word": "x", "gloss": "y"}]"#).expect("valid glossary");
+    assert_eq!(parsed.len(), 1);
+    assert!(parsed[0].comment.is_empty());
+}
+
+fn rule(description: &str) -> FormationRule {
+    formation_rule(
+        description,
+        FormationType::Inflection,
+        "fn transform(word) { word }",
+    )
+}
+
+/// `sample_project` plus a segment using the derived word `alphaes` (alpha + plural + genitive)
+fn project_with_derived_word() -> Project {
+    let mut project = sample_project();
+    project.formation_rules = vec![rule("plural"), rule("genitive")];
+    project.segments.push(Segment {
+        tokens: vec![
+            token("alpha"),
+            Token {
+                original: "alphaes".to_string(),
+                base_word: Some("alpha".to_string()),
+                formation_rule_indices: vec![0, 1],
+            },
+        ],
+        ..Default::default()
+    });
+    project
+        .formatted_word_comments
+        .insert("alphaes".to_string(), "rare form".to_string());
+    project
+}
+
+#[test]
+fn test_glossary_appendix_lists_every_word_sorted() {
+    let project = project_with_derived_word();
+    let appendix = build_glossary_appendix(&project);
+    let words: Vec<&str> = appendix.iter().map(|e| e.word.as_str()).collect();
+
+    assert_eq!(words, vec!["alpha", "alphaes", "empty", "ωμέγα", "日本"]);
+
+    let derived = appendix
+        .iter()
+        .find(|e| e.word == "alphaes")
+        .expect("derived word should be listed");
+    assert_eq!(derived.gloss, "first");
+    assert_eq!(derived.comment, "rare form");
+    assert_eq!(derived.derivation(), "alpha + plural + genitive");
+    assert!(
+        appendix
+            .iter()
+            .find(|e| e.word == "empty")
+            .is_some_and(|e| e.derivation().is_empty())
+    );
+}
+
+#[test]
+fn test_glossary_appendix_only_when_enabled() {
+    let mut project = project_with_derived_word();
+    assert!(!generate_markdown_content(&project).contains("## Glossary"));
+    assert!(!generate_typst_content(&project).contains("= Glossary"));
+
+    project.include_glossary_appendix = true;
+
+    let markdown = generate_markdown_content(&project);
+    let appendix = &markdown[markdown.find("## Glossary").expect("markdown appendix")..];
+    let alpha = appendix.find("| alpha |").expect("alpha row");
+    let derived = appendix.find("| alphaes |").expect("alphaes row");
+    let omega = appendix.find("| ωμέγα |").expect("omega row");
+    assert!(alpha < derived && derived < omega);
+    assert!(appendix.contains("| alphaes | first | alpha + plural + genitive | rare form |"));
+
+    let typst = generate_typst_content(&project);
+    let appendix = &typst[typst.find("= Glossary").expect("typst appendix")..];
+    for entry in build_glossary_appendix(&project) {
+        assert!(appendix.contains(&format!("  [{}],", entry.word)));
+    }
+}
@@ -0,0 +1,71 @@
+use tdector::io::generate_similarity_dot;
+use tdector::libs::{Project, Segment};
+
+use crate::common;
+
+fn segment(words: &[&str], translation: &str) -> Segment {
+    Segment {
+        translation: translation.to_string(),
+        ..common::segment(words)
+    }
+}
+
+fn corpus() -> Project {
+    Project {
+        segments: vec![
+            segment(&["red", "cat", "sat"], "The red cat sat."),
+            segment(&["red", "cat", "ran"], ""),
+            segment(&["blue", "dog", "ran"], "A \"blue\" dog\nran."),
+            segment(&["green", "bird", "flew"], ""),
+        ],
+        ..Default::default()
+    }
+}
+
+fn count_nodes(dot: &str) -> usize {
+    dot.lines().filter(|line| line.contains("[label=")).count()
+}
+
+fn edges(dot: &str) -> Vec<&str> {
+    dot.lines()
+        .filter(|line| line.contains(" -- "))
+        .map(|line| line.trim().split(" [").next().unwrap_or_default())
+        .collect()
+}
+
+#[test]
+fn test_similarity_dot_links_overlapping_segments_once() {
+    let dot = generate_similarity_dot(&corpus(), 3, 0.0);
+
+    assert!(dot.starts_with("graph similarity {"));
+    assert!(dot.trim_end().ends_with('}'));
+    assert_eq!(count_nodes(&dot), 4);
+    assert_eq!(edges(&dot), vec!["s1 -- s2", "s2 -- s3"]);
+}
+
+#[test]
+fn test_similarity_dot_threshold_and_k_drop_edges() {
+    let dot = generate_similarity_dot(&corpus(), 3, 1.01);
+    assert_eq!(count_nodes(&dot), 4);
+    assert!(edges(&dot).is_empty());
+
+    let dot = generate_similarity_dot(&corpus(), 0, 0.0);
+    assert!(edges(&dot).is_empty());
+}
+
+#[test]
+fn test_similarity_dot_escapes_labels() {
+    let dot = generate_similarity_dot(&corpus(), 3, 0.0);
+
+    assert!(dot.contains(r#"s1 [label="[1] The red cat sat."];"#));
+    assert!(dot.contains(r#"s2 [label="[2] red cat ran"];"#));
+    assert!(dot.contains(r#"s3 [label="[3] A \"blue\" dog\nran."];"#));
+}
+
+#[test]
+fn test_similarity_dot_for_empty_project() {
+    let dot = generate_similarity_dot(&Project::default(), 3, 0.0);
+
+    assert_eq!(count_nodes(&dot), 0);
+    assert!(edges(&dot).is_empty());
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use tdector::io::generate_html_content;
+use tdector::io::html::escape_html;
+use tdector::libs::project::CustomFont;
+use tdector::libs::{Project, Segment};
+
+use crate::common::token;
+
+#[test]
+fn test_escape_html_special_characters() {
+    assert_eq!(
+        escape_html(r#"<b>"Tom" & 'Jerry'</b>"#),
+        "&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;"
+    );
+    assert_eq!(escape_html("plain 語"), "plain 語");
+}
+
+#[test]
+fn test_html_escapes_user_strings() {
+    let project = Project {
+        project_name: "<script>alert(1)</script>".to_string(),
+        segments: vec![Segment {
+            tokens: vec![token("a<b")],
+            translation: "x & \"y\"".to_string(),
+            ..Default::default()
+        }],
+        vocabulary: HashMap::from([("a<b".to_string(), "say \"hi\"".to_string())]),
+        ..Default::default()
+    };
+
+    let content = generate_html_content(&project);
+
+    assert!(!content.contains("<script>alert"));
+    assert!(content.contains("<title>&lt;script&gt;alert(1)&lt;/script&gt;</title>"));
+    assert!(
+        content.contains("<span class=\"token\" data-gloss=\"say &quot;hi&quot;\">a&lt;b</span>")
+    );
+    assert!(content.contains("<p class=\"translation\">x &amp; &quot;y&quot;</p>"));
+}
+
+#[test]
+fn test_html_two_segment_document_structure() {
+    let project = Project {
+        project_name: "Sample".to_string(),
+        segments: vec![
+            Segment {
+                tokens: vec![token("ka"), token("tu")],
+                translation: "cat sleeps".to_string(),
+                ..Default::default()
+            },
+            Segment {
+                tokens: vec![token("ri")],
+                ..Default::default()
+            },
+        ],
+        vocabulary: HashMap::from([
+            ("ka".to_string(), "cat".to_string()),
+            ("tu".to_string(), "sleep".to_string()),
+        ]),
+        ..Default::default()
+    };
+
+    let content = generate_html_content(&project);
+
+    assert!(content.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n"));
+    assert!(content.contains("<h1>Sample</h1>\n"));
+    assert!(content.contains(concat!(
+        "<div class=\"segment\" id=\"segment-1\">\n",
+        "<div class=\"number\">[1]</div>\n",
+        "<div class=\"tokens\" dir=\"ltr\">",
+        "<span class=\"token\" data-gloss=\"cat\">ka</span>",
+        "<span class=\"token\" data-gloss=\"sleep\">tu</span></div>\n",
+        "<p class=\"translation\">cat sleeps</p>\n",
+        "</div>\n",
+    )));
+    assert!(content.contains(concat!(
+        "<div class=\"segment\" id=\"segment-2\">\n",
+        "<div class=\"number\">[2]</div>\n",
+        "<div class=\"tokens\" dir=\"ltr\">",
+        "<span class=\"token unglossed\" data-gloss=\"\">ri</span></div>\n",
+        "</div>\n",
+    )));
+    assert_eq!(content.matches("class=\"translation\"").count(), 1);
+    assert!(content.contains("<script>\n"));
+    assert!(content.ends_with("</body>\n</html>\n"));
+    assert!(!content.contains("@font-face"));
+}
+
+#[test]
+fn test_html_embeds_loaded_font() {
+    let project = Project {
+        fonts: vec![CustomFont {
+            name: "Script.otf".to_string(),
+            path: None,
+            data: b"font".to_vec(),
+        }],
+        segments: vec![Segment {
+            tokens: vec![token("ka")],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let content = generate_html_content(&project);
+
+    assert!(content.contains(
+        "@font-face { font-family: \"tdector-font-0\"; src: url(data:font/otf;base64,Zm9udA==); }"
+    ));
+    assert!(content.contains("<div class=\"segment font-0\" id=\"segment-1\">"));
+}
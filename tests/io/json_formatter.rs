@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use tdector::io::json_formatter::{DEFAULT_INDENT, Formatter};
+use tdector::libs::project::{Project, Segment, load_project_from_json};
+
+use crate::common::token;
+
+fn sample_project() -> Project {
+    Project {
+        project_name: "Sample".to_string(),
+        segments: vec![Segment {
+            tokens: vec![token("ka"), token("tu")],
+            translation: "cat sleeps".to_string(),
+            tags: vec!["verse".to_string()],
+            ..Default::default()
+        }],
+        vocabulary: HashMap::from([
+            ("ka".to_string(), "cat".to_string()),
+            ("tu".to_string(), String::new()),
+        ]),
+        ..Default::default()
+    }
+}
+
+fn serialize(project: &Project, formatter: Formatter) -> String {
+    let saved = project.to_saved().expect("project converts");
+    let mut buf = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
+    serde::Serialize::serialize(&saved, &mut serializer).expect("project serializes");
+    String::from_utf8(buf).expect("output is UTF-8")
+}
+
+fn reload(text: &str) -> Project {
+    let json: serde_json::Value = serde_json::from_str(text).expect("output is valid JSON");
+    load_project_from_json(json).expect("project loads")
+}
+
+#[test]
+fn test_compact_and_pretty_output_load_to_the_same_project() {
+    let project = sample_project();
+    let pretty = serialize(&project, Formatter::new());
+    let compact = serialize(&project, Formatter::with_options(DEFAULT_INDENT, true));
+
+    assert!(pretty.contains("\n  \""));
+    assert!(!compact.contains('\n'));
+    assert!(compact.len() < pretty.len());
+
+    let from_pretty = reload(&pretty);
+    let from_compact = reload(&compact);
+    assert_eq!(
+        serialize(&from_pretty, Formatter::new()),
+        serialize(&from_compact, Formatter::new())
+    );
+    assert_eq!(from_compact.vocabulary, project.vocabulary);
+    assert_eq!(from_compact.segments[0].tags, project.segments[0].tags);
+}
+
+#[test]
+fn test_custom_indent_width() {
+    let output = serialize(&sample_project(), Formatter::with_options(4, false));
+    assert!(output.starts_with("{\n    \""));
+    assert_eq!(reload(&output).segments.len(), 1);
+}
+
+#[test]
+fn test_compact_flag_is_saved_with_the_project() {
+    let project = Project {
+        compact_json: true,
+        ..sample_project()
+    };
+    let loaded = reload(&serialize(&project, Formatter::new()));
+    assert!(loaded.compact_json);
+
+    let json = serde_json::to_value(sample_project().to_saved().expect("project converts"))
+        .expect("project serializes");
+    assert!(json.get("compact_json").is_none());
+}
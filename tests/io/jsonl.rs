@@ -0,0 +1,74 @@
+use tdector::io::jsonl::{is_jsonl_filename, parse_jsonl_segments};
+
+fn originals(import: &tdector::io::jsonl::JsonlImport, idx: usize) -> Vec<&str> {
+    import.segments[idx]
+        .tokens
+        .iter()
+        .map(|token| token.original.as_str())
+        .collect()
+}
+
+#[test]
+fn test_valid_lines_become_segments() {
+    let content = concat!(
+        r#"{"tokens": ["kaa", "mur"], "translation": "the river"}"#,
+        "\n",
+        r#"{"tokens": ["tel"], "score": 0.8, "source": "generator"}"#,
+        "\n",
+    );
+    let import = parse_jsonl_segments(content);
+
+    assert!(import.errors.is_empty());
+    assert_eq!(import.segments.len(), 2);
+    assert_eq!(originals(&import, 0), ["kaa", "mur"]);
+    assert_eq!(import.segments[0].translation, "the river");
+    assert_eq!(originals(&import, 1), ["tel"]);
+    assert_eq!(import.segments[1].translation, "");
+    assert!(
+        import.segments[0]
+            .tokens
+            .iter()
+            .all(|token| token.formation_rule_indices.is_empty())
+    );
+}
+
+#[test]
+fn test_malformed_line_reported_with_line_number() {
+    let content = concat!(
+        r#"{"tokens": ["kaa"]}"#,
+        "\n",
+        r#"{"tokens": ["mur""#,
+        "\n",
+        r#"{"translation": "no tokens here"}"#,
+        "\n",
+        r#"{"tokens": []}"#,
+        "\n",
+        r#"{"tokens": ["sa"]}"#,
+        "\n",
+    );
+    let import = parse_jsonl_segments(content);
+
+    assert_eq!(import.segments.len(), 2);
+    assert_eq!(originals(&import, 0), ["kaa"]);
+    assert_eq!(originals(&import, 1), ["sa"]);
+    let lines: Vec<usize> = import.errors.iter().map(|e| e.line).collect();
+    assert_eq!(lines, [2, 3, 4]);
+}
+
+#[test]
+fn test_empty_lines_skipped() {
+    let content = "\n  \n{\"tokens\": [\"kaa\"]}\n\n\t\n{\"tokens\": [\"mur\"]}\n\n";
+    let import = parse_jsonl_segments(content);
+
+    assert!(import.errors.is_empty());
+    assert_eq!(import.segments.len(), 2);
+    assert_eq!(originals(&import, 1), ["mur"]);
+}
+
+#[test]
+fn test_jsonl_filename_detection() {
+    assert!(is_jsonl_filename("candidates.jsonl"));
+    assert!(is_jsonl_filename("CANDIDATES.JSONL"));
+    assert!(!is_jsonl_filename("candidates.txt"));
+    assert!(!is_jsonl_filename("jsonl"));
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use tdector::io::generate_latex_content;
+use tdector::libs::{Project, Segment};
+
+use crate::common::token;
+
+#[test]
+fn test_latex_escapes_special_characters() {
+    let project = Project {
+        project_name: "R&D_notes 100%".to_string(),
+        segments: vec![Segment {
+            tokens: vec![token("a_b"), token("c&d")],
+            translation: "50% of x_1 & y".to_string(),
+            ..Default::default()
+        }],
+        vocabulary: HashMap::from([("a_b".to_string(), "one_two".to_string())]),
+        ..Default::default()
+    };
+
+    let content = generate_latex_content(&project);
+
+    assert!(content.contains("\\section*{R\\&D\\_notes 100\\%}"));
+    assert!(content.contains("\\gll a\\_b c\\&d\\\\\n"));
+    assert!(content.contains("     one\\_two {}\\\\\n"));
+    assert!(content.contains("\\trans `50\\% of x\\_1 \\& y'\n"));
+}
+
+#[test]
+fn test_latex_two_segment_document_structure() {
+    let project = Project {
+        segments: vec![
+            Segment {
+                tokens: vec![token("ka"), token("tu")],
+                translation: "cat sleeps".to_string(),
+                ..Default::default()
+            },
+            Segment {
+                tokens: vec![token("ri")],
+                ..Default::default()
+            },
+        ],
+        vocabulary: HashMap::from([
+            ("ka".to_string(), "cat".to_string()),
+            ("tu".to_string(), "to sleep".to_string()),
+        ]),
+        ..Default::default()
+    };
+
+    let content = generate_latex_content(&project);
+
+    assert!(content.starts_with("\\documentclass{article}\n\\usepackage{gb4e}\n"));
+    assert!(content.ends_with("\\end{exe}\n\n\\end{document}\n"));
+    assert_eq!(content.matches("\\begin{exe}").count(), 1);
+    assert_eq!(content.matches("\\ex\n").count(), 2);
+    assert!(!content.contains("\\section*"));
+    assert!(content.contains(
+        "\\ex\n\\gll ka tu\\\\\n     cat {to sleep}\\\\\n\\trans `cat sleeps'\n\\ex\n\\gll ri\\\\\n     {}\\\\\n\\trans `'\n"
+    ));
+}
+
+#[test]
+fn test_latex_empty_project_has_no_exe_environment() {
+    let project = Project {
+        project_name: "Empty".to_string(),
+        ..Default::default()
+    };
+
+    let content = generate_latex_content(&project);
+
+    assert!(!content.contains("\\begin{exe}"));
+    assert!(!content.contains("\\end{exe}"));
+    assert!(content.ends_with("\\section*{Empty}\n\n\\end{document}\n"));
+}
@@ -0,0 +1,18 @@
+#[cfg(not(target_arch = "wasm32"))]
+mod batch;
+mod comments;
+mod csv;
+mod filename;
+mod fonts;
+mod glossary;
+mod graph;
+mod html;
+mod json_formatter;
+mod jsonl;
+mod latex;
+mod plaintext;
+mod rules;
+#[cfg(not(target_arch = "wasm32"))]
+mod segment_image;
+mod text_encoding;
+mod typst;
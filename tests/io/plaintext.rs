@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use tdector::io::{format_segment_plaintext, generate_untranslated_wordlist};
+use tdector::libs::{Project, Segment};
+
+use crate::common::token;
+
+#[test]
+fn test_plaintext_aligns_short_and_long_tokens() {
+    let segment = Segment {
+        tokens: vec![token("a"), token("longword"), token("xyz")],
+        translation: "The translation".to_string(),
+        ..Default::default()
+    };
+    let vocabulary = HashMap::from([
+        ("a".to_string(), "article".to_string()),
+        ("longword".to_string(), "big".to_string()),
+    ]);
+
+    let text = format_segment_plaintext(&segment, &vocabulary, &[]);
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines[0], "a        longword  xyz");
+    assert_eq!(lines[1], "article  big");
+    assert_eq!(lines[2], "The translation");
+}
+
+#[test]
+fn test_plaintext_counts_characters_not_bytes() {
+    let segment = Segment {
+        tokens: vec![token("ωω"), token("b")],
+        ..Default::default()
+    };
+    let vocabulary = HashMap::from([("b".to_string(), "bee".to_string())]);
+
+    let text = format_segment_plaintext(&segment, &vocabulary, &[]);
+
+    assert_eq!(text, "ωω  b\n    bee");
+}
+
+#[test]
+fn test_untranslated_wordlist_is_frequency_sorted() {
+    let project = Project {
+        segments: vec![
+            Segment {
+                tokens: vec![token("b"), token("a"), token("c")],
+                ..Default::default()
+            },
+            Segment {
+                tokens: vec![token("c")],
+                ..Default::default()
+            },
+        ],
+        vocabulary: HashMap::from([("a".to_string(), "alpha".to_string())]),
+        ..Default::default()
+    };
+
+    assert_eq!(generate_untranslated_wordlist(&project), "c\nb\n");
+}
@@ -0,0 +1,89 @@
+use tdector::enums::FormationType;
+use tdector::io::rules::{RuleImport, generate_rules_json, import_rules, parse_rules_json};
+use tdector::libs::eval::FormationRule;
+
+use crate::common::formation_rule;
+
+fn rule(description: &str, command: &str) -> FormationRule {
+    formation_rule(description, FormationType::Inflection, command)
+}
+
+fn descriptions(rules: &[FormationRule]) -> Vec<&str> {
+    rules.iter().map(|r| r.description.as_str()).collect()
+}
+
+#[test]
+fn test_import_appends_new_rules_after_existing() {
+    let mut rules = vec![rule("plural", "fn transform(w) { w + \"s\" }")];
+
+    let result = import_rules(
+        &mut rules,
+        vec![rule("past", "fn transform(w) { w + \"ed\" }")],
+    );
+
+    assert_eq!(
+        result,
+        RuleImport {
+            added: 1,
+            skipped: 0,
+            duplicate_descriptions: Vec::new(),
+        }
+    );
+    assert_eq!(descriptions(&rules), vec!["plural", "past"]);
+}
+
+#[test]
+fn test_import_skips_identical_rules() {
+    let mut rules = vec![rule("plural", "fn transform(w) { w + \"s\" }")];
+
+    let result = import_rules(
+        &mut rules,
+        vec![
+            rule("plural", "fn transform(w) { w + \"s\" }"),
+            rule("past", "fn transform(w) { w + \"ed\" }"),
+            rule("past", "fn transform(w) { w + \"ed\" }"),
+        ],
+    );
+
+    assert_eq!(result.added, 1);
+    assert_eq!(result.skipped, 2);
+    assert!(result.duplicate_descriptions.is_empty());
+    assert_eq!(descriptions(&rules), vec!["plural", "past"]);
+}
+
+#[test]
+fn test_import_warns_on_shared_description() {
+    let mut rules = vec![rule("plural", "fn transform(w) { w + \"s\" }")];
+
+    let result = import_rules(
+        &mut rules,
+        vec![
+            rule("plural", "fn transform(w) { w + \"es\" }"),
+            rule("plural", "fn transform(w) { w + \"en\" }"),
+        ],
+    );
+
+    assert_eq!(result.added, 2);
+    assert_eq!(result.duplicate_descriptions, vec!["plural".to_string()]);
+    assert_eq!(rules.len(), 3);
+}
+
+#[test]
+fn test_rules_json_round_trip() {
+    let rules = vec![
+        rule("plural", "fn transform(w) { w + \"s\" }"),
+        rule("past", "fn transform(w) {\n    w + \"ed\"\n}"),
+    ];
+
+    let json = generate_rules_json(&rules).expect("rules serialize");
+    let parsed = parse_rules_json(&json).expect("rules parse");
+
+    assert_eq!(descriptions(&parsed), descriptions(&rules));
+    assert_eq!(parsed[1].command, rules[1].command);
+    assert_eq!(parsed[1].rule_type, FormationType::Inflection);
+}
+
+#[test]
+fn test_parse_rules_rejects_invalid_json() {
+    assert!(parse_rules_json("{\"not\": \"a list\"}").is_err());
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+
+use tdector::io::default_font_definitions;
+use tdector::io::segment_image::{encode_png, render_segment_image};
+use tdector::libs::Segment;
+
+use crate::common::segment;
+
+fn sample_segment() -> Segment {
+    Segment {
+        translation: "The river runs east".to_string(),
+        ..segment(&["kaa", "mur", "tel"])
+    }
+}
+
+#[test]
+fn test_renders_a_non_empty_image_with_ink() {
+    let vocabulary = HashMap::from([
+        ("kaa".to_string(), "river".to_string()),
+        ("mur".to_string(), "run".to_string()),
+    ]);
+    let image = render_segment_image(
+        &sample_segment(),
+        &vocabulary,
+        &[],
+        default_font_definitions(),
+        None,
+    );
+
+    assert!(image.width() > 0 && image.height() > 0);
+    assert!(
+        image.pixels().any(|pixel| pixel.0 != [255, 255, 255, 255]),
+        "text should leave non-white pixels"
+    );
+
+    let png = encode_png(&image).expect("image should encode");
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
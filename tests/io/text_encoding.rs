@@ -0,0 +1,48 @@
+use tdector::io::decode_text_bytes;
+
+const SAMPLE: &str = "ᚠᚢᚦ wyrd\r\nline 語";
+
+fn utf16_bytes(text: &str, bom: [u8; 2], to_bytes: fn(u16) -> [u8; 2]) -> Vec<u8> {
+    let mut bytes = bom.to_vec();
+    bytes.extend(text.encode_utf16().flat_map(to_bytes));
+    bytes
+}
+
+#[test]
+fn test_plain_utf8_is_the_default() {
+    let decoded = decode_text_bytes(SAMPLE.as_bytes()).expect("UTF-8 decodes");
+    assert_eq!(decoded, SAMPLE);
+}
+
+#[test]
+fn test_utf8_bom_is_stripped() {
+    let mut bytes = vec![0xEF, 0xBB, 0xBF];
+    bytes.extend_from_slice(SAMPLE.as_bytes());
+
+    let decoded = decode_text_bytes(&bytes).expect("UTF-8 with BOM decodes");
+    assert_eq!(decoded, SAMPLE);
+}
+
+#[test]
+fn test_utf16_le_with_bom() {
+    let bytes = utf16_bytes(SAMPLE, [0xFF, 0xFE], u16::to_le_bytes);
+
+    let decoded = decode_text_bytes(&bytes).expect("UTF-16LE decodes");
+    assert_eq!(decoded, SAMPLE);
+}
+
+#[test]
+fn test_utf16_be_with_bom() {
+    let bytes = utf16_bytes(SAMPLE, [0xFE, 0xFF], u16::to_be_bytes);
+
+    let decoded = decode_text_bytes(&bytes).expect("UTF-16BE decodes");
+    assert_eq!(decoded, SAMPLE);
+}
+
+#[test]
+fn test_undecodable_input_is_an_error() {
+    assert!(decode_text_bytes(&[0xC3, 0x28]).is_err());
+    assert!(decode_text_bytes(&[0xFF, 0xFE, 0x41]).is_err());
+    // Unpaired high surrogate
+    assert!(decode_text_bytes(&[0xFF, 0xFE, 0x00, 0xD8]).is_err());
+}
@@ -0,0 +1,30 @@
+use tdector::io::generate_bilingual_typst;
+use tdector::libs::{Project, Segment};
+
+use crate::common::token;
+
+#[test]
+fn test_bilingual_table_leaves_missing_translation_blank() {
+    let project = Project {
+        project_name: "Sample".to_string(),
+        segments: vec![
+            Segment {
+                tokens: vec![token("ka"), token("tu")],
+                translation: "cat sleeps".to_string(),
+                ..Default::default()
+            },
+            Segment {
+                tokens: vec![token("ri")],
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+
+    let content = generate_bilingual_typst(&project);
+
+    assert!(content.contains("= Sample\n"));
+    assert!(content.contains(
+        "#table(\n  columns: (1fr, 1fr),\n  [*Source*], [*Translation*],\n  [ka tu], [cat sleeps],\n  [ri], [],\n)\n"
+    ));
+}
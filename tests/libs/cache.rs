@@ -0,0 +1,182 @@
+use std::time::{Duration, Instant};
+use tdector::enums::DefinitionScope;
+
+use tdector::libs::Project;
+use tdector::libs::cache::{BackgroundTfidf, CachedTfidf, LookupCache, SuggestionCache};
+
+use crate::common::{project, segment};
+
+fn rebuilt(project: &Project) -> LookupCache {
+    let mut cache = LookupCache::default();
+    cache.rebuild(project);
+    cache
+}
+
+fn assert_same_maps(left: &LookupCache, right: &LookupCache) {
+    assert_eq!(left.headword_lookup(), right.headword_lookup());
+    assert_eq!(left.usage_lookup(), right.usage_lookup());
+}
+
+#[test]
+fn test_incremental_edit_matches_rebuild() {
+    let mut project = project(&[&["a", "b"], &["b", "c", "b"], &["a", "d"]]);
+    let mut cache = rebuilt(&project);
+
+    project.segments[1] = segment(&["a", "e"]);
+    assert!(cache.update_segment(&project, 1));
+
+    assert_same_maps(&cache, &rebuilt(&project));
+    let usage = cache.usage_lookup().expect("maps are built");
+    assert_eq!(usage.get("a"), Some(&vec![0, 1, 2]));
+    assert!(!usage.contains_key("c"));
+}
+
+#[test]
+fn test_incremental_edit_with_normalized_keys() {
+    let mut project = project(&[&["Café"], &["cafe", "x"]]);
+    project.normalize_lookups = true;
+    let mut cache = rebuilt(&project);
+
+    project.segments[0] = segment(&["x"]);
+    assert!(cache.update_segment(&project, 0));
+
+    assert_same_maps(&cache, &rebuilt(&project));
+}
+
+#[test]
+fn test_token_frequency_counts_per_segment() {
+    let mut project = project(&[&["a", "b", "a"], &["c"], &["a"]]);
+    let mut cache = rebuilt(&project);
+    assert_eq!(cache.token_frequency("a"), (3, &[(0, 2), (2, 1)][..]));
+    assert_eq!(cache.token_frequency("z"), (0, &[][..]));
+
+    project.segments[1] = segment(&["a", "a", "a"]);
+    project.segments[0] = segment(&["b"]);
+    assert!(cache.update_segment(&project, 1));
+    assert!(cache.update_segment(&project, 0));
+
+    assert_eq!(cache.token_frequency("a"), (4, &[(1, 3), (2, 1)][..]));
+    assert_eq!(
+        cache.token_frequency("a"),
+        rebuilt(&project).token_frequency("a")
+    );
+    assert_eq!(cache.token_frequency("c"), (0, &[][..]));
+}
+
+#[test]
+fn test_added_segment_requires_rebuild() {
+    let mut project = project(&[&["a"], &["b"]]);
+    let mut cache = rebuilt(&project);
+
+    project.segments.push(segment(&["a", "c"]));
+    assert!(!cache.update_segment(&project, 2));
+
+    cache.rebuild(&project);
+    assert_same_maps(&cache, &rebuilt(&project));
+}
+
+#[test]
+fn test_deleted_segment_requires_rebuild() {
+    let mut project = project(&[&["a"], &["b"], &["a"]]);
+    let mut cache = rebuilt(&project);
+
+    project.segments.remove(1);
+    assert!(!cache.update_segment(&project, 0));
+
+    cache.rebuild(&project);
+    assert_eq!(
+        cache.usage_lookup().and_then(|map| map.get("a")),
+        Some(&vec![0, 1])
+    );
+}
+
+#[test]
+fn test_definition_scopes_select_headword_or_usage_indices() {
+    let project = project(&[&["ka", "ro"], &["ne", "ka"], &["ka"], &["ti", "ro"]]);
+    let cache = rebuilt(&project);
+    let (headword, usage) = (cache.headword_lookup(), cache.usage_lookup());
+    let indices = |scope: DefinitionScope, word: &str| {
+        scope
+            .select(&headword, &usage)
+            .and_then(|map| map.get(word))
+            .cloned()
+            .unwrap_or_default()
+    };
+
+    assert_eq!(indices(DefinitionScope::Headword, "ka"), vec![0, 2]);
+    assert_eq!(indices(DefinitionScope::AnyOccurrence, "ka"), vec![0, 1, 2]);
+    assert!(indices(DefinitionScope::Headword, "ro").is_empty());
+    assert_eq!(indices(DefinitionScope::AnyOccurrence, "ro"), vec![0, 3]);
+}
+
+/// Poll a background rebuild the way the update loop does, giving up after a few seconds
+fn wait_for(build: &mut BackgroundTfidf) -> CachedTfidf {
+    let deadline = Instant::now() + Duration::from_secs(10);
+    loop {
+        if let Some(cache) = build.poll() {
+            return cache;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "background rebuild never finished"
+        );
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_background_rebuild_matches_synchronous_rebuild() {
+    let mut project = project(&[&["a", "b"], &["b", "c"], &["c", "d", "the"]]);
+    project.similarity_by_base_word = true;
+    project.stopwords.insert("the".to_string());
+    let mut expected = CachedTfidf::default();
+    expected.rebuild(&project);
+
+    let mut build = BackgroundTfidf::default();
+    assert!(build.poll().is_none(), "nothing was started");
+    build.start(&project);
+    assert!(build.is_running());
+
+    let cache = wait_for(&mut build);
+    assert!(!build.is_running());
+    assert_eq!(cache.get_matrix(), expected.get_matrix());
+    assert!(build.poll().is_none(), "the result is taken only once");
+}
+
+#[test]
+fn test_background_rebuild_uses_snapshot_taken_at_start() {
+    let mut project = project(&[&["a", "b"], &["b", "c"]]);
+    let mut build = BackgroundTfidf::default();
+    build.start(&project);
+    // Starting again while running keeps the first snapshot
+    project.segments.push(segment(&["x"]));
+    build.start(&project);
+
+    let cache = wait_for(&mut build);
+    assert_eq!(cache.get_matrix().map(|m| m.nrows()), Some(2));
+}
+
+#[test]
+fn test_background_rebuild_of_empty_project_leaves_cache_dirty() {
+    let mut build = BackgroundTfidf::default();
+    build.start(&Project::default());
+
+    assert!(wait_for(&mut build).is_dirty());
+    assert!(!build.is_running());
+}
+
+#[test]
+fn test_suggestion_cache_waits_for_edits_to_pause() {
+    let mut cache = SuggestionCache::default();
+    assert_eq!(cache.remaining_delay(0.0, 0.5), None);
+
+    cache.insert(3, Vec::new());
+    assert!(cache.contains(3));
+    cache.invalidate(10.0);
+    assert!(!cache.contains(3));
+
+    let remaining = cache.remaining_delay(10.2, 0.5).expect("still waiting");
+    assert!((remaining - 0.3).abs() < 1e-9);
+    assert_eq!(cache.remaining_delay(10.5, 0.5), None);
+    assert_eq!(cache.remaining_delay(10.6, 0.5), None);
+}
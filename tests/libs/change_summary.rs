@@ -0,0 +1,43 @@
+use tdector::libs::change_summary::ChangeSummary;
+
+#[test]
+fn test_repeated_edits_to_the_same_item_count_once() {
+    let mut summary = ChangeSummary::default();
+    summary.record_gloss("kaa");
+    summary.record_gloss("kaa");
+    summary.record_gloss("mur");
+    summary.record_translation(4);
+    summary.record_translation(4);
+    summary.record_rule_added();
+
+    assert_eq!(summary.gloss_count(), 2);
+    assert_eq!(summary.translation_count(), 1);
+    assert_eq!(
+        summary.lines(),
+        vec![
+            "2 glosses edited".to_string(),
+            "1 translation edited".to_string(),
+            "1 formation rule added".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_reset_on_save_clears_every_counter() {
+    let mut summary = ChangeSummary::default();
+    summary.record_gloss("kaa");
+    summary.record_translation(0);
+    summary.record_rule_added();
+    summary.record_rule_added();
+    assert!(!summary.is_empty());
+
+    summary.reset();
+
+    assert!(summary.is_empty());
+    assert_eq!(summary.gloss_count(), 0);
+    assert_eq!(summary.translation_count(), 0);
+    assert!(summary.lines().is_empty());
+
+    summary.record_translation(1);
+    assert_eq!(summary.lines(), vec!["1 translation edited".to_string()]);
+}
@@ -0,0 +1,47 @@
+use tdector::libs::cross_refs::{CommentSpan, parse_segment_refs, split_segment_refs};
+
+#[test]
+fn test_parse_multiple_refs() {
+    let refs = parse_segment_refs("see [12] and [3], cf. [7]");
+    assert_eq!(refs, vec![(4..8, 12), (13..16, 3), (22..25, 7)]);
+}
+
+#[test]
+fn test_parse_malformed_brackets() {
+    assert!(parse_segment_refs("[] [a] [12 [ 3] 4] [1a]").is_empty());
+    assert_eq!(parse_segment_refs("[[5]]"), vec![(1..4, 5)]);
+    assert!(parse_segment_refs("[2").is_empty());
+}
+
+#[test]
+fn test_parse_refs_after_unicode() {
+    let text = "語 [2]";
+    let refs = parse_segment_refs(text);
+    assert_eq!(refs.len(), 1);
+    assert_eq!(&text[refs[0].0.clone()], "[2]");
+}
+
+#[test]
+fn test_split_out_of_range_refs_stay_text() {
+    let spans = split_segment_refs("see [0], [2] and [9]", 3);
+    assert_eq!(
+        spans,
+        vec![
+            CommentSpan::Text("see [0], "),
+            CommentSpan::SegmentLink {
+                label: "[2]",
+                segment_idx: 1,
+            },
+            CommentSpan::Text(" and [9]"),
+        ]
+    );
+}
+
+#[test]
+fn test_split_without_refs() {
+    assert_eq!(
+        split_segment_refs("plain note", 10),
+        vec![CommentSpan::Text("plain note")]
+    );
+    assert!(split_segment_refs("", 10).is_empty());
+}
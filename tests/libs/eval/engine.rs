@@ -0,0 +1,139 @@
+use tdector::enums::{AppError, FormationType};
+use tdector::libs::eval::{FormationRule, TokenizationRule, default_cached_ast};
+
+use crate::common;
+
+fn formation_rule(command: &str) -> FormationRule {
+    common::formation_rule("test", FormationType::Derivation, command)
+}
+
+fn tokenization_rule(command: &str) -> TokenizationRule {
+    TokenizationRule {
+        description: "test".to_string(),
+        command: command.to_string(),
+        cached_ast: default_cached_ast(),
+    }
+}
+
+fn is_limit(error: &AppError, what: &str) -> bool {
+    matches!(error, AppError::ScriptLimitExceeded(msg) if msg.contains(what))
+}
+
+#[test]
+fn test_operation_limit_in_transform() {
+    let rule = formation_rule(
+        r#"
+fn transform(word) {
+    let n = 0;
+    loop { n += 1; }
+    word
+}
+"#,
+    );
+
+    let error = rule.apply("word").expect_err("loop never finishes");
+    assert!(is_limit(&error, "operation limit"), "{error}");
+}
+
+#[test]
+fn test_operation_limit_in_tokenize() {
+    let rule = tokenization_rule(
+        r#"
+fn tokenize(line) {
+    let tokens = [];
+    let n = 0;
+    for i in 0..1000000000 { n += i; }
+    tokens
+}
+"#,
+    );
+
+    let error = rule.tokenize("a b").expect_err("loop exceeds the limit");
+    assert!(is_limit(&error, "operation limit"), "{error}");
+}
+
+#[test]
+fn test_depth_limit_from_unbounded_recursion() {
+    let rule = formation_rule(
+        r#"
+fn transform(word) {
+    transform(word + "x")
+}
+"#,
+    );
+
+    let error = rule.apply("word").expect_err("recursion never ends");
+    assert!(is_limit(&error, "depth limit"), "{error}");
+}
+
+#[test]
+fn test_ordinary_errors_are_not_limits() {
+    let rule = formation_rule("fn transform(word) { undefined_function(word) }");
+
+    let error = rule.apply("word").expect_err("function is undefined");
+    assert!(
+        matches!(error, AppError::ScriptExecutionError(_)),
+        "{error}"
+    );
+}
+
+fn transform(body: &str, word: &str) -> String {
+    formation_rule(&format!("fn transform(word) {{ {body} }}"))
+        .apply(word)
+        .expect("helper script runs")
+}
+
+#[test]
+fn test_reverse_helper() {
+    assert_eq!(transform("reverse(word)", "abc"), "cba");
+    assert_eq!(transform("word.reverse()", "ωαβ"), "βαω");
+    assert_eq!(transform("reverse(word)", ""), "");
+}
+
+#[test]
+fn test_capitalize_helper() {
+    assert_eq!(transform("capitalize(word)", "hello"), "Hello");
+    assert_eq!(transform("capitalize(word)", "éclair"), "Éclair");
+    assert_eq!(transform("capitalize(word)", ""), "");
+}
+
+#[test]
+fn test_replace_prefix_helper() {
+    assert_eq!(
+        transform(r#"replace_prefix(word, "un", "re")"#, "undo"),
+        "redo"
+    );
+    assert_eq!(transform(r#"replace_prefix(word, "un", "re")"#, "do"), "do");
+}
+
+#[test]
+fn test_replace_suffix_helper() {
+    assert_eq!(
+        transform(r#"replace_suffix(word, "y", "ies")"#, "party"),
+        "parties"
+    );
+    assert_eq!(
+        transform(r#"replace_suffix(word, "y", "ies")"#, "cat"),
+        "cat"
+    );
+}
+
+#[test]
+fn test_helpers_available_to_tokenize() {
+    let rule = tokenization_rule(
+        r#"
+fn tokenize(line) {
+    let tokens = [];
+    for word in line.split() {
+        tokens.push(capitalize(reverse(word)));
+    }
+    tokens
+}
+"#,
+    );
+
+    assert_eq!(
+        rule.tokenize("ab cd").expect("tokenize runs"),
+        vec!["Ba".to_string(), "Dc".to_string()]
+    );
+}
@@ -0,0 +1,122 @@
+use tdector::enums::FormationType;
+use tdector::libs::eval::{FormationRule, FormationStep, formation_chain, is_noop_result};
+
+use crate::common::formation_rule as rule;
+
+fn rules() -> Vec<FormationRule> {
+    vec![
+        rule(
+            "plural",
+            FormationType::Inflection,
+            r#"fn transform(word) { word + "s" }"#,
+        ),
+        rule(
+            "agent noun",
+            FormationType::Derivation,
+            r#"fn transform(word) { word + "er" }"#,
+        ),
+        rule(
+            "broken",
+            FormationType::Nonmorphological,
+            "fn transform(word) { missing(word) }",
+        ),
+    ]
+}
+
+#[test]
+fn test_chain_applies_two_rules_in_order() {
+    let steps = formation_chain("teach", &[1, 0], &rules());
+
+    assert_eq!(
+        steps,
+        vec![
+            FormationStep::Applied {
+                rule_idx: 1,
+                description: "agent noun".to_string(),
+                rule_type: FormationType::Derivation,
+                result: "teacher".to_string(),
+            },
+            FormationStep::Applied {
+                rule_idx: 0,
+                description: "plural".to_string(),
+                rule_type: FormationType::Inflection,
+                result: "teachers".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_chain_continues_past_failed_and_missing_rules() {
+    let steps = formation_chain("teach", &[2, 7, 1], &rules());
+
+    assert!(matches!(
+        &steps[0],
+        FormationStep::Failed { rule_idx: 2, .. }
+    ));
+    assert_eq!(steps[1], FormationStep::MissingRule { rule_idx: 7 });
+    assert!(matches!(
+        &steps[2],
+        FormationStep::Applied { result, .. } if result == "teacher"
+    ));
+}
+
+#[test]
+fn test_chain_without_rules_is_empty() {
+    assert!(formation_chain("teach", &[], &rules()).is_empty());
+}
+
+#[test]
+fn test_rule_note_round_trips() {
+    let mut noted = rule(
+        "plural",
+        FormationType::Inflection,
+        r#"fn transform(word) { word + "s" }"#,
+    );
+    noted.note = "Regular plural, see grammar §12".to_string();
+
+    let json = serde_json::to_string(&noted).expect("serialize");
+    let loaded: FormationRule = serde_json::from_str(&json).expect("deserialize");
+    assert_eq!(loaded.note, noted.note);
+    assert_eq!(loaded.description, "plural");
+}
+
+#[test]
+fn test_rule_without_note_round_trips() {
+    let plain = rule(
+        "plural",
+        FormationType::Inflection,
+        r#"fn transform(word) { word + "s" }"#,
+    );
+
+    let json = serde_json::to_string(&plain).expect("serialize");
+    assert!(!json.contains("note"));
+    let loaded: FormationRule = serde_json::from_str(&json).expect("deserialize");
+    assert!(loaded.note.is_empty());
+
+    let legacy =
+        r#"{"description":"plural","type":"inflection","command":"fn transform(w) { w }"}"#;
+    let loaded: FormationRule = serde_json::from_str(legacy).expect("deserialize legacy");
+    assert!(loaded.note.is_empty());
+}
+
+#[test]
+fn test_noop_result_detects_rule_that_changes_nothing() {
+    let identity = rule(
+        "identity",
+        FormationType::Nonmorphological,
+        "fn transform(word) { word }",
+    );
+    let plural = &rules()[0];
+
+    let unchanged = identity.apply("kaa").expect("identity applies");
+    assert!(is_noop_result("kaa", &unchanged));
+
+    let changed = plural.apply("kaa").expect("plural applies");
+    assert!(!is_noop_result("kaa", &changed));
+}
+
+#[test]
+fn test_noop_result_ignores_empty_base_word() {
+    assert!(!is_noop_result("", ""));
+}
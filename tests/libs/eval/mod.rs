@@ -0,0 +1,3 @@
+mod engine;
+mod formation;
+mod tokenization;
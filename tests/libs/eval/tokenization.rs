@@ -0,0 +1,64 @@
+use tdector::libs::eval::{TokenizationRule, default_cached_ast};
+
+fn rule(command: &str) -> TokenizationRule {
+    TokenizationRule {
+        description: "test".to_string(),
+        command: command.to_string(),
+        cached_ast: default_cached_ast(),
+    }
+}
+
+fn strings(tokens: &[&str]) -> Vec<String> {
+    tokens.iter().map(|t| t.to_string()).collect()
+}
+
+#[test]
+fn test_verify_accepts_faithful_tokens() {
+    assert!(TokenizationRule::verify(
+        "the cat  sat",
+        &strings(&["the", "cat", "sat"])
+    ));
+    assert!(TokenizationRule::verify("ab c", &strings(&["a", "b", "c"])));
+    assert!(TokenizationRule::verify("   ", &[]));
+}
+
+#[test]
+fn test_verify_rejects_lossy_tokens() {
+    assert!(!TokenizationRule::verify("the cat", &strings(&["the"])));
+    assert!(!TokenizationRule::verify(
+        "the cat",
+        &strings(&["the", "cat", "cat"])
+    ));
+    assert!(!TokenizationRule::verify(
+        "the cat",
+        &strings(&["cat", "the"])
+    ));
+    assert!(!TokenizationRule::verify("abc", &strings(&["ABC"])));
+}
+
+#[test]
+fn test_faithful_tokenizer_has_no_lossy_lines() {
+    let text = "the cat sat\n\nabc def\n";
+
+    let whitespace = TokenizationRule::default_whitespace();
+    let character = TokenizationRule::default_character();
+
+    assert_eq!(whitespace.find_lossy_lines(text).ok(), Some(Vec::new()));
+    assert_eq!(character.find_lossy_lines(text).ok(), Some(Vec::new()));
+}
+
+#[test]
+fn test_lossy_tokenizer_reports_affected_lines() {
+    // Keeps only the first word of each line
+    let lossy = rule(
+        r#"
+fn tokenize(line) {
+    let words = line.split();
+    [words[0]]
+}
+"#,
+    );
+    let text = "single\n\ntwo words\nalone\nthree more words";
+
+    assert_eq!(lossy.find_lossy_lines(text).ok(), Some(vec![3, 5]));
+}
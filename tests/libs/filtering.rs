@@ -0,0 +1,273 @@
+use tdector::consts::domain::FILTER_HISTORY_CAPACITY;
+use tdector::libs::filtering::{FilterHistory, FilterOperation};
+use tdector::libs::{Project, Segment, Token};
+
+fn project(translations: &[&str]) -> Project {
+    Project {
+        segments: translations
+            .iter()
+            .map(|translation| Segment {
+                translation: translation.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_filter_by_translation_keeps_matching_indices_in_order() {
+    let project = project(&["The King rode", "a river", "the kingdom fell", "king"]);
+
+    let filtered = FilterOperation::filter_by_translation(&project, &[3, 0, 1, 2], "KING");
+    assert_eq!(filtered, vec![3, 0, 2]);
+}
+
+#[test]
+fn test_filter_by_translation_empty_query_keeps_all() {
+    let project = project(&["one", "two"]);
+
+    assert_eq!(
+        FilterOperation::filter_by_translation(&project, &[1, 0], "  "),
+        vec![1, 0]
+    );
+}
+
+#[test]
+fn test_filter_by_translation_drops_out_of_range_indices() {
+    let project = project(&["one", "two"]);
+
+    assert_eq!(
+        FilterOperation::filter_by_translation(&project, &[0, 5, 1], ""),
+        vec![0, 1]
+    );
+    assert!(FilterOperation::filter_by_translation(&project, &[0, 1], "three").is_empty());
+}
+
+fn tagged(translations: &[(&str, &[&str])]) -> Project {
+    let mut project = project(&translations.iter().map(|(t, _)| *t).collect::<Vec<_>>());
+    for (segment, (_, tags)) in project.segments.iter_mut().zip(translations) {
+        segment.tags = tags.iter().map(|tag| tag.to_string()).collect();
+    }
+    project
+}
+
+#[test]
+fn test_tag_filter_selects_only_tagged_segments() {
+    let project = tagged(&[
+        ("sing of arms", &["verse"]),
+        ("plain words", &["prose"]),
+        ("a song", &["Verse", "uncertain"]),
+        ("untagged song", &[]),
+    ]);
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "tag:verse"),
+        vec![0, 2]
+    );
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "tag:verse tag:uncertain"),
+        vec![2]
+    );
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "song tag:verse"),
+        vec![2]
+    );
+    assert!(FilterOperation::apply_filter(&project, "tag:missing").is_empty());
+}
+
+#[test]
+fn test_text_filter_ignores_tags() {
+    let project = tagged(&[("one", &["verse"]), ("verse two", &[])]);
+
+    assert_eq!(FilterOperation::apply_filter(&project, "verse"), vec![1]);
+}
+
+#[test]
+fn test_step_match_forward_wraps_to_first() {
+    assert_eq!(FilterOperation::step_match(Some(0), 3, true), Some(1));
+    assert_eq!(FilterOperation::step_match(Some(2), 3, true), Some(0));
+}
+
+#[test]
+fn test_step_match_backward_wraps_to_last() {
+    assert_eq!(FilterOperation::step_match(Some(2), 3, false), Some(1));
+    assert_eq!(FilterOperation::step_match(Some(0), 3, false), Some(2));
+}
+
+#[test]
+fn test_step_match_without_cursor_starts_at_an_end() {
+    assert_eq!(FilterOperation::step_match(None, 4, true), Some(0));
+    assert_eq!(FilterOperation::step_match(None, 4, false), Some(3));
+    // A cursor left over from a larger result set is treated as no cursor
+    assert_eq!(FilterOperation::step_match(Some(9), 4, true), Some(0));
+}
+
+#[test]
+fn test_step_match_with_no_matches() {
+    assert_eq!(FilterOperation::step_match(None, 0, true), None);
+    assert_eq!(FilterOperation::step_match(Some(0), 0, false), None);
+}
+
+#[test]
+fn test_filter_history_dedups_most_recent_first() {
+    let mut history = FilterHistory::default();
+    history.push("kaa");
+    history.push("  mur ");
+    history.push("");
+    history.push("   ");
+    history.push("kaa");
+
+    assert_eq!(history.entries(), ["kaa", "mur"]);
+}
+
+#[test]
+fn test_filter_history_is_capped() {
+    let mut history = FilterHistory::default();
+    for i in 0..FILTER_HISTORY_CAPACITY + 5 {
+        history.push(&format!("query {i}"));
+    }
+
+    let entries = history.entries();
+    assert_eq!(entries.len(), FILTER_HISTORY_CAPACITY);
+    assert_eq!(entries[0], format!("query {}", FILTER_HISTORY_CAPACITY + 4));
+    assert_eq!(entries[FILTER_HISTORY_CAPACITY - 1], "query 5");
+
+    // Re-using an old entry moves it to the front without growing the list
+    history.push("query 10");
+    assert_eq!(history.entries().len(), FILTER_HISTORY_CAPACITY);
+    assert_eq!(history.entries()[0], "query 10");
+}
+
+#[test]
+fn test_reviewed_filter_selects_by_review_status() {
+    let mut project = project(&["first song", "second song", "third verse"]);
+    project.segments[1].reviewed = true;
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "reviewed:true"),
+        vec![1]
+    );
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "reviewed:false"),
+        vec![0, 2]
+    );
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "song reviewed:False"),
+        vec![0]
+    );
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "reviewed:maybe"),
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        FilterOperation::text_term("song reviewed:false").as_deref(),
+        Some("song")
+    );
+}
+
+/// Segments of four tokens each, with 0, 1, 2 and 4 of them glossed (0%, 25%, 50%, 100%)
+fn graded() -> Project {
+    let mut project = project(&["none", "quarter", "half", "complete:all"]);
+    for (segment, glossed) in project.segments.iter_mut().zip([0, 1, 2, 4]) {
+        segment.tokens = (0..4)
+            .map(|i| Token {
+                original: if i < glossed {
+                    format!("known{i}")
+                } else {
+                    format!("unknown{i}")
+                },
+                ..Default::default()
+            })
+            .collect();
+    }
+    for i in 0..4 {
+        project
+            .vocabulary
+            .insert(format!("known{i}"), format!("gloss{i}"));
+    }
+    project
+}
+
+#[test]
+fn test_complete_filter_less_than() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:<50"),
+        vec![0, 1]
+    );
+}
+
+#[test]
+fn test_complete_filter_greater_than() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:>25"),
+        vec![2, 3]
+    );
+}
+
+#[test]
+fn test_complete_filter_at_most() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:<=50"),
+        vec![0, 1, 2]
+    );
+}
+
+#[test]
+fn test_complete_filter_at_least() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:>=25"),
+        vec![1, 2, 3]
+    );
+}
+
+#[test]
+fn test_complete_filter_equal() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:=100"),
+        vec![3]
+    );
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:0"),
+        vec![0]
+    );
+}
+
+#[test]
+fn test_complete_filter_combines_with_text() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:>0 complete:<100 half"),
+        vec![2]
+    );
+    assert_eq!(
+        FilterOperation::text_term("half complete:>0").as_deref(),
+        Some("half")
+    );
+}
+
+#[test]
+fn test_complete_filter_malformed_value_is_literal_text() {
+    let project = graded();
+
+    assert_eq!(
+        FilterOperation::apply_filter(&project, "complete:all"),
+        vec![3]
+    );
+    assert!(FilterOperation::apply_filter(&project, "complete:<lots").is_empty());
+    assert_eq!(
+        FilterOperation::text_term("complete:>x").as_deref(),
+        Some("complete:>x")
+    );
+}
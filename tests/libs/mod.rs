@@ -1 +1,11 @@
+mod cache;
+mod change_summary;
+mod cross_refs;
+mod eval;
+mod filtering;
+mod normalization;
 mod project;
+mod similarity_sentence;
+mod similarity_token;
+mod text_analysis;
+mod tokenizer;
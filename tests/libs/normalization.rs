@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use tdector::libs::normalization::{
+    build_normalized_index, normalize_word, resolve_vocabulary_key, suggest_gloss_key,
+};
+use tdector::libs::project::{convert_to_saved_project, load_project_from_json};
+
+use crate::common::project;
+
+fn vocabulary() -> HashMap<String, String> {
+    let mut vocabulary = HashMap::new();
+    vocabulary.insert("cafe".to_string(), "coffee".to_string());
+    vocabulary.insert("Rome".to_string(), "city".to_string());
+    vocabulary
+}
+
+#[test]
+fn test_normalize_folds_accents() {
+    assert_eq!(normalize_word("é"), "e");
+    assert_eq!(normalize_word("e\u{301}"), "e");
+    assert_eq!(normalize_word("café"), normalize_word("cafe"));
+}
+
+#[test]
+fn test_normalize_folds_latin_extended() {
+    // Latin Extended-A/B and Latin Extended Additional letters all decompose
+    assert_eq!(normalize_word("ǎǐǒǔ"), "aiou");
+    assert_eq!(normalize_word("ṣṭḥ"), "sth");
+    assert_eq!(normalize_word("Việt"), "viet");
+    assert_eq!(normalize_word("ǖ"), "u");
+    // Letters without a decomposition are left as they are
+    assert_eq!(normalize_word("øł"), "øł");
+}
+
+#[test]
+fn test_normalize_folds_case() {
+    assert_eq!(normalize_word("É"), "e");
+    assert_eq!(normalize_word("ROME"), normalize_word("rome"));
+}
+
+#[test]
+fn test_lookup_without_toggle_is_exact() {
+    let vocabulary = vocabulary();
+
+    assert_eq!(resolve_vocabulary_key(&vocabulary, None, "café"), "café");
+    assert_eq!(resolve_vocabulary_key(&vocabulary, None, "rome"), "rome");
+    assert_eq!(resolve_vocabulary_key(&vocabulary, None, "Rome"), "Rome");
+}
+
+#[test]
+fn test_lookup_with_toggle_routes_to_canonical_key() {
+    let vocabulary = vocabulary();
+    let index = build_normalized_index(&vocabulary);
+
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "café"),
+        "cafe"
+    );
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "CAFÉ"),
+        "cafe"
+    );
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "rome"),
+        "Rome"
+    );
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "paris"),
+        "paris"
+    );
+}
+
+#[test]
+fn test_exact_key_wins_over_normalized_match() {
+    let mut vocabulary = vocabulary();
+    vocabulary.insert("café".to_string(), "a place".to_string());
+    let index = build_normalized_index(&vocabulary);
+
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "café"),
+        "café"
+    );
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "CAFE"),
+        "cafe"
+    );
+}
+
+#[test]
+fn test_unglossed_exact_key_routes_to_normalized_match() {
+    let mut vocabulary = vocabulary();
+    vocabulary.insert("Café".to_string(), String::new());
+    let index = build_normalized_index(&vocabulary);
+
+    assert_eq!(
+        resolve_vocabulary_key(&vocabulary, Some(&index), "Café"),
+        "cafe"
+    );
+    assert_eq!(resolve_vocabulary_key(&vocabulary, None, "Café"), "Café");
+}
+
+#[test]
+fn test_lookup_routes_variants_after_save_and_load() {
+    let mut original = project(&[&["cafe", "Café", "CAFÉ"]]);
+    original
+        .vocabulary
+        .insert("cafe".to_string(), "coffee".to_string());
+
+    let saved = convert_to_saved_project(&original).expect("project converts");
+    let value = serde_json::to_value(&saved).expect("project serializes");
+    let loaded = load_project_from_json(value).expect("project loads");
+    assert!(loaded.vocabulary.contains_key("Café"));
+
+    let index = build_normalized_index(&loaded.vocabulary);
+    assert_eq!(
+        resolve_vocabulary_key(&loaded.vocabulary, Some(&index), "Café"),
+        "cafe"
+    );
+    assert_eq!(
+        resolve_vocabulary_key(&loaded.vocabulary, Some(&index), "CAFÉ"),
+        "cafe"
+    );
+}
+
+#[test]
+fn test_gloss_suggestion_offers_glossed_variant() {
+    let mut vocabulary = vocabulary();
+    vocabulary.insert("Café".to_string(), String::new());
+    let index = build_normalized_index(&vocabulary);
+
+    assert_eq!(suggest_gloss_key(&vocabulary, &index, "Café"), Some("cafe"));
+    assert_eq!(suggest_gloss_key(&vocabulary, &index, "rome"), Some("Rome"));
+}
+
+#[test]
+fn test_gloss_suggestion_skips_glossed_and_unrelated_words() {
+    let mut vocabulary = vocabulary();
+    vocabulary.insert("CAFE".to_string(), "café au lait".to_string());
+    vocabulary.insert("kafe".to_string(), String::new());
+    let index = build_normalized_index(&vocabulary);
+
+    assert_eq!(suggest_gloss_key(&vocabulary, &index, "cafe"), None);
+    assert_eq!(suggest_gloss_key(&vocabulary, &index, "CAFE"), None);
+    assert_eq!(suggest_gloss_key(&vocabulary, &index, "kafe"), None);
+    assert_eq!(suggest_gloss_key(&vocabulary, &index, "paris"), None);
+}
+
+#[test]
+fn test_gloss_suggestion_ignores_empty_glosses() {
+    let mut vocabulary = HashMap::new();
+    vocabulary.insert("Alpha".to_string(), String::new());
+    vocabulary.insert("álpha".to_string(), "first".to_string());
+    let index = build_normalized_index(&vocabulary);
+
+    assert_eq!(
+        suggest_gloss_key(&vocabulary, &index, "alpha"),
+        Some("álpha")
+    );
+}
@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tdector::libs::project::operations::category_color;
+use tdector::libs::project::{Project, convert_to_saved_project, load_project_from_json};
+
+use crate::common;
+
+fn project() -> Project {
+    let mut project = common::project(&[&["run", "dog", "quickly"]]);
+    assert!(project.add_category("verb", [200, 40, 40]));
+    assert!(project.add_category("noun", [40, 40, 200]));
+    project.set_word_category("run", Some("verb".to_string()));
+    project.set_word_category("dog", Some("noun".to_string()));
+    project
+}
+
+#[test]
+fn test_category_color_mapping() {
+    let categories: HashMap<String, String> = [
+        ("run".to_string(), "verb".to_string()),
+        ("maybe".to_string(), "particle".to_string()),
+    ]
+    .into_iter()
+    .collect();
+    let palette: BTreeMap<String, [u8; 3]> =
+        [("verb".to_string(), [1, 2, 3])].into_iter().collect();
+
+    assert_eq!(
+        category_color(&categories, &palette, "run"),
+        Some([1, 2, 3])
+    );
+    assert_eq!(category_color(&categories, &palette, "maybe"), None);
+    assert_eq!(category_color(&categories, &palette, "dog"), None);
+}
+
+#[test]
+fn test_categories_survive_save_and_load() {
+    let project = project();
+
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+    let loaded = load_project_from_json(json).expect("project loads");
+
+    assert_eq!(loaded.category_colors, project.category_colors);
+    assert_eq!(loaded.vocabulary_categories, project.vocabulary_categories);
+    assert_eq!(
+        category_color(
+            &loaded.vocabulary_categories,
+            &loaded.category_colors,
+            "dog"
+        ),
+        Some([40, 40, 200])
+    );
+}
+
+#[test]
+fn test_uncategorized_project_omits_category_fields() {
+    let project = Project {
+        segments: project().segments,
+        ..Default::default()
+    };
+
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+
+    assert!(json.get("category_colors").is_none());
+    assert!(json["vocabulary"]["original"][0].get("category").is_none());
+}
+
+#[test]
+fn test_remove_category_unassigns_words() {
+    let mut project = project();
+    assert!(!project.add_category("verb", [0, 0, 0]));
+
+    project.remove_category("verb");
+
+    assert!(!project.category_colors.contains_key("verb"));
+    assert!(!project.vocabulary_categories.contains_key("run"));
+    assert_eq!(project.category_word_counts().get("noun"), Some(&1));
+}
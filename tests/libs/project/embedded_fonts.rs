@@ -0,0 +1,68 @@
+use tdector::libs::project::{
+    CustomFont, Project, convert_to_saved_project, load_project_from_json,
+};
+
+use crate::common::segment;
+
+fn project_with_font(data: Vec<u8>, embed_fonts: bool) -> Project {
+    Project {
+        segments: vec![segment(&["word"])],
+        fonts: vec![CustomFont {
+            name: "script.ttf".to_string(),
+            path: None,
+            data,
+        }],
+        embed_fonts,
+        ..Default::default()
+    }
+}
+
+fn round_trip(project: &Project) -> Project {
+    let saved = convert_to_saved_project(project).expect("project converts");
+    let json = serde_json::to_string(&saved).expect("project serializes");
+    let value = serde_json::from_str(&json).expect("saved JSON parses");
+    load_project_from_json(value).expect("project loads")
+}
+
+#[test]
+fn test_embedded_font_survives_save_and_load() {
+    for len in [0, 1, 2, 3, 4, 5, 256] {
+        let data: Vec<u8> = (0..len).map(|i| (i * 7 % 256) as u8).collect();
+        let loaded = round_trip(&project_with_font(data.clone(), true));
+
+        assert!(loaded.embed_fonts);
+        assert_eq!(loaded.fonts.len(), 1);
+        assert_eq!(loaded.fonts[0].name, "script.ttf");
+        assert_eq!(loaded.fonts[0].data, data, "font of {len} bytes");
+    }
+}
+
+#[test]
+fn test_fonts_not_saved_unless_embedding() {
+    let project = project_with_font(vec![1, 2, 3], false);
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+
+    assert!(json.get("fonts").is_none());
+    assert!(json.get("embed_fonts").is_none());
+    assert!(round_trip(&project).fonts.is_empty());
+}
+
+#[test]
+fn test_embedded_font_is_base64_text() {
+    let project = project_with_font(b"Man".to_vec(), true);
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+
+    assert_eq!(json["fonts"][0]["data"], "TWFu");
+}
+
+#[test]
+fn test_malformed_embedded_font_is_rejected() {
+    let project = project_with_font(b"Man".to_vec(), true);
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let mut json = serde_json::to_value(&saved).expect("project serializes");
+    json["fonts"][0]["data"] = "not*base64".into();
+
+    assert!(load_project_from_json(json).is_err());
+}
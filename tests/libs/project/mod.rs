@@ -1 +1,10 @@
+mod categories;
+mod embedded_fonts;
 mod migrate_v1_to_v2;
+mod no_gloss;
+mod operations;
+mod parse_errors;
+mod phrases;
+mod reviewed;
+mod round_trip;
+mod tags;
@@ -0,0 +1,68 @@
+use tdector::libs::project::{Project, convert_to_saved_project, load_project_from_json};
+use tdector::libs::text_analysis::TextProcessor;
+
+use crate::common;
+
+fn project(words: &[&str]) -> Project {
+    common::project(&[words])
+}
+
+#[test]
+fn test_no_gloss_words_are_not_untranslated() {
+    let mut project = project(&["ka", ",", "tu", ","]);
+    project
+        .vocabulary
+        .insert("ka".to_string(), "cat".to_string());
+
+    assert_eq!(
+        project.untranslated_words(),
+        vec![(",".to_string(), 2), ("tu".to_string(), 1)]
+    );
+
+    assert!(project.set_no_gloss(",", true));
+    assert!(!project.set_no_gloss(",", true));
+    assert_eq!(project.untranslated_words(), vec![("tu".to_string(), 1)]);
+    assert!(!project.needs_gloss(","));
+    assert!(project.needs_gloss("tu"));
+    assert_eq!(project.vocabulary[","], "");
+
+    assert_eq!(
+        TextProcessor::count_segment_translated_tokens(&project.segments[0], &project),
+        3
+    );
+    assert_eq!(project.next_untranslated_token(0, 0, None), Some((0, 2)));
+    assert_eq!(project.next_untranslated_token(0, 2, None), None);
+
+    assert!(project.set_no_gloss(",", false));
+    assert_eq!(project.untranslated_words().len(), 2);
+}
+
+#[test]
+fn test_no_gloss_mark_survives_save_and_load() {
+    let mut project = project(&["ka", "."]);
+    project.set_no_gloss(".", true);
+
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+    let entries = json["vocabulary"]["original"]
+        .as_array()
+        .expect("vocabulary entries");
+    for entry in entries {
+        let marked = entry["word"] == ".";
+        assert_eq!(entry.get("no_gloss").is_some(), marked, "{entry}");
+    }
+
+    let loaded = load_project_from_json(json).expect("project loads");
+    assert!(loaded.no_gloss_words.contains("."));
+    assert!(!loaded.no_gloss_words.contains("ka"));
+}
+
+#[test]
+fn test_renamed_word_keeps_no_gloss_mark() {
+    let mut project = project(&["-"]);
+    project.set_no_gloss("-", true);
+    project.rename_word("-", "—");
+
+    assert!(project.no_gloss_words.contains("—"));
+    assert!(!project.no_gloss_words.contains("-"));
+}
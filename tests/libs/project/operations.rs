@@ -0,0 +1,802 @@
+use tdector::enums::{FormationType, PunctuationMode, SegmentationMode};
+use tdector::libs::eval::FormationRule;
+use tdector::libs::project::operations::{
+    CycleError, RenameOutcome, resolve_base_chain, strip_affix,
+};
+use tdector::libs::project::{CustomFont, ImportSettings};
+use tdector::libs::{Project, Segment};
+
+use crate::common::{derived, formation_rule, project, segment, token};
+
+#[test]
+fn test_token_frequency_counts_repeats_within_segment() {
+    let project = project(&[&["a", "b", "a"], &["c"], &["a", "c"]]);
+
+    let (total, per_segment) = project.token_frequency("a");
+    assert_eq!(total, 3);
+    assert_eq!(per_segment, vec![(0, 2), (2, 1)]);
+}
+
+#[test]
+fn test_token_frequency_missing_word() {
+    let project = project(&[&["a", "b"]]);
+
+    let (total, per_segment) = project.token_frequency("z");
+    assert_eq!(total, 0);
+    assert!(per_segment.is_empty());
+}
+
+#[test]
+fn test_token_frequency_respects_normalization() {
+    let mut project = project(&[&["Café", "cafe"], &["CAFE"]]);
+
+    assert_eq!(project.token_frequency("cafe").0, 1);
+
+    project.normalize_lookups = true;
+    let (total, per_segment) = project.token_frequency("cafe");
+    assert_eq!(total, 3);
+    assert_eq!(per_segment, vec![(0, 2), (1, 1)]);
+}
+
+#[test]
+fn test_merge_segments_combines_tokens_and_translation() {
+    let mut project = project(&[&["a", "b"], &["c"], &["d"]]);
+    project.segments[0].translation = "first".to_string();
+    project.segments[1].translation = "second".to_string();
+
+    assert!(project.merge_segments(0));
+
+    assert_eq!(project.segments.len(), 2);
+    let words: Vec<&str> = project.segments[0]
+        .tokens
+        .iter()
+        .map(|t| t.original.as_str())
+        .collect();
+    assert_eq!(words, vec!["a", "b", "c"]);
+    assert_eq!(project.segments[0].translation, "first second");
+    assert_eq!(project.segments[1].tokens[0].original, "d");
+}
+
+#[test]
+fn test_merge_segments_skips_empty_translation() {
+    let mut project = project(&[&["a"], &["b"]]);
+    project.segments[1].translation = "only".to_string();
+
+    assert!(project.merge_segments(0));
+    assert_eq!(project.segments[0].translation, "only");
+}
+
+#[test]
+fn test_merge_segments_unions_tags() {
+    let mut project = project(&[&["a"], &["b"]]);
+    project.segments[0].tags = vec!["draft".to_string(), "names".to_string()];
+    project.segments[1].tags = vec!["Names".to_string(), "poem".to_string()];
+
+    assert!(project.merge_segments(0));
+    assert_eq!(project.segments[0].tags, vec!["draft", "names", "poem"]);
+}
+
+#[test]
+fn test_merge_segments_reviewed_only_when_both_were() {
+    let mut project = project(&[&["a"], &["b"], &["c"], &["d"]]);
+    project.segments[0].reviewed = true;
+    project.segments[2].reviewed = true;
+    project.segments[3].reviewed = true;
+
+    assert!(project.merge_segments(0));
+    assert!(!project.segments[0].reviewed);
+    assert!(project.merge_segments(1));
+    assert!(project.segments[1].reviewed);
+}
+
+#[test]
+fn test_merge_segments_keeps_first_font_then_falls_back() {
+    let mut project = project(&[&["a"], &["b"], &["c"], &["d"]]);
+    project.segments[0].font = Some(1);
+    project.segments[1].font = Some(2);
+    project.segments[3].font = Some(2);
+
+    assert!(project.merge_segments(0));
+    assert_eq!(project.segments[0].font, Some(1));
+    assert!(project.merge_segments(1));
+    assert_eq!(project.segments[1].font, Some(2));
+}
+
+#[test]
+fn test_merge_segments_rejects_last_segment() {
+    let mut project = project(&[&["a"], &["b"]]);
+
+    assert!(!project.merge_segments(1));
+    assert!(!project.merge_segments(5));
+    assert_eq!(project.segments.len(), 2);
+}
+
+#[test]
+fn test_remove_segment_shifts_following_indices() {
+    let mut project = project(&[&["a"], &["b"], &["c"]]);
+
+    let removed = project.remove_segment(1).expect("index 1 is in bounds");
+    assert_eq!(removed.tokens[0].original, "b");
+    assert_eq!(project.segments.len(), 2);
+    assert_eq!(project.segments[1].tokens[0].original, "c");
+}
+
+#[test]
+fn test_remove_segment_out_of_bounds() {
+    let mut project = project(&[&["a"]]);
+
+    assert!(project.remove_segment(1).is_none());
+    assert_eq!(project.segments.len(), 1);
+    assert!(project.remove_segment(0).is_some());
+    assert!(project.remove_segment(0).is_none());
+}
+
+#[test]
+fn test_find_duplicate_segments_exact() {
+    let project = project(&[&["a", "b"], &["c"], &["a", "b"], &["c"], &["a", "b"]]);
+
+    assert_eq!(
+        project.find_duplicate_segments(),
+        vec![vec![0, 2, 4], vec![1, 3]]
+    );
+}
+
+#[test]
+fn test_find_duplicate_segments_ignores_whitespace() {
+    let project = project(&[&["a ", "b"], &[" a", "b\n"], &["a", " ", "b"]]);
+
+    assert_eq!(project.find_duplicate_segments(), vec![vec![0, 1, 2]]);
+}
+
+#[test]
+fn test_find_duplicate_segments_shared_prefix_is_not_duplicate() {
+    let project = project(&[&["a", "b"], &["a", "b", "c"], &["a"], &[], &[]]);
+
+    assert!(project.find_duplicate_segments().is_empty());
+}
+
+#[test]
+fn test_word_frequencies_counts_and_gloss_status() {
+    let mut project = project(&[&["b", "a", "b"], &["c", "b"], &["a"]]);
+    project
+        .vocabulary
+        .insert("a".to_string(), "gloss".to_string());
+    project.vocabulary.insert("c".to_string(), String::new());
+
+    let frequencies = project.word_frequencies();
+    let rows: Vec<(&str, usize, bool)> = frequencies
+        .iter()
+        .map(|row| (row.word.as_str(), row.count, row.glossed))
+        .collect();
+    assert_eq!(rows, vec![("b", 3, false), ("a", 2, true), ("c", 1, false)]);
+}
+
+#[test]
+fn test_word_frequencies_gloss_derived_tokens_through_base_word() {
+    let mut project = project(&[&["kaas", "kaa", "kaas"]]);
+    for token in &mut project.segments[0].tokens {
+        if token.original == "kaas" {
+            token.base_word = Some("kaa".to_string());
+            token.formation_rule_indices = vec![0];
+        }
+    }
+    project
+        .vocabulary
+        .insert("kaa".to_string(), "water".to_string());
+
+    let rows: Vec<(String, usize, bool)> = project
+        .word_frequencies()
+        .into_iter()
+        .map(|row| (row.word, row.count, row.glossed))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![("kaas".to_string(), 2, true), ("kaa".to_string(), 1, true)]
+    );
+}
+
+#[test]
+fn test_vocabulary_frequencies_attribute_derived_tokens_to_base_word() {
+    let mut project = project(&[&["kaa", "kaas"], &["kaas", "mur"]]);
+    for token in project
+        .segments
+        .iter_mut()
+        .flat_map(|segment| &mut segment.tokens)
+        .filter(|token| token.original == "kaas")
+    {
+        token.base_word = Some("kaa".to_string());
+        token.formation_rule_indices = vec![0];
+    }
+    project
+        .vocabulary
+        .insert("kaa".to_string(), "water".to_string());
+    project.vocabulary.insert("mur".to_string(), String::new());
+    project.vocabulary.insert("tel".to_string(), String::new());
+
+    let rows: Vec<(String, usize, bool)> = project
+        .vocabulary_frequencies()
+        .into_iter()
+        .map(|row| (row.word, row.count, row.glossed))
+        .collect();
+    assert_eq!(
+        rows,
+        vec![
+            ("kaa".to_string(), 3, true),
+            ("mur".to_string(), 1, false),
+            ("tel".to_string(), 0, false),
+        ]
+    );
+}
+
+#[test]
+fn test_remove_font_remaps_segment_overrides() {
+    let mut project = project(&[&["a"], &["b"], &["c"], &["d"]]);
+    project.fonts = ["one.ttf", "two.ttf", "three.ttf"]
+        .iter()
+        .map(|name| CustomFont {
+            name: name.to_string(),
+            ..Default::default()
+        })
+        .collect();
+    for (segment, font) in project
+        .segments
+        .iter_mut()
+        .zip([None, Some(0), Some(1), Some(2)])
+    {
+        segment.font = font;
+    }
+
+    let removed = project.remove_font(1).expect("font exists");
+
+    assert_eq!(removed.name, "two.ttf");
+    let fonts: Vec<Option<usize>> = project.segments.iter().map(|s| s.font).collect();
+    assert_eq!(fonts, vec![None, Some(0), None, Some(1)]);
+    assert!(project.remove_font(5).is_none());
+}
+
+fn past_tense_rule() -> FormationRule {
+    formation_rule(
+        "past tense",
+        FormationType::Inflection,
+        r#"fn transform(word) { if word.ends_with("e") { word + "d" } else { word + "ed" } }"#,
+    )
+}
+
+#[test]
+fn test_strip_affix_patterns() {
+    assert_eq!(strip_affix("walked", "-ed").as_deref(), Some("walk"));
+    assert_eq!(strip_affix("walked", "ed").as_deref(), Some("walk"));
+    assert_eq!(strip_affix("undo", "un-").as_deref(), Some("do"));
+    assert_eq!(strip_affix("walk", "-ed"), None);
+    assert_eq!(strip_affix("ed", "-ed"), None);
+    assert_eq!(strip_affix("walked", "-"), None);
+}
+
+#[test]
+fn test_round_trip_validation() {
+    let rule = past_tense_rule();
+
+    assert!(rule.round_trips("walk", "walked"));
+    assert!(rule.round_trips("bake", "baked"));
+    assert!(rule.round_trips("bak", "baked"));
+    assert!(!rule.round_trips("walk", "walks"));
+    assert!(!rule.round_trips("fe", "feed"));
+}
+
+#[test]
+fn test_propose_rule_matches_flags_non_round_trips() {
+    let mut project = project(&[&["walked", "baked", "feed"], &["walked", "walk"]]);
+    project.formation_rules.push(past_tense_rule());
+
+    let proposals = project.propose_rule_matches(0, "-ed");
+    let found: Vec<(&str, &str, usize, bool)> = proposals
+        .iter()
+        .map(|m| {
+            (
+                m.word.as_str(),
+                m.base_word.as_str(),
+                m.count,
+                m.round_trips,
+            )
+        })
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("baked", "bak", 1, true),
+            ("feed", "fe", 1, false),
+            ("walked", "walk", 2, true),
+        ]
+    );
+    assert!(project.propose_rule_matches(3, "-ed").is_empty());
+}
+
+#[test]
+fn test_derive_word_updates_tokens_and_vocabulary() {
+    let mut project = project(&[&["walked", "home"], &["walked"]]);
+    project
+        .vocabulary
+        .insert("walked".to_string(), "went on foot".to_string());
+
+    assert_eq!(project.derive_word("walked", "walk", 0), 2);
+
+    for segment in &project.segments {
+        let token = &segment.tokens[0];
+        assert_eq!(token.original, "walked");
+        assert_eq!(token.base_word.as_deref(), Some("walk"));
+        assert_eq!(token.formation_rule_indices, vec![0]);
+    }
+    assert!(!project.vocabulary.contains_key("walked"));
+    assert!(project.vocabulary.contains_key("walk"));
+    assert_eq!(project.derive_word("walked", "walk", 0), 0);
+}
+
+fn rule_set(project: &mut Project, count: usize) {
+    for idx in 0..count {
+        let mut rule = past_tense_rule();
+        rule.description = format!("rule {idx}");
+        project.formation_rules.push(rule);
+    }
+}
+
+#[test]
+fn test_remove_middle_formation_rule_remaps_indices() {
+    let mut project = project(&[&["plain"]]);
+    rule_set(&mut project, 3);
+    project.segments[0].tokens.push(derived("ab", "a", &[0, 2]));
+    project.segments[0].tokens.push(derived("cd", "c", &[2]));
+
+    let removed = project.remove_formation_rule(1).expect("rule unused");
+
+    assert_eq!(removed.description, "rule 1");
+    let chains: Vec<Vec<usize>> = project.segments[0]
+        .tokens
+        .iter()
+        .map(|t| t.formation_rule_indices.clone())
+        .collect();
+    assert_eq!(chains, vec![vec![], vec![0, 1], vec![1]]);
+    let descriptions: Vec<&str> = project
+        .formation_rules
+        .iter()
+        .map(|r| r.description.as_str())
+        .collect();
+    assert_eq!(descriptions, vec!["rule 0", "rule 2"]);
+}
+
+#[test]
+fn test_remove_used_formation_rule_is_refused() {
+    let mut project = project(&[]);
+    rule_set(&mut project, 2);
+    project.segments.push(Segment {
+        tokens: vec![derived("ab", "a", &[1])],
+        ..Default::default()
+    });
+
+    assert_eq!(project.rule_usage_counts(), vec![0, 1]);
+    assert!(project.remove_formation_rule(1).is_none());
+    assert!(project.remove_formation_rule(2).is_none());
+    assert_eq!(project.formation_rules.len(), 2);
+
+    assert_eq!(project.clear_formation_rule(1), 1);
+    let token = &project.segments[0].tokens[0];
+    assert_eq!(token.original, "ab");
+    assert!(token.base_word.is_none());
+    assert!(token.formation_rule_indices.is_empty());
+    assert!(project.remove_formation_rule(1).is_some());
+}
+
+#[test]
+fn test_next_untranslated_token_in_reading_order() {
+    let mut project = project(&[&["a", "b", "c"], &["b"], &["d", "a"]]);
+    project
+        .vocabulary
+        .insert("a".to_string(), "one".to_string());
+    project
+        .vocabulary
+        .insert("b".to_string(), "two".to_string());
+    project.vocabulary.insert("c".to_string(), String::new());
+
+    assert_eq!(project.next_untranslated_token(0, 0, None), Some((0, 2)));
+    assert_eq!(project.next_untranslated_token(0, 2, None), Some((2, 0)));
+    assert_eq!(project.next_untranslated_token(2, 0, None), None);
+}
+
+#[test]
+fn test_next_untranslated_token_skips_derived_tokens() {
+    let mut project = project(&[&["a"]]);
+    project.segments[0].tokens.push(derived("ab", "a", &[0]));
+    project.segments[0].tokens.push(token("z"));
+
+    assert_eq!(project.next_untranslated_token(0, 0, None), Some((0, 2)));
+}
+
+#[test]
+fn test_locked_segment_ignores_edits() {
+    let mut project = project(&[&["a", "b"], &["c"]]);
+    project.segments[0].translation = "final".to_string();
+    project.segments[0].locked = true;
+
+    let segment = &mut project.segments[0];
+    assert!(!segment.set_translation("changed".to_string()));
+    assert!(!segment.set_phrase_gloss(0, 1, "ab".to_string()));
+    assert_eq!(segment.translation, "final");
+    assert!(segment.phrases.is_empty());
+    assert!(!project.merge_segments(0));
+    assert_eq!(project.segments.len(), 2);
+
+    project.segments[0].locked = false;
+    assert!(project.segments[0].set_translation("changed".to_string()));
+    assert!(!project.segments[0].set_translation("changed".to_string()));
+    assert_eq!(project.segments[0].translation, "changed");
+}
+
+#[test]
+fn test_rename_word_moves_tokens_and_entries() {
+    let mut project = project(&[&["Walk", "home"], &["Walk"]]);
+    project.segments[1].tokens[0].base_word = Some("Walk".to_string());
+    project
+        .vocabulary
+        .insert("Walk".to_string(), "go on foot".to_string());
+    project
+        .vocabulary_comments
+        .insert("Walk".to_string(), "typo".to_string());
+    project
+        .vocabulary_categories
+        .insert("Walk".to_string(), "verb".to_string());
+
+    assert_eq!(
+        project.rename_word("Walk", "Wolk"),
+        RenameOutcome::Renamed(2)
+    );
+
+    assert_eq!(project.segments[0].tokens[0].original, "Wolk");
+    assert_eq!(project.segments[1].tokens[0].original, "Wolk");
+    assert_eq!(
+        project.segments[1].tokens[0].base_word.as_deref(),
+        Some("Wolk")
+    );
+    assert_eq!(project.segments[0].tokens[1].original, "home");
+    assert!(!project.vocabulary.contains_key("Walk"));
+    assert_eq!(project.vocabulary["Wolk"], "go on foot");
+    assert_eq!(project.vocabulary_comments["Wolk"], "typo");
+    assert_eq!(project.vocabulary_categories["Wolk"], "verb");
+}
+
+#[test]
+fn test_rename_word_rederives_tokens_built_on_it() {
+    let mut project = project(&[&["bake", "baked"]]);
+    project.formation_rules.push(past_tense_rule());
+    project.derive_word("baked", "bake", 0);
+    project
+        .formatted_word_comments
+        .insert("baked".to_string(), "oven".to_string());
+
+    assert_eq!(
+        project.rename_word("bake", "cook"),
+        RenameOutcome::Renamed(2)
+    );
+
+    let derived = &project.segments[0].tokens[1];
+    assert_eq!(derived.original, "cooked");
+    assert_eq!(derived.base_word.as_deref(), Some("cook"));
+    assert_eq!(project.formatted_word_comments["cooked"], "oven");
+}
+
+#[test]
+fn test_rename_word_refuses_existing_target() {
+    let mut project = project(&[&["colour", "color"]]);
+    project
+        .vocabulary
+        .insert("colour".to_string(), "hue".to_string());
+
+    assert_eq!(
+        project.rename_word("colour", "color"),
+        RenameOutcome::TargetExists
+    );
+    assert_eq!(project.segments[0].tokens[0].original, "colour");
+    assert_eq!(project.vocabulary["colour"], "hue");
+}
+
+#[test]
+fn test_rename_merge_keeps_target_entries() {
+    let mut project = project(&[&["colour", "color"]]);
+    project
+        .vocabulary
+        .insert("colour".to_string(), "hue".to_string());
+    project
+        .vocabulary
+        .insert("color".to_string(), "tint".to_string());
+    project
+        .vocabulary_comments
+        .insert("colour".to_string(), "British".to_string());
+
+    assert_eq!(
+        project.merge_words("colour", "color"),
+        RenameOutcome::Merged(1)
+    );
+
+    let words: Vec<&str> = project.segments[0]
+        .tokens
+        .iter()
+        .map(|token| token.original.as_str())
+        .collect();
+    assert_eq!(words, vec!["color", "color"]);
+    assert!(!project.vocabulary.contains_key("colour"));
+    assert_eq!(project.vocabulary["color"], "tint");
+    assert_eq!(project.vocabulary_comments["color"], "British");
+}
+
+#[test]
+fn test_rename_word_unchanged_cases() {
+    let mut project = project(&[&["a"]]);
+
+    assert_eq!(project.rename_word("a", "a"), RenameOutcome::Unchanged);
+    assert_eq!(project.rename_word("a", "  "), RenameOutcome::Unchanged);
+    assert_eq!(project.rename_word("zzz", "b"), RenameOutcome::Unchanged);
+}
+
+#[test]
+fn test_merge_words_consolidates_occurrences_glosses_and_comments() {
+    let mut project = project(&[&["grey", "cat"], &["gray", "grey"]]);
+    project
+        .vocabulary
+        .insert("grey".to_string(), "a colour".to_string());
+    project
+        .vocabulary
+        .insert("gray".to_string(), "a color".to_string());
+    project
+        .vocabulary_comments
+        .insert("grey".to_string(), "British spelling".to_string());
+    project
+        .vocabulary_comments
+        .insert("gray".to_string(), "American spelling".to_string());
+    project
+        .vocabulary_categories
+        .insert("grey".to_string(), "adjective".to_string());
+
+    assert_eq!(
+        project.merge_words("grey", "gray"),
+        RenameOutcome::Merged(2)
+    );
+
+    let (total, per_segment) = project.token_frequency("gray");
+    assert_eq!(total, 3);
+    assert_eq!(per_segment, vec![(0, 1), (1, 2)]);
+    assert_eq!(project.token_frequency("grey").0, 0);
+    assert!(!project.vocabulary.contains_key("grey"));
+    assert_eq!(project.vocabulary["gray"], "a color");
+    assert_eq!(
+        project.vocabulary_comments["gray"],
+        "American spelling\nBritish spelling"
+    );
+    assert!(!project.vocabulary_comments.contains_key("grey"));
+    assert_eq!(project.vocabulary_categories["gray"], "adjective");
+}
+
+#[test]
+fn test_merge_words_fills_empty_target_gloss() {
+    let mut project = project(&[&["teh", "the"]]);
+    project
+        .vocabulary
+        .insert("teh".to_string(), "definite article".to_string());
+    project.vocabulary.insert("the".to_string(), String::new());
+
+    assert_eq!(project.merge_words("teh", "the"), RenameOutcome::Merged(1));
+    assert_eq!(project.vocabulary["the"], "definite article");
+}
+
+#[test]
+fn test_untranslated_words_counts_only_unglossed_tokens() {
+    let mut project = project(&[&["the", "dog", "barked"], &["the", "cat", "mewed", "cat"]]);
+    project.formation_rules.push(past_tense_rule());
+    project.derive_word("barked", "bark", 0);
+    project.derive_word("mewed", "mew", 0);
+    project
+        .vocabulary
+        .insert("the".to_string(), "DEF".to_string());
+    project
+        .vocabulary
+        .insert("bark".to_string(), "woof".to_string());
+    project
+        .vocabulary
+        .insert("cat".to_string(), " ".to_string());
+
+    assert_eq!(
+        project.untranslated_words(),
+        vec![
+            ("cat".to_string(), 2),
+            ("dog".to_string(), 1),
+            ("mewed".to_string(), 1),
+        ]
+    );
+}
+
+fn translations(project: &Project) -> Vec<&str> {
+    project
+        .segments
+        .iter()
+        .map(|segment| segment.translation.as_str())
+        .collect()
+}
+
+#[test]
+fn test_parallel_translations_matched_line_count() {
+    let mut project = project(&[&["a"], &["b"], &["c"]]);
+
+    let set = project.apply_parallel_translations(&["one", "two ", "three"]);
+
+    assert_eq!(set, 3);
+    assert_eq!(translations(&project), vec!["one", "two", "three"]);
+}
+
+#[test]
+fn test_parallel_translations_fewer_lines_than_segments() {
+    let mut project = project(&[&["a"], &["b"], &["c"]]);
+    project.segments[2].translation = "kept".to_string();
+
+    let set = project.apply_parallel_translations(&["one", ""]);
+
+    assert_eq!(set, 1);
+    assert_eq!(translations(&project), vec!["one", "", "kept"]);
+}
+
+#[test]
+fn test_parallel_translations_more_lines_than_segments() {
+    let mut project = project(&[&["a"], &["b"]]);
+    project.segments[1].locked = true;
+
+    let set = project.apply_parallel_translations(&["one", "two", "three"]);
+
+    assert_eq!(set, 1);
+    assert_eq!(translations(&project), vec!["one", ""]);
+}
+
+#[test]
+fn test_import_defaults_guess_from_text_without_stored_settings() {
+    let project = Project::default();
+
+    assert_eq!(
+        project.import_defaults("这是一个没有空格的句子"),
+        ImportSettings {
+            mode: SegmentationMode::Character,
+            punctuation: PunctuationMode::Keep,
+        }
+    );
+    assert_eq!(
+        project.import_defaults("words split by spaces").mode,
+        SegmentationMode::Whitespace
+    );
+}
+
+#[test]
+fn test_import_defaults_prefer_stored_settings_over_guess() {
+    let stored = ImportSettings {
+        mode: SegmentationMode::Whitespace,
+        punctuation: PunctuationMode::Separate,
+    };
+    let project = Project {
+        import_settings: Some(stored),
+        ..Default::default()
+    };
+
+    assert_eq!(project.import_defaults("这是一个没有空格的句子"), stored);
+}
+
+#[test]
+fn test_resolve_base_chain_follows_derived_base_words() {
+    let mut project = project(&[&["walk"]]);
+    project.segments.push(Segment {
+        tokens: vec![
+            derived("walked", "walk", &[0]),
+            derived("walkeds", "walked", &[1]),
+        ],
+        ..Default::default()
+    });
+
+    let plain = &project.segments[0].tokens[0];
+    assert_eq!(
+        resolve_base_chain(&project, plain),
+        Ok(("walk".to_string(), Vec::new()))
+    );
+    let nested = &project.segments[1].tokens[1];
+    assert_eq!(
+        resolve_base_chain(&project, nested),
+        Ok(("walk".to_string(), vec![0, 1]))
+    );
+    assert!(project.cyclic_derived_words().is_empty());
+}
+
+#[test]
+fn test_resolve_base_chain_detects_cycles() {
+    let mut project = Project::default();
+    project.segments.push(Segment {
+        tokens: vec![
+            derived("kaa", "mur", &[0]),
+            derived("mur", "kaa", &[0]),
+            derived("tel", "tel", &[1]),
+        ],
+        ..Default::default()
+    });
+
+    let tokens = &project.segments[0].tokens;
+    assert_eq!(
+        resolve_base_chain(&project, &tokens[0]),
+        Err(CycleError {
+            chain: vec!["kaa".to_string(), "mur".to_string(), "kaa".to_string()],
+        })
+    );
+    assert_eq!(
+        resolve_base_chain(&project, &tokens[2]),
+        Err(CycleError {
+            chain: vec!["tel".to_string(), "tel".to_string()],
+        })
+    );
+    assert_eq!(project.cyclic_derived_words(), vec!["kaa", "mur", "tel"]);
+}
+
+#[test]
+fn test_derive_word_rejects_cyclic_base() {
+    let mut project = project(&[&["mur", "sa"]]);
+    project.formation_rules.push(past_tense_rule());
+    project.segments[0].tokens.push(derived("kaa", "mur", &[0]));
+
+    assert!(project.derivation_cycles("mur", "kaa"));
+    assert!(project.derivation_cycles("sa", "sa"));
+    assert_eq!(project.derive_word("mur", "kaa", 0), 0);
+    assert_eq!(project.derive_word("sa", "sa", 0), 0);
+    assert!(
+        project.segments[0].tokens[0]
+            .formation_rule_indices
+            .is_empty()
+    );
+
+    assert!(!project.derivation_cycles("sa", "mur"));
+    assert_eq!(project.derive_word("sa", "mur", 0), 1);
+    assert!(project.cyclic_derived_words().is_empty());
+}
+
+#[test]
+fn test_append_segments_adds_after_existing_and_merges_vocabulary() {
+    let mut project = project(&[&["kaa", "mur"], &["tel"]]);
+    project.segments[0].translation = "the river".to_string();
+    for word in ["kaa", "mur", "tel"] {
+        project.vocabulary.insert(word.to_string(), String::new());
+    }
+    project
+        .vocabulary
+        .insert("kaa".to_string(), "water".to_string());
+
+    let added = project.append_segments(vec![segment(&["kaa", "sa"]), segment(&["ru", "sa"])]);
+
+    assert_eq!(added, 2);
+    assert_eq!(project.segments.len(), 4);
+    assert_eq!(project.segments[0].translation, "the river");
+    assert_eq!(project.segments[1].tokens[0].original, "tel");
+    assert_eq!(project.segments[2].tokens[1].original, "sa");
+    assert_eq!(project.segments[3].tokens[0].original, "ru");
+    assert_eq!(project.vocabulary.len(), 5);
+    assert_eq!(project.vocabulary["kaa"], "water");
+    assert_eq!(project.vocabulary["sa"], "");
+    assert_eq!(project.token_frequency("sa").0, 2);
+    assert_eq!(project.token_frequency("kaa").0, 2);
+}
+
+#[test]
+fn test_seed_vocabulary_from_segment_adds_missing_base_words() {
+    let mut project = project(&[&["kaa", "mur", "kaa"], &["tel"]]);
+    project.segments[0].tokens.push(derived("sa-s", "sa", &[0]));
+    project
+        .vocabulary
+        .insert("kaa".to_string(), "water".to_string());
+
+    assert_eq!(project.seed_vocabulary_from_segment(0), 2);
+    assert_eq!(project.vocabulary.len(), 3);
+    assert_eq!(project.vocabulary["kaa"], "water");
+    assert_eq!(project.vocabulary["mur"], "");
+    assert_eq!(project.vocabulary["sa"], "");
+    assert!(!project.vocabulary.contains_key("sa-s"));
+    assert!(!project.vocabulary.contains_key("tel"));
+
+    assert_eq!(project.seed_vocabulary_from_segment(0), 0);
+    assert_eq!(project.seed_vocabulary_from_segment(5), 0);
+}
@@ -0,0 +1,28 @@
+use tdector::libs::project::parse_project_json;
+
+#[test]
+fn test_malformed_json_reports_line_and_column() {
+    let text = "{\n  \"version\": 2,\n  \"project_name\": \"Sample\"\n  \"sentences\": []\n}";
+    let error = parse_project_json(text).expect_err("missing comma should fail");
+
+    assert!(
+        error.starts_with("Parse error at line 4, column 3: "),
+        "{error}"
+    );
+    assert!(!error.ends_with("column 3"), "{error}");
+}
+
+#[test]
+fn test_truncated_json_reports_position_of_end() {
+    let error = parse_project_json("{\n  \"version\": 2,\n").expect_err("truncated file");
+    assert!(
+        error.starts_with("Parse error at line 3, column 0: "),
+        "{error}"
+    );
+}
+
+#[test]
+fn test_valid_json_parses() {
+    let value = parse_project_json("{\"version\": 2}").expect("valid JSON");
+    assert_eq!(value["version"], 2);
+}
@@ -0,0 +1,128 @@
+use tdector::libs::project::models::PhraseGloss;
+use tdector::libs::project::{Project, convert_to_saved_project, load_project_from_json};
+
+use crate::common::segment;
+
+fn phrase(start: usize, end: usize, gloss: &str) -> PhraseGloss {
+    PhraseGloss {
+        start,
+        end,
+        gloss: gloss.to_string(),
+    }
+}
+
+fn round_trip(project: &Project) -> Project {
+    let saved = convert_to_saved_project(project).expect("project converts");
+    let json = serde_json::to_string(&saved).expect("project serializes");
+    let value = serde_json::from_str(&json).expect("saved JSON parses");
+    load_project_from_json(value).expect("project loads")
+}
+
+#[test]
+fn test_phrase_glosses_survive_save_and_load() {
+    let mut glossed = segment(&["kick", "the", "bucket", "today"]);
+    assert!(glossed.set_phrase_gloss(0, 2, "die".to_string()));
+    let project = Project {
+        segments: vec![glossed, segment(&["plain"])],
+        ..Default::default()
+    };
+
+    let loaded = round_trip(&project);
+
+    assert_eq!(loaded.segments[0].phrases, vec![phrase(0, 2, "die")]);
+    assert!(loaded.segments[1].phrases.is_empty());
+}
+
+#[test]
+fn test_sentences_without_phrases_omit_the_field() {
+    let project = Project {
+        segments: vec![segment(&["plain"])],
+        ..Default::default()
+    };
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+
+    assert!(json["sentences"][0].get("phrases").is_none());
+}
+
+#[test]
+fn test_invalid_saved_phrases_are_dropped_on_load() {
+    let value = serde_json::json!({
+        "version": 2,
+        "vocabulary": { "original": [{ "word": "ka", "meaning": "cat" }] },
+        "sentences": [{
+            "words": [0, 0, 0, 0],
+            "meaning": "",
+            "phrases": [
+                { "start": 2, "end": 3, "gloss": "late" },
+                { "start": 1, "end": 2, "gloss": "overlap" },
+                { "start": 0, "end": 1, "gloss": "early" },
+                { "start": 3, "end": 4, "gloss": "past the end" },
+                { "start": 2, "end": 2, "gloss": "single" }
+            ]
+        }]
+    });
+
+    let loaded = load_project_from_json(value).expect("project loads");
+
+    assert_eq!(
+        loaded.segments[0].phrases,
+        vec![phrase(0, 1, "early"), phrase(2, 3, "late")]
+    );
+}
+
+#[test]
+fn test_overlapping_phrase_is_rejected() {
+    let mut segment = segment(&["a", "b", "c", "d", "e"]);
+    assert!(segment.set_phrase_gloss(1, 2, "bc".to_string()));
+
+    assert!(!segment.set_phrase_gloss(2, 3, "cd".to_string()));
+    assert!(!segment.set_phrase_gloss(0, 1, "ab".to_string()));
+    assert!(!segment.set_phrase_gloss(0, 4, "all".to_string()));
+    assert!(segment.set_phrase_gloss(3, 4, "de".to_string()));
+    assert_eq!(
+        segment.phrases,
+        vec![phrase(1, 2, "bc"), phrase(3, 4, "de")]
+    );
+}
+
+#[test]
+fn test_phrase_range_must_span_several_tokens() {
+    let mut segment = segment(&["a", "b"]);
+
+    assert!(!segment.set_phrase_gloss(1, 1, "b".to_string()));
+    assert!(!segment.set_phrase_gloss(1, 0, "ba".to_string()));
+    assert!(!segment.set_phrase_gloss(0, 2, "ab?".to_string()));
+    assert!(segment.phrases.is_empty());
+}
+
+#[test]
+fn test_same_range_replaces_gloss() {
+    let mut segment = segment(&["a", "b", "c"]);
+    assert!(segment.set_phrase_gloss(0, 1, "first".to_string()));
+    assert!(segment.set_phrase_gloss(0, 1, "second".to_string()));
+
+    assert_eq!(segment.phrases, vec![phrase(0, 1, "second")]);
+    assert_eq!(segment.phrase_at(1), Some(&phrase(0, 1, "second")));
+    assert!(segment.phrase_at(2).is_none());
+    assert!(segment.remove_phrase_gloss(0).is_some());
+    assert!(segment.phrases.is_empty());
+}
+
+#[test]
+fn test_merge_segments_shifts_phrases() {
+    let mut first = segment(&["a", "b"]);
+    assert!(first.set_phrase_gloss(0, 1, "ab".to_string()));
+    let mut second = segment(&["c", "d", "e"]);
+    assert!(second.set_phrase_gloss(1, 2, "de".to_string()));
+    let mut project = Project {
+        segments: vec![first, second],
+        ..Default::default()
+    };
+
+    assert!(project.merge_segments(0));
+    assert_eq!(
+        project.segments[0].phrases,
+        vec![phrase(0, 1, "ab"), phrase(3, 4, "de")]
+    );
+}
@@ -0,0 +1,43 @@
+use tdector::libs::project::{Project, Segment, convert_to_saved_project, load_project_from_json};
+
+use crate::common::segment;
+
+fn project(reviewed: &[bool]) -> Project {
+    Project {
+        segments: reviewed
+            .iter()
+            .map(|&reviewed| Segment {
+                reviewed,
+                ..segment(&["word"])
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_reviewed_flag_survives_save_and_load() {
+    let project = project(&[true, false]);
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+
+    assert_eq!(json["sentences"][0]["reviewed"], serde_json::json!(true));
+    assert!(json["sentences"][1].get("reviewed").is_none());
+
+    let loaded = load_project_from_json(json).expect("project loads");
+    assert!(loaded.segments[0].reviewed);
+    assert!(!loaded.segments[1].reviewed);
+}
+
+#[test]
+fn test_missing_reviewed_flag_loads_as_unreviewed() {
+    let saved = convert_to_saved_project(&project(&[false])).expect("project converts");
+    let mut json = serde_json::to_value(&saved).expect("project serializes");
+    json["sentences"][0]
+        .as_object_mut()
+        .expect("sentence object")
+        .remove("reviewed");
+
+    let loaded = load_project_from_json(json).expect("project loads");
+    assert!(!loaded.segments[0].reviewed);
+}
@@ -0,0 +1,332 @@
+use std::collections::{BTreeMap, HashMap};
+
+use tdector::enums::{FormationType, PunctuationMode, SegmentationMode, TextDirection};
+use tdector::libs::eval::FormationRule;
+use tdector::libs::project::{
+    CustomFont, ImportSettings, Project, Segment, Token, load_project_from_json,
+    models::SavedProjectV2,
+};
+
+use crate::common::{formation_rule as rule, token};
+
+/// Small deterministic generator so failures reproduce from the seed alone
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        self.0 >> 33
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.next() % 100 < percent
+    }
+
+    fn text(&mut self, max_len: usize) -> String {
+        const CHARS: &[char] = &['a', 'e', 'k', 'r', 'ß', 'é', ' ', '"', '\\', '\n', '語'];
+        (0..self.below(max_len + 1))
+            .map(|_| CHARS[self.below(CHARS.len())])
+            .collect()
+    }
+}
+
+/// Rules whose outputs can never collide with the lowercase base words
+fn rule_pool() -> Vec<FormationRule> {
+    vec![
+        rule(
+            "plural",
+            FormationType::Inflection,
+            r#"fn transform(word) { word + "-S" }"#,
+        ),
+        rule(
+            "negation",
+            FormationType::Derivation,
+            r#"fn transform(word) { "UN-" + word }"#,
+        ),
+        rule(
+            "past",
+            FormationType::Inflection,
+            r#"fn transform(word) { word + "-ED" }"#,
+        ),
+        rule(
+            "reduplication",
+            FormationType::Nonmorphological,
+            r#"fn transform(word) { word + "~" + word }"#,
+        ),
+    ]
+}
+
+fn random_project(seed: u64) -> Project {
+    let mut rng = Lcg(seed);
+    let mut project = Project {
+        project_name: rng.text(12),
+        normalize_lookups: rng.chance(50),
+        similarity_by_base_word: rng.chance(50),
+        text_direction: if rng.chance(30) {
+            TextDirection::Rtl
+        } else {
+            TextDirection::Ltr
+        },
+        import_settings: rng.chance(50).then(|| ImportSettings {
+            mode: SegmentationMode::MODES[rng.below(2)],
+            punctuation: PunctuationMode::MODES[rng.below(3)],
+        }),
+        fonts: vec![
+            CustomFont {
+                name: "first.ttf".to_string(),
+                path: Some("/fonts/first.ttf".to_string()),
+                data: Vec::new(),
+            },
+            CustomFont {
+                name: "second.ttf".to_string(),
+                path: Some("/fonts/second.ttf".to_string()),
+                data: Vec::new(),
+            },
+        ],
+        ..Default::default()
+    };
+
+    // A shuffled subset of rules, so saving has to renumber them
+    let mut pool = rule_pool();
+    for _ in 0..=rng.below(pool.len()) {
+        let rule = pool.remove(rng.below(pool.len()));
+        project.formation_rules.push(rule);
+    }
+
+    let words: Vec<String> = (0..1 + rng.below(8))
+        .map(|i| format!("{}{i}", ["ka", "ro", "ne", "ti"][rng.below(4)]))
+        .collect();
+    for word in &words {
+        project.vocabulary.insert(word.clone(), rng.text(10));
+        if rng.chance(40) {
+            project
+                .vocabulary_comments
+                .insert(word.clone(), format!("note {}", rng.text(8)));
+        }
+        if rng.chance(40) {
+            let category = ["noun", "verb"][rng.below(2)].to_string();
+            project
+                .category_colors
+                .insert(category.clone(), [rng.below(256) as u8, 0, 255]);
+            project.vocabulary_categories.insert(word.clone(), category);
+        }
+        if rng.chance(20) {
+            project.stopwords.insert(word.clone());
+        }
+    }
+
+    for _ in 0..1 + rng.below(6) {
+        let mut segment = Segment {
+            translation: rng.text(20),
+            comment: rng.text(10),
+            font: [None, Some(0), Some(1)][rng.below(3)],
+            ..Default::default()
+        };
+        for _ in 0..1 + rng.below(5) {
+            let base = words[rng.below(words.len())].clone();
+            let chain: Vec<usize> = (0..rng.below(3))
+                .map(|_| rng.below(project.formation_rules.len()))
+                .collect();
+            if chain.is_empty() {
+                segment.tokens.push(token(&base));
+                continue;
+            }
+            let mut original = base.clone();
+            for rule_idx in &chain {
+                original = project.formation_rules[*rule_idx]
+                    .apply(&original)
+                    .expect("rule applies");
+            }
+            if rng.chance(50) {
+                project
+                    .formatted_word_comments
+                    .insert(original.clone(), rng.text(8));
+            }
+            segment.tokens.push(Token {
+                original,
+                base_word: Some(base),
+                formation_rule_indices: chain,
+            });
+        }
+        if segment.tokens.len() >= 2 && rng.chance(50) {
+            let end = 1 + rng.below(segment.tokens.len() - 1);
+            assert!(segment.set_phrase_gloss(0, end, format!("phrase {}", rng.text(6))));
+        }
+        if rng.chance(40) {
+            segment.tags = vec!["verse".to_string(), rng.text(5)];
+        }
+        segment.locked = rng.chance(25);
+        segment.reviewed = rng.chance(30);
+        project.segments.push(segment);
+    }
+    project
+}
+
+fn save_to_json(project: &Project) -> serde_json::Value {
+    let saved = project.to_saved().expect("project converts");
+    serde_json::to_value(&saved).expect("project serializes")
+}
+
+fn reload(json: &serde_json::Value) -> Project {
+    let text = serde_json::to_string(json).expect("value serializes");
+    let saved: SavedProjectV2 = serde_json::from_str(&text).expect("saved JSON parses");
+    Project::from_saved(saved).expect("project converts back")
+}
+
+/// Each token as (surface form, base word, descriptions of the applied rules), which is
+/// independent of how the rules happen to be numbered
+fn token_chains(project: &Project) -> Vec<Vec<(String, String, Vec<String>)>> {
+    project
+        .segments
+        .iter()
+        .map(|segment| {
+            segment
+                .tokens
+                .iter()
+                .map(|token| {
+                    (
+                        token.original.clone(),
+                        token
+                            .base_word
+                            .clone()
+                            .unwrap_or_else(|| token.original.clone()),
+                        token
+                            .formation_rule_indices
+                            .iter()
+                            .map(|idx| project.formation_rules[*idx].description.clone())
+                            .collect(),
+                    )
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn non_empty(map: &HashMap<String, String>) -> BTreeMap<String, String> {
+    map.iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+#[test]
+fn test_save_load_save_is_lossless_for_random_projects() {
+    for seed in 0..200 {
+        let project = random_project(seed);
+        let first = save_to_json(&project);
+        let loaded = reload(&first);
+        let second = save_to_json(&loaded);
+
+        assert_eq!(first, second, "seed {seed}: second save differs");
+
+        assert_eq!(loaded.project_name, project.project_name, "seed {seed}");
+        assert_eq!(loaded.vocabulary, project.vocabulary, "seed {seed}");
+        assert_eq!(
+            non_empty(&loaded.vocabulary_comments),
+            non_empty(&project.vocabulary_comments),
+            "seed {seed}"
+        );
+        assert_eq!(
+            loaded.vocabulary_categories, project.vocabulary_categories,
+            "seed {seed}"
+        );
+        assert_eq!(
+            loaded.category_colors, project.category_colors,
+            "seed {seed}"
+        );
+        assert_eq!(
+            non_empty(&loaded.formatted_word_comments),
+            non_empty(&project.formatted_word_comments),
+            "seed {seed}"
+        );
+        assert_eq!(token_chains(&loaded), token_chains(&project), "seed {seed}");
+        assert_eq!(loaded.stopwords, project.stopwords, "seed {seed}");
+        assert_eq!(loaded.normalize_lookups, project.normalize_lookups);
+        assert_eq!(
+            loaded.similarity_by_base_word,
+            project.similarity_by_base_word
+        );
+        assert_eq!(loaded.text_direction, project.text_direction);
+        assert_eq!(loaded.import_settings, project.import_settings);
+        assert_eq!(loaded.formation_rules.len(), project.formation_rules.len());
+
+        for (loaded_seg, seg) in loaded.segments.iter().zip(&project.segments) {
+            assert_eq!(loaded_seg.translation, seg.translation, "seed {seed}");
+            assert_eq!(loaded_seg.comment, seg.comment, "seed {seed}");
+            assert_eq!(loaded_seg.phrases, seg.phrases, "seed {seed}");
+            assert_eq!(loaded_seg.locked, seg.locked, "seed {seed}");
+            assert_eq!(loaded_seg.reviewed, seg.reviewed, "seed {seed}");
+            assert_eq!(loaded_seg.tags, seg.tags, "seed {seed}");
+            assert_eq!(loaded_seg.font, seg.font, "seed {seed}");
+        }
+    }
+}
+
+#[test]
+fn test_loaded_json_round_trips_through_the_migrating_loader() {
+    let project = random_project(7);
+    let json = save_to_json(&project);
+    let loaded = load_project_from_json(json.clone()).expect("project loads");
+
+    assert_eq!(save_to_json(&loaded), json);
+}
+
+#[test]
+fn test_segment_font_follows_fonts_that_are_dropped_on_save() {
+    let font = |name: &str, path: Option<&str>| CustomFont {
+        name: name.to_string(),
+        path: path.map(str::to_string),
+        data: vec![1, 2, 3],
+    };
+    let segment = |font| Segment {
+        tokens: vec![token("word")],
+        font,
+        ..Default::default()
+    };
+    let project = Project {
+        fonts: vec![
+            font("unsaved.ttf", None),
+            font("kept.ttf", Some("/fonts/kept.ttf")),
+        ],
+        segments: vec![segment(Some(0)), segment(Some(1)), segment(None)],
+        ..Default::default()
+    };
+
+    let loaded = reload(&save_to_json(&project));
+
+    assert_eq!(loaded.fonts.len(), 1);
+    assert_eq!(loaded.fonts[0].name, "kept.ttf");
+    let fonts: Vec<Option<usize>> = loaded.segments.iter().map(|s| s.font).collect();
+    assert_eq!(fonts, vec![None, Some(0), None]);
+}
+
+#[test]
+fn test_import_settings_survive_reload_as_dialog_defaults() {
+    let stored = ImportSettings {
+        mode: SegmentationMode::Character,
+        punctuation: PunctuationMode::Strip,
+    };
+    let project = Project {
+        text_direction: TextDirection::Rtl,
+        import_settings: Some(stored),
+        ..Default::default()
+    };
+
+    let json = save_to_json(&project);
+    let loaded = reload(&json);
+
+    assert_eq!(loaded.import_defaults("plain spaced words"), stored);
+    assert_eq!(loaded.text_direction, TextDirection::Rtl);
+    assert!(
+        save_to_json(&Project::default())
+            .get("import_settings")
+            .is_none()
+    );
+}
@@ -0,0 +1,43 @@
+use tdector::libs::project::operations::parse_tags;
+use tdector::libs::project::{Project, Segment, convert_to_saved_project, load_project_from_json};
+
+use crate::common::segment;
+
+fn project(tags: &[&[&str]]) -> Project {
+    Project {
+        segments: tags
+            .iter()
+            .map(|tags| Segment {
+                tags: tags.iter().map(|tag| tag.to_string()).collect(),
+                ..segment(&["word"])
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_tags_survive_save_and_load() {
+    let project = project(&[&["verse", "uncertain"], &[]]);
+    let saved = convert_to_saved_project(&project).expect("project converts");
+    let json = serde_json::to_value(&saved).expect("project serializes");
+
+    assert_eq!(
+        json["sentences"][0]["tags"],
+        serde_json::json!(["verse", "uncertain"])
+    );
+    assert!(json["sentences"][1].get("tags").is_none());
+
+    let loaded = load_project_from_json(json).expect("project loads");
+    assert_eq!(loaded.segments[0].tags, vec!["verse", "uncertain"]);
+    assert!(loaded.segments[1].tags.is_empty());
+}
+
+#[test]
+fn test_parse_tags_splits_and_dedupes() {
+    assert_eq!(
+        parse_tags(" verse, prose  Verse,,uncertain "),
+        vec!["verse", "prose", "uncertain"]
+    );
+    assert!(parse_tags(" , ").is_empty());
+}
@@ -0,0 +1,352 @@
+use ndarray::Array2;
+use scirs2_text::{TfidfVectorizer, Vectorizer, WhitespaceTokenizer};
+use std::collections::HashSet;
+use tdector::libs::cache::CachedTfidf;
+
+use tdector::libs::similarity_sentence::{
+    SimilarityEngine, TermOptions, TfidfIndex, jaccard_similarity, parse_stopwords,
+    translation_suggestions,
+};
+use tdector::libs::{Project, Token};
+
+use crate::common::{project, segment, token};
+
+fn corpus() -> Project {
+    project(&[
+        &["the", "cat", "sat"],
+        &["the", "dog", "sat"],
+        &["a", "cat", "ran"],
+        &["the", "bird", "flew"],
+    ])
+}
+
+fn assert_same_scores(cache: &CachedTfidf, project: &Project) {
+    let incremental = cache.get_matrix().expect("cache holds a matrix");
+    let full = SimilarityEngine::compute_tfidf_matrix(project).expect("project has segments");
+    assert_same_similarities(incremental, &full);
+}
+
+/// Every pairwise similarity agrees; column order may differ between the matrices
+fn assert_same_similarities(left_matrix: &Array2<f64>, right_matrix: &Array2<f64>) {
+    assert_eq!(left_matrix.nrows(), right_matrix.nrows());
+    for target in 0..left_matrix.nrows() {
+        // Compare by segment index so near-equal scores cannot swap places
+        let mut left = SimilarityEngine::find_similar(left_matrix, target, usize::MAX);
+        let mut right = SimilarityEngine::find_similar(right_matrix, target, usize::MAX);
+        left.sort_by_key(|&(idx, _)| idx);
+        right.sort_by_key(|&(idx, _)| idx);
+        assert_eq!(left.len(), right.len(), "target {target}");
+        for ((li, ls), (ri, rs)) in left.iter().zip(&right) {
+            assert_eq!(li, ri, "target {target}");
+            assert!((ls - rs).abs() < 1e-9, "target {target}: {ls} vs {rs}");
+        }
+    }
+}
+
+#[test]
+fn test_incremental_edit_with_known_terms_matches_full() {
+    let mut project = corpus();
+    let mut cache = CachedTfidf::new();
+    cache.rebuild(&project);
+
+    project.segments[3] = segment(&["the", "cat", "ran"]);
+    assert!(cache.update_segment(&project, 3));
+
+    assert_same_scores(&cache, &project);
+}
+
+#[test]
+fn test_incremental_edit_with_new_terms_matches_full() {
+    let mut project = corpus();
+    let mut cache = CachedTfidf::new();
+    cache.rebuild(&project);
+
+    project.segments[1] = segment(&["a", "fish", "swam"]);
+    assert!(cache.update_segment(&project, 1));
+
+    assert_same_scores(&cache, &project);
+}
+
+#[test]
+fn test_incremental_update_rejects_added_segment() {
+    let mut project = corpus();
+    let mut cache = CachedTfidf::new();
+    cache.rebuild(&project);
+
+    project.segments.push(segment(&["new"]));
+    assert!(!cache.update_segment(&project, 0));
+}
+
+/// Matrix from the scirs2 vectorizer the similarity search used before `TfidfIndex`
+fn scirs2_matrix(project: &Project) -> Array2<f64> {
+    let documents: Vec<String> = project
+        .segments
+        .iter()
+        .map(|segment| {
+            let words: Vec<&str> = segment.tokens.iter().map(|t| t.original.as_str()).collect();
+            words.join(" ")
+        })
+        .collect();
+    let doc_refs: Vec<&str> = documents.iter().map(String::as_str).collect();
+    let mut vectorizer = TfidfVectorizer::with_tokenizer(
+        Box::new(WhitespaceTokenizer::new()),
+        false,
+        true,
+        Some("l2".to_string()),
+    );
+    vectorizer
+        .fit_transform(&doc_refs)
+        .expect("scirs2 vectorizes the corpus")
+}
+
+#[test]
+fn test_index_scores_match_scirs2_vectorizer() {
+    let project = project(&[
+        &["the", "cat", "sat", "the"],
+        &["the", "dog", "sat"],
+        &["a", "big cat", "ran", "ran"],
+        &["the", "bird", "flew"],
+        &["Cat", "cat"],
+    ]);
+
+    let index = SimilarityEngine::compute_tfidf_matrix(&project).expect("project has segments");
+    assert_same_similarities(&index, &scirs2_matrix(&project));
+}
+
+#[test]
+fn test_parallel_build_matches_serial() {
+    let words = [
+        "the", "cat", "dog", "sat", "ran", "a", "bird", "flew", "on", "mat",
+    ];
+    let segments: Vec<Vec<&str>> = (0..200)
+        .map(|i| {
+            (0..(i % 7 + 1))
+                .map(|j| words[(i * 3 + j * 5) % words.len()])
+                .collect()
+        })
+        .collect();
+    let segment_refs: Vec<&[&str]> = segments.iter().map(Vec::as_slice).collect();
+    let project = project(&segment_refs);
+
+    let parallel = TfidfIndex::build(&project).matrix();
+    let serial = TfidfIndex::build_serial(&project).matrix();
+    assert_eq!(parallel, serial);
+}
+
+fn score_between(project: &Project, left: usize, right: usize) -> f64 {
+    let matrix = SimilarityEngine::compute_tfidf_matrix(project).expect("project has segments");
+    SimilarityEngine::find_similar(&matrix, left, usize::MAX)
+        .into_iter()
+        .find(|&(idx, _)| idx == right)
+        .map_or(0.0, |(_, score)| score)
+}
+
+#[test]
+fn test_base_word_mode_collapses_inflections() {
+    let derived = |original: &str, base: &str| Token {
+        original: original.to_string(),
+        base_word: Some(base.to_string()),
+        formation_rule_indices: vec![0],
+    };
+    let mut project = project(&[&[], &[], &["bird", "flew"]]);
+    project.segments[0].tokens = vec![derived("walked", "walk"), token("home")];
+    project.segments[1].tokens = vec![derived("walks", "walk"), token("home")];
+
+    let surface = score_between(&project, 0, 1);
+    project.similarity_by_base_word = true;
+    let lemma = score_between(&project, 0, 1);
+
+    assert!(
+        lemma > surface,
+        "lemma {lemma} should exceed surface {surface}"
+    );
+    assert!((lemma - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_stopwords_removed_from_document_terms() {
+    let mut project = corpus();
+    project.stopwords = parse_stopwords("the\na");
+
+    let terms =
+        SimilarityEngine::document_terms(&project.segments[0], TermOptions::from_project(&project));
+    assert_eq!(terms, vec!["cat", "sat"]);
+}
+
+#[test]
+fn test_stopword_only_overlap_is_not_similar() {
+    let mut project = corpus();
+    // Segments 0 and 3 share only "the"
+    assert!(score_between(&project, 0, 3) > 0.0);
+
+    project.stopwords = parse_stopwords("the");
+    assert_eq!(score_between(&project, 0, 3), 0.0);
+}
+
+#[test]
+fn test_empty_stopwords_keep_matrix_unchanged() {
+    let mut project = corpus();
+    let before = SimilarityEngine::compute_tfidf_matrix(&project);
+    project.stopwords = parse_stopwords("  \n ");
+    assert!(project.stopwords.is_empty());
+    assert_eq!(SimilarityEngine::compute_tfidf_matrix(&project), before);
+}
+
+#[test]
+fn test_similar_segments_matches_cached_matrix() {
+    let project = corpus();
+    let matrix = SimilarityEngine::compute_tfidf_matrix(&project).expect("project has segments");
+
+    assert_eq!(
+        SimilarityEngine::similar_segments(&project, 0, 2),
+        SimilarityEngine::find_similar(&matrix, 0, 2)
+    );
+    assert!(SimilarityEngine::similar_segments(&project, 0, 2).len() <= 2);
+}
+
+#[test]
+fn test_similar_segments_out_of_range_or_empty() {
+    assert!(SimilarityEngine::similar_segments(&corpus(), 99, 5).is_empty());
+    assert!(SimilarityEngine::similar_segments(&Project::default(), 0, 5).is_empty());
+}
+
+fn term_set<'a>(words: &[&'a str]) -> HashSet<&'a str> {
+    words.iter().copied().collect()
+}
+
+#[test]
+fn test_jaccard_of_hand_computed_overlaps() {
+    // {a, b, c} and {b, c, d} share 2 of 4 distinct terms
+    assert_eq!(
+        jaccard_similarity(&term_set(&["a", "b", "c"]), &term_set(&["b", "c", "d"])),
+        0.5
+    );
+    // {a, b} inside {a, b, c, d, e}: 2 of 5
+    assert_eq!(
+        jaccard_similarity(
+            &term_set(&["a", "b"]),
+            &term_set(&["a", "b", "c", "d", "e"])
+        ),
+        0.4
+    );
+    assert_eq!(
+        jaccard_similarity(&term_set(&["a", "b"]), &term_set(&["b", "a"])),
+        1.0
+    );
+    assert_eq!(
+        jaccard_similarity(&term_set(&["a"]), &term_set(&["b"])),
+        0.0
+    );
+    assert_eq!(jaccard_similarity(&term_set(&[]), &term_set(&[])), 0.0);
+}
+
+#[test]
+fn test_jaccard_ranking_counts_distinct_tokens() {
+    let project = project(&[
+        &["red", "cat", "red", "sat"],
+        &["red", "cat", "ran"],
+        &["blue", "dog", "sat"],
+        &["green", "bird", "flew"],
+        &["red", "cat", "sat"],
+    ]);
+
+    // Distinct terms of segment 0 are {red, cat, sat}
+    assert_eq!(
+        SimilarityEngine::find_similar_jaccard(&project, 0, 10),
+        vec![(4, 1.0), (1, 0.5), (2, 0.2)]
+    );
+    assert_eq!(
+        SimilarityEngine::find_similar_jaccard(&project, 0, 1),
+        vec![(4, 1.0)]
+    );
+}
+
+#[test]
+fn test_jaccard_respects_stopwords_and_range() {
+    let mut project = project(&[&["the", "cat"], &["the", "dog"]]);
+    assert_eq!(
+        SimilarityEngine::find_similar_jaccard(&project, 0, 5),
+        vec![(1, 1.0 / 3.0)]
+    );
+
+    project.stopwords = parse_stopwords("the");
+    assert!(SimilarityEngine::find_similar_jaccard(&project, 0, 5).is_empty());
+    assert!(SimilarityEngine::find_similar_jaccard(&project, 9, 5).is_empty());
+}
+
+#[test]
+fn test_translation_suggestions_skip_blank_and_repeated_translations() {
+    let mut project = project(&[
+        &["the", "cat", "sat"],
+        &["the", "cat", "ran"],
+        &["a", "cat", "sat"],
+        &["the", "cat", "sat", "down"],
+        &["one", "cat", "sat"],
+    ]);
+    project.segments[1].translation = "  ".to_string();
+    project.segments[2].translation = "a cat sat".to_string();
+    project.segments[3].translation = "the cat sat down".to_string();
+    project.segments[4].translation = "a cat sat".to_string();
+
+    let similar = [(1, 0.9), (3, 0.8), (2, 0.7), (4, 0.6), (0, 0.5)];
+    let suggestions = translation_suggestions(&project, &similar, 5);
+
+    let picked: Vec<(usize, &str)> = suggestions
+        .iter()
+        .map(|s| (s.segment_idx, s.translation.as_str()))
+        .collect();
+    assert_eq!(picked, [(3, "the cat sat down"), (2, "a cat sat")]);
+    assert!((suggestions[0].score - 0.8).abs() < f64::EPSILON);
+
+    assert_eq!(translation_suggestions(&project, &similar, 1).len(), 1);
+}
+
+#[test]
+fn test_translation_suggestions_from_tfidf_ranking() {
+    let mut project = corpus();
+    for (idx, segment) in project.segments.iter_mut().enumerate().skip(1) {
+        segment.translation = format!("translation {idx}");
+    }
+    let matrix = SimilarityEngine::compute_tfidf_matrix(&project).expect("matrix");
+    let similar = SimilarityEngine::find_similar(&matrix, 0, 10);
+    let suggestions = translation_suggestions(&project, &similar, 10);
+
+    assert_eq!(suggestions.len(), similar.len());
+    assert!(suggestions.iter().all(|s| s.segment_idx != 0));
+    assert_eq!(suggestions[0].segment_idx, similar[0].0);
+}
+
+fn corpus_matrix() -> ndarray::Array2<f64> {
+    SimilarityEngine::compute_tfidf_matrix(&corpus()).expect("matrix")
+}
+
+#[test]
+fn test_find_similar_with_zero_limit_is_empty() {
+    let matrix = corpus_matrix();
+    assert!(SimilarityEngine::find_similar(&matrix, 0, 0).is_empty());
+    assert!(SimilarityEngine::find_similar_jaccard(&corpus(), 0, 0).is_empty());
+}
+
+#[test]
+fn test_find_similar_with_limit_one_returns_best_match() {
+    let matrix = corpus_matrix();
+    let all = SimilarityEngine::find_similar(&matrix, 0, usize::MAX);
+    let best = SimilarityEngine::find_similar(&matrix, 0, 1);
+
+    assert_eq!(best.len(), 1);
+    assert_eq!(best[0], all[0]);
+    assert!(all.iter().all(|&(_, score)| score <= best[0].1));
+}
+
+#[test]
+fn test_find_similar_with_limit_beyond_corpus_returns_all_sorted() {
+    let matrix = corpus_matrix();
+
+    for limit in [4, 1_000, usize::MAX] {
+        let similar = SimilarityEngine::find_similar(&matrix, 0, limit);
+        let mut indices: Vec<usize> = similar.iter().map(|&(idx, _)| idx).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, [1, 2, 3]);
+        assert!(similar.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    }
+}
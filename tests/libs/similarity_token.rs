@@ -0,0 +1,46 @@
+use tdector::consts::domain::DEFAULT_RELATED_WORDS_COUNT;
+use tdector::libs::similarity_token::find_related_words;
+
+fn vocabulary(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| (*word).to_string()).collect()
+}
+
+#[test]
+fn test_exact_word_ranks_first() {
+    let words = vocabulary(&["akaa", "kaa", "kaam", "mur"]);
+    let related = find_related_words(&words, "kaa", DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(related, vec!["kaa", "kaam", "akaa"]);
+}
+
+#[test]
+fn test_prefix_matches_precede_inner_matches_and_ignore_case() {
+    let words = vocabulary(&["tel", "Moratel", "telun", "TELA", "sa"]);
+    let related = find_related_words(&words, "tel", DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(related, vec!["tel", "TELA", "telun", "Moratel"]);
+}
+
+#[test]
+fn test_one_typo_falls_back_to_edit_distance() {
+    let words = vocabulary(&["murak", "murat", "sotel", "xyzzy"]);
+    let related = find_related_words(&words, "murek", DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(related, vec!["murak", "murat"]);
+}
+
+#[test]
+fn test_fuzzy_fallback_only_when_nothing_contains_the_query() {
+    let words = vocabulary(&["kaam", "kam"]);
+    // "kam" contains the query, so the one-edit neighbour "kaam" is not offered
+    assert_eq!(find_related_words(&words, "kam", 5), vec!["kam"]);
+}
+
+#[test]
+fn test_results_are_capped() {
+    let words: Vec<String> = (0..20).map(|i| format!("word{i:02}")).collect();
+    let related = find_related_words(&words, "word", DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(related.len(), DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(related[0], "word00");
+
+    let fuzzy = find_related_words(&words, "wxrd05", DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(fuzzy.len(), DEFAULT_RELATED_WORDS_COUNT);
+    assert_eq!(fuzzy[0], "word05");
+}
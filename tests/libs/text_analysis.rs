@@ -0,0 +1,388 @@
+use tdector::enums::{PunctuationMode, SegmentationMode};
+use tdector::libs::text_analysis::{
+    DiffOp, SegmentStats, SegmentationPreview, TextProcessor, align_tokens, apply_punctuation_mode,
+    is_punctuation, segment_length, segment_stats, suggest_segmentation, translated_ratio,
+};
+
+use crate::common::project;
+
+#[test]
+fn test_concordance_context_in_middle() {
+    let project = project(&[&["a", "b", "c", "key", "d", "e", "f"]]);
+
+    let lines = TextProcessor::concordance_lines(&project, "key", 2);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].segment_idx, 0);
+    assert_eq!(lines[0].token_idx, 3);
+    assert_eq!(lines[0].left, vec!["b", "c"]);
+    assert_eq!(lines[0].keyword, "key");
+    assert_eq!(lines[0].right, vec!["d", "e"]);
+}
+
+#[test]
+fn test_concordance_clips_at_segment_boundaries() {
+    let project = project(&[&["key", "a"], &["b", "key"], &["key"]]);
+
+    let lines = TextProcessor::concordance_lines(&project, "key", 3);
+    assert_eq!(lines.len(), 3);
+
+    assert!(lines[0].left.is_empty());
+    assert_eq!(lines[0].right, vec!["a"]);
+
+    assert_eq!(lines[1].segment_idx, 1);
+    assert_eq!(lines[1].left, vec!["b"]);
+    assert!(lines[1].right.is_empty());
+
+    assert!(lines[2].left.is_empty());
+    assert!(lines[2].right.is_empty());
+}
+
+#[test]
+fn test_concordance_zero_context() {
+    let project = project(&[&["a", "key", "b", "key"]]);
+
+    let lines = TextProcessor::concordance_lines(&project, "key", 0);
+    assert_eq!(lines.len(), 2);
+    assert!(
+        lines
+            .iter()
+            .all(|l| l.left.is_empty() && l.right.is_empty())
+    );
+    assert_eq!(lines[1].token_idx, 3);
+}
+
+const IMPORT_TEXT: &str = "the quick fox\n\n  \njumps over\nthe dog";
+
+#[test]
+fn test_segmentation_preview_counts_match_committed_segments() {
+    for mode in SegmentationMode::MODES {
+        let preview = SegmentationPreview::new(IMPORT_TEXT, mode, PunctuationMode::Keep)
+            .expect("preview segments");
+        let committed = TextProcessor::segment_content(IMPORT_TEXT, mode, PunctuationMode::Keep)
+            .expect("text segments");
+
+        assert_eq!(preview.segment_count(), committed.len(), "{mode:?}");
+        assert_eq!(
+            preview.token_count,
+            committed.iter().map(|s| s.tokens.len()).sum::<usize>(),
+            "{mode:?}"
+        );
+        let previewed: Vec<Vec<&str>> = preview
+            .segments
+            .iter()
+            .map(|s| s.tokens.iter().map(|t| t.original.as_str()).collect())
+            .collect();
+        let imported: Vec<Vec<&str>> = committed
+            .iter()
+            .map(|s| s.tokens.iter().map(|t| t.original.as_str()).collect())
+            .collect();
+        assert_eq!(previewed, imported, "{mode:?}");
+    }
+}
+
+#[test]
+fn test_segmentation_preview_totals_per_mode() {
+    let by_word = SegmentationPreview::new(
+        IMPORT_TEXT,
+        SegmentationMode::Whitespace,
+        PunctuationMode::Keep,
+    )
+    .expect("preview segments");
+    assert_eq!(by_word.segment_count(), 3);
+    assert_eq!(by_word.token_count, 7);
+
+    let by_char = SegmentationPreview::new(
+        IMPORT_TEXT,
+        SegmentationMode::Character,
+        PunctuationMode::Keep,
+    )
+    .expect("preview segments");
+    assert_eq!(by_char.segment_count(), 3);
+    assert!(by_char.token_count > by_word.token_count);
+}
+
+fn words(line: &str, mode: SegmentationMode, punctuation: PunctuationMode) -> Vec<String> {
+    let segments = TextProcessor::segment_content(line, mode, punctuation).expect("line segments");
+    segments[0]
+        .tokens
+        .iter()
+        .map(|token| token.original.clone())
+        .collect()
+}
+
+const MIXED_LINE: &str = "«Hi», she said — (twice)!";
+
+#[test]
+fn test_keep_punctuation_on_mixed_line() {
+    assert_eq!(
+        words(
+            MIXED_LINE,
+            SegmentationMode::Whitespace,
+            PunctuationMode::Keep
+        ),
+        vec!["«Hi»,", "she", "said—", "(twice)!"]
+    );
+}
+
+#[test]
+fn test_strip_punctuation_on_mixed_line() {
+    assert_eq!(
+        words(
+            MIXED_LINE,
+            SegmentationMode::Whitespace,
+            PunctuationMode::Strip
+        ),
+        vec!["Hi", "she", "said", "twice"]
+    );
+}
+
+#[test]
+fn test_separate_punctuation_on_mixed_line() {
+    assert_eq!(
+        words(
+            MIXED_LINE,
+            SegmentationMode::Whitespace,
+            PunctuationMode::Separate
+        ),
+        vec![
+            "«", "Hi", "»", ",", "she", "said", "—", "(", "twice", ")", "!"
+        ]
+    );
+}
+
+#[test]
+fn test_character_mode_punctuation() {
+    let line = "你好，世界。";
+    assert_eq!(
+        words(line, SegmentationMode::Character, PunctuationMode::Keep),
+        vec!["你", "好，", "世", "界。"]
+    );
+    assert_eq!(
+        words(line, SegmentationMode::Character, PunctuationMode::Strip),
+        vec!["你", "好", "世", "界"]
+    );
+    assert_eq!(
+        words(line, SegmentationMode::Character, PunctuationMode::Separate),
+        vec!["你", "好", "，", "世", "界", "。"]
+    );
+}
+
+#[test]
+fn test_keep_attaches_leading_punctuation_forward() {
+    let tokens = vec!["¿".to_string(), "qué".to_string(), "?".to_string()];
+    assert_eq!(
+        apply_punctuation_mode(tokens, PunctuationMode::Keep),
+        vec!["¿qué?"]
+    );
+    let only = vec!["...".to_string(), "!".to_string()];
+    assert_eq!(
+        apply_punctuation_mode(only, PunctuationMode::Keep),
+        vec!["...!"]
+    );
+}
+
+#[test]
+fn test_unicode_punctuation_categories() {
+    for c in ['.', '_', '-', '(', ')', '«', '»', '，', '。', '¿', '—'] {
+        assert!(is_punctuation(c), "{c:?}");
+    }
+    for c in ['a', '語', '1', ' ', '+', '$'] {
+        assert!(!is_punctuation(c), "{c:?}");
+    }
+}
+
+#[test]
+fn test_suggests_character_mode_for_chinese() {
+    let sample = "道可道，非常道。名可名，非常名。\n无名天地之始；有名万物之母。";
+    assert_eq!(
+        suggest_segmentation(sample),
+        (SegmentationMode::Character, true)
+    );
+}
+
+#[test]
+fn test_suggests_word_mode_for_english() {
+    let sample = "The quick brown fox jumps over the lazy dog.\nIt was not amused.";
+    assert_eq!(
+        suggest_segmentation(sample),
+        (SegmentationMode::Whitespace, true)
+    );
+}
+
+#[test]
+fn test_space_separated_chinese_is_word_based() {
+    // Already segmented into words, so splitting on spaces keeps them intact
+    let sample = "我们 明天 去 北京\n他们 已经 到 了";
+    assert_eq!(suggest_segmentation(sample).0, SegmentationMode::Whitespace);
+}
+
+#[test]
+fn test_suggestion_is_unsure_for_mixed_or_empty_text() {
+    let mixed = "这是一个 mixed 句子 with 中文 and English";
+    assert!(!suggest_segmentation(mixed).1);
+    assert_eq!(
+        suggest_segmentation("  ...  \n"),
+        (SegmentationMode::Whitespace, false)
+    );
+}
+
+#[test]
+fn test_count_segment_translated_tokens() {
+    let mut project = project(&[&["ka", "ro", "ka", "ne", "ti"]]);
+    project
+        .vocabulary
+        .insert("ka".to_string(), "water".to_string());
+    project
+        .vocabulary
+        .insert("ro".to_string(), "  ".to_string());
+    project
+        .vocabulary
+        .insert("ti".to_string(), "stone".to_string());
+
+    let glossed = TextProcessor::count_segment_translated_tokens(&project.segments[0], &project);
+    // Both "ka" tokens count; a blank gloss and a missing entry do not
+    assert_eq!(glossed, 3);
+    assert_eq!(project.segments[0].tokens.len(), 5);
+}
+
+#[test]
+fn test_segment_stats_with_repeated_tokens() {
+    let mut project = project(&[&["the", "cat", "saw", "the", "other", "cat"]]);
+    project
+        .vocabulary
+        .insert("cat".to_string(), "feline".to_string());
+    project
+        .vocabulary
+        .insert("the".to_string(), "  ".to_string());
+
+    let stats = segment_stats(&project.segments[0], &project);
+
+    assert_eq!(
+        stats,
+        SegmentStats {
+            token_count: 6,
+            unique_count: 4,
+            translated_count: 2,
+        }
+    );
+    assert!((stats.type_token_ratio() - 4.0 / 6.0).abs() < 1e-12);
+    assert!((stats.translated_ratio() - 2.0 / 6.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_segment_stats_all_distinct_and_empty() {
+    let project = project(&[&["a", "b", "c"], &[]]);
+
+    let distinct = segment_stats(&project.segments[0], &project);
+    assert_eq!(distinct.type_token_ratio(), 1.0);
+    assert_eq!(distinct.translated_ratio(), 0.0);
+
+    let empty = segment_stats(&project.segments[1], &project);
+    assert_eq!(empty, SegmentStats::default());
+    assert_eq!(empty.type_token_ratio(), 0.0);
+}
+
+#[test]
+fn test_translated_ratio_with_derived_and_untranslated_tokens() {
+    let mut project = project(&[&["walk", "walked", "walking", "run", "ran"]]);
+    // "walked" and "walking" are derived from "walk"
+    for (idx, token) in project.segments[0].tokens.iter_mut().enumerate() {
+        if idx == 1 || idx == 2 {
+            token.base_word = Some("walk".to_string());
+            token.formation_rule_indices = vec![0];
+        }
+    }
+    project
+        .vocabulary
+        .insert("walk".to_string(), "go on foot".to_string());
+    project
+        .vocabulary
+        .insert("run".to_string(), " ".to_string());
+
+    let segment = &project.segments[0];
+    assert_eq!(segment_length(segment), 5);
+    // "walk" counts and both derived tokens inherit its gloss;
+    // "run" has a blank gloss and "ran" no entry at all
+    assert_eq!(translated_ratio(segment, &project), 3.0 / 5.0);
+    assert_eq!(
+        TextProcessor::count_segment_translated_tokens(segment, &project),
+        3
+    );
+
+    // A word marked as having no gloss counts as translated
+    project.set_no_gloss("ran", true);
+    let segment = &project.segments[0];
+    assert_eq!(translated_ratio(segment, &project), 4.0 / 5.0);
+    assert_eq!(segment_stats(segment, &project).translated_count, 4);
+    assert_eq!(
+        translated_ratio(segment, &project),
+        segment_stats(segment, &project).translated_ratio()
+    );
+}
+
+#[test]
+fn test_translated_ratio_bounds() {
+    let mut project = project(&[&["a", "a", "b", "c"], &[]]);
+    assert_eq!(translated_ratio(&project.segments[0], &project), 0.0);
+
+    // Repeated tokens count once per occurrence
+    project
+        .vocabulary
+        .insert("a".to_string(), "one".to_string());
+    assert_eq!(translated_ratio(&project.segments[0], &project), 0.5);
+
+    project
+        .vocabulary
+        .insert("b".to_string(), "two".to_string());
+    project
+        .vocabulary
+        .insert("c".to_string(), "three".to_string());
+    assert_eq!(translated_ratio(&project.segments[0], &project), 1.0);
+
+    assert_eq!(segment_length(&project.segments[1]), 0);
+    assert_eq!(translated_ratio(&project.segments[1], &project), 0.0);
+}
+
+#[test]
+fn test_align_tokens_matches_longest_common_subsequence() {
+    let project = project(&[
+        &["the", "cat", "sat", "on", "mat"],
+        &["the", "dog", "sat", "on", "the", "mat"],
+    ]);
+    let (a, b) = (&project.segments[0].tokens, &project.segments[1].tokens);
+
+    let ops = align_tokens(a, b);
+    assert_eq!(
+        ops,
+        vec![
+            DiffOp::Equal(0, 0),
+            DiffOp::Delete(1),
+            DiffOp::Insert(1),
+            DiffOp::Equal(2, 2),
+            DiffOp::Equal(3, 3),
+            DiffOp::Insert(4),
+            DiffOp::Equal(4, 5),
+        ]
+    );
+
+    let first: Vec<usize> = ops.iter().filter_map(|op| op.first()).collect();
+    let second: Vec<usize> = ops.iter().filter_map(|op| op.second()).collect();
+    assert_eq!(first, (0..a.len()).collect::<Vec<_>>());
+    assert_eq!(second, (0..b.len()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_align_tokens_with_an_empty_side() {
+    let project = project(&[&[], &["a", "b"]]);
+    let (empty, full) = (&project.segments[0].tokens, &project.segments[1].tokens);
+
+    assert_eq!(
+        align_tokens(empty, full),
+        vec![DiffOp::Insert(0), DiffOp::Insert(1)]
+    );
+    assert_eq!(
+        align_tokens(full, empty),
+        vec![DiffOp::Delete(0), DiffOp::Delete(1)]
+    );
+    assert!(align_tokens(empty, empty).is_empty());
+}
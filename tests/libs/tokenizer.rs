@@ -0,0 +1,93 @@
+use tdector::libs::Token;
+use tdector::libs::eval::{TokenizationRule, default_cached_ast};
+use tdector::libs::text_analysis::TextProcessor;
+use tdector::libs::tokenizer::{CharTokenizer, ScriptTokenizer, Tokenizer, WhitespaceTokenizer};
+
+fn originals(tokens: &[Token]) -> Vec<&str> {
+    tokens.iter().map(|token| token.original.as_str()).collect()
+}
+
+fn tokenize(tokenizer: &dyn Tokenizer, line: &str) -> Vec<String> {
+    let tokens = tokenizer.tokenize(line).expect("line tokenizes");
+    assert!(
+        tokens
+            .iter()
+            .all(|token| token.base_word.is_none() && token.formation_rule_indices.is_empty())
+    );
+    originals(&tokens).into_iter().map(str::to_string).collect()
+}
+
+#[test]
+fn test_whitespace_tokenizer() {
+    assert_eq!(
+        tokenize(&WhitespaceTokenizer, "  the\tquick  fox\u{3000}jumps "),
+        vec!["the", "quick", "fox", "jumps"]
+    );
+    assert!(tokenize(&WhitespaceTokenizer, "   ").is_empty());
+}
+
+#[test]
+fn test_char_tokenizer() {
+    assert_eq!(
+        tokenize(&CharTokenizer, "你好 é"),
+        vec!["你", "好", " ", "é"]
+    );
+    assert!(tokenize(&CharTokenizer, "").is_empty());
+}
+
+#[test]
+fn test_script_tokenizer() {
+    let rule = TokenizationRule {
+        description: "Split on hyphens".to_string(),
+        command: r#"fn tokenize(line) { line.split("-") }"#.to_string(),
+        cached_ast: default_cached_ast(),
+    };
+    assert_eq!(
+        tokenize(&ScriptTokenizer(rule), "to-day-and"),
+        vec!["to", "day", "and"]
+    );
+}
+
+#[test]
+fn test_script_tokenizer_reports_script_errors() {
+    let rule = TokenizationRule {
+        description: "Broken".to_string(),
+        command: "fn tokenize(line) {".to_string(),
+        cached_ast: default_cached_ast(),
+    };
+    assert!(ScriptTokenizer(rule).tokenize("text").is_err());
+}
+
+#[test]
+fn test_builtin_tokenizers_match_their_rhai_scripts() {
+    let line = "the cat, 你好";
+    let whitespace = TokenizationRule::default_whitespace()
+        .tokenize(line)
+        .expect("script tokenizes");
+    let character = TokenizationRule::default_character()
+        .tokenize(line)
+        .expect("script tokenizes");
+
+    assert_eq!(tokenize(&WhitespaceTokenizer, line), whitespace);
+    assert_eq!(tokenize(&CharTokenizer, line), character);
+}
+
+/// A tokenizer defined outside the library plugs into segmentation unchanged
+struct FirstWordTokenizer;
+
+impl Tokenizer for FirstWordTokenizer {
+    fn tokenize(&self, line: &str) -> tdector::enums::AppResult<Vec<Token>> {
+        WhitespaceTokenizer
+            .tokenize(line)
+            .map(|tokens| tokens.into_iter().take(1).collect())
+    }
+}
+
+#[test]
+fn test_custom_tokenizer_drives_segmentation() {
+    let segments =
+        TextProcessor::segment_with_tokenizer("one two\n\nthree four", &FirstWordTokenizer)
+            .expect("text segments");
+    let words: Vec<Vec<&str>> = segments.iter().map(|s| originals(&s.tokens)).collect();
+    assert_eq!(words, vec![vec!["one"], vec!["three"]]);
+}
@@ -1 +1,5 @@
+mod common;
+mod enums;
+mod io;
 mod libs;
+mod ui;
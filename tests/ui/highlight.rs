@@ -0,0 +1,101 @@
+use eframe::egui;
+use tdector::consts::colors::HIGHLIGHT_BG;
+use tdector::libs::filtering::FilterOperation;
+use tdector::ui::highlight::{create_highlighted_layout, toggle_highlight_word};
+
+#[test]
+fn test_click_sets_highlight() {
+    let mut current = None;
+    toggle_highlight_word(&mut current, "word");
+    assert_eq!(current.as_deref(), Some("word"));
+}
+
+#[test]
+fn test_second_click_clears_highlight() {
+    let mut current = None;
+    toggle_highlight_word(&mut current, "word");
+    toggle_highlight_word(&mut current, "word");
+    assert_eq!(current, None);
+}
+
+#[test]
+fn test_click_other_word_replaces_highlight() {
+    let mut current = Some("word".to_string());
+    toggle_highlight_word(&mut current, "other");
+    assert_eq!(current.as_deref(), Some("other"));
+}
+
+/// Each section of a layout job as (text, highlighted)
+fn spans(text: &str, query: Option<&str>) -> Vec<(String, bool)> {
+    let job = create_highlighted_layout(text, query, egui::FontId::default(), egui::Color32::WHITE);
+    // The job must reproduce the text exactly, or edit cursors drift
+    assert_eq!(job.text, text);
+    job.sections
+        .iter()
+        .map(|section| {
+            (
+                job.text[section.byte_range.start.0..section.byte_range.end.0].to_string(),
+                section.format.background == HIGHLIGHT_BG,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_translation_term_is_highlighted_ignoring_case() {
+    assert_eq!(
+        spans("The cat saw another Cat.", Some("cat")),
+        vec![
+            ("The ".to_string(), false),
+            ("cat".to_string(), true),
+            (" saw another ".to_string(), false),
+            ("Cat".to_string(), true),
+            (".".to_string(), false),
+        ]
+    );
+}
+
+#[test]
+fn test_no_query_is_a_single_plain_span() {
+    assert_eq!(
+        spans("plain text", None),
+        vec![("plain text".to_string(), false)]
+    );
+    assert_eq!(
+        spans("plain text", Some("")),
+        vec![("plain text".to_string(), false)]
+    );
+}
+
+#[test]
+fn test_highlight_spans_stay_on_char_boundaries() {
+    assert_eq!(
+        spans("ÉCOLE école", Some("éco")),
+        vec![
+            ("ÉCO".to_string(), true),
+            ("LE ".to_string(), false),
+            ("éco".to_string(), true),
+            ("le".to_string(), false),
+        ]
+    );
+    // "İ" lowercases to two chars, which used to shift every later span
+    assert_eq!(
+        spans("İi straße", Some("STRASSE")),
+        vec![("İi straße".to_string(), false)]
+    );
+    // Adjacent matches share a format, so the layout job merges them into one section
+    assert_eq!(
+        spans("İİ ii", Some("i")),
+        vec![("İİ ".to_string(), false), ("ii".to_string(), true)]
+    );
+}
+
+#[test]
+fn test_highlight_term_skips_tag_filters() {
+    assert_eq!(
+        FilterOperation::text_term("tag:draft cat").as_deref(),
+        Some("cat")
+    );
+    assert_eq!(FilterOperation::text_term("tag:draft"), None);
+    assert_eq!(FilterOperation::text_term("").as_deref(), None);
+}
@@ -0,0 +1,9 @@
+mod highlight;
+mod pagination;
+mod panels;
+mod segment;
+mod states;
+mod status_bar;
+mod sticky_menu;
+#[cfg(not(target_arch = "wasm32"))]
+mod window;
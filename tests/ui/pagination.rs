@@ -0,0 +1,78 @@
+use tdector::enums::PageSizePreset;
+use tdector::ui::{clamp_page, clamp_page_size, fit_page, page_slice, total_pages};
+
+#[test]
+fn test_clamp_page_keeps_valid_page() {
+    assert_eq!(clamp_page(1, 25, 10), 1);
+    assert_eq!(clamp_page(2, 25, 10), 2);
+}
+
+#[test]
+fn test_clamp_page_after_last_page_emptied() {
+    // Deleting the only segment on page 3 leaves 20 items in two pages
+    assert_eq!(clamp_page(2, 20, 10), 1);
+}
+
+#[test]
+fn test_clamp_page_with_no_items() {
+    assert_eq!(clamp_page(3, 0, 10), 0);
+}
+
+#[test]
+fn test_page_slice_middle_and_last_pages() {
+    let items: Vec<usize> = (0..23).collect();
+    assert_eq!(page_slice(&items, 0, 10), &items[0..10]);
+    assert_eq!(page_slice(&items, 1, 10), &items[10..20]);
+    assert_eq!(page_slice(&items, 2, 10), &items[20..23]);
+}
+
+#[test]
+fn test_page_slice_clamps_past_end() {
+    let items = [1, 2, 3];
+    assert_eq!(page_slice(&items, 5, 2), &[3]);
+    assert!(page_slice::<u8>(&[], 3, 10).is_empty());
+}
+
+#[test]
+fn test_page_size_one() {
+    let items = [1, 2, 3];
+    assert_eq!(total_pages(3, 1), 3);
+    assert_eq!(clamp_page(7, 3, 1), 2);
+    assert_eq!(page_slice(&items, 1, 1), &[2]);
+    assert_eq!(page_slice(&items, 7, 1), &[3]);
+}
+
+#[test]
+fn test_zero_page_size_is_clamped() {
+    let stored = 0;
+    let page_size = clamp_page_size(stored);
+    assert_eq!(page_size, 1);
+
+    let items = [1, 2, 3];
+    assert_eq!(total_pages(3, stored), 3);
+    assert_eq!(total_pages(0, stored), 0);
+    assert_eq!(clamp_page(5, 3, stored), 2);
+    assert_eq!(page_slice(&items, 0, stored), &[1]);
+    assert_eq!(page_slice(&items, 0, page_size), &[1]);
+}
+
+#[test]
+fn test_fit_page_after_filter_shrinks_results() {
+    // On page 5 of 100 segments, a filter leaves only 3
+    let (page_size, page) = fit_page(4, 3, PageSizePreset::Small);
+    assert_eq!((page_size, page), (10, 0));
+    let items = [7, 8, 9];
+    assert_eq!(page_slice(&items, page, page_size), &items);
+
+    // Shrinking to 26 segments at 10 per page lands on the last page
+    assert_eq!(fit_page(4, 26, PageSizePreset::Small), (10, 2));
+    // A page still in range is kept
+    assert_eq!(fit_page(1, 26, PageSizePreset::Small), (10, 1));
+}
+
+#[test]
+fn test_fit_page_with_empty_result() {
+    assert_eq!(fit_page(4, 0, PageSizePreset::Small), (10, 0));
+    assert_eq!(fit_page(4, 0, PageSizePreset::All), (1, 0));
+    assert_eq!(fit_page(4, 3, PageSizePreset::All), (3, 0));
+}
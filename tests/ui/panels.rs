@@ -0,0 +1,62 @@
+use tdector::ui::panels::{FilterKeyAction, filter_key_action, row_offsets, visible_rows};
+
+#[test]
+fn test_focus_shortcut_focuses_idle_filter_box() {
+    assert_eq!(
+        filter_key_action(true, false, false, false),
+        Some(FilterKeyAction::Focus)
+    );
+}
+
+#[test]
+fn test_focus_shortcut_ignored_while_editing_another_field() {
+    assert_eq!(filter_key_action(true, false, false, true), None);
+}
+
+#[test]
+fn test_focus_shortcut_ignored_when_filter_already_focused() {
+    assert_eq!(filter_key_action(true, false, true, false), None);
+}
+
+#[test]
+fn test_escape_clears_only_a_focused_filter() {
+    assert_eq!(
+        filter_key_action(false, true, true, false),
+        Some(FilterKeyAction::Clear)
+    );
+    assert_eq!(filter_key_action(false, true, false, false), None);
+    assert_eq!(filter_key_action(false, true, false, true), None);
+}
+
+#[test]
+fn test_no_keys_no_action() {
+    assert_eq!(filter_key_action(false, false, true, false), None);
+    assert_eq!(filter_key_action(false, false, false, false), None);
+}
+
+#[test]
+fn test_row_offsets_accumulate_heights_and_spacing() {
+    assert_eq!(
+        row_offsets([10.0, 30.0, 20.0], 5.0),
+        vec![0.0, 15.0, 50.0, 75.0]
+    );
+    assert_eq!(row_offsets([], 5.0), vec![0.0]);
+}
+
+#[test]
+fn test_visible_rows_follow_uneven_heights() {
+    let offsets = row_offsets([10.0, 100.0, 10.0, 10.0], 0.0);
+
+    assert_eq!(visible_rows(&offsets, 0.0, 20.0), 0..2);
+    assert_eq!(visible_rows(&offsets, 10.0, 110.0), 1..2);
+    assert_eq!(visible_rows(&offsets, 50.0, 115.0), 1..3);
+    assert_eq!(visible_rows(&offsets, 125.0, 500.0), 3..4);
+}
+
+#[test]
+fn test_visible_rows_outside_content() {
+    let offsets = row_offsets([10.0, 10.0], 0.0);
+
+    assert_eq!(visible_rows(&offsets, 100.0, 200.0), 2..2);
+    assert_eq!(visible_rows(&row_offsets([], 0.0), 0.0, 100.0), 0..0);
+}
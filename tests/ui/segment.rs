@@ -0,0 +1,116 @@
+use tdector::consts::ui::{
+    GLOSS_BOX_EXTRA_WIDTH, GLOSS_BOX_MAX_WIDTH, GLOSS_BOX_MIN_WIDTH, TOKEN_FONT_SIZE,
+};
+use tdector::enums::FormationType;
+use tdector::libs::Token;
+use tdector::libs::eval::FormationRule;
+use tdector::ui::CollapsedSegments;
+use tdector::ui::segment::{rule_type_badge, segment_scroll_id, token_column_width};
+
+use crate::common::{self, formation_rule};
+
+#[test]
+fn test_segment_scroll_id_is_stable_per_segment() {
+    // The id depends only on the project index, so a segment keeps its scroll
+    // offset whichever page or row it is drawn on
+    assert_eq!(segment_scroll_id(7), segment_scroll_id(7));
+}
+
+#[test]
+fn test_segment_scroll_id_differs_between_segments() {
+    assert_ne!(segment_scroll_id(0), segment_scroll_id(1));
+    assert_ne!(segment_scroll_id(1), segment_scroll_id(10));
+}
+
+#[test]
+fn test_token_column_width_caps_paragraph_long_token() {
+    let token = "lorem ipsum dolor sit amet ".repeat(400);
+    // Generous estimate of the laid-out width: half an em per character
+    let measured = token.chars().count() as f32 * TOKEN_FONT_SIZE * 0.5;
+
+    assert_eq!(token_column_width(measured, 0.0), GLOSS_BOX_MAX_WIDTH);
+    assert_eq!(token_column_width(0.0, measured), GLOSS_BOX_MAX_WIDTH);
+    assert_eq!(token_column_width(f32::INFINITY, 12.0), GLOSS_BOX_MAX_WIDTH);
+}
+
+#[test]
+fn test_token_column_width_for_ordinary_tokens() {
+    assert_eq!(token_column_width(0.0, 0.0), GLOSS_BOX_MIN_WIDTH);
+    assert_eq!(token_column_width(60.0, 35.0), 60.0 + GLOSS_BOX_EXTRA_WIDTH);
+    assert_eq!(token_column_width(35.0, 80.0), 80.0 + GLOSS_BOX_EXTRA_WIDTH);
+}
+
+#[test]
+fn test_collapsed_segments_toggle() {
+    let mut collapsed = CollapsedSegments::default();
+    assert!(collapsed.is_empty());
+
+    collapsed.toggle(2);
+    assert!(collapsed.is_collapsed(2));
+    assert!(!collapsed.is_collapsed(1));
+
+    collapsed.toggle(2);
+    assert!(!collapsed.is_collapsed(2));
+    assert!(collapsed.is_empty());
+}
+
+#[test]
+fn test_collapsed_segments_collapse_and_expand_all() {
+    let mut collapsed = CollapsedSegments::default();
+    collapsed.collapse_all(3);
+    assert!((0..3).all(|idx| collapsed.is_collapsed(idx)));
+    assert!(!collapsed.is_collapsed(3));
+
+    collapsed.toggle(1);
+    assert!(!collapsed.is_collapsed(1));
+
+    collapsed.expand_all();
+    assert!(collapsed.is_empty());
+}
+
+#[test]
+fn test_collapsed_segments_follow_removed_segment() {
+    let mut collapsed = CollapsedSegments::default();
+    for idx in [0, 2, 3, 5] {
+        collapsed.toggle(idx);
+    }
+
+    collapsed.segment_removed(2);
+
+    let folded: Vec<usize> = (0..6).filter(|&idx| collapsed.is_collapsed(idx)).collect();
+    assert_eq!(folded, [0, 2, 4]);
+}
+
+fn rule(rule_type: FormationType) -> FormationRule {
+    formation_rule(
+        &format!("{rule_type:?}"),
+        rule_type,
+        "fn transform(word) { word }",
+    )
+}
+
+fn derived(rule_indices: Vec<usize>) -> Token {
+    common::derived("kaasen", "kaa", &rule_indices)
+}
+
+#[test]
+fn test_rule_type_badge_uses_final_rule_in_chain() {
+    let rules = [
+        rule(FormationType::Derivation),
+        rule(FormationType::Inflection),
+        rule(FormationType::Nonmorphological),
+    ];
+
+    assert_eq!(rule_type_badge(&derived(vec![0]), &rules), Some("[D]"));
+    assert_eq!(rule_type_badge(&derived(vec![0, 1]), &rules), Some("[I]"));
+    assert_eq!(rule_type_badge(&derived(vec![1, 2]), &rules), Some("[N]"));
+    assert_eq!(rule_type_badge(&derived(vec![2, 0]), &rules), Some("[D]"));
+}
+
+#[test]
+fn test_rule_type_badge_absent_for_underived_or_unknown_rule() {
+    let rules = [rule(FormationType::Inflection)];
+
+    assert_eq!(rule_type_badge(&derived(Vec::new()), &rules), None);
+    assert_eq!(rule_type_badge(&derived(vec![0, 5]), &rules), None);
+}
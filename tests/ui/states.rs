@@ -0,0 +1,60 @@
+use eframe::egui;
+use tdector::enums::AppAction;
+use tdector::libs::Segment;
+use tdector::ui::DecryptionApp;
+
+use crate::common::token;
+
+fn edited_app(ctx: &egui::Context) -> DecryptionApp {
+    let mut app = DecryptionApp::default();
+    let project = app.project_mut();
+    project.project_name = "draft".to_string();
+    project.segments.push(Segment {
+        tokens: vec![token("kaa")],
+        translation: "water".to_string(),
+        ..Default::default()
+    });
+    project
+        .vocabulary
+        .insert("kaa".to_string(), "water".to_string());
+    app.update_dirty_status(true, ctx);
+    app
+}
+
+#[test]
+fn test_new_asks_before_discarding_unsaved_changes() {
+    let ctx = egui::Context::default();
+    let mut app = edited_app(&ctx);
+
+    app.trigger_action(AppAction::New, &ctx);
+
+    assert_eq!(app.pending_confirmation(), Some(AppAction::New));
+    assert!(app.is_dirty());
+    assert_eq!(app.project().segments.len(), 1);
+    assert_eq!(app.project().project_name, "draft");
+}
+
+#[test]
+fn test_new_resets_project_once_confirmed() {
+    let ctx = egui::Context::default();
+    let mut app = edited_app(&ctx);
+
+    app.execute_action(AppAction::New, &ctx);
+
+    assert!(!app.is_dirty());
+    assert!(app.project().segments.is_empty());
+    assert!(app.project().vocabulary.is_empty());
+    assert!(app.project().project_name.is_empty());
+}
+
+#[test]
+fn test_new_runs_immediately_without_unsaved_changes() {
+    let ctx = egui::Context::default();
+    let mut app = edited_app(&ctx);
+    app.update_dirty_status(false, &ctx);
+
+    app.trigger_action(AppAction::New, &ctx);
+
+    assert_eq!(app.pending_confirmation(), None);
+    assert!(app.project().segments.is_empty());
+}
@@ -0,0 +1,30 @@
+use tdector::enums::{SortDirection, SortField, SortMode};
+use tdector::ui::status_summary;
+
+#[test]
+fn test_status_summary_without_filter() {
+    assert_eq!(
+        status_summary(10, 10, SortMode::DEFAULT, ""),
+        "Showing 10 of 10 segments · Sort: Index (Asc)"
+    );
+}
+
+#[test]
+fn test_status_summary_with_filter_and_sort() {
+    let sort_mode = SortMode {
+        field: SortField::Length,
+        direction: SortDirection::Descending,
+    };
+    assert_eq!(
+        status_summary(3, 42, sort_mode, " tag:draft ka "),
+        "Showing 3 of 42 segments · Sort: Length (Longest First) · Filter: \"tag:draft ka\""
+    );
+}
+
+#[test]
+fn test_status_summary_single_segment() {
+    assert_eq!(
+        status_summary(0, 1, SortMode::DEFAULT, "zz"),
+        "Showing 0 of 1 segment · Sort: Index (Asc) · Filter: \"zz\""
+    );
+}
@@ -0,0 +1,45 @@
+use tdector::ui::sticky_menu::{MenuEvent, StickyMenu};
+
+#[test]
+fn test_unpinned_menu_closes_after_action() {
+    let mut menu = StickyMenu::default();
+    assert!(!menu.handle(MenuEvent::Action));
+}
+
+#[test]
+fn test_unpinned_menu_closes_on_outside_click() {
+    let mut menu = StickyMenu::default();
+    assert!(!menu.handle(MenuEvent::ClickedOutside));
+}
+
+#[test]
+fn test_pinned_menu_survives_actions_until_dismissed() {
+    let mut menu = StickyMenu::default();
+    assert!(menu.handle(MenuEvent::TogglePin));
+    assert!(menu.pinned);
+
+    assert!(menu.handle(MenuEvent::Action));
+    assert!(menu.handle(MenuEvent::Action));
+
+    assert!(!menu.handle(MenuEvent::Dismiss));
+    assert!(!menu.pinned);
+}
+
+#[test]
+fn test_unpinning_restores_closing_behavior() {
+    let mut menu = StickyMenu::default();
+    assert!(menu.handle(MenuEvent::TogglePin));
+    assert!(menu.handle(MenuEvent::TogglePin));
+    assert!(!menu.pinned);
+    assert!(!menu.handle(MenuEvent::Action));
+}
+
+#[test]
+fn test_pinned_menu_closes_on_outside_click() {
+    let mut menu = StickyMenu::default();
+    assert!(menu.handle(MenuEvent::TogglePin));
+    assert!(menu.handle(MenuEvent::Action));
+
+    assert!(!menu.handle(MenuEvent::ClickedOutside));
+    assert!(!menu.pinned);
+}
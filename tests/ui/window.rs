@@ -0,0 +1,90 @@
+use eframe::egui;
+use tdector::consts::ui::{MIN_WINDOW_HEIGHT, MIN_WINDOW_WIDTH, WINDOW_HEIGHT, WINDOW_WIDTH};
+use tdector::ui::window::WindowGeometry;
+
+#[test]
+fn test_geometry_round_trips_through_json() {
+    let geometry = WindowGeometry {
+        inner_size: [1280.0, 900.0],
+        position: Some([-1800.0, 40.0]),
+        monitor_size: Some([1920.0, 1080.0]),
+    };
+    let json = serde_json::to_string(&geometry).expect("geometry serializes");
+    let loaded: WindowGeometry = serde_json::from_str(&json).expect("geometry parses");
+    assert_eq!(loaded, geometry);
+
+    let size_only: WindowGeometry =
+        serde_json::from_str(r#"{"inner_size":[800.0,600.0]}"#).expect("geometry parses");
+    assert_eq!(size_only.position, None);
+    assert_eq!(size_only.monitor_size, None);
+}
+
+#[test]
+fn test_clamped_raises_small_sizes_and_keeps_sane_positions() {
+    let geometry = WindowGeometry {
+        inner_size: [100.0, 2000.0],
+        position: Some([-1800.0, 40.0]),
+        monitor_size: None,
+    }
+    .clamped();
+    assert_eq!(geometry.inner_size, [MIN_WINDOW_WIDTH, 2000.0]);
+    assert_eq!(geometry.position, Some([-1800.0, 40.0]));
+}
+
+#[test]
+fn test_clamped_falls_back_on_unusable_values() {
+    let geometry = WindowGeometry {
+        inner_size: [f32::NAN, 500.0],
+        position: Some([1.0e9, 0.0]),
+        monitor_size: None,
+    }
+    .clamped();
+    assert_eq!(geometry.inner_size, [WINDOW_WIDTH, WINDOW_HEIGHT]);
+    assert_eq!(geometry.position, None);
+
+    let infinite = WindowGeometry {
+        inner_size: [640.0, 0.0],
+        position: Some([f32::INFINITY, 0.0]),
+        monitor_size: None,
+    }
+    .clamped();
+    assert_eq!(infinite.inner_size, [640.0, MIN_WINDOW_HEIGHT]);
+    assert_eq!(infinite.position, None);
+}
+
+#[test]
+fn test_monitor_changed_only_with_a_recorded_monitor() {
+    let mut geometry = WindowGeometry::default();
+    assert!(!geometry.monitor_changed(egui::vec2(1920.0, 1080.0)));
+
+    geometry.monitor_size = Some([2560.0, 1440.0]);
+    assert!(geometry.monitor_changed(egui::vec2(1920.0, 1080.0)));
+    assert!(!geometry.monitor_changed(egui::vec2(2560.0, 1440.0)));
+}
+
+#[test]
+fn test_from_viewport_skips_unknown_and_maximized_windows() {
+    let mut info = egui::ViewportInfo::default();
+    assert_eq!(WindowGeometry::from_viewport(&info), None);
+
+    info.inner_rect = Some(egui::Rect::from_min_size(
+        egui::pos2(10.0, 40.0),
+        egui::vec2(900.0, 700.0),
+    ));
+    info.outer_rect = Some(egui::Rect::from_min_size(
+        egui::pos2(10.0, 10.0),
+        egui::vec2(900.0, 730.0),
+    ));
+    info.monitor_size = Some(egui::vec2(1920.0, 1080.0));
+    assert_eq!(
+        WindowGeometry::from_viewport(&info),
+        Some(WindowGeometry {
+            inner_size: [900.0, 700.0],
+            position: Some([10.0, 10.0]),
+            monitor_size: Some([1920.0, 1080.0]),
+        })
+    );
+
+    info.maximized = Some(true);
+    assert_eq!(WindowGeometry::from_viewport(&info), None);
+}